@@ -24,28 +24,15 @@ android {
         applicationId = "com.ultraelectronica.flick"
         // You can update the following values to match your application needs.
         // For more information, see: https://flutter.dev/to/review-gradle-config.
-        minSdk = flutter.minSdkVersion
+        // The Rust audio engine outputs through AAudio, available from API 26.
+        minSdk = maxOf(flutter.minSdkVersion, 26)
         targetSdk = flutter.targetSdkVersion
         versionCode = flutter.versionCode
         versionName = flutter.versionName
-        
-        // Required for cpal/oboe C++ runtime
+
         ndk {
             abiFilters += listOf("arm64-v8a", "armeabi-v7a", "x86_64", "x86")
         }
-        
-        externalNativeBuild {
-            cmake {
-                arguments += "-DANDROID_STL=c++_shared"
-            }
-        }
-    }
-    
-    // Pack the C++ shared library
-    packagingOptions {
-        jniLibs {
-            useLegacyPackaging = true
-        }
     }
 
     buildTypes {
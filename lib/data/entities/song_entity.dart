@@ -48,7 +48,7 @@ class SongEntity {
   /// File type (e.g., mp3, flac, wav)
   String? fileType;
 
-  /// Bitrate in kbps
+  /// Bitrate in bits per second
   int? bitrate;
 
   /// Sample rate in Hz
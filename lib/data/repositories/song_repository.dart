@@ -1,7 +1,10 @@
+import 'dart:io';
+
 import 'package:isar_community/isar.dart';
 
 import '../database.dart';
 import '../../models/song.dart';
+import '../../src/rust/api/library.dart';
 
 /// Repository for song CRUD operations.
 class SongRepository {
@@ -34,7 +37,26 @@ class SongRepository {
   }
 
   /// Search songs by title, artist, or album.
+  ///
+  /// On desktop the Rust library ranks the matches and tolerates typos and
+  /// missing accents. Until it is opened, and on Android, songs are filtered
+  /// by substring.
   Future<List<Song>> searchSongs(String query) async {
+    if (!Platform.isAndroid) {
+      try {
+        final found = await librarySearch(query: query, limit: 500);
+        final entities = await _isar.songEntitys.getAllByFilePath([
+          for (final song in found) song.metadata.path,
+        ]);
+        return [
+          for (final entity in entities)
+            if (entity != null) _entityToSong(entity),
+        ];
+      } catch (_) {
+        // The library has not been opened yet
+      }
+    }
+
     final lowerQuery = query.toLowerCase();
     final entities = await _isar.songEntitys
         .filter()
@@ -106,6 +128,11 @@ class SongRepository {
     return await _isar.songEntitys.where().findAll();
   }
 
+  /// Get the song entity for a file path, if it is in the library.
+  Future<SongEntity?> getSongEntityByPath(String path) async {
+    return await _isar.songEntitys.filter().filePathEqualTo(path).findFirst();
+  }
+
   /// Delete songs by their file paths.
   Future<void> deleteSongsByPath(List<String> paths) async {
     await _isar.writeTxn(() async {
@@ -123,6 +150,39 @@ class SongRepository {
     });
   }
 
+  /// Delete songs at or below the given file or folder paths.
+  Future<void> deleteSongsUnderPaths(List<String> paths) async {
+    await _isar.writeTxn(() async {
+      for (final path in paths) {
+        await _isar.songEntitys
+            .filter()
+            .filePathEqualTo(path)
+            .or()
+            .filePathStartsWith('$path${Platform.pathSeparator}')
+            .deleteAll();
+      }
+    });
+  }
+
+  /// Point songs at the paths their files were moved to, keeping their ids
+  /// and with them play counts, favorites and playlist entries.
+  Future<void> moveSongs(Map<String, String> newPaths, String folderUri) async {
+    await _isar.writeTxn(() async {
+      for (final move in newPaths.entries) {
+        final song = await _isar.songEntitys
+            .filter()
+            .filePathEqualTo(move.key)
+            .findFirst();
+        if (song == null) continue;
+
+        song
+          ..filePath = move.value
+          ..folderUri = folderUri;
+        await _isar.songEntitys.put(song);
+      }
+    });
+  }
+
   /// Count songs in a folder.
   Future<int> countSongsInFolder(String folderUri) async {
     return await _isar.songEntitys.filter().folderUriEqualTo(folderUri).count();
@@ -136,7 +196,32 @@ class SongRepository {
   }
 
   /// Get all unique albums with their songs.
+  ///
+  /// On desktop the Rust library groups them, merging spellings of the album
+  /// artist, the discs of an album and the songs of a compilation. Albums
+  /// sharing a title are told apart by their artist.
   Future<Map<String, List<Song>>> getSongsByAlbum() async {
+    if (!Platform.isAndroid) {
+      try {
+        final albums = await libraryAlbums(query: libraryDefaultGroupQuery());
+        final titles = <String, int>{};
+        for (final album in albums.albums) {
+          titles.update(album.name, (count) => count + 1, ifAbsent: () => 1);
+        }
+        return await _libraryGroups(
+          {
+            for (final album in albums.albums)
+              album.key: titles[album.name]! > 1 && album.artist != null
+                  ? '${album.name} (${album.artist})'
+                  : album.name,
+          },
+          (song) => [if (song.albumKey != null) song.albumKey!],
+        );
+      } catch (_) {
+        // The library has not been opened yet
+      }
+    }
+
     final songs = await getAllSongs();
     final albumMap = <String, List<Song>>{};
     for (final song in songs) {
@@ -147,7 +232,22 @@ class SongRepository {
   }
 
   /// Get all unique artists with their songs.
+  ///
+  /// On desktop the Rust library groups them, splitting songs that credit
+  /// several artists and merging spellings like "Beatles, The".
   Future<Map<String, List<Song>>> getSongsByArtist() async {
+    if (!Platform.isAndroid) {
+      try {
+        final artists = await libraryArtists(query: libraryDefaultGroupQuery());
+        return await _libraryGroups(
+          {for (final artist in artists.groups) artist.key: artist.name},
+          (song) => song.artistKeys,
+        );
+      } catch (_) {
+        // The library has not been opened yet
+      }
+    }
+
     final songs = await getAllSongs();
     final artistMap = <String, List<Song>>{};
     for (final song in songs) {
@@ -156,6 +256,35 @@ class SongRepository {
     return artistMap;
   }
 
+  /// Songs of the library's groups by name, in the order of `names`, which
+  /// maps group keys to names. `keysOf` gives the groups a song is in.
+  Future<Map<String, List<Song>>> _libraryGroups(
+    Map<String, String> names,
+    Iterable<String> Function(LibrarySong song) keysOf,
+  ) async {
+    final page = await librarySongs(
+      query: const SongQuery(
+        sort: SongSortType.album,
+        descending: false,
+        offset: 0,
+      ),
+    );
+    final entities = await _isar.songEntitys.getAllByFilePath([
+      for (final song in page.songs) song.metadata.path,
+    ]);
+    final groups = {for (final name in names.values) name: <Song>[]};
+    for (var i = 0; i < page.songs.length; i++) {
+      final entity = entities[i];
+      if (entity == null) continue;
+      final song = _entityToSong(entity);
+      for (final key in keysOf(page.songs[i])) {
+        groups[names[key]]?.add(song);
+      }
+    }
+    groups.removeWhere((_, songs) => songs.isEmpty);
+    return groups;
+  }
+
   /// Get unique folder URIs from songs.
   Future<List<String>> getUniqueFolderUris() async {
     final entities = await _isar.songEntitys.where().findAll();
@@ -1,9 +1,12 @@
+import 'dart:async';
+
 import 'package:flutter/material.dart';
 import 'package:flutter_displaymode/flutter_displaymode.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:flick/src/rust/frb_generated.dart';
 import 'package:flick/app/app.dart';
 import 'package:flick/data/database.dart';
+import 'package:flick/services/library_scanner_service.dart';
 import 'package:flick/services/permission_service.dart';
 import 'package:flick/services/player_service.dart';
 
@@ -28,6 +31,9 @@ Future<void> main() async {
   // Restore last played song state
   await _restoreLastPlayedSong();
 
+  // Pick up files copied into music folders while the app runs (desktop)
+  unawaited(LibraryScannerService().watchFolders());
+
   runApp(const ProviderScope(child: FlickPlayerApp()));
 }
 
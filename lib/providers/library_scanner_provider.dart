@@ -181,6 +181,7 @@ class MusicFoldersNotifier extends AsyncNotifier<List<FolderEntity>> {
   Future<void> addFolder(FolderEntity folder) async {
     final repository = ref.read(folderRepositoryProvider);
     await repository.upsertFolder(folder);
+    unawaited(ref.read(libraryScannerServiceProvider).watchFolders());
     ref.invalidateSelf();
   }
 
@@ -188,6 +189,7 @@ class MusicFoldersNotifier extends AsyncNotifier<List<FolderEntity>> {
   Future<void> removeFolder(String folderUri) async {
     final repository = ref.read(folderRepositoryProvider);
     await repository.deleteFolder(folderUri);
+    unawaited(ref.read(libraryScannerServiceProvider).watchFolders());
     ref.invalidateSelf();
   }
 }
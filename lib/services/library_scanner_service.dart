@@ -1,11 +1,13 @@
 import 'dart:async';
 import 'dart:io';
 import 'package:flutter/foundation.dart';
+import 'package:path_provider/path_provider.dart';
 import '../data/database.dart';
 import '../data/entities/song_entity.dart';
 import '../data/repositories/song_repository.dart';
 import '../data/repositories/folder_repository.dart';
 import '../services/music_folder_service.dart';
+import '../src/rust/api/library.dart';
 import '../src/rust/api/scanner.dart'; // Rust bridge
 
 /// Progress update during library scanning.
@@ -16,12 +18,16 @@ class ScanProgress {
   final String? currentFolder;
   final bool isComplete;
 
+  /// Audio files that could not be read so far
+  final int failedFiles;
+
   ScanProgress({
     required this.songsFound,
     required this.totalFiles,
     this.currentFile,
     this.currentFolder,
     this.isComplete = false,
+    this.failedFiles = 0,
   });
 }
 
@@ -32,6 +38,14 @@ class LibraryScannerService {
   final MusicFolderService _musicFolderService;
 
   bool _isCancelled = false;
+  final List<ScanFailure> _problemFiles = [];
+
+  /// The Rust library database, opened on first use by desktop scans.
+  static Future<void>? _libraryOpened;
+
+  /// Extensions and content sniffing for desktop scans; `null` uses
+  /// [scanDefaultOptions] with move tracking.
+  ScanOptions? scanOptions;
 
   LibraryScannerService({
     SongRepository? songRepository,
@@ -41,8 +55,15 @@ class LibraryScannerService {
        _folderRepository = folderRepository ?? FolderRepository(),
        _musicFolderService = musicFolderService ?? MusicFolderService();
 
+  /// Files the last scan found but could not read, for the problem files
+  /// report.
+  List<ScanFailure> get problemFiles => List.unmodifiable(_problemFiles);
+
   void cancelScan() {
     _isCancelled = true;
+    if (!Platform.isAndroid) {
+      scanCancel();
+    }
   }
 
   /// Scan a single folder using appropriate method for platform.
@@ -237,6 +258,7 @@ class LibraryScannerService {
     String displayName,
   ) async* {
     _isCancelled = false;
+    _problemFiles.clear();
 
     yield ScanProgress(
       songsFound: 0,
@@ -245,92 +267,79 @@ class LibraryScannerService {
       isComplete: false,
     );
 
-    // 1. Fetch existing file state from DB
-    final existingSongs = await _songRepository.getAllSongEntities();
-    final knownFiles = <String, int>{};
-    final existingMap = <String, SongEntity>{};
-
-    for (var song in existingSongs) {
-      existingMap[song.filePath] = song;
-      // Only consider file "known" (up to date) if it has new metadata fields.
-      // Otherwise, exclude it so Rust scanner treats it as new and extracts metadata.
-      final hasMetadata = song.albumArtPath != null || song.bitrate != null;
-
-      if (song.lastModified != null && hasMetadata) {
-        // Rust expects seconds for comparison usually, or matches implementation
-        // ScanResult sends back lastModified in seconds usually, let's check.
-        // Rust side: `known_files.get(&path_str)` ... `modified > known_timestamp`
-        // Dart side sends ms/1000.
-        knownFiles[song.filePath] =
-            song.lastModified!.millisecondsSinceEpoch ~/ 1000;
-      }
-    }
+    // 1. The Rust library knows which files are up to date; Isar entries
+    // are only needed to keep song ids
+    await _openLibrary();
+    final existingMap = {
+      for (final song in await _songRepository.getAllSongEntities())
+        song.filePath: song,
+    };
+    final firstScan = !(await libraryFolders()).any(
+      (folder) => folder.path == folderUri && folder.scannedAt != null,
+    );
 
-    // 2. Call Rust Scanner
-    final result = await scanRootDir(
+    // 2. Stream batches from the Rust scanner as files are read
+    final supportDir = await getApplicationSupportDirectory();
+    final events = libraryScanFolder(
       rootPath: folderUri,
-      knownFiles: knownFiles,
+      artCacheDir: '${supportDir.path}/album_art',
+      options:
+          scanOptions ??
+          _trackingMoves(
+            scanDefaultOptions(),
+            '${supportDir.path}/scan_fingerprints',
+          ),
     );
 
-    // 3. Process Deletions
-    if (result.deletedPaths.isNotEmpty) {
-      await _songRepository.deleteSongsByPath(result.deletedPaths);
-    }
-
-    // 4. Process New/Modified
     int processed = 0;
-    int total = result.newOrModified.length;
+    int total = 0;
 
-    // Batch insert
-    final batch = <SongEntity>[];
-    const batchSize = 100;
+    await for (final event in events) {
+      processed = event.processed;
+      total = event.discovered;
+      _problemFiles.addAll(event.failures);
 
-    for (final metadata in result.newOrModified) {
-      if (_isCancelled) break;
+      // 3. Process New/Modified
+      final batch = <SongEntity>[];
+      for (final metadata in event.files) {
+        final existing = existingMap[metadata.path];
 
-      final existing = existingMap[metadata.path];
-
-      final song = SongEntity()
-        ..filePath = metadata.path
-        ..title =
-            metadata.title ??
-            _extractTitleFromFilename(metadata.path.split('/').last)
-        ..artist = metadata.artist ?? 'Unknown Artist'
-        ..album = metadata.album
-        ..durationMs = metadata.durationSecs != null
-            ? (metadata.durationSecs! * BigInt.from(1000)).toInt()
-            : 0
-        ..fileType = metadata.format.toUpperCase()
-        ..dateAdded = existing?.dateAdded ?? DateTime.now()
-        ..lastModified = DateTime.fromMillisecondsSinceEpoch(
-          metadata.lastModified * 1000,
-        )
-        ..folderUri = folderUri;
-
-      if (existing != null) {
-        song.id = existing.id;
+        batch.add(_songFromMetadata(metadata, existing, folderUri));
       }
 
-      batch.add(song);
-      processed++;
-
-      if (batch.length >= batchSize) {
+      if (batch.isNotEmpty) {
         await _songRepository.upsertSongs(batch);
-        batch.clear();
-
-        yield ScanProgress(
-          songsFound: processed,
-          totalFiles:
-              total, // Approximate total since we only know new/modified count here + deletions
-          currentFile: song.title,
-          currentFolder: displayName,
-          isComplete: false,
+      }
+
+      // 4. Re-path moved songs, so they keep their history, and process
+      // deletions once the walk is complete
+      if (event.kind == ScanEventType.finished && event.moved.isNotEmpty) {
+        await _songRepository.moveSongs(
+          {for (final move in event.moved) move.oldPath: move.newPath},
+          folderUri,
         );
       }
-    }
+      if (event.kind == ScanEventType.finished &&
+          event.deletedPaths.isNotEmpty) {
+        await _songRepository.deleteSongsByPath(event.deletedPaths);
+      }
+
+      // The first scan into the library reports every file as new, so
+      // songs deleted before then are found by their absence
+      if (event.kind == ScanEventType.finished && firstScan) {
+        await _removeSongsMissingFromLibrary(folderUri);
+      }
+
+      if (event.kind != ScanEventType.batch) break;
 
-    if (batch.isNotEmpty) {
-      await _songRepository.upsertSongs(batch);
+      yield ScanProgress(
+        songsFound: processed,
+        totalFiles: total,
+        currentFile: batch.isNotEmpty ? batch.last.title : null,
+        currentFolder: displayName,
+        isComplete: false,
+        failedFiles: event.failed,
+      );
     }
 
     // Update folder stats
@@ -342,6 +351,7 @@ class LibraryScannerService {
       totalFiles: total,
       currentFolder: displayName,
       isComplete: true,
+      failedFiles: _problemFiles.length,
     );
   }
 
@@ -358,6 +368,207 @@ class LibraryScannerService {
     }
   }
 
+  /// Keep the library in step with the registered folders on desktop,
+  /// applying files as they are copied in, changed, moved or removed.
+  ///
+  /// Replaces any running watch and completes once it is stopped with
+  /// [stopWatching]. If changes were missed, every folder is rescanned.
+  Future<void> watchFolders() async {
+    if (Platform.isAndroid) return;
+
+    final folders = await _folderRepository.getAllFolders();
+    final roots = [for (final folder in folders) folder.uri];
+    final supportDir = await getApplicationSupportDirectory();
+
+    try {
+      await _syncLibraryFolders(roots);
+      if (roots.isEmpty) {
+        scanWatchStop();
+        return;
+      }
+      final events = libraryWatch(
+        artCacheDir: '${supportDir.path}/album_art',
+        options: scanOptions,
+      );
+      await for (final event in events) {
+        await _applyWatchEvent(event, roots);
+        if (event.rescanNeeded) {
+          await scanAllFolders().drain<void>();
+        }
+      }
+    } catch (e) {
+      debugPrint("Error watching music folders: $e");
+    }
+  }
+
+  /// Stop the watch started by [watchFolders].
+  void stopWatching() {
+    if (!Platform.isAndroid) {
+      scanWatchStop();
+    }
+  }
+
+  /// Group library songs that hold the same recording, best copy first.
+  ///
+  /// Acoustic fingerprints are cached, so only new or changed files are
+  /// decoded again. Throws where the native audio engine is unavailable.
+  Future<DuplicateReport> findDuplicateSongs() async {
+    final songs = await _songRepository.getAllSongEntities();
+    final supportDir = await getApplicationSupportDirectory();
+    return findDuplicates(
+      paths: [for (final song in songs) song.filePath],
+      fingerprintIndex: '${supportDir.path}/acoustic_fingerprints',
+    );
+  }
+
+  Future<void> _openLibrary() {
+    return _libraryOpened ??= () async {
+      final supportDir = await getApplicationSupportDirectory();
+      await libraryOpen(dbPath: '${supportDir.path}/library.db');
+    }();
+  }
+
+  /// Register [roots] with the Rust library and drop folders that were
+  /// removed, with their songs.
+  Future<void> _syncLibraryFolders(List<String> roots) async {
+    await _openLibrary();
+    final registered = {
+      for (final folder in await libraryFolders()) folder.path,
+    };
+    for (final root in roots) {
+      if (!registered.contains(root)) {
+        await libraryAddFolder(path: root);
+      }
+    }
+    for (final path in registered.difference(roots.toSet())) {
+      await libraryRemoveFolder(path: path);
+    }
+  }
+
+  /// Delete songs in [folderUri] that the Rust library does not hold.
+  Future<void> _removeSongsMissingFromLibrary(String folderUri) async {
+    final query = libraryDefaultSongQuery();
+    final page = await librarySongs(
+      query: SongQuery(
+        folder: folderUri,
+        sort: query.sort,
+        descending: query.descending,
+        offset: 0,
+      ),
+    );
+    final inLibrary = {for (final song in page.songs) song.metadata.path};
+    final songs = await _songRepository.getSongEntitiesByFolder(folderUri);
+    final missing = [
+      for (final song in songs)
+        if (!inLibrary.contains(song.filePath)) song.filePath,
+    ];
+    if (missing.isNotEmpty) {
+      await _songRepository.deleteSongsByPath(missing);
+    }
+  }
+
+  Future<void> _applyWatchEvent(WatchEvent event, List<String> roots) async {
+    _problemFiles.addAll(event.failures);
+    final touchedRoots = <String>{};
+
+    // Moves first, so the re-read files below update the moved songs
+    final movesByRoot = <String, Map<String, String>>{};
+    for (final move in event.moved) {
+      final root = _rootOf(move.newPath, roots);
+      movesByRoot.putIfAbsent(root, () => {})[move.oldPath] = move.newPath;
+    }
+    for (final entry in movesByRoot.entries) {
+      await _songRepository.moveSongs(entry.value, entry.key);
+      touchedRoots.add(entry.key);
+    }
+
+    final songs = <SongEntity>[];
+    for (final metadata in [...event.added, ...event.modified]) {
+      final root = _rootOf(metadata.path, roots);
+      final existing = await _songRepository.getSongEntityByPath(metadata.path);
+      songs.add(_songFromMetadata(metadata, existing, root));
+      touchedRoots.add(root);
+    }
+    if (songs.isNotEmpty) {
+      await _songRepository.upsertSongs(songs);
+    }
+
+    if (event.removedPaths.isNotEmpty) {
+      await _songRepository.deleteSongsUnderPaths(event.removedPaths);
+      touchedRoots.addAll(event.removedPaths.map((p) => _rootOf(p, roots)));
+    }
+
+    for (final root in touchedRoots) {
+      final count = await _songRepository.countSongsInFolder(root);
+      await _folderRepository.updateFolderScanInfo(root, count);
+    }
+  }
+
+  /// The watched folder containing [path]; the innermost if folders nest.
+  String _rootOf(String path, List<String> roots) {
+    return roots
+        .where((root) => path.startsWith(root))
+        .fold(roots.first, (a, b) => b.length > a.length ? b : a);
+  }
+
+  /// Library entry for a file read by the Rust scanner. [existing] keeps
+  /// the song's id and date added.
+  SongEntity _songFromMetadata(
+    AudioFileMetadata metadata,
+    SongEntity? existing,
+    String folderUri,
+  ) {
+    final song = SongEntity()
+      ..filePath = metadata.path
+      ..title =
+          metadata.title ??
+          _extractTitleFromFilename(metadata.path.split('/').last)
+      ..artist = metadata.artist ?? 'Unknown Artist'
+      ..album = metadata.album
+      ..albumArtist = metadata.albumArtist
+      ..trackNumber = metadata.trackNumber
+      ..discNumber = metadata.discNumber
+      ..year = metadata.year
+      ..genre = metadata.genre
+      ..durationMs = metadata.durationSecs != null
+          ? (metadata.durationSecs! * BigInt.from(1000)).toInt()
+          : 0
+      ..fileType = metadata.format.toUpperCase()
+      ..bitrate = metadata.bitrateKbps != null
+          ? metadata.bitrateKbps! * 1000
+          : null
+      ..sampleRate = metadata.sampleRate
+      ..channels = metadata.channels
+      ..bitDepth = metadata.bitDepth
+      ..albumArtPath = metadata.albumArtPath
+      ..dateAdded = existing?.dateAdded ?? DateTime.now()
+      ..lastModified = DateTime.fromMillisecondsSinceEpoch(
+        metadata.lastModified * 1000,
+      )
+      ..folderUri = folderUri;
+
+    if (existing != null) {
+      song.id = existing.id;
+    }
+    return song;
+  }
+
+  /// [options] with content fingerprints kept in [indexPath], so renamed
+  /// and moved files are recognized.
+  ScanOptions _trackingMoves(ScanOptions options, String indexPath) {
+    return ScanOptions(
+      extensions: options.extensions,
+      sniffContent: options.sniffContent,
+      excludeGlobs: options.excludeGlobs,
+      honorMarkerFiles: options.honorMarkerFiles,
+      minDurationSecs: options.minDurationSecs,
+      minFileSize: options.minFileSize,
+      maxDepth: options.maxDepth,
+      followSymlinks: options.followSymlinks,
+      fingerprintIndex: indexPath,
+    );
+  }
+
   String _extractTitleFromFilename(String filename) {
     final dotIndex = filename.lastIndexOf('.');
     String name = dotIndex > 0 ? filename.substring(0, dotIndex) : filename;
@@ -35,12 +35,22 @@ class RustAudioService {
   final ValueNotifier<double> crossfadeDurationNotifier = ValueNotifier(3.0);
   final ValueNotifier<double> playbackSpeedNotifier = ValueNotifier(1.0);
 
+  /// Line of the current track's synced lyrics to highlight, if any.
+  final ValueNotifier<int?> lyricsLineNotifier = ValueNotifier(null);
+
+  /// Chapter of the current track being played, if it has chapters.
+  final ValueNotifier<int?> chapterNotifier = ValueNotifier(null);
+
   // Event callbacks
   void Function(String path)? onTrackEnded;
   void Function(String fromPath, String toPath)? onCrossfadeStarted;
   void Function(String path)? onNextTrackReady;
   void Function(String message)? onError;
 
+  /// A long track started where it was left off, so the UI can offer to
+  /// start over.
+  void Function(String path, Duration position)? onResumed;
+
   Timer? _progressTimer;
   Timer? _eventPollTimer;
   bool _initialized = false;
@@ -98,14 +108,25 @@ class RustAudioService {
     }
   }
 
-  /// Play an audio file.
-  Future<void> play(String path) async {
+  /// Play an audio file, or the part of it from [startSecs] to [endSecs]
+  /// for a track of a CUE sheet.
+  Future<void> play(String path, {double? startSecs, double? endSecs}) async {
     if (!_initialized) {
       throw StateError('Rust audio engine not initialized');
     }
 
-    await rust_audio.audioPlay(path: path);
+    if (startSecs != null) {
+      await rust_audio.audioPlayRange(
+        path: path,
+        startSecs: startSecs,
+        endSecs: endSecs,
+      );
+    } else {
+      await rust_audio.audioPlay(path: path);
+    }
     _currentPath = path;
+    lyricsLineNotifier.value = null;
+    chapterNotifier.value = null;
     // Also sync from Rust engine to ensure accuracy
     _currentPath = rust_audio.audioGetCurrentPath() ?? path;
     _startProgressUpdates();
@@ -113,13 +134,26 @@ class RustAudioService {
 
   /// Queue the next track for gapless playback.
   /// The next track will automatically start when the current one ends.
-  Future<void> queueNext(String path) async {
+  /// Tracks of a CUE sheet pass their [startSecs] and [endSecs] in the file.
+  Future<void> queueNext(
+    String path, {
+    double? startSecs,
+    double? endSecs,
+  }) async {
     if (!_initialized) {
       throw StateError('Rust audio engine not initialized');
     }
 
     _nextPath = path;
-    await rust_audio.audioQueueNext(path: path);
+    if (startSecs != null) {
+      await rust_audio.audioQueueNextRange(
+        path: path,
+        startSecs: startSecs,
+        endSecs: endSecs,
+      );
+    } else {
+      await rust_audio.audioQueueNext(path: path);
+    }
   }
 
   /// Pause playback.
@@ -154,6 +188,18 @@ class RustAudioService {
     await rust_audio.audioSeek(positionSecs: position.inMilliseconds / 1000.0);
   }
 
+  /// Seek to the start of the next chapter of the current track.
+  Future<void> nextChapter() async {
+    if (!_initialized) return;
+    await rust_audio.audioNextChapter();
+  }
+
+  /// Seek back to the start of the current or previous chapter.
+  Future<void> previousChapter() async {
+    if (!_initialized) return;
+    await rust_audio.audioPreviousChapter();
+  }
+
   /// Set the volume (0.0 to 1.0).
   Future<void> setVolume(double volume) async {
     if (!_initialized) return;
@@ -180,6 +226,21 @@ class RustAudioService {
     );
   }
 
+  /// Configure resuming podcasts, audiobooks and other long files.
+  Future<void> setResume({
+    required bool autoResume,
+    required Duration minDuration,
+  }) async {
+    if (!_initialized) return;
+    await rust_audio.audioSetResume(
+      autoResume: autoResume,
+      minDurationSecs: minDuration.inMilliseconds / 1000.0,
+    );
+  }
+
+  /// Play the current track from the beginning, e.g. after it resumed.
+  Future<void> startOver() => seek(Duration.zero);
+
   /// Skip to the next queued track (with crossfade if enabled).
   Future<void> skipToNext() async {
     if (!_initialized) return;
@@ -333,6 +394,8 @@ class RustAudioService {
             // Update from Rust engine to ensure sync
             _currentPath = rust_audio.audioGetCurrentPath();
           }
+          lyricsLineNotifier.value = null;
+          chapterNotifier.value = null;
           onTrackEnded?.call(path);
         },
         crossfadeStarted: (fromPath, toPath) {
@@ -345,6 +408,18 @@ class RustAudioService {
         nextTrackReady: (path) {
           onNextTrackReady?.call(path);
         },
+        lyricsLineChanged: (path, lineIndex) {
+          lyricsLineNotifier.value = lineIndex;
+        },
+        chapterChanged: (path, chapterIndex) {
+          chapterNotifier.value = chapterIndex;
+        },
+        resumed: (path, positionSecs) {
+          onResumed?.call(
+            path,
+            Duration(milliseconds: (positionSecs * 1000).round()),
+          );
+        },
       );
     }
   }
@@ -361,5 +436,7 @@ class RustAudioService {
     crossfadeEnabledNotifier.dispose();
     crossfadeDurationNotifier.dispose();
     playbackSpeedNotifier.dispose();
+    lyricsLineNotifier.dispose();
+    chapterNotifier.dispose();
   }
 }
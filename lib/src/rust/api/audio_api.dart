@@ -8,7 +8,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'audio_api.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`
 
 /// Check if native audio is available on this platform.
 bool audioIsNativeAvailable() =>
@@ -25,10 +25,44 @@ bool audioIsInitialized() =>
 Future<void> audioPlay({required String path}) =>
     RustLib.instance.api.crateApiAudioApiAudioPlay(path: path);
 
+/// Play part of an audio file as a track, from `start_secs` to `end_secs`
+/// (or the end of the file), e.g. a track of a CUE sheet.
+Future<void> audioPlayRange({
+  required String path,
+  required double startSecs,
+  double? endSecs,
+}) => RustLib.instance.api.crateApiAudioApiAudioPlayRange(
+  path: path,
+  startSecs: startSecs,
+  endSecs: endSecs,
+);
+
 /// Queue the next track for gapless playback.
 Future<void> audioQueueNext({required String path}) =>
     RustLib.instance.api.crateApiAudioApiAudioQueueNext(path: path);
 
+/// Queue part of an audio file as the next track for gapless playback.
+///
+/// Tracks of the same file queued after each other play without a gap.
+Future<void> audioQueueNextRange({
+  required String path,
+  required double startSecs,
+  double? endSecs,
+}) => RustLib.instance.api.crateApiAudioApiAudioQueueNextRange(
+  path: path,
+  startSecs: startSecs,
+  endSecs: endSecs,
+);
+
+/// Seek to the start of the next chapter of the current track.
+Future<void> audioNextChapter() =>
+    RustLib.instance.api.crateApiAudioApiAudioNextChapter();
+
+/// Seek to the start of the current chapter, or to the previous chapter
+/// within a few seconds of a chapter's start.
+Future<void> audioPreviousChapter() =>
+    RustLib.instance.api.crateApiAudioApiAudioPreviousChapter();
+
 /// Pause playback.
 Future<void> audioPause() => RustLib.instance.api.crateApiAudioApiAudioPause();
 
@@ -56,6 +90,20 @@ Future<void> audioSetCrossfade({
   durationSecs: durationSecs,
 );
 
+/// Configure resuming long files such as podcasts and audiobooks.
+///
+/// The engine saves where playback of files at least `min_duration_secs`
+/// long is left off. With `auto_resume`, playing such a file starts there
+/// and sends a `Resumed` event. By default files of ten minutes or more
+/// resume automatically.
+Future<void> audioSetResume({
+  required bool autoResume,
+  required double minDurationSecs,
+}) => RustLib.instance.api.crateApiAudioApiAudioSetResume(
+  autoResume: autoResume,
+  minDurationSecs: minDurationSecs,
+);
+
 /// Skip to the next queued track.
 Future<void> audioSkipToNext() =>
     RustLib.instance.api.crateApiAudioApiAudioSkipToNext();
@@ -95,10 +143,57 @@ String? audioGetCurrentPath() =>
 BigInt? audioGetChannels() =>
     RustLib.instance.api.crateApiAudioApiAudioGetChannels();
 
+/// Get the output format negotiated with the device.
+AudioOutputFormat? audioGetOutputFormat() =>
+    RustLib.instance.api.crateApiAudioApiAudioGetOutputFormat();
+
+/// Set the dither used when the output device takes integer samples.
+Future<void> audioSetDither({required DitherType dither}) =>
+    RustLib.instance.api.crateApiAudioApiAudioSetDither(dither: dither);
+
 /// Shutdown the audio engine.
 Future<void> audioShutdown() =>
     RustLib.instance.api.crateApiAudioApiAudioShutdown();
 
+/// Render a queue to a WAV or FLAC file, faster than real time.
+///
+/// Tracks are mixed with the given crossfade, EQ, volume and normalization.
+/// Progress is streamed to Dart and the stream closes when the file is
+/// complete (or carries the error). Only one render can run at a time.
+Stream<AudioRenderProgress> audioRenderToFile({
+  required List<String> paths,
+  required AudioRenderSettings settings,
+  required String outPath,
+  required RenderFileFormat format,
+}) => RustLib.instance.api.crateApiAudioApiAudioRenderToFile(
+  paths: paths,
+  settings: settings,
+  outPath: outPath,
+  format: format,
+);
+
+/// Cancel the running render, if any. Its partial output is deleted.
+void audioRenderCancel() =>
+    RustLib.instance.api.crateApiAudioApiAudioRenderCancel();
+
+/// Transcode a batch of files to WAV, FLAC or Ogg/Opus.
+///
+/// Files are converted in parallel. Per-file progress and errors are
+/// streamed to Dart; a failed file does not stop the rest of the batch.
+/// The stream closes when every job has finished. Only one batch can run
+/// at a time.
+Stream<AudioTranscodeEvent> audioTranscodeFiles({
+  required List<AudioTranscodeJob> jobs,
+  required AudioTranscodeSettings settings,
+}) => RustLib.instance.api.crateApiAudioApiAudioTranscodeFiles(
+  jobs: jobs,
+  settings: settings,
+);
+
+/// Cancel the running transcode batch. Unfinished files are not written.
+void audioTranscodeCancel() =>
+    RustLib.instance.api.crateApiAudioApiAudioTranscodeCancel();
+
 @freezed
 sealed class AudioEventType with _$AudioEventType {
   const AudioEventType._();
@@ -120,6 +215,67 @@ sealed class AudioEventType with _$AudioEventType {
       AudioEventType_Error;
   const factory AudioEventType.nextTrackReady({required String path}) =
       AudioEventType_NextTrackReady;
+
+  /// The line of the current track's synced lyrics to highlight; `None`
+  /// before the first line
+  const factory AudioEventType.lyricsLineChanged({
+    required String path,
+    int? lineIndex,
+  }) = AudioEventType_LyricsLineChanged;
+
+  /// Playback reached another chapter of the current track; `None` before
+  /// the first chapter
+  const factory AudioEventType.chapterChanged({
+    required String path,
+    int? chapterIndex,
+  }) = AudioEventType_ChapterChanged;
+
+  /// A long track started where it was left off; seek to zero to start
+  /// over
+  const factory AudioEventType.resumed({
+    required String path,
+    required double positionSecs,
+  }) = AudioEventType_Resumed;
+}
+
+/// Output format negotiated with the device.
+class AudioOutputFormat {
+  final OutputSampleFormatType sampleFormat;
+
+  /// Word length the mix is dithered to; `None` for float output
+  final int? bitsPerSample;
+  final int sampleRate;
+  final int channels;
+
+  /// Dither used for integer formats
+  final DitherType dither;
+
+  const AudioOutputFormat({
+    required this.sampleFormat,
+    this.bitsPerSample,
+    required this.sampleRate,
+    required this.channels,
+    required this.dither,
+  });
+
+  @override
+  int get hashCode =>
+      sampleFormat.hashCode ^
+      bitsPerSample.hashCode ^
+      sampleRate.hashCode ^
+      channels.hashCode ^
+      dither.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioOutputFormat &&
+          runtimeType == other.runtimeType &&
+          sampleFormat == other.sampleFormat &&
+          bitsPerSample == other.bitsPerSample &&
+          sampleRate == other.sampleRate &&
+          channels == other.channels &&
+          dither == other.dither;
 }
 
 /// Progress information returned to Dart.
@@ -153,5 +309,259 @@ class AudioProgress {
           bufferLevel == other.bufferLevel;
 }
 
+/// Render progress for Dart.
+class AudioRenderProgress {
+  final RenderPhaseType phase;
+  final int trackIndex;
+  final int trackCount;
+  final double positionSecs;
+
+  /// Estimated length of the output in seconds
+  final double totalSecs;
+
+  const AudioRenderProgress({
+    required this.phase,
+    required this.trackIndex,
+    required this.trackCount,
+    required this.positionSecs,
+    required this.totalSecs,
+  });
+
+  @override
+  int get hashCode =>
+      phase.hashCode ^
+      trackIndex.hashCode ^
+      trackCount.hashCode ^
+      positionSecs.hashCode ^
+      totalSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioRenderProgress &&
+          runtimeType == other.runtimeType &&
+          phase == other.phase &&
+          trackIndex == other.trackIndex &&
+          trackCount == other.trackCount &&
+          positionSecs == other.positionSecs &&
+          totalSecs == other.totalSecs;
+}
+
+/// How `audio_render_to_file` mixes and encodes a queue.
+class AudioRenderSettings {
+  /// Crossfade between tracks in seconds (0 for gapless)
+  final double crossfadeSecs;
+  final CrossfadeCurveType crossfadeCurve;
+
+  /// Output volume (0.0 to 1.0)
+  final double volume;
+  final double eqPreampDb;
+  final List<EqualizerBand> eqBands;
+
+  /// Normalize the peak of the mix to -1 dBFS
+  final bool normalize;
+
+  /// 16 or 24 (integer), or 32 (float, WAV only)
+  final int bitDepth;
+
+  const AudioRenderSettings({
+    required this.crossfadeSecs,
+    required this.crossfadeCurve,
+    required this.volume,
+    required this.eqPreampDb,
+    required this.eqBands,
+    required this.normalize,
+    required this.bitDepth,
+  });
+
+  @override
+  int get hashCode =>
+      crossfadeSecs.hashCode ^
+      crossfadeCurve.hashCode ^
+      volume.hashCode ^
+      eqPreampDb.hashCode ^
+      eqBands.hashCode ^
+      normalize.hashCode ^
+      bitDepth.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioRenderSettings &&
+          runtimeType == other.runtimeType &&
+          crossfadeSecs == other.crossfadeSecs &&
+          crossfadeCurve == other.crossfadeCurve &&
+          volume == other.volume &&
+          eqPreampDb == other.eqPreampDb &&
+          eqBands == other.eqBands &&
+          normalize == other.normalize &&
+          bitDepth == other.bitDepth;
+}
+
+/// Transcode batch event for Dart.
+class AudioTranscodeEvent {
+  /// Index of the job in the submitted list
+  final int index;
+  final TranscodeStatusType status;
+
+  /// Fraction of the job done (0.0 to 1.0)
+  final double fraction;
+
+  /// Error message for `Failed`
+  final String? message;
+
+  const AudioTranscodeEvent({
+    required this.index,
+    required this.status,
+    required this.fraction,
+    this.message,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^ status.hashCode ^ fraction.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioTranscodeEvent &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          status == other.status &&
+          fraction == other.fraction &&
+          message == other.message;
+}
+
+/// One file of a transcode batch.
+class AudioTranscodeJob {
+  final String inputPath;
+  final String outputPath;
+
+  const AudioTranscodeJob({required this.inputPath, required this.outputPath});
+
+  @override
+  int get hashCode => inputPath.hashCode ^ outputPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioTranscodeJob &&
+          runtimeType == other.runtimeType &&
+          inputPath == other.inputPath &&
+          outputPath == other.outputPath;
+}
+
+/// How `audio_transcode_files` converts files.
+class AudioTranscodeSettings {
+  final TranscodeFileFormat format;
+
+  /// Output sample rate in Hz; `None` keeps the source rate
+  final int? sampleRate;
+
+  /// 16 or 24 (integer), or 32 (float, WAV only)
+  final int bitDepth;
+  final DitherType dither;
+
+  /// Ogg/Opus bitrate in bits per second
+  final int opusBitrate;
+
+  /// Copy tags and cover art from the source file
+  final bool copyTags;
+
+  const AudioTranscodeSettings({
+    required this.format,
+    this.sampleRate,
+    required this.bitDepth,
+    required this.dither,
+    required this.opusBitrate,
+    required this.copyTags,
+  });
+
+  @override
+  int get hashCode =>
+      format.hashCode ^
+      sampleRate.hashCode ^
+      bitDepth.hashCode ^
+      dither.hashCode ^
+      opusBitrate.hashCode ^
+      copyTags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioTranscodeSettings &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          sampleRate == other.sampleRate &&
+          bitDepth == other.bitDepth &&
+          dither == other.dither &&
+          opusBitrate == other.opusBitrate &&
+          copyTags == other.copyTags;
+}
+
 /// Crossfade curve type for Dart.
 enum CrossfadeCurveType { equalPower, linear, squareRoot, sCurve }
+
+/// Dither applied when reducing to 16 or 24 bit.
+enum DitherType {
+  none,
+
+  /// TPDF noise of +-1 LSB
+  triangular,
+
+  /// TPDF noise with noise shaping towards high frequencies
+  noiseShaped,
+}
+
+/// A peaking equalizer band.
+class EqualizerBand {
+  final double frequencyHz;
+  final double gainDb;
+  final double q;
+
+  const EqualizerBand({
+    required this.frequencyHz,
+    required this.gainDb,
+    required this.q,
+  });
+
+  @override
+  int get hashCode => frequencyHz.hashCode ^ gainDb.hashCode ^ q.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EqualizerBand &&
+          runtimeType == other.runtimeType &&
+          frequencyHz == other.frequencyHz &&
+          gainDb == other.gainDb &&
+          q == other.q;
+}
+
+/// Sample format of the output device.
+enum OutputSampleFormatType {
+  i16,
+
+  /// 24-bit samples in a 32-bit word
+  i24,
+  i32,
+  f32,
+}
+
+/// Container format for rendered files.
+enum RenderFileFormat { wav, flac }
+
+/// Stage of a running render.
+enum RenderPhaseType { mixing, normalizing }
+
+/// Container format for transcoded files.
+enum TranscodeFileFormat {
+  wav,
+  flac,
+
+  /// Only available in builds with the `opus` feature
+  oggOpus,
+}
+
+/// Stage of a job in a transcode batch.
+enum TranscodeStatusType { started, progress, finished, failed }
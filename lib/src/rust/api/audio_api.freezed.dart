@@ -55,7 +55,7 @@ extension AudioEventTypePatterns on AudioEventType {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( AudioEventType_StateChanged value)?  stateChanged,TResult Function( AudioEventType_Progress value)?  progress,TResult Function( AudioEventType_TrackEnded value)?  trackEnded,TResult Function( AudioEventType_CrossfadeStarted value)?  crossfadeStarted,TResult Function( AudioEventType_Error value)?  error,TResult Function( AudioEventType_NextTrackReady value)?  nextTrackReady,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( AudioEventType_StateChanged value)?  stateChanged,TResult Function( AudioEventType_Progress value)?  progress,TResult Function( AudioEventType_TrackEnded value)?  trackEnded,TResult Function( AudioEventType_CrossfadeStarted value)?  crossfadeStarted,TResult Function( AudioEventType_Error value)?  error,TResult Function( AudioEventType_NextTrackReady value)?  nextTrackReady,TResult Function( AudioEventType_LyricsLineChanged value)?  lyricsLineChanged,TResult Function( AudioEventType_ChapterChanged value)?  chapterChanged,TResult Function( AudioEventType_Resumed value)?  resumed,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case AudioEventType_StateChanged() when stateChanged != null:
@@ -64,7 +64,10 @@ return progress(_that);case AudioEventType_TrackEnded() when trackEnded != null:
 return trackEnded(_that);case AudioEventType_CrossfadeStarted() when crossfadeStarted != null:
 return crossfadeStarted(_that);case AudioEventType_Error() when error != null:
 return error(_that);case AudioEventType_NextTrackReady() when nextTrackReady != null:
-return nextTrackReady(_that);case _:
+return nextTrackReady(_that);case AudioEventType_LyricsLineChanged() when lyricsLineChanged != null:
+return lyricsLineChanged(_that);case AudioEventType_ChapterChanged() when chapterChanged != null:
+return chapterChanged(_that);case AudioEventType_Resumed() when resumed != null:
+return resumed(_that);case _:
   return orElse();
 
 }
@@ -82,7 +85,7 @@ return nextTrackReady(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( AudioEventType_StateChanged value)  stateChanged,required TResult Function( AudioEventType_Progress value)  progress,required TResult Function( AudioEventType_TrackEnded value)  trackEnded,required TResult Function( AudioEventType_CrossfadeStarted value)  crossfadeStarted,required TResult Function( AudioEventType_Error value)  error,required TResult Function( AudioEventType_NextTrackReady value)  nextTrackReady,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( AudioEventType_StateChanged value)  stateChanged,required TResult Function( AudioEventType_Progress value)  progress,required TResult Function( AudioEventType_TrackEnded value)  trackEnded,required TResult Function( AudioEventType_CrossfadeStarted value)  crossfadeStarted,required TResult Function( AudioEventType_Error value)  error,required TResult Function( AudioEventType_NextTrackReady value)  nextTrackReady,required TResult Function( AudioEventType_LyricsLineChanged value)  lyricsLineChanged,required TResult Function( AudioEventType_ChapterChanged value)  chapterChanged,required TResult Function( AudioEventType_Resumed value)  resumed,}){
 final _that = this;
 switch (_that) {
 case AudioEventType_StateChanged():
@@ -91,7 +94,10 @@ return progress(_that);case AudioEventType_TrackEnded():
 return trackEnded(_that);case AudioEventType_CrossfadeStarted():
 return crossfadeStarted(_that);case AudioEventType_Error():
 return error(_that);case AudioEventType_NextTrackReady():
-return nextTrackReady(_that);}
+return nextTrackReady(_that);case AudioEventType_LyricsLineChanged():
+return lyricsLineChanged(_that);case AudioEventType_ChapterChanged():
+return chapterChanged(_that);case AudioEventType_Resumed():
+return resumed(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -105,7 +111,7 @@ return nextTrackReady(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( AudioEventType_StateChanged value)?  stateChanged,TResult? Function( AudioEventType_Progress value)?  progress,TResult? Function( AudioEventType_TrackEnded value)?  trackEnded,TResult? Function( AudioEventType_CrossfadeStarted value)?  crossfadeStarted,TResult? Function( AudioEventType_Error value)?  error,TResult? Function( AudioEventType_NextTrackReady value)?  nextTrackReady,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( AudioEventType_StateChanged value)?  stateChanged,TResult? Function( AudioEventType_Progress value)?  progress,TResult? Function( AudioEventType_TrackEnded value)?  trackEnded,TResult? Function( AudioEventType_CrossfadeStarted value)?  crossfadeStarted,TResult? Function( AudioEventType_Error value)?  error,TResult? Function( AudioEventType_NextTrackReady value)?  nextTrackReady,TResult? Function( AudioEventType_LyricsLineChanged value)?  lyricsLineChanged,TResult? Function( AudioEventType_ChapterChanged value)?  chapterChanged,TResult? Function( AudioEventType_Resumed value)?  resumed,}){
 final _that = this;
 switch (_that) {
 case AudioEventType_StateChanged() when stateChanged != null:
@@ -114,7 +120,10 @@ return progress(_that);case AudioEventType_TrackEnded() when trackEnded != null:
 return trackEnded(_that);case AudioEventType_CrossfadeStarted() when crossfadeStarted != null:
 return crossfadeStarted(_that);case AudioEventType_Error() when error != null:
 return error(_that);case AudioEventType_NextTrackReady() when nextTrackReady != null:
-return nextTrackReady(_that);case _:
+return nextTrackReady(_that);case AudioEventType_LyricsLineChanged() when lyricsLineChanged != null:
+return lyricsLineChanged(_that);case AudioEventType_ChapterChanged() when chapterChanged != null:
+return chapterChanged(_that);case AudioEventType_Resumed() when resumed != null:
+return resumed(_that);case _:
   return null;
 
 }
@@ -131,7 +140,7 @@ return nextTrackReady(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String state)?  stateChanged,TResult Function( double positionSecs,  double? durationSecs,  double bufferLevel)?  progress,TResult Function( String path)?  trackEnded,TResult Function( String fromPath,  String toPath)?  crossfadeStarted,TResult Function( String message)?  error,TResult Function( String path)?  nextTrackReady,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String state)?  stateChanged,TResult Function( double positionSecs,  double? durationSecs,  double bufferLevel)?  progress,TResult Function( String path)?  trackEnded,TResult Function( String fromPath,  String toPath)?  crossfadeStarted,TResult Function( String message)?  error,TResult Function( String path)?  nextTrackReady,TResult Function( String path,  int? lineIndex)?  lyricsLineChanged,TResult Function( String path,  int? chapterIndex)?  chapterChanged,TResult Function( String path,  double positionSecs)?  resumed,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case AudioEventType_StateChanged() when stateChanged != null:
 return stateChanged(_that.state);case AudioEventType_Progress() when progress != null:
@@ -139,7 +148,10 @@ return progress(_that.positionSecs,_that.durationSecs,_that.bufferLevel);case Au
 return trackEnded(_that.path);case AudioEventType_CrossfadeStarted() when crossfadeStarted != null:
 return crossfadeStarted(_that.fromPath,_that.toPath);case AudioEventType_Error() when error != null:
 return error(_that.message);case AudioEventType_NextTrackReady() when nextTrackReady != null:
-return nextTrackReady(_that.path);case _:
+return nextTrackReady(_that.path);case AudioEventType_LyricsLineChanged() when lyricsLineChanged != null:
+return lyricsLineChanged(_that.path,_that.lineIndex);case AudioEventType_ChapterChanged() when chapterChanged != null:
+return chapterChanged(_that.path,_that.chapterIndex);case AudioEventType_Resumed() when resumed != null:
+return resumed(_that.path,_that.positionSecs);case _:
   return orElse();
 
 }
@@ -157,7 +169,7 @@ return nextTrackReady(_that.path);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String state)  stateChanged,required TResult Function( double positionSecs,  double? durationSecs,  double bufferLevel)  progress,required TResult Function( String path)  trackEnded,required TResult Function( String fromPath,  String toPath)  crossfadeStarted,required TResult Function( String message)  error,required TResult Function( String path)  nextTrackReady,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String state)  stateChanged,required TResult Function( double positionSecs,  double? durationSecs,  double bufferLevel)  progress,required TResult Function( String path)  trackEnded,required TResult Function( String fromPath,  String toPath)  crossfadeStarted,required TResult Function( String message)  error,required TResult Function( String path)  nextTrackReady,required TResult Function( String path,  int? lineIndex)  lyricsLineChanged,required TResult Function( String path,  int? chapterIndex)  chapterChanged,required TResult Function( String path,  double positionSecs)  resumed,}) {final _that = this;
 switch (_that) {
 case AudioEventType_StateChanged():
 return stateChanged(_that.state);case AudioEventType_Progress():
@@ -165,7 +177,10 @@ return progress(_that.positionSecs,_that.durationSecs,_that.bufferLevel);case Au
 return trackEnded(_that.path);case AudioEventType_CrossfadeStarted():
 return crossfadeStarted(_that.fromPath,_that.toPath);case AudioEventType_Error():
 return error(_that.message);case AudioEventType_NextTrackReady():
-return nextTrackReady(_that.path);}
+return nextTrackReady(_that.path);case AudioEventType_LyricsLineChanged():
+return lyricsLineChanged(_that.path,_that.lineIndex);case AudioEventType_ChapterChanged():
+return chapterChanged(_that.path,_that.chapterIndex);case AudioEventType_Resumed():
+return resumed(_that.path,_that.positionSecs);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -179,7 +194,7 @@ return nextTrackReady(_that.path);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String state)?  stateChanged,TResult? Function( double positionSecs,  double? durationSecs,  double bufferLevel)?  progress,TResult? Function( String path)?  trackEnded,TResult? Function( String fromPath,  String toPath)?  crossfadeStarted,TResult? Function( String message)?  error,TResult? Function( String path)?  nextTrackReady,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String state)?  stateChanged,TResult? Function( double positionSecs,  double? durationSecs,  double bufferLevel)?  progress,TResult? Function( String path)?  trackEnded,TResult? Function( String fromPath,  String toPath)?  crossfadeStarted,TResult? Function( String message)?  error,TResult? Function( String path)?  nextTrackReady,TResult? Function( String path,  int? lineIndex)?  lyricsLineChanged,TResult? Function( String path,  int? chapterIndex)?  chapterChanged,TResult? Function( String path,  double positionSecs)?  resumed,}) {final _that = this;
 switch (_that) {
 case AudioEventType_StateChanged() when stateChanged != null:
 return stateChanged(_that.state);case AudioEventType_Progress() when progress != null:
@@ -187,7 +202,10 @@ return progress(_that.positionSecs,_that.durationSecs,_that.bufferLevel);case Au
 return trackEnded(_that.path);case AudioEventType_CrossfadeStarted() when crossfadeStarted != null:
 return crossfadeStarted(_that.fromPath,_that.toPath);case AudioEventType_Error() when error != null:
 return error(_that.message);case AudioEventType_NextTrackReady() when nextTrackReady != null:
-return nextTrackReady(_that.path);case _:
+return nextTrackReady(_that.path);case AudioEventType_LyricsLineChanged() when lyricsLineChanged != null:
+return lyricsLineChanged(_that.path,_that.lineIndex);case AudioEventType_ChapterChanged() when chapterChanged != null:
+return chapterChanged(_that.path,_that.chapterIndex);case AudioEventType_Resumed() when resumed != null:
+return resumed(_that.path,_that.positionSecs);case _:
   return null;
 
 }
@@ -595,6 +613,210 @@ as String,
 }
 
 
+}
+
+/// @nodoc
+
+
+class AudioEventType_LyricsLineChanged extends AudioEventType {
+  const AudioEventType_LyricsLineChanged({required this.path, this.lineIndex}): super._();
+  
+
+ final  String path;
+ final  int? lineIndex;
+
+/// Create a copy of AudioEventType
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AudioEventType_LyricsLineChangedCopyWith<AudioEventType_LyricsLineChanged> get copyWith => _$AudioEventType_LyricsLineChangedCopyWithImpl<AudioEventType_LyricsLineChanged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioEventType_LyricsLineChanged&&(identical(other.path, path) || other.path == path)&&(identical(other.lineIndex, lineIndex) || other.lineIndex == lineIndex));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,path,lineIndex);
+
+@override
+String toString() {
+  return 'AudioEventType.lyricsLineChanged(path: $path, lineIndex: $lineIndex)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AudioEventType_LyricsLineChangedCopyWith<$Res> implements $AudioEventTypeCopyWith<$Res> {
+  factory $AudioEventType_LyricsLineChangedCopyWith(AudioEventType_LyricsLineChanged value, $Res Function(AudioEventType_LyricsLineChanged) _then) = _$AudioEventType_LyricsLineChangedCopyWithImpl;
+@useResult
+$Res call({
+ String path, int? lineIndex
+});
+
+
+
+
+}
+/// @nodoc
+class _$AudioEventType_LyricsLineChangedCopyWithImpl<$Res>
+    implements $AudioEventType_LyricsLineChangedCopyWith<$Res> {
+  _$AudioEventType_LyricsLineChangedCopyWithImpl(this._self, this._then);
+
+  final AudioEventType_LyricsLineChanged _self;
+  final $Res Function(AudioEventType_LyricsLineChanged) _then;
+
+/// Create a copy of AudioEventType
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? path = null,Object? lineIndex = freezed,}) {
+  return _then(AudioEventType_LyricsLineChanged(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,lineIndex: freezed == lineIndex ? _self.lineIndex : lineIndex // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class AudioEventType_ChapterChanged extends AudioEventType {
+  const AudioEventType_ChapterChanged({required this.path, this.chapterIndex}): super._();
+  
+
+ final  String path;
+ final  int? chapterIndex;
+
+/// Create a copy of AudioEventType
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AudioEventType_ChapterChangedCopyWith<AudioEventType_ChapterChanged> get copyWith => _$AudioEventType_ChapterChangedCopyWithImpl<AudioEventType_ChapterChanged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioEventType_ChapterChanged&&(identical(other.path, path) || other.path == path)&&(identical(other.chapterIndex, chapterIndex) || other.chapterIndex == chapterIndex));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,path,chapterIndex);
+
+@override
+String toString() {
+  return 'AudioEventType.chapterChanged(path: $path, chapterIndex: $chapterIndex)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AudioEventType_ChapterChangedCopyWith<$Res> implements $AudioEventTypeCopyWith<$Res> {
+  factory $AudioEventType_ChapterChangedCopyWith(AudioEventType_ChapterChanged value, $Res Function(AudioEventType_ChapterChanged) _then) = _$AudioEventType_ChapterChangedCopyWithImpl;
+@useResult
+$Res call({
+ String path, int? chapterIndex
+});
+
+
+
+
+}
+/// @nodoc
+class _$AudioEventType_ChapterChangedCopyWithImpl<$Res>
+    implements $AudioEventType_ChapterChangedCopyWith<$Res> {
+  _$AudioEventType_ChapterChangedCopyWithImpl(this._self, this._then);
+
+  final AudioEventType_ChapterChanged _self;
+  final $Res Function(AudioEventType_ChapterChanged) _then;
+
+/// Create a copy of AudioEventType
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? path = null,Object? chapterIndex = freezed,}) {
+  return _then(AudioEventType_ChapterChanged(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,chapterIndex: freezed == chapterIndex ? _self.chapterIndex : chapterIndex // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class AudioEventType_Resumed extends AudioEventType {
+  const AudioEventType_Resumed({required this.path, required this.positionSecs}): super._();
+  
+
+ final  String path;
+ final  double positionSecs;
+
+/// Create a copy of AudioEventType
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AudioEventType_ResumedCopyWith<AudioEventType_Resumed> get copyWith => _$AudioEventType_ResumedCopyWithImpl<AudioEventType_Resumed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioEventType_Resumed&&(identical(other.path, path) || other.path == path)&&(identical(other.positionSecs, positionSecs) || other.positionSecs == positionSecs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,path,positionSecs);
+
+@override
+String toString() {
+  return 'AudioEventType.resumed(path: $path, positionSecs: $positionSecs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AudioEventType_ResumedCopyWith<$Res> implements $AudioEventTypeCopyWith<$Res> {
+  factory $AudioEventType_ResumedCopyWith(AudioEventType_Resumed value, $Res Function(AudioEventType_Resumed) _then) = _$AudioEventType_ResumedCopyWithImpl;
+@useResult
+$Res call({
+ String path, double positionSecs
+});
+
+
+
+
+}
+/// @nodoc
+class _$AudioEventType_ResumedCopyWithImpl<$Res>
+    implements $AudioEventType_ResumedCopyWith<$Res> {
+  _$AudioEventType_ResumedCopyWithImpl(this._self, this._then);
+
+  final AudioEventType_Resumed _self;
+  final $Res Function(AudioEventType_Resumed) _then;
+
+/// Create a copy of AudioEventType
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? path = null,Object? positionSecs = null,}) {
+  return _then(AudioEventType_Resumed(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,positionSecs: null == positionSecs ? _self.positionSecs : positionSecs // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
 }
 
 // dart format on
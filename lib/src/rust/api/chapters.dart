@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// The chapters of the file at `path` in playing order; empty if it has
+/// none.
+Future<List<Chapter>> getChapters({required String path}) =>
+    RustLib.instance.api.crateApiChaptersGetChapters(path: path);
+
+/// A chapter of an audio file.
+class Chapter {
+  final String? title;
+
+  /// Where the chapter starts in the file
+  final double startSecs;
+
+  /// Where the next chapter starts, or the end of the file
+  final double endSecs;
+
+  const Chapter({this.title, required this.startSecs, required this.endSecs});
+
+  @override
+  int get hashCode => title.hashCode ^ startSecs.hashCode ^ endSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Chapter &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          startSecs == other.startSecs &&
+          endSecs == other.endSecs;
+}
@@ -0,0 +1,565 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scanner.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Open the library database at `db_path`, creating it if needed.
+///
+/// Must be called before any other library function. Opening another
+/// database closes the previous one.
+Future<void> libraryOpen({required String dbPath}) =>
+    RustLib.instance.api.crateApiLibraryLibraryOpen(dbPath: dbPath);
+
+/// Register a music folder. Adding a registered folder does nothing.
+Future<void> libraryAddFolder({required String path}) =>
+    RustLib.instance.api.crateApiLibraryLibraryAddFolder(path: path);
+
+/// Remove a music folder and its songs from the library.
+Future<void> libraryRemoveFolder({required String path}) =>
+    RustLib.instance.api.crateApiLibraryLibraryRemoveFolder(path: path);
+
+Future<List<LibraryFolder>> libraryFolders() =>
+    RustLib.instance.api.crateApiLibraryLibraryFolders();
+
+/// Scan `root_path` into the library, streaming progress like
+/// `scan_root_dir_stream`.
+///
+/// Known files come from the library, and every event is written to it
+/// before it is sent, so the stream only reports what changed. The folder
+/// is registered if it was not. Cancelled by `scan_cancel`; only one scan
+/// can run at a time.
+Stream<ScanStreamEvent> libraryScanFolder({
+  required String rootPath,
+  String? artCacheDir,
+  ScanOptions? options,
+}) => RustLib.instance.api.crateApiLibraryLibraryScanFolder(
+  rootPath: rootPath,
+  artCacheDir: artCacheDir,
+  options: options,
+);
+
+/// Watch the library's folders like `scan_watch`, writing changes into
+/// the library before they are sent. Changes the library could not store
+/// are sent as an error instead. Stopped by `scan_watch_stop`.
+Stream<WatchEvent> libraryWatch({String? artCacheDir, ScanOptions? options}) =>
+    RustLib.instance.api.crateApiLibraryLibraryWatch(
+      artCacheDir: artCacheDir,
+      options: options,
+    );
+
+/// Songs matching `query`, one page at a time.
+Future<SongPage> librarySongs({required SongQuery query}) =>
+    RustLib.instance.api.crateApiLibraryLibrarySongs(query: query);
+
+/// Up to `limit` songs matching `query`, best match first.
+///
+/// Unlike the `search` of [`library_songs`], this ranks songs by how well
+/// their title, artist and album match, and tolerates typos: "bjrok"
+/// finds "Björk". Words of Chinese, Japanese and Korean titles are found
+/// without spaces.
+Future<List<LibrarySong>> librarySearch({
+  required String query,
+  required int limit,
+}) => RustLib.instance.api.crateApiLibraryLibrarySearch(
+  query: query,
+  limit: limit,
+);
+
+/// Count a play of the song at `path`. Songs the audio engine plays to the
+/// end are counted already.
+Future<void> libraryRecordPlay({required String path}) =>
+    RustLib.instance.api.crateApiLibraryLibraryRecordPlay(path: path);
+
+/// Mark the song at `path` as a favorite, or not.
+Future<void> librarySetFavorite({
+  required String path,
+  required bool favorite,
+}) => RustLib.instance.api.crateApiLibraryLibrarySetFavorite(
+  path: path,
+  favorite: favorite,
+);
+
+/// The library song at `path`, if any.
+Future<LibrarySong?> librarySong({required String path}) =>
+    RustLib.instance.api.crateApiLibraryLibrarySong(path: path);
+
+Future<AlbumPage> libraryAlbums({required GroupQuery query}) =>
+    RustLib.instance.api.crateApiLibraryLibraryAlbums(query: query);
+
+Future<GroupPage> libraryArtists({required GroupQuery query}) =>
+    RustLib.instance.api.crateApiLibraryLibraryArtists(query: query);
+
+Future<GroupPage> libraryGenres({required GroupQuery query}) =>
+    RustLib.instance.api.crateApiLibraryLibraryGenres(query: query);
+
+/// The options albums and artists are grouped by.
+Future<GroupingOptions> libraryGroupingOptions() =>
+    RustLib.instance.api.crateApiLibraryLibraryGroupingOptions();
+
+/// Change how albums and artists are grouped, regrouping every song.
+Future<void> librarySetGroupingOptions({required GroupingOptions options}) =>
+    RustLib.instance.api.crateApiLibraryLibrarySetGroupingOptions(
+      options: options,
+    );
+
+/// All songs, by title.
+SongQuery libraryDefaultSongQuery() =>
+    RustLib.instance.api.crateApiLibraryLibraryDefaultSongQuery();
+
+/// All albums, artists or genres, by name.
+GroupQuery libraryDefaultGroupQuery() =>
+    RustLib.instance.api.crateApiLibraryLibraryDefaultGroupQuery();
+
+/// Split artists on ";", " / " and "feat." credits, and sort past "The",
+/// "A" and "An".
+GroupingOptions libraryDefaultGroupingOptions() =>
+    RustLib.instance.api.crateApiLibraryLibraryDefaultGroupingOptions();
+
+/// One page of albums.
+class AlbumPage {
+  final List<LibraryAlbum> albums;
+  final int total;
+
+  const AlbumPage({required this.albums, required this.total});
+
+  @override
+  int get hashCode => albums.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AlbumPage &&
+          runtimeType == other.runtimeType &&
+          albums == other.albums &&
+          total == other.total;
+}
+
+/// One page of artists or genres.
+class GroupPage {
+  final List<LibraryGroup> groups;
+  final int total;
+
+  const GroupPage({required this.groups, required this.total});
+
+  @override
+  int get hashCode => groups.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupPage &&
+          runtimeType == other.runtimeType &&
+          groups == other.groups &&
+          total == other.total;
+}
+
+/// Which albums, artists or genres are returned.
+class GroupQuery {
+  /// Words that must all start a word of the name; case and accents are
+  /// ignored
+  final String? search;
+
+  /// Only count songs found in this music folder
+  final String? folder;
+  final GroupSortType sort;
+  final bool descending;
+  final int offset;
+  final int? limit;
+
+  const GroupQuery({
+    this.search,
+    this.folder,
+    required this.sort,
+    required this.descending,
+    required this.offset,
+    this.limit,
+  });
+
+  @override
+  int get hashCode =>
+      search.hashCode ^
+      folder.hashCode ^
+      sort.hashCode ^
+      descending.hashCode ^
+      offset.hashCode ^
+      limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupQuery &&
+          runtimeType == other.runtimeType &&
+          search == other.search &&
+          folder == other.folder &&
+          sort == other.sort &&
+          descending == other.descending &&
+          offset == other.offset &&
+          limit == other.limit;
+}
+
+/// Order of albums, artists and genres.
+enum GroupSortType {
+  name,
+  songCount,
+
+  /// By the latest year of their songs
+  year,
+
+  /// By when their latest song was added
+  dateAdded,
+}
+
+/// How songs are grouped into albums and artists.
+class GroupingOptions {
+  /// Separators artist tags are split on, ignoring ASCII case, e.g. ";"
+  /// or " feat. "
+  final List<String> artistSeparators;
+
+  /// Leading articles ignored when sorting names; "Beatles, The" is read
+  /// as "The Beatles"
+  final List<String> articles;
+
+  /// Album artist of compilations, e.g. "Various Artists"
+  final String variousArtists;
+
+  const GroupingOptions({
+    required this.artistSeparators,
+    required this.articles,
+    required this.variousArtists,
+  });
+
+  @override
+  int get hashCode =>
+      artistSeparators.hashCode ^
+      articles.hashCode ^
+      variousArtists.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupingOptions &&
+          runtimeType == other.runtimeType &&
+          artistSeparators == other.artistSeparators &&
+          articles == other.articles &&
+          variousArtists == other.variousArtists;
+}
+
+/// An album in the library.
+class LibraryAlbum {
+  /// Identifies the album for [`SongQuery::album_key`]
+  final String key;
+  final String name;
+
+  /// The album artist, or the track artist where none is tagged; the
+  /// compilation artist for compilations
+  final String? artist;
+
+  /// Tagged as a compilation, or untagged songs by several artists in one
+  /// folder
+  final bool compilation;
+  final int? year;
+  final int songCount;
+  final int discCount;
+  final BigInt durationSecs;
+  final String? albumArtPath;
+
+  const LibraryAlbum({
+    required this.key,
+    required this.name,
+    this.artist,
+    required this.compilation,
+    this.year,
+    required this.songCount,
+    required this.discCount,
+    required this.durationSecs,
+    this.albumArtPath,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^
+      name.hashCode ^
+      artist.hashCode ^
+      compilation.hashCode ^
+      year.hashCode ^
+      songCount.hashCode ^
+      discCount.hashCode ^
+      durationSecs.hashCode ^
+      albumArtPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibraryAlbum &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          name == other.name &&
+          artist == other.artist &&
+          compilation == other.compilation &&
+          year == other.year &&
+          songCount == other.songCount &&
+          discCount == other.discCount &&
+          durationSecs == other.durationSecs &&
+          albumArtPath == other.albumArtPath;
+}
+
+/// A music folder registered with the library.
+class LibraryFolder {
+  final String path;
+  final int songCount;
+
+  /// When the folder was added, in seconds since the epoch
+  final PlatformInt64 addedAt;
+
+  /// When a scan of the folder last finished
+  final PlatformInt64? scannedAt;
+
+  const LibraryFolder({
+    required this.path,
+    required this.songCount,
+    required this.addedAt,
+    this.scannedAt,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      songCount.hashCode ^
+      addedAt.hashCode ^
+      scannedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibraryFolder &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          songCount == other.songCount &&
+          addedAt == other.addedAt &&
+          scannedAt == other.scannedAt;
+}
+
+/// An artist or genre in the library.
+class LibraryGroup {
+  /// The normalized name; spellings of an artist's name share a key
+  final String key;
+  final String name;
+  final int songCount;
+  final int albumCount;
+  final BigInt durationSecs;
+
+  /// Cover of one of its albums
+  final String? albumArtPath;
+
+  const LibraryGroup({
+    required this.key,
+    required this.name,
+    required this.songCount,
+    required this.albumCount,
+    required this.durationSecs,
+    this.albumArtPath,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^
+      name.hashCode ^
+      songCount.hashCode ^
+      albumCount.hashCode ^
+      durationSecs.hashCode ^
+      albumArtPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibraryGroup &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          name == other.name &&
+          songCount == other.songCount &&
+          albumCount == other.albumCount &&
+          durationSecs == other.durationSecs &&
+          albumArtPath == other.albumArtPath;
+}
+
+/// A song in the library.
+class LibrarySong {
+  final PlatformInt64 id;
+
+  /// The music folder the song was found in
+  final String folder;
+
+  /// The tagged title, or one made from the file name
+  final String displayTitle;
+
+  /// When the song was first added, in seconds since the epoch
+  final PlatformInt64 dateAdded;
+
+  /// Times the song was played to the end
+  final int playCount;
+
+  /// When the song was last played to the end
+  final PlatformInt64? lastPlayed;
+  final bool favorite;
+
+  /// [`LibraryAlbum::key`] of its album
+  final String? albumKey;
+
+  /// [`LibraryGroup::key`]s of the artists it credits
+  final List<String> artistKeys;
+  final AudioFileMetadata metadata;
+
+  const LibrarySong({
+    required this.id,
+    required this.folder,
+    required this.displayTitle,
+    required this.dateAdded,
+    required this.playCount,
+    this.lastPlayed,
+    required this.favorite,
+    this.albumKey,
+    required this.artistKeys,
+    required this.metadata,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      folder.hashCode ^
+      displayTitle.hashCode ^
+      dateAdded.hashCode ^
+      playCount.hashCode ^
+      lastPlayed.hashCode ^
+      favorite.hashCode ^
+      albumKey.hashCode ^
+      artistKeys.hashCode ^
+      metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibrarySong &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          folder == other.folder &&
+          displayTitle == other.displayTitle &&
+          dateAdded == other.dateAdded &&
+          playCount == other.playCount &&
+          lastPlayed == other.lastPlayed &&
+          favorite == other.favorite &&
+          albumKey == other.albumKey &&
+          artistKeys == other.artistKeys &&
+          metadata == other.metadata;
+}
+
+/// One page of songs.
+class SongPage {
+  final List<LibrarySong> songs;
+
+  /// Songs matching the query, across all pages
+  final int total;
+
+  const SongPage({required this.songs, required this.total});
+
+  @override
+  int get hashCode => songs.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SongPage &&
+          runtimeType == other.runtimeType &&
+          songs == other.songs &&
+          total == other.total;
+}
+
+/// Which songs [`library_songs`] returns.
+class SongQuery {
+  /// Words that must all start a word of the title, artist, album, album
+  /// artist, genre or composer; case and accents are ignored
+  final String? search;
+
+  /// Only songs found in this music folder
+  final String? folder;
+
+  /// Only songs crediting this artist, by name or [`LibraryGroup::key`]
+  final String? artist;
+  final String? album;
+
+  /// Only songs of the album with this [`LibraryAlbum::key`]
+  final String? albumKey;
+  final String? genre;
+  final SongSortType sort;
+  final bool descending;
+
+  /// Matching songs to skip
+  final int offset;
+
+  /// Most songs to return; all when unset
+  final int? limit;
+
+  const SongQuery({
+    this.search,
+    this.folder,
+    this.artist,
+    this.album,
+    this.albumKey,
+    this.genre,
+    required this.sort,
+    required this.descending,
+    required this.offset,
+    this.limit,
+  });
+
+  @override
+  int get hashCode =>
+      search.hashCode ^
+      folder.hashCode ^
+      artist.hashCode ^
+      album.hashCode ^
+      albumKey.hashCode ^
+      genre.hashCode ^
+      sort.hashCode ^
+      descending.hashCode ^
+      offset.hashCode ^
+      limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SongQuery &&
+          runtimeType == other.runtimeType &&
+          search == other.search &&
+          folder == other.folder &&
+          artist == other.artist &&
+          album == other.album &&
+          albumKey == other.albumKey &&
+          genre == other.genre &&
+          sort == other.sort &&
+          descending == other.descending &&
+          offset == other.offset &&
+          limit == other.limit;
+}
+
+/// Order of songs returned by [`library_songs`].
+enum SongSortType {
+  title,
+
+  /// By artist, then album and track
+  artist,
+
+  /// By album, then disc and track
+  album,
+
+  /// By year, then album and track
+  year,
+  duration,
+  dateAdded,
+  path,
+}
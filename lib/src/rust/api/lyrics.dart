@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Read the lyrics of the song at `path`, or `None` if it has none.
+Future<Lyrics?> getLyrics({required String path}) =>
+    RustLib.instance.api.crateApiLyricsGetLyrics(path: path);
+
+/// The index of the line of synced `lyrics` that is active at
+/// `position_secs`, or `None` before the first line and for plain lyrics.
+int? lyricsActiveLine({required Lyrics lyrics, required double positionSecs}) =>
+    RustLib.instance.api.crateApiLyricsLyricsActiveLine(
+      lyrics: lyrics,
+      positionSecs: positionSecs,
+    );
+
+/// The lyrics of a song.
+class Lyrics {
+  final LyricsSourceType source;
+
+  /// Every line has a start time, and lines are in time order
+  final bool synced;
+  final List<LyricsLine> lines;
+
+  const Lyrics({
+    required this.source,
+    required this.synced,
+    required this.lines,
+  });
+
+  @override
+  int get hashCode => source.hashCode ^ synced.hashCode ^ lines.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Lyrics &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          synced == other.synced &&
+          lines == other.lines;
+}
+
+/// A line of lyrics.
+class LyricsLine {
+  /// When the line starts, for synced lyrics
+  final int? startMs;
+  final String text;
+
+  /// Word timings, when the LRC file has them
+  final List<LyricsWord> words;
+
+  const LyricsLine({this.startMs, required this.text, required this.words});
+
+  @override
+  int get hashCode => startMs.hashCode ^ text.hashCode ^ words.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LyricsLine &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          text == other.text &&
+          words == other.words;
+}
+
+/// Where a song's lyrics were found.
+enum LyricsSourceType {
+  /// A `.lrc` file with the same name as the song
+  sidecar,
+
+  /// An ID3v2 SYLT frame
+  syncedTag,
+
+  /// A lyrics tag (USLT, `LYRICS`, `©lyr`), which may hold LRC text
+  tag,
+}
+
+/// A word of an enhanced LRC line.
+class LyricsWord {
+  final int startMs;
+
+  /// The word with any spacing that follows it
+  final String text;
+
+  const LyricsWord({required this.startMs, required this.text});
+
+  @override
+  int get hashCode => startMs.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LyricsWord &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          text == other.text;
+}
@@ -0,0 +1,151 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Read the playlist file at `path`.
+Future<Playlist> playlistRead({required String path}) =>
+    RustLib.instance.api.crateApiPlaylistsPlaylistRead(path: path);
+
+/// Read the playlist file at `path` and match its entries to library songs.
+Future<PlaylistImport> playlistImport({required String path}) =>
+    RustLib.instance.api.crateApiPlaylistsPlaylistImport(path: path);
+
+/// Write `playlist` to `path` in the format of its extension: `.m3u`,
+/// `.m3u8`, `.pls` or `.xspf`.
+///
+/// With `relative_paths`, entries are written relative to the playlist's
+/// folder where they can be. The file is replaced in one step.
+Future<void> playlistWrite({
+  required String path,
+  required Playlist playlist,
+  required bool relativePaths,
+}) => RustLib.instance.api.crateApiPlaylistsPlaylistWrite(
+  path: path,
+  playlist: playlist,
+  relativePaths: relativePaths,
+);
+
+/// Write the library songs at `song_paths` to `path` like
+/// [`playlist_write`], with their titles, artists, albums and durations.
+Future<void> playlistExport({
+  required String path,
+  String? name,
+  required List<String> songPaths,
+  required bool relativePaths,
+}) => RustLib.instance.api.crateApiPlaylistsPlaylistExport(
+  path: path,
+  name: name,
+  songPaths: songPaths,
+  relativePaths: relativePaths,
+);
+
+class Playlist {
+  /// The title the file gives, or its file name
+  final String? name;
+  final List<PlaylistEntry> entries;
+
+  const Playlist({this.name, required this.entries});
+
+  @override
+  int get hashCode => name.hashCode ^ entries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Playlist &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          entries == other.entries;
+}
+
+/// An entry of a playlist file.
+class PlaylistEntry {
+  /// Absolute path of the file, or the URL of a stream
+  final String location;
+  final String? title;
+  final String? artist;
+  final String? album;
+  final double? durationSecs;
+
+  const PlaylistEntry({
+    required this.location,
+    this.title,
+    this.artist,
+    this.album,
+    this.durationSecs,
+  });
+
+  @override
+  int get hashCode =>
+      location.hashCode ^
+      title.hashCode ^
+      artist.hashCode ^
+      album.hashCode ^
+      durationSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaylistEntry &&
+          runtimeType == other.runtimeType &&
+          location == other.location &&
+          title == other.title &&
+          artist == other.artist &&
+          album == other.album &&
+          durationSecs == other.durationSecs;
+}
+
+/// A playlist file matched to the library.
+class PlaylistImport {
+  final String? name;
+
+  /// Library paths of the entries that matched a song, in playlist order
+  final List<String> paths;
+
+  /// Entries that matched no song, e.g. streams and missing files
+  final List<UnresolvedEntry> unresolved;
+
+  const PlaylistImport({
+    this.name,
+    required this.paths,
+    required this.unresolved,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ paths.hashCode ^ unresolved.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaylistImport &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          paths == other.paths &&
+          unresolved == other.unresolved;
+}
+
+/// An entry of an imported playlist that no library song matched.
+class UnresolvedEntry {
+  /// Position of the entry in the playlist
+  final int index;
+  final PlaylistEntry entry;
+
+  const UnresolvedEntry({required this.index, required this.entry});
+
+  @override
+  int get hashCode => index.hashCode ^ entry.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UnresolvedEntry &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          entry == other.entry;
+}
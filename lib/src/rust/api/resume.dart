@@ -0,0 +1,15 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Where playback of the file at `path` was left off, if it was saved.
+Future<double?> getResumePosition({required String path}) =>
+    RustLib.instance.api.crateApiResumeGetResumePosition(path: path);
+
+/// Forget where playback of the file at `path` was left off.
+Future<void> clearResumePosition({required String path}) =>
+    RustLib.instance.api.crateApiResumeClearResumePosition(path: path);
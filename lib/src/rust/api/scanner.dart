@@ -6,33 +6,232 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `take`, `watch_stopped`
 
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ScanSlot`
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `drop`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Scan options when none are given: every supported extension, phone
+/// system sounds and app data excluded, marker files honored.
+ScanOptions scanDefaultOptions() =>
+    RustLib.instance.api.crateApiScannerScanDefaultOptions();
+
+/// Scan `root_path` for new, modified and deleted audio files.
+///
+/// When `art_cache_dir` is set, cover art is extracted into it as thumbnails
+/// and `album_art_path` points at them. Audio files that cannot be read are
+/// listed in `failed` rather than dropped. `options` defaults to
+/// [`scan_default_options`]; an invalid exclude pattern is an error.
 Future<ScanResult> scanRootDir({
   required String rootPath,
   required Map<String, PlatformInt64> knownFiles,
+  String? artCacheDir,
+  ScanOptions? options,
 }) => RustLib.instance.api.crateApiScannerScanRootDir(
   rootPath: rootPath,
   knownFiles: knownFiles,
+  artCacheDir: artCacheDir,
+  options: options,
 );
 
+/// Scan like [`scan_root_dir`], streaming results as they are read.
+///
+/// New and modified files arrive in batches together with running counts,
+/// so large libraries show progress immediately. The stream ends with a
+/// `Finished` event carrying the deleted and moved paths, or `Cancelled`
+/// after [`scan_cancel`]. Only one streamed scan can run at a time.
+Stream<ScanStreamEvent> scanRootDirStream({
+  required String rootPath,
+  required Map<String, PlatformInt64> knownFiles,
+  String? artCacheDir,
+  ScanOptions? options,
+}) => RustLib.instance.api.crateApiScannerScanRootDirStream(
+  rootPath: rootPath,
+  knownFiles: knownFiles,
+  artCacheDir: artCacheDir,
+  options: options,
+);
+
+/// Cancel the running streamed scan after its current batch.
+void scanCancel() => RustLib.instance.api.crateApiScannerScanCancel();
+
+/// Watch `roots` and stream library changes as they happen.
+///
+/// Bursts of activity, such as a folder being copied in, are reported once
+/// they settle, with changed files read like [`scan_root_dir`] reads them.
+/// The stream stays open until [`scan_watch_stop`] is called, Dart closes
+/// it, or another watch replaces it. Returns once the roots are watched,
+/// leaving the watch to a thread of its own; fails if a root cannot be
+/// watched.
+Stream<WatchEvent> scanWatch({
+  required List<String> roots,
+  String? artCacheDir,
+  ScanOptions? options,
+}) => RustLib.instance.api.crateApiScannerScanWatch(
+  roots: roots,
+  artCacheDir: artCacheDir,
+  options: options,
+);
+
+/// Stop the running watch.
+void scanWatchStop() => RustLib.instance.api.crateApiScannerScanWatchStop();
+
+/// Find files in `paths` that hold the same recording.
+///
+/// Files are compared by an acoustic fingerprint of their decoded audio,
+/// so copies in different formats or with different tags are found. Each
+/// group lists its copies lossless first, then by resolution or bitrate,
+/// and suggests the best one to keep. When `fingerprint_index` is set,
+/// fingerprints are kept in that file and only recomputed for changed
+/// files; it must not be the scanner's `ScanOptions::fingerprint_index`.
+Future<DuplicateReport> findDuplicates({
+  required List<String> paths,
+  String? fingerprintIndex,
+}) => RustLib.instance.api.crateApiScannerFindDuplicates(
+  paths: paths,
+  fingerprintIndex: fingerprintIndex,
+);
+
+/// Palette of the image at `image_path`.
+///
+/// Thumbnails from the scanner's art cache return their precomputed palette
+/// without decoding the image.
+Future<AlbumPalette?> albumPaletteFromImage({required String imagePath}) =>
+    RustLib.instance.api.crateApiScannerAlbumPaletteFromImage(
+      imagePath: imagePath,
+    );
+
+/// Palette of an audio file's embedded cover, or its folder image.
+Future<AlbumPalette?> albumPaletteFromAudioFile({required String audioPath}) =>
+    RustLib.instance.api.crateApiScannerAlbumPaletteFromAudioFile(
+      audioPath: audioPath,
+    );
+
+/// Colors for theming the player around a piece of album art.
+///
+/// Each color is packed as `0xAARRGGBB`, so Dart can use `Color(value)`.
+class AlbumPalette {
+  /// The color covering most of the image
+  final int dominant;
+
+  /// A saturated accent color
+  final int vibrant;
+
+  /// A desaturated color for backgrounds
+  final int muted;
+
+  /// Text color readable on `dominant` (7:1 contrast where possible)
+  final int text;
+
+  /// Dimmer text color with at least 4.5:1 contrast on `dominant`
+  final int secondaryText;
+
+  const AlbumPalette({
+    required this.dominant,
+    required this.vibrant,
+    required this.muted,
+    required this.text,
+    required this.secondaryText,
+  });
+
+  @override
+  int get hashCode =>
+      dominant.hashCode ^
+      vibrant.hashCode ^
+      muted.hashCode ^
+      text.hashCode ^
+      secondaryText.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AlbumPalette &&
+          runtimeType == other.runtimeType &&
+          dominant == other.dominant &&
+          vibrant == other.vibrant &&
+          muted == other.muted &&
+          text == other.text &&
+          secondaryText == other.secondaryText;
+}
+
 class AudioFileMetadata {
   final String path;
   final String? title;
   final String? artist;
   final String? album;
+  final String? albumArtist;
+  final int? trackNumber;
+  final int? trackTotal;
+  final int? discNumber;
+  final int? discTotal;
+  final int? year;
+  final String? genre;
+  final String? composer;
+
+  /// Part of a various-artists compilation
+  final bool compilation;
   final BigInt? durationSecs;
+
+  /// Sample rate in Hz
+  final int? sampleRate;
+
+  /// Bits per sample; only known for lossless formats
+  final int? bitDepth;
+
+  /// Audio bitrate in kbps
+  final int? bitrateKbps;
+  final int? channels;
+
+  /// The tag carries unsynchronized lyrics
+  final bool hasLyrics;
+  final String? musicbrainzRecordingId;
+  final String? musicbrainzReleaseId;
+  final String? musicbrainzReleaseGroupId;
+  final String? musicbrainzArtistId;
+  final String? musicbrainzReleaseArtistId;
+
+  /// Cached cover thumbnail, from embedded art or a folder image
+  final String? albumArtPath;
+
+  /// Colors of the album art, computed alongside the thumbnail
+  final AlbumPalette? albumPalette;
   final String format;
   final PlatformInt64 lastModified;
 
+  /// For a track of a CUE sheet, the part of the file it plays
+  final TrackRange? range;
+
   const AudioFileMetadata({
     required this.path,
     this.title,
     this.artist,
     this.album,
+    this.albumArtist,
+    this.trackNumber,
+    this.trackTotal,
+    this.discNumber,
+    this.discTotal,
+    this.year,
+    this.genre,
+    this.composer,
+    required this.compilation,
     this.durationSecs,
+    this.sampleRate,
+    this.bitDepth,
+    this.bitrateKbps,
+    this.channels,
+    required this.hasLyrics,
+    this.musicbrainzRecordingId,
+    this.musicbrainzReleaseId,
+    this.musicbrainzReleaseGroupId,
+    this.musicbrainzArtistId,
+    this.musicbrainzReleaseArtistId,
+    this.albumArtPath,
+    this.albumPalette,
     required this.format,
     required this.lastModified,
+    this.range,
   });
 
   @override
@@ -41,9 +240,31 @@ class AudioFileMetadata {
       title.hashCode ^
       artist.hashCode ^
       album.hashCode ^
+      albumArtist.hashCode ^
+      trackNumber.hashCode ^
+      trackTotal.hashCode ^
+      discNumber.hashCode ^
+      discTotal.hashCode ^
+      year.hashCode ^
+      genre.hashCode ^
+      composer.hashCode ^
+      compilation.hashCode ^
       durationSecs.hashCode ^
+      sampleRate.hashCode ^
+      bitDepth.hashCode ^
+      bitrateKbps.hashCode ^
+      channels.hashCode ^
+      hasLyrics.hashCode ^
+      musicbrainzRecordingId.hashCode ^
+      musicbrainzReleaseId.hashCode ^
+      musicbrainzReleaseGroupId.hashCode ^
+      musicbrainzArtistId.hashCode ^
+      musicbrainzReleaseArtistId.hashCode ^
+      albumArtPath.hashCode ^
+      albumPalette.hashCode ^
       format.hashCode ^
-      lastModified.hashCode;
+      lastModified.hashCode ^
+      range.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -54,19 +275,308 @@ class AudioFileMetadata {
           title == other.title &&
           artist == other.artist &&
           album == other.album &&
+          albumArtist == other.albumArtist &&
+          trackNumber == other.trackNumber &&
+          trackTotal == other.trackTotal &&
+          discNumber == other.discNumber &&
+          discTotal == other.discTotal &&
+          year == other.year &&
+          genre == other.genre &&
+          composer == other.composer &&
+          compilation == other.compilation &&
           durationSecs == other.durationSecs &&
+          sampleRate == other.sampleRate &&
+          bitDepth == other.bitDepth &&
+          bitrateKbps == other.bitrateKbps &&
+          channels == other.channels &&
+          hasLyrics == other.hasLyrics &&
+          musicbrainzRecordingId == other.musicbrainzRecordingId &&
+          musicbrainzReleaseId == other.musicbrainzReleaseId &&
+          musicbrainzReleaseGroupId == other.musicbrainzReleaseGroupId &&
+          musicbrainzArtistId == other.musicbrainzArtistId &&
+          musicbrainzReleaseArtistId == other.musicbrainzReleaseArtistId &&
+          albumArtPath == other.albumArtPath &&
+          albumPalette == other.albumPalette &&
+          format == other.format &&
+          lastModified == other.lastModified &&
+          range == other.range;
+}
+
+/// One copy of a recording, with what its quality was ranked by.
+class DuplicateFile {
+  final String path;
+  final String format;
+  final bool lossless;
+
+  /// Audio bitrate in kbps
+  final int? bitrateKbps;
+
+  /// Sample rate in Hz
+  final int? sampleRate;
+
+  /// Bits per sample; only known for lossless formats
+  final int? bitDepth;
+  final BigInt? durationSecs;
+
+  /// Acoustic similarity to the copy to keep (0.0 to 1.0)
+  final double similarity;
+
+  const DuplicateFile({
+    required this.path,
+    required this.format,
+    required this.lossless,
+    this.bitrateKbps,
+    this.sampleRate,
+    this.bitDepth,
+    this.durationSecs,
+    required this.similarity,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      format.hashCode ^
+      lossless.hashCode ^
+      bitrateKbps.hashCode ^
+      sampleRate.hashCode ^
+      bitDepth.hashCode ^
+      durationSecs.hashCode ^
+      similarity.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateFile &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
           format == other.format &&
-          lastModified == other.lastModified;
+          lossless == other.lossless &&
+          bitrateKbps == other.bitrateKbps &&
+          sampleRate == other.sampleRate &&
+          bitDepth == other.bitDepth &&
+          durationSecs == other.durationSecs &&
+          similarity == other.similarity;
+}
+
+/// Files holding the same recording.
+class DuplicateGroup {
+  /// The copies, best quality first
+  final List<DuplicateFile> files;
+
+  /// The suggested copy to keep, the first of `files`
+  final String keepPath;
+
+  const DuplicateGroup({required this.files, required this.keepPath});
+
+  @override
+  int get hashCode => files.hashCode ^ keepPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateGroup &&
+          runtimeType == other.runtimeType &&
+          files == other.files &&
+          keepPath == other.keepPath;
+}
+
+/// Files found by [`find_duplicates`].
+class DuplicateReport {
+  final List<DuplicateGroup> groups;
+
+  /// Files that could not be read or decoded
+  final List<ScanFailure> failed;
+
+  const DuplicateReport({required this.groups, required this.failed});
+
+  @override
+  int get hashCode => groups.hashCode ^ failed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateReport &&
+          runtimeType == other.runtimeType &&
+          groups == other.groups &&
+          failed == other.failed;
+}
+
+/// Stage of a streamed scan.
+enum ScanEventType {
+  /// Another batch of new or modified files
+  batch,
+
+  /// The walk is complete; `deleted_paths` and `moved` are filled in
+  finished,
+
+  /// The scan was cancelled; files already sent are valid
+  cancelled,
+}
+
+/// An audio file the scanner found but could not read.
+class ScanFailure {
+  final String path;
+  final ScanFailureType reason;
+
+  /// The underlying error message
+  final String detail;
+
+  const ScanFailure({
+    required this.path,
+    required this.reason,
+    required this.detail,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ reason.hashCode ^ detail.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanFailure &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          reason == other.reason &&
+          detail == other.detail;
+}
+
+/// Why a file could not be added to the library.
+enum ScanFailureType {
+  /// The file could not be opened or read
+  io,
+
+  /// The file is not in a container format that can be read
+  unsupportedContainer,
+
+  /// A tag in the file is malformed
+  badTag,
+
+  /// The audio stream is damaged or truncated
+  corrupt,
+
+  /// The file contains no audio
+  zeroDuration,
+}
+
+/// A known file that was renamed or moved, identified by its content.
+class ScanMove {
+  final String oldPath;
+  final String newPath;
+
+  const ScanMove({required this.oldPath, required this.newPath});
+
+  @override
+  int get hashCode => oldPath.hashCode ^ newPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanMove &&
+          runtimeType == other.runtimeType &&
+          oldPath == other.oldPath &&
+          newPath == other.newPath;
+}
+
+/// Which files the scanner picks up.
+class ScanOptions {
+  /// Lowercase extensions to read, without the dot
+  final List<String> extensions;
+
+  /// Also identify audio files with a wrong or missing extension from
+  /// their first bytes; slower, as other files have to be opened
+  final bool sniffContent;
+
+  /// Glob patterns, relative to the scanned folder, for files and folders
+  /// to skip (e.g. `**/Ringtones/**`); matched case-insensitively
+  final List<String> excludeGlobs;
+
+  /// Skip folders containing a `.nomedia` or `.ignore` file
+  final bool honorMarkerFiles;
+
+  /// Skip audio shorter than this many seconds
+  final int minDurationSecs;
+
+  /// Skip files smaller than this many bytes
+  final int minFileSize;
+
+  /// Folder levels to descend below the scanned folder; 0 reads only the
+  /// files directly inside it. Unlimited when unset
+  final int? maxDepth;
+
+  /// Follow symbolic links; links leading back into a folder being walked
+  /// are skipped
+  final bool followSymlinks;
+
+  /// File the scanner keeps content fingerprints in between scans. When
+  /// set, renamed and moved files are reported in `moved` rather than as a
+  /// delete plus an add, and files whose content is unchanged are not
+  /// read again just because their mtime moved
+  final String? fingerprintIndex;
+
+  const ScanOptions({
+    required this.extensions,
+    required this.sniffContent,
+    required this.excludeGlobs,
+    required this.honorMarkerFiles,
+    required this.minDurationSecs,
+    required this.minFileSize,
+    this.maxDepth,
+    required this.followSymlinks,
+    this.fingerprintIndex,
+  });
+
+  @override
+  int get hashCode =>
+      extensions.hashCode ^
+      sniffContent.hashCode ^
+      excludeGlobs.hashCode ^
+      honorMarkerFiles.hashCode ^
+      minDurationSecs.hashCode ^
+      minFileSize.hashCode ^
+      maxDepth.hashCode ^
+      followSymlinks.hashCode ^
+      fingerprintIndex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanOptions &&
+          runtimeType == other.runtimeType &&
+          extensions == other.extensions &&
+          sniffContent == other.sniffContent &&
+          excludeGlobs == other.excludeGlobs &&
+          honorMarkerFiles == other.honorMarkerFiles &&
+          minDurationSecs == other.minDurationSecs &&
+          minFileSize == other.minFileSize &&
+          maxDepth == other.maxDepth &&
+          followSymlinks == other.followSymlinks &&
+          fingerprintIndex == other.fingerprintIndex;
 }
 
 class ScanResult {
   final List<AudioFileMetadata> newOrModified;
   final List<String> deletedPaths;
 
-  const ScanResult({required this.newOrModified, required this.deletedPaths});
+  /// Known files found under a new path; only detected with a
+  /// fingerprint index
+  final List<ScanMove> moved;
+
+  /// Audio files that could not be read, with the reason
+  final List<ScanFailure> failed;
+
+  const ScanResult({
+    required this.newOrModified,
+    required this.deletedPaths,
+    required this.moved,
+    required this.failed,
+  });
 
   @override
-  int get hashCode => newOrModified.hashCode ^ deletedPaths.hashCode;
+  int get hashCode =>
+      newOrModified.hashCode ^
+      deletedPaths.hashCode ^
+      moved.hashCode ^
+      failed.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -74,5 +584,158 @@ class ScanResult {
       other is ScanResult &&
           runtimeType == other.runtimeType &&
           newOrModified == other.newOrModified &&
-          deletedPaths == other.deletedPaths;
+          deletedPaths == other.deletedPaths &&
+          moved == other.moved &&
+          failed == other.failed;
+}
+
+/// Progress of a streamed scan.
+///
+/// Counts are cumulative. `processed` includes unchanged files that needed
+/// no work, so it approaches `discovered` as the scan goes on.
+class ScanStreamEvent {
+  final ScanEventType kind;
+
+  /// New or modified files since the previous event
+  final List<AudioFileMetadata> files;
+
+  /// Files that could not be read since the previous event
+  final List<ScanFailure> failures;
+
+  /// Known files no longer on disk; only set on `Finished`
+  final List<String> deletedPaths;
+
+  /// Known files found under a new path; only set on `Finished`
+  final List<ScanMove> moved;
+
+  /// Audio files found so far
+  final int discovered;
+
+  /// Audio files handled so far
+  final int processed;
+
+  /// Audio files that could not be read
+  final int failed;
+
+  const ScanStreamEvent({
+    required this.kind,
+    required this.files,
+    required this.failures,
+    required this.deletedPaths,
+    required this.moved,
+    required this.discovered,
+    required this.processed,
+    required this.failed,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      files.hashCode ^
+      failures.hashCode ^
+      deletedPaths.hashCode ^
+      moved.hashCode ^
+      discovered.hashCode ^
+      processed.hashCode ^
+      failed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanStreamEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          files == other.files &&
+          failures == other.failures &&
+          deletedPaths == other.deletedPaths &&
+          moved == other.moved &&
+          discovered == other.discovered &&
+          processed == other.processed &&
+          failed == other.failed;
+}
+
+/// Where a track of a CUE sheet lies in its audio file.
+class TrackRange {
+  /// The audio file; the track's own path has its number appended
+  final String sourcePath;
+  final double startSecs;
+
+  /// `None` for the last track, which plays to the end of the file
+  final double? endSecs;
+
+  const TrackRange({
+    required this.sourcePath,
+    required this.startSecs,
+    this.endSecs,
+  });
+
+  @override
+  int get hashCode =>
+      sourcePath.hashCode ^
+      startSecs.hashCode ^
+      endSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrackRange &&
+          runtimeType == other.runtimeType &&
+          sourcePath == other.sourcePath &&
+          startSecs == other.startSecs &&
+          endSecs == other.endSecs;
+}
+
+/// Library changes picked up by [`scan_watch`] once a burst of file system
+/// activity has settled.
+class WatchEvent {
+  /// Files that appeared
+  final List<AudioFileMetadata> added;
+
+  /// Files whose content or location changed
+  final List<AudioFileMetadata> modified;
+
+  /// Removed files and folders; songs at or below these paths, and the
+  /// CUE tracks of these files, are gone. Apply these before `added` and
+  /// `modified`, which may list a file's new tracks
+  final List<String> removedPaths;
+
+  /// Files that were renamed or moved; apply these before `modified`
+  final List<ScanMove> moved;
+
+  /// Changed files that could not be read
+  final List<ScanFailure> failures;
+
+  /// Notifications were lost, e.g. to a full event queue, so only a full
+  /// scan can catch up
+  final bool rescanNeeded;
+
+  const WatchEvent({
+    required this.added,
+    required this.modified,
+    required this.removedPaths,
+    required this.moved,
+    required this.failures,
+    required this.rescanNeeded,
+  });
+
+  @override
+  int get hashCode =>
+      added.hashCode ^
+      modified.hashCode ^
+      removedPaths.hashCode ^
+      moved.hashCode ^
+      failures.hashCode ^
+      rescanNeeded.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatchEvent &&
+          runtimeType == other.runtimeType &&
+          added == other.added &&
+          modified == other.modified &&
+          removedPaths == other.removedPaths &&
+          moved == other.moved &&
+          failures == other.failures &&
+          rescanNeeded == other.rescanNeeded;
 }
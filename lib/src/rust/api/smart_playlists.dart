@@ -0,0 +1,243 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'library.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scanner.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// The songs of `playlist`, in its order.
+Future<List<LibrarySong>> smartPlaylistSongs({
+  required SmartPlaylist playlist,
+}) => RustLib.instance.api.crateApiSmartPlaylistsSmartPlaylistSongs(
+  playlist: playlist,
+);
+
+/// Stream the songs of `playlist` now and again whenever they change.
+///
+/// Rules on how long ago something happened are checked again on every
+/// change to the library, and every minute as time passes. Watching with a
+/// `key` already watched replaces that watch.
+Stream<List<LibrarySong>> smartPlaylistWatch({
+  required String key,
+  required SmartPlaylist playlist,
+}) => RustLib.instance.api.crateApiSmartPlaylistsSmartPlaylistWatch(
+  key: key,
+  playlist: playlist,
+);
+
+/// Stop the watch started with `key`, closing its stream.
+Future<void> smartPlaylistUnwatch({required String key}) =>
+    RustLib.instance.api.crateApiSmartPlaylistsSmartPlaylistUnwatch(key: key);
+
+/// `playlist` as JSON, for storing.
+String smartPlaylistToJson({required SmartPlaylist playlist}) =>
+    RustLib.instance.api.crateApiSmartPlaylistsSmartPlaylistToJson(
+      playlist: playlist,
+    );
+
+/// Read a playlist stored by [`smart_playlist_to_json`].
+SmartPlaylist smartPlaylistFromJson({required String json}) =>
+    RustLib.instance.api.crateApiSmartPlaylistsSmartPlaylistFromJson(
+      json: json,
+    );
+
+/// All songs, by title.
+SmartPlaylist smartPlaylistDefault() =>
+    RustLib.instance.api.crateApiSmartPlaylistsSmartPlaylistDefault();
+
+/// A test of one field of a song.
+class SmartCondition {
+  final SmartField field;
+  final SmartOperator operator_;
+
+  /// The value of text operators
+  final String text;
+
+  /// The value of number and date operators: a time in seconds since the
+  /// epoch for `Before` and `After`, a span of seconds for `InTheLast`
+  final double number;
+
+  const SmartCondition({
+    required this.field,
+    required this.operator_,
+    required this.text,
+    required this.number,
+  });
+
+  @override
+  int get hashCode =>
+      field.hashCode ^
+      operator_.hashCode ^
+      text.hashCode ^
+      number.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SmartCondition &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          operator_ == other.operator_ &&
+          text == other.text &&
+          number == other.number;
+}
+
+/// A song property a condition tests.
+enum SmartField {
+  /// The tagged title, or the one made from the file name
+  title,
+
+  /// The artist tag, or any artist it credits
+  artist,
+  album,
+  albumArtist,
+  genre,
+  composer,
+
+  /// The file format, e.g. "flac"
+  format,
+  path,
+  year,
+  trackNumber,
+  discNumber,
+  durationSecs,
+  sampleRate,
+  bitDepth,
+  bitrateKbps,
+  channels,
+  playCount,
+  dateAdded,
+  lastPlayed,
+
+  /// When the file was last modified
+  lastModified,
+  favorite,
+  compilation,
+  hasLyrics,
+}
+
+/// How a condition compares its field with its value.
+///
+/// Text comparisons ignore case and accents. A song missing the field only
+/// matches the negative operators: `IsNot`, `DoesNotContain`,
+/// `NotInTheLast` and `IsEmpty`.
+enum SmartOperator {
+  /// Text or number equal to the value
+  is_,
+  isNot,
+  contains,
+  doesNotContain,
+  startsWith,
+  endsWith,
+  greaterThan,
+  lessThan,
+
+  /// Date before the time in [`SmartCondition::number`]
+  before,
+  after,
+
+  /// Date within the last [`SmartCondition::number`] seconds
+  inTheLast,
+  notInTheLast,
+
+  /// Flag set
+  isTrue,
+  isFalse,
+
+  /// Text, number or date missing
+  isEmpty,
+  isNotEmpty,
+}
+
+/// A rule with the order and limits of the songs it selects.
+class SmartPlaylist {
+  final SmartRule rule;
+  final SmartSortType sort;
+  final bool descending;
+
+  /// Most songs to keep, in sort order
+  final int? limit;
+
+  /// Most total duration to keep, in sort order
+  final int? durationLimitSecs;
+
+  const SmartPlaylist({
+    required this.rule,
+    required this.sort,
+    required this.descending,
+    this.limit,
+    this.durationLimitSecs,
+  });
+
+  @override
+  int get hashCode =>
+      rule.hashCode ^
+      sort.hashCode ^
+      descending.hashCode ^
+      limit.hashCode ^
+      durationLimitSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SmartPlaylist &&
+          runtimeType == other.runtimeType &&
+          rule == other.rule &&
+          sort == other.sort &&
+          descending == other.descending &&
+          limit == other.limit &&
+          durationLimitSecs == other.durationLimitSecs;
+}
+
+/// A group of conditions and nested groups.
+class SmartRule {
+  /// Whether songs must match every condition and group, or any of them.
+  /// An empty group matches every song.
+  final bool matchAll;
+  final List<SmartCondition> conditions;
+  final List<SmartRule> groups;
+
+  const SmartRule({
+    required this.matchAll,
+    required this.conditions,
+    required this.groups,
+  });
+
+  @override
+  int get hashCode => matchAll.hashCode ^ conditions.hashCode ^ groups.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SmartRule &&
+          runtimeType == other.runtimeType &&
+          matchAll == other.matchAll &&
+          conditions == other.conditions &&
+          groups == other.groups;
+}
+
+/// Order of the songs of a smart playlist.
+enum SmartSortType {
+  title,
+
+  /// By artist, then album and track
+  artist,
+
+  /// By album, then disc and track
+  album,
+
+  /// By year, then album and track
+  year,
+  duration,
+  dateAdded,
+  playCount,
+  lastPlayed,
+
+  /// Shuffled, in an order that stays the same as the library changes
+  random,
+}
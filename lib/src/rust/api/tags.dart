@@ -0,0 +1,142 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// No changes, to set the fields to change on.
+TagChanges tagsDefaultChanges() =>
+    RustLib.instance.api.crateApiTagsTagsDefaultChanges();
+
+/// Apply `changes` to the tags of the audio file at `path`.
+///
+/// Every tag in the file is changed, and the format's own tag (ID3v2 for
+/// MP3, Vorbis comments for FLAC and Ogg, MP4 atoms, APE for Monkey's Audio
+/// and WavPack) is created if it is missing.
+Future<void> writeTags({required String path, required TagChanges changes}) =>
+    RustLib.instance.api.crateApiTagsWriteTags(path: path, changes: changes);
+
+/// Apply the same `changes` to every file in `paths`, e.g. the songs of an
+/// album. Files that fail are reported and the rest are still written.
+Future<List<TagWriteFailure>> writeTagsBatch({
+  required List<String> paths,
+  required TagChanges changes,
+}) => RustLib.instance.api.crateApiTagsWriteTagsBatch(
+  paths: paths,
+  changes: changes,
+);
+
+/// Changes to a file's tags. Unset fields are left as they are.
+class TagChanges {
+  final String? title;
+  final String? artist;
+  final String? album;
+  final String? albumArtist;
+  final int? trackNumber;
+  final int? trackTotal;
+  final int? discNumber;
+  final int? discTotal;
+  final int? year;
+  final String? genre;
+
+  /// Unsynchronized lyrics
+  final String? lyrics;
+
+  /// A JPEG, PNG, GIF, BMP or TIFF image to embed as the front cover
+  final Uint8List? coverArt;
+
+  /// Fields to remove, before the fields above are set
+  final List<TagField> remove;
+
+  const TagChanges({
+    this.title,
+    this.artist,
+    this.album,
+    this.albumArtist,
+    this.trackNumber,
+    this.trackTotal,
+    this.discNumber,
+    this.discTotal,
+    this.year,
+    this.genre,
+    this.lyrics,
+    this.coverArt,
+    required this.remove,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      artist.hashCode ^
+      album.hashCode ^
+      albumArtist.hashCode ^
+      trackNumber.hashCode ^
+      trackTotal.hashCode ^
+      discNumber.hashCode ^
+      discTotal.hashCode ^
+      year.hashCode ^
+      genre.hashCode ^
+      lyrics.hashCode ^
+      coverArt.hashCode ^
+      remove.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagChanges &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          artist == other.artist &&
+          album == other.album &&
+          albumArtist == other.albumArtist &&
+          trackNumber == other.trackNumber &&
+          trackTotal == other.trackTotal &&
+          discNumber == other.discNumber &&
+          discTotal == other.discTotal &&
+          year == other.year &&
+          genre == other.genre &&
+          lyrics == other.lyrics &&
+          coverArt == other.coverArt &&
+          remove == other.remove;
+}
+
+/// A tag field that can be removed.
+enum TagField {
+  title,
+  artist,
+  album,
+  albumArtist,
+  trackNumber,
+  trackTotal,
+  discNumber,
+  discTotal,
+  year,
+  genre,
+  lyrics,
+
+  /// Every embedded picture
+  coverArt,
+}
+
+/// A file [`write_tags_batch`] could not change.
+class TagWriteFailure {
+  final String path;
+  final String error;
+
+  const TagWriteFailure({required this.path, required this.error});
+
+  @override
+  int get hashCode => path.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagWriteFailure &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          error == other.error;
+}
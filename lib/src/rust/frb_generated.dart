@@ -4,8 +4,15 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/audio_api.dart';
+import 'api/chapters.dart';
+import 'api/library.dart';
+import 'api/lyrics.dart';
+import 'api/playlists.dart';
+import 'api/resume.dart';
 import 'api/scanner.dart';
 import 'api/simple.dart';
+import 'api/smart_playlists.dart';
+import 'api/tags.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -68,7 +75,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1169394639;
+  int get rustContentHash => -1313141232;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,10 +86,20 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<AlbumPalette?> crateApiScannerAlbumPaletteFromAudioFile({
+    required String audioPath,
+  });
+
+  Future<AlbumPalette?> crateApiScannerAlbumPaletteFromImage({
+    required String imagePath,
+  });
+
   BigInt? crateApiAudioApiAudioGetChannels();
 
   String? crateApiAudioApiAudioGetCurrentPath();
 
+  AudioOutputFormat? crateApiAudioApiAudioGetOutputFormat();
+
   double? crateApiAudioApiAudioGetPlaybackSpeed();
 
   AudioProgress? crateApiAudioApiAudioGetProgress();
@@ -97,14 +114,39 @@ abstract class RustLibApi extends BaseApi {
 
   bool crateApiAudioApiAudioIsNativeAvailable();
 
+  Future<void> crateApiAudioApiAudioNextChapter();
+
   Future<void> crateApiAudioApiAudioPause();
 
   Future<void> crateApiAudioApiAudioPlay({required String path});
 
+  Future<void> crateApiAudioApiAudioPlayRange({
+    required String path,
+    required double startSecs,
+    double? endSecs,
+  });
+
   AudioEventType? crateApiAudioApiAudioPollEvent();
 
+  Future<void> crateApiAudioApiAudioPreviousChapter();
+
   Future<void> crateApiAudioApiAudioQueueNext({required String path});
 
+  Future<void> crateApiAudioApiAudioQueueNextRange({
+    required String path,
+    required double startSecs,
+    double? endSecs,
+  });
+
+  void crateApiAudioApiAudioRenderCancel();
+
+  Stream<AudioRenderProgress> crateApiAudioApiAudioRenderToFile({
+    required List<String> paths,
+    required AudioRenderSettings settings,
+    required String outPath,
+    required RenderFileFormat format,
+  });
+
   Future<void> crateApiAudioApiAudioResume();
 
   Future<void> crateApiAudioApiAudioSeek({required double positionSecs});
@@ -118,8 +160,15 @@ abstract class RustLibApi extends BaseApi {
     required CrossfadeCurveType curve,
   });
 
+  Future<void> crateApiAudioApiAudioSetDither({required DitherType dither});
+
   Future<void> crateApiAudioApiAudioSetPlaybackSpeed({required double speed});
 
+  Future<void> crateApiAudioApiAudioSetResume({
+    required bool autoResume,
+    required double minDurationSecs,
+  });
+
   Future<void> crateApiAudioApiAudioSetVolume({required double volume});
 
   Future<void> crateApiAudioApiAudioShutdown();
@@ -128,13 +177,172 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiAudioApiAudioStop();
 
+  void crateApiAudioApiAudioTranscodeCancel();
+
+  Stream<AudioTranscodeEvent> crateApiAudioApiAudioTranscodeFiles({
+    required List<AudioTranscodeJob> jobs,
+    required AudioTranscodeSettings settings,
+  });
+
+  Future<void> crateApiResumeClearResumePosition({required String path});
+
+  Future<DuplicateReport> crateApiScannerFindDuplicates({
+    required List<String> paths,
+    String? fingerprintIndex,
+  });
+
+  Future<List<Chapter>> crateApiChaptersGetChapters({required String path});
+
+  Future<Lyrics?> crateApiLyricsGetLyrics({required String path});
+
+  Future<double?> crateApiResumeGetResumePosition({required String path});
+
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
 
+  Future<void> crateApiLibraryLibraryAddFolder({required String path});
+
+  Future<AlbumPage> crateApiLibraryLibraryAlbums({required GroupQuery query});
+
+  Future<GroupPage> crateApiLibraryLibraryArtists({required GroupQuery query});
+
+  GroupQuery crateApiLibraryLibraryDefaultGroupQuery();
+
+  GroupingOptions crateApiLibraryLibraryDefaultGroupingOptions();
+
+  SongQuery crateApiLibraryLibraryDefaultSongQuery();
+
+  Future<List<LibraryFolder>> crateApiLibraryLibraryFolders();
+
+  Future<GroupPage> crateApiLibraryLibraryGenres({required GroupQuery query});
+
+  Future<GroupingOptions> crateApiLibraryLibraryGroupingOptions();
+
+  Future<void> crateApiLibraryLibraryOpen({required String dbPath});
+
+  Future<void> crateApiLibraryLibraryRecordPlay({required String path});
+
+  Future<void> crateApiLibraryLibraryRemoveFolder({required String path});
+
+  Stream<ScanStreamEvent> crateApiLibraryLibraryScanFolder({
+    required String rootPath,
+    String? artCacheDir,
+    ScanOptions? options,
+  });
+
+  Future<List<LibrarySong>> crateApiLibraryLibrarySearch({
+    required String query,
+    required int limit,
+  });
+
+  Future<void> crateApiLibraryLibrarySetFavorite({
+    required String path,
+    required bool favorite,
+  });
+
+  Future<void> crateApiLibraryLibrarySetGroupingOptions({
+    required GroupingOptions options,
+  });
+
+  Future<LibrarySong?> crateApiLibraryLibrarySong({required String path});
+
+  Future<SongPage> crateApiLibraryLibrarySongs({required SongQuery query});
+
+  Stream<WatchEvent> crateApiLibraryLibraryWatch({
+    String? artCacheDir,
+    ScanOptions? options,
+  });
+
+  int? crateApiLyricsLyricsActiveLine({
+    required Lyrics lyrics,
+    required double positionSecs,
+  });
+
+  Future<Playlist> crateApiPlaylistsPlaylistDefault();
+
+  Future<PlaylistEntry> crateApiPlaylistsPlaylistEntryDefault();
+
+  Future<void> crateApiPlaylistsPlaylistExport({
+    required String path,
+    String? name,
+    required List<String> songPaths,
+    required bool relativePaths,
+  });
+
+  Future<PlaylistImport> crateApiPlaylistsPlaylistImport({
+    required String path,
+  });
+
+  Future<Playlist> crateApiPlaylistsPlaylistRead({required String path});
+
+  Future<void> crateApiPlaylistsPlaylistWrite({
+    required String path,
+    required Playlist playlist,
+    required bool relativePaths,
+  });
+
+  void crateApiScannerScanCancel();
+
+  ScanOptions crateApiScannerScanDefaultOptions();
+
   Future<ScanResult> crateApiScannerScanRootDir({
     required String rootPath,
     required Map<String, PlatformInt64> knownFiles,
+    String? artCacheDir,
+    ScanOptions? options,
+  });
+
+  Stream<ScanStreamEvent> crateApiScannerScanRootDirStream({
+    required String rootPath,
+    required Map<String, PlatformInt64> knownFiles,
+    String? artCacheDir,
+    ScanOptions? options,
+  });
+
+  Stream<WatchEvent> crateApiScannerScanWatch({
+    required List<String> roots,
+    String? artCacheDir,
+    ScanOptions? options,
+  });
+
+  void crateApiScannerScanWatchStop();
+
+  SmartPlaylist crateApiSmartPlaylistsSmartPlaylistDefault();
+
+  SmartPlaylist crateApiSmartPlaylistsSmartPlaylistFromJson({
+    required String json,
+  });
+
+  Future<List<LibrarySong>> crateApiSmartPlaylistsSmartPlaylistSongs({
+    required SmartPlaylist playlist,
+  });
+
+  String crateApiSmartPlaylistsSmartPlaylistToJson({
+    required SmartPlaylist playlist,
+  });
+
+  Future<void> crateApiSmartPlaylistsSmartPlaylistUnwatch({
+    required String key,
+  });
+
+  Stream<List<LibrarySong>> crateApiSmartPlaylistsSmartPlaylistWatch({
+    required String key,
+    required SmartPlaylist playlist,
+  });
+
+  Future<TagChanges> crateApiTagsTagChangesDefault();
+
+  TagChanges crateApiTagsTagsDefaultChanges();
+
+  Future<void> crateApiTagsWriteTags({
+    required String path,
+    required TagChanges changes,
+  });
+
+  Future<List<TagWriteFailure>> crateApiTagsWriteTagsBatch({
+    required List<String> paths,
+    required TagChanges changes,
   });
 }
 
@@ -146,13 +354,79 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  Future<AlbumPalette?> crateApiScannerAlbumPaletteFromAudioFile({
+    required String audioPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(audioPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_album_palette,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScannerAlbumPaletteFromAudioFileConstMeta,
+        argValues: [audioPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerAlbumPaletteFromAudioFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "album_palette_from_audio_file",
+        argNames: ["audioPath"],
+      );
+
+  @override
+  Future<AlbumPalette?> crateApiScannerAlbumPaletteFromImage({
+    required String imagePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(imagePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_album_palette,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScannerAlbumPaletteFromImageConstMeta,
+        argValues: [imagePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerAlbumPaletteFromImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "album_palette_from_image",
+        argNames: ["imagePath"],
+      );
+
   @override
   BigInt? crateApiAudioApiAudioGetChannels() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_usize,
@@ -174,7 +448,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_String,
@@ -190,13 +464,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiAudioApiAudioGetCurrentPathConstMeta =>
       const TaskConstMeta(debugName: "audio_get_current_path", argNames: []);
 
+  @override
+  AudioOutputFormat? crateApiAudioApiAudioGetOutputFormat() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_audio_output_format,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAudioApiAudioGetOutputFormatConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioGetOutputFormatConstMeta =>
+      const TaskConstMeta(debugName: "audio_get_output_format", argNames: []);
+
   @override
   double? crateApiAudioApiAudioGetPlaybackSpeed() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_f_32,
@@ -218,7 +514,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_audio_progress,
@@ -240,7 +536,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
@@ -262,7 +558,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -284,7 +580,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -306,7 +602,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -328,7 +624,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -344,6 +640,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiAudioApiAudioIsNativeAvailableConstMeta =>
       const TaskConstMeta(debugName: "audio_is_native_available", argNames: []);
 
+  @override
+  Future<void> crateApiAudioApiAudioNextChapter() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAudioApiAudioNextChapterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioNextChapterConstMeta =>
+      const TaskConstMeta(debugName: "audio_next_chapter", argNames: []);
+
   @override
   Future<void> crateApiAudioApiAudioPause() {
     return handler.executeNormal(
@@ -353,7 +676,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 14,
             port: port_,
           );
         },
@@ -381,7 +704,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 15,
             port: port_,
           );
         },
@@ -399,13 +722,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiAudioApiAudioPlayConstMeta =>
       const TaskConstMeta(debugName: "audio_play", argNames: ["path"]);
 
+  @override
+  Future<void> crateApiAudioApiAudioPlayRange({
+    required String path,
+    required double startSecs,
+    double? endSecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_f_64(startSecs, serializer);
+          sse_encode_opt_box_autoadd_f_64(endSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAudioApiAudioPlayRangeConstMeta,
+        argValues: [path, startSecs, endSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioPlayRangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "audio_play_range",
+        argNames: ["path", "startSecs", "endSecs"],
+      );
+
   @override
   AudioEventType? crateApiAudioApiAudioPollEvent() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_audio_event_type,
@@ -422,16 +782,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "audio_poll_event", argNames: []);
 
   @override
-  Future<void> crateApiAudioApiAudioQueueNext({required String path}) {
+  Future<void> crateApiAudioApiAudioPreviousChapter() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 18,
             port: port_,
           );
         },
@@ -439,26 +798,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiAudioApiAudioQueueNextConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiAudioApiAudioPreviousChapterConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAudioApiAudioQueueNextConstMeta =>
-      const TaskConstMeta(debugName: "audio_queue_next", argNames: ["path"]);
+  TaskConstMeta get kCrateApiAudioApiAudioPreviousChapterConstMeta =>
+      const TaskConstMeta(debugName: "audio_previous_chapter", argNames: []);
 
   @override
-  Future<void> crateApiAudioApiAudioResume() {
+  Future<void> crateApiAudioApiAudioQueueNext({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 19,
             port: port_,
           );
         },
@@ -466,27 +826,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiAudioApiAudioResumeConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAudioApiAudioQueueNextConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAudioApiAudioResumeConstMeta =>
-      const TaskConstMeta(debugName: "audio_resume", argNames: []);
+  TaskConstMeta get kCrateApiAudioApiAudioQueueNextConstMeta =>
+      const TaskConstMeta(debugName: "audio_queue_next", argNames: ["path"]);
 
   @override
-  Future<void> crateApiAudioApiAudioSeek({required double positionSecs}) {
+  Future<void> crateApiAudioApiAudioQueueNextRange({
+    required String path,
+    required double startSecs,
+    double? endSecs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_f_64(positionSecs, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_f_64(startSecs, serializer);
+          sse_encode_opt_box_autoadd_f_64(endSecs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 20,
             port: port_,
           );
         },
@@ -494,22 +860,146 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiAudioApiAudioSeekConstMeta,
-        argValues: [positionSecs],
+        constMeta: kCrateApiAudioApiAudioQueueNextRangeConstMeta,
+        argValues: [path, startSecs, endSecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAudioApiAudioSeekConstMeta =>
-      const TaskConstMeta(debugName: "audio_seek", argNames: ["positionSecs"]);
+  TaskConstMeta get kCrateApiAudioApiAudioQueueNextRangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "audio_queue_next_range",
+        argNames: ["path", "startSecs", "endSecs"],
+      );
 
   @override
-  Future<void> crateApiAudioApiAudioSetCrossfade({
-    required bool enabled,
-    required double durationSecs,
-  }) {
-    return handler.executeNormal(
+  void crateApiAudioApiAudioRenderCancel() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAudioApiAudioRenderCancelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioRenderCancelConstMeta =>
+      const TaskConstMeta(debugName: "audio_render_cancel", argNames: []);
+
+  @override
+  Stream<AudioRenderProgress> crateApiAudioApiAudioRenderToFile({
+    required List<String> paths,
+    required AudioRenderSettings settings,
+    required String outPath,
+    required RenderFileFormat format,
+  }) {
+    final sink = RustStreamSink<AudioRenderProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(paths, serializer);
+            sse_encode_box_autoadd_audio_render_settings(settings, serializer);
+            sse_encode_String(outPath, serializer);
+            sse_encode_render_file_format(format, serializer);
+            sse_encode_StreamSink_audio_render_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 22,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiAudioApiAudioRenderToFileConstMeta,
+          argValues: [paths, settings, outPath, format, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioRenderToFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "audio_render_to_file",
+        argNames: ["paths", "settings", "outPath", "format", "sink"],
+      );
+
+  @override
+  Future<void> crateApiAudioApiAudioResume() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAudioApiAudioResumeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioResumeConstMeta =>
+      const TaskConstMeta(debugName: "audio_resume", argNames: []);
+
+  @override
+  Future<void> crateApiAudioApiAudioSeek({required double positionSecs}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_f_64(positionSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAudioApiAudioSeekConstMeta,
+        argValues: [positionSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioSeekConstMeta =>
+      const TaskConstMeta(debugName: "audio_seek", argNames: ["positionSecs"]);
+
+  @override
+  Future<void> crateApiAudioApiAudioSetCrossfade({
+    required bool enabled,
+    required double durationSecs,
+  }) {
+    return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -518,7 +1008,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 25,
             port: port_,
           );
         },
@@ -551,7 +1041,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 26,
             port: port_,
           );
         },
@@ -572,6 +1062,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["curve"],
       );
 
+  @override
+  Future<void> crateApiAudioApiAudioSetDither({required DitherType dither}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_dither_type(dither, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAudioApiAudioSetDitherConstMeta,
+        argValues: [dither],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioSetDitherConstMeta =>
+      const TaskConstMeta(debugName: "audio_set_dither", argNames: ["dither"]);
+
   @override
   Future<void> crateApiAudioApiAudioSetPlaybackSpeed({required double speed}) {
     return handler.executeNormal(
@@ -582,7 +1100,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 28,
             port: port_,
           );
         },
@@ -603,6 +1121,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["speed"],
       );
 
+  @override
+  Future<void> crateApiAudioApiAudioSetResume({
+    required bool autoResume,
+    required double minDurationSecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(autoResume, serializer);
+          sse_encode_f_64(minDurationSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAudioApiAudioSetResumeConstMeta,
+        argValues: [autoResume, minDurationSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioSetResumeConstMeta =>
+      const TaskConstMeta(
+        debugName: "audio_set_resume",
+        argNames: ["autoResume", "minDurationSecs"],
+      );
+
   @override
   Future<void> crateApiAudioApiAudioSetVolume({required double volume}) {
     return handler.executeNormal(
@@ -613,7 +1166,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 30,
             port: port_,
           );
         },
@@ -640,7 +1193,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 31,
             port: port_,
           );
         },
@@ -667,7 +1220,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 32,
             port: port_,
           );
         },
@@ -694,7 +1247,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 33,
             port: port_,
           );
         },
@@ -713,866 +1266,5750 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "audio_stop", argNames: []);
 
   @override
-  String crateApiSimpleGreet({required String name}) {
+  void crateApiAudioApiAudioTranscodeCancel() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiAudioApiAudioTranscodeCancelConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiAudioApiAudioTranscodeCancelConstMeta =>
+      const TaskConstMeta(debugName: "audio_transcode_cancel", argNames: []);
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Stream<AudioTranscodeEvent> crateApiAudioApiAudioTranscodeFiles({
+    required List<AudioTranscodeJob> jobs,
+    required AudioTranscodeSettings settings,
+  }) {
+    final sink = RustStreamSink<AudioTranscodeEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_audio_transcode_job(jobs, serializer);
+            sse_encode_box_autoadd_audio_transcode_settings(
+              settings,
+              serializer,
+            );
+            sse_encode_StreamSink_audio_transcode_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 35,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiAudioApiAudioTranscodeFilesConstMeta,
+          argValues: [jobs, settings, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAudioApiAudioTranscodeFilesConstMeta =>
+      const TaskConstMeta(
+        debugName: "audio_transcode_files",
+        argNames: ["jobs", "settings", "sink"],
+      );
+
+  @override
+  Future<void> crateApiResumeClearResumePosition({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiResumeClearResumePositionConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiResumeClearResumePositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_resume_position",
+        argNames: ["path"],
+      );
 
   @override
-  Future<ScanResult> crateApiScannerScanRootDir({
-    required String rootPath,
-    required Map<String, PlatformInt64> knownFiles,
+  Future<DuplicateReport> crateApiScannerFindDuplicates({
+    required List<String> paths,
+    String? fingerprintIndex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(rootPath, serializer);
-          sse_encode_Map_String_i_64_None(knownFiles, serializer);
+          sse_encode_list_String(paths, serializer);
+          sse_encode_opt_String(fingerprintIndex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 37,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_scan_result,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_duplicate_report,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiScannerScanRootDirConstMeta,
-        argValues: [rootPath, knownFiles],
+        constMeta: kCrateApiScannerFindDuplicatesConstMeta,
+        argValues: [paths, fingerprintIndex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiScannerScanRootDirConstMeta => const TaskConstMeta(
-    debugName: "scan_root_dir",
-    argNames: ["rootPath", "knownFiles"],
-  );
+  TaskConstMeta get kCrateApiScannerFindDuplicatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_duplicates",
+        argNames: ["paths", "fingerprintIndex"],
+      );
 
-  @protected
-  Map<String, PlatformInt64> dco_decode_Map_String_i_64_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_i_64(raw).map((e) => MapEntry(e.$1, e.$2)),
+  @override
+  Future<List<Chapter>> crateApiChaptersGetChapters({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_chapter,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChaptersGetChaptersConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiChaptersGetChaptersConstMeta =>
+      const TaskConstMeta(debugName: "get_chapters", argNames: ["path"]);
 
-  @protected
-  AudioEventType dco_decode_audio_event_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return AudioEventType_StateChanged(state: dco_decode_String(raw[1]));
-      case 1:
-        return AudioEventType_Progress(
-          positionSecs: dco_decode_f_64(raw[1]),
-          durationSecs: dco_decode_opt_box_autoadd_f_64(raw[2]),
-          bufferLevel: dco_decode_f_32(raw[3]),
-        );
-      case 2:
-        return AudioEventType_TrackEnded(path: dco_decode_String(raw[1]));
-      case 3:
-        return AudioEventType_CrossfadeStarted(
-          fromPath: dco_decode_String(raw[1]),
-          toPath: dco_decode_String(raw[2]),
-        );
-      case 4:
-        return AudioEventType_Error(message: dco_decode_String(raw[1]));
-      case 5:
-        return AudioEventType_NextTrackReady(path: dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
+  @override
+  Future<Lyrics?> crateApiLyricsGetLyrics({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyrics,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLyricsGetLyricsConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
+  TaskConstMeta get kCrateApiLyricsGetLyricsConstMeta =>
+      const TaskConstMeta(debugName: "get_lyrics", argNames: ["path"]);
+
+  @override
+  Future<double?> crateApiResumeGetResumePosition({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_f_64,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiResumeGetResumePositionConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResumeGetResumePositionConstMeta =>
+      const TaskConstMeta(debugName: "get_resume_position", argNames: ["path"]);
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiLibraryLibraryAddFolder({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryAddFolderConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryAddFolderConstMeta =>
+      const TaskConstMeta(debugName: "library_add_folder", argNames: ["path"]);
+
+  @override
+  Future<AlbumPage> crateApiLibraryLibraryAlbums({required GroupQuery query}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_group_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_album_page,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryAlbumsConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryAlbumsConstMeta =>
+      const TaskConstMeta(debugName: "library_albums", argNames: ["query"]);
+
+  @override
+  Future<GroupPage> crateApiLibraryLibraryArtists({required GroupQuery query}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_group_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_page,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryArtistsConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryArtistsConstMeta =>
+      const TaskConstMeta(debugName: "library_artists", argNames: ["query"]);
+
+  @override
+  GroupQuery crateApiLibraryLibraryDefaultGroupQuery() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_query,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLibraryLibraryDefaultGroupQueryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryDefaultGroupQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_default_group_query",
+        argNames: [],
+      );
+
+  @override
+  GroupingOptions crateApiLibraryLibraryDefaultGroupingOptions() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_grouping_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLibraryLibraryDefaultGroupingOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryDefaultGroupingOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_default_grouping_options",
+        argNames: [],
+      );
+
+  @override
+  SongQuery crateApiLibraryLibraryDefaultSongQuery() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_song_query,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLibraryLibraryDefaultSongQueryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryDefaultSongQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_default_song_query",
+        argNames: [],
+      );
+
+  @override
+  Future<List<LibraryFolder>> crateApiLibraryLibraryFolders() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_library_folder,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryFoldersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryFoldersConstMeta =>
+      const TaskConstMeta(debugName: "library_folders", argNames: []);
+
+  @override
+  Future<GroupPage> crateApiLibraryLibraryGenres({required GroupQuery query}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_group_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_page,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryGenresConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryGenresConstMeta =>
+      const TaskConstMeta(debugName: "library_genres", argNames: ["query"]);
+
+  @override
+  Future<GroupingOptions> crateApiLibraryLibraryGroupingOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_grouping_options,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryGroupingOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryGroupingOptionsConstMeta =>
+      const TaskConstMeta(debugName: "library_grouping_options", argNames: []);
+
+  @override
+  Future<void> crateApiLibraryLibraryOpen({required String dbPath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dbPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryOpenConstMeta,
+        argValues: [dbPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryOpenConstMeta =>
+      const TaskConstMeta(debugName: "library_open", argNames: ["dbPath"]);
+
+  @override
+  Future<void> crateApiLibraryLibraryRecordPlay({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryRecordPlayConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryRecordPlayConstMeta =>
+      const TaskConstMeta(debugName: "library_record_play", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiLibraryLibraryRemoveFolder({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibraryRemoveFolderConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryRemoveFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_remove_folder",
+        argNames: ["path"],
+      );
+
+  @override
+  Stream<ScanStreamEvent> crateApiLibraryLibraryScanFolder({
+    required String rootPath,
+    String? artCacheDir,
+    ScanOptions? options,
+  }) {
+    final sink = RustStreamSink<ScanStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(rootPath, serializer);
+            sse_encode_opt_String(artCacheDir, serializer);
+            sse_encode_opt_box_autoadd_scan_options(options, serializer);
+            sse_encode_StreamSink_scan_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 55,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiLibraryLibraryScanFolderConstMeta,
+          argValues: [rootPath, artCacheDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryScanFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_scan_folder",
+        argNames: ["rootPath", "artCacheDir", "options", "sink"],
+      );
+
+  @override
+  Future<List<LibrarySong>> crateApiLibraryLibrarySearch({
+    required String query,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_library_song,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibrarySearchConstMeta,
+        argValues: [query, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibrarySearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_search",
+        argNames: ["query", "limit"],
+      );
+
+  @override
+  Future<void> crateApiLibraryLibrarySetFavorite({
+    required String path,
+    required bool favorite,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_bool(favorite, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibrarySetFavoriteConstMeta,
+        argValues: [path, favorite],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibrarySetFavoriteConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_set_favorite",
+        argNames: ["path", "favorite"],
+      );
+
+  @override
+  Future<void> crateApiLibraryLibrarySetGroupingOptions({
+    required GroupingOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_grouping_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibrarySetGroupingOptionsConstMeta,
+        argValues: [options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibrarySetGroupingOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_set_grouping_options",
+        argNames: ["options"],
+      );
+
+  @override
+  Future<LibrarySong?> crateApiLibraryLibrarySong({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_library_song,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibrarySongConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibrarySongConstMeta =>
+      const TaskConstMeta(debugName: "library_song", argNames: ["path"]);
+
+  @override
+  Future<SongPage> crateApiLibraryLibrarySongs({required SongQuery query}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_song_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_song_page,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiLibraryLibrarySongsConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibrarySongsConstMeta =>
+      const TaskConstMeta(debugName: "library_songs", argNames: ["query"]);
+
+  @override
+  Stream<WatchEvent> crateApiLibraryLibraryWatch({
+    String? artCacheDir,
+    ScanOptions? options,
+  }) {
+    final sink = RustStreamSink<WatchEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_opt_String(artCacheDir, serializer);
+            sse_encode_opt_box_autoadd_scan_options(options, serializer);
+            sse_encode_StreamSink_watch_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 61,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiLibraryLibraryWatchConstMeta,
+          argValues: [artCacheDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiLibraryLibraryWatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "library_watch",
+        argNames: ["artCacheDir", "options", "sink"],
+      );
+
+  @override
+  int? crateApiLyricsLyricsActiveLine({
+    required Lyrics lyrics,
+    required double positionSecs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_lyrics(lyrics, serializer);
+          sse_encode_f_64(positionSecs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLyricsLyricsActiveLineConstMeta,
+        argValues: [lyrics, positionSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLyricsLyricsActiveLineConstMeta =>
+      const TaskConstMeta(
+        debugName: "lyrics_active_line",
+        argNames: ["lyrics", "positionSecs"],
+      );
+
+  @override
+  Future<Playlist> crateApiPlaylistsPlaylistDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_playlist,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPlaylistsPlaylistDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlaylistsPlaylistDefaultConstMeta =>
+      const TaskConstMeta(debugName: "playlist_default", argNames: []);
+
+  @override
+  Future<PlaylistEntry> crateApiPlaylistsPlaylistEntryDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_playlist_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPlaylistsPlaylistEntryDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlaylistsPlaylistEntryDefaultConstMeta =>
+      const TaskConstMeta(debugName: "playlist_entry_default", argNames: []);
+
+  @override
+  Future<void> crateApiPlaylistsPlaylistExport({
+    required String path,
+    String? name,
+    required List<String> songPaths,
+    required bool relativePaths,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(name, serializer);
+          sse_encode_list_String(songPaths, serializer);
+          sse_encode_bool(relativePaths, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPlaylistsPlaylistExportConstMeta,
+        argValues: [path, name, songPaths, relativePaths],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlaylistsPlaylistExportConstMeta =>
+      const TaskConstMeta(
+        debugName: "playlist_export",
+        argNames: ["path", "name", "songPaths", "relativePaths"],
+      );
+
+  @override
+  Future<PlaylistImport> crateApiPlaylistsPlaylistImport({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_playlist_import,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPlaylistsPlaylistImportConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlaylistsPlaylistImportConstMeta =>
+      const TaskConstMeta(debugName: "playlist_import", argNames: ["path"]);
+
+  @override
+  Future<Playlist> crateApiPlaylistsPlaylistRead({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_playlist,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPlaylistsPlaylistReadConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlaylistsPlaylistReadConstMeta =>
+      const TaskConstMeta(debugName: "playlist_read", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiPlaylistsPlaylistWrite({
+    required String path,
+    required Playlist playlist,
+    required bool relativePaths,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_playlist(playlist, serializer);
+          sse_encode_bool(relativePaths, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPlaylistsPlaylistWriteConstMeta,
+        argValues: [path, playlist, relativePaths],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlaylistsPlaylistWriteConstMeta =>
+      const TaskConstMeta(
+        debugName: "playlist_write",
+        argNames: ["path", "playlist", "relativePaths"],
+      );
+
+  @override
+  void crateApiScannerScanCancel() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScannerScanCancelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerScanCancelConstMeta =>
+      const TaskConstMeta(debugName: "scan_cancel", argNames: []);
+
+  @override
+  ScanOptions crateApiScannerScanDefaultOptions() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scan_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScannerScanDefaultOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerScanDefaultOptionsConstMeta =>
+      const TaskConstMeta(debugName: "scan_default_options", argNames: []);
+
+  @override
+  Future<ScanResult> crateApiScannerScanRootDir({
+    required String rootPath,
+    required Map<String, PlatformInt64> knownFiles,
+    String? artCacheDir,
+    ScanOptions? options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(rootPath, serializer);
+          sse_encode_Map_String_i_64_None(knownFiles, serializer);
+          sse_encode_opt_String(artCacheDir, serializer);
+          sse_encode_opt_box_autoadd_scan_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scan_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiScannerScanRootDirConstMeta,
+        argValues: [rootPath, knownFiles, artCacheDir, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerScanRootDirConstMeta => const TaskConstMeta(
+    debugName: "scan_root_dir",
+    argNames: ["rootPath", "knownFiles", "artCacheDir", "options"],
+  );
+
+  @override
+  Stream<ScanStreamEvent> crateApiScannerScanRootDirStream({
+    required String rootPath,
+    required Map<String, PlatformInt64> knownFiles,
+    String? artCacheDir,
+    ScanOptions? options,
+  }) {
+    final sink = RustStreamSink<ScanStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(rootPath, serializer);
+            sse_encode_Map_String_i_64_None(knownFiles, serializer);
+            sse_encode_opt_String(artCacheDir, serializer);
+            sse_encode_opt_box_autoadd_scan_options(options, serializer);
+            sse_encode_StreamSink_scan_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 72,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiScannerScanRootDirStreamConstMeta,
+          argValues: [rootPath, knownFiles, artCacheDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiScannerScanRootDirStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_root_dir_stream",
+        argNames: ["rootPath", "knownFiles", "artCacheDir", "options", "sink"],
+      );
+
+  @override
+  Stream<WatchEvent> crateApiScannerScanWatch({
+    required List<String> roots,
+    String? artCacheDir,
+    ScanOptions? options,
+  }) {
+    final sink = RustStreamSink<WatchEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(roots, serializer);
+            sse_encode_opt_String(artCacheDir, serializer);
+            sse_encode_opt_box_autoadd_scan_options(options, serializer);
+            sse_encode_StreamSink_watch_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 73,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiScannerScanWatchConstMeta,
+          argValues: [roots, artCacheDir, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiScannerScanWatchConstMeta => const TaskConstMeta(
+    debugName: "scan_watch",
+    argNames: ["roots", "artCacheDir", "options", "sink"],
+  );
+
+  @override
+  void crateApiScannerScanWatchStop() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScannerScanWatchStopConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerScanWatchStopConstMeta =>
+      const TaskConstMeta(debugName: "scan_watch_stop", argNames: []);
+
+  @override
+  SmartPlaylist crateApiSmartPlaylistsSmartPlaylistDefault() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_smart_playlist,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSmartPlaylistsSmartPlaylistDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistsSmartPlaylistDefaultConstMeta =>
+      const TaskConstMeta(debugName: "smart_playlist_default", argNames: []);
+
+  @override
+  SmartPlaylist crateApiSmartPlaylistsSmartPlaylistFromJson({
+    required String json,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_smart_playlist,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSmartPlaylistsSmartPlaylistFromJsonConstMeta,
+        argValues: [json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistsSmartPlaylistFromJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "smart_playlist_from_json",
+        argNames: ["json"],
+      );
+
+  @override
+  Future<List<LibrarySong>> crateApiSmartPlaylistsSmartPlaylistSongs({
+    required SmartPlaylist playlist,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_smart_playlist(playlist, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_library_song,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSmartPlaylistsSmartPlaylistSongsConstMeta,
+        argValues: [playlist],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistsSmartPlaylistSongsConstMeta =>
+      const TaskConstMeta(
+        debugName: "smart_playlist_songs",
+        argNames: ["playlist"],
+      );
+
+  @override
+  String crateApiSmartPlaylistsSmartPlaylistToJson({
+    required SmartPlaylist playlist,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_smart_playlist(playlist, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSmartPlaylistsSmartPlaylistToJsonConstMeta,
+        argValues: [playlist],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistsSmartPlaylistToJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "smart_playlist_to_json",
+        argNames: ["playlist"],
+      );
+
+  @override
+  Future<void> crateApiSmartPlaylistsSmartPlaylistUnwatch({
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSmartPlaylistsSmartPlaylistUnwatchConstMeta,
+        argValues: [key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistsSmartPlaylistUnwatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "smart_playlist_unwatch",
+        argNames: ["key"],
+      );
+
+  @override
+  Stream<List<LibrarySong>> crateApiSmartPlaylistsSmartPlaylistWatch({
+    required String key,
+    required SmartPlaylist playlist,
+  }) {
+    final sink = RustStreamSink<List<LibrarySong>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(key, serializer);
+            sse_encode_box_autoadd_smart_playlist(playlist, serializer);
+            sse_encode_StreamSink_list_library_song_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 80,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_String,
+          ),
+          constMeta: kCrateApiSmartPlaylistsSmartPlaylistWatchConstMeta,
+          argValues: [key, playlist, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistsSmartPlaylistWatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "smart_playlist_watch",
+        argNames: ["key", "playlist", "sink"],
+      );
+
+  @override
+  Future<TagChanges> crateApiTagsTagChangesDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tag_changes,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagsTagChangesDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagsTagChangesDefaultConstMeta =>
+      const TaskConstMeta(debugName: "tag_changes_default", argNames: []);
+
+  @override
+  TagChanges crateApiTagsTagsDefaultChanges() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tag_changes,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagsTagsDefaultChangesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagsTagsDefaultChangesConstMeta =>
+      const TaskConstMeta(debugName: "tags_default_changes", argNames: []);
+
+  @override
+  Future<void> crateApiTagsWriteTags({
+    required String path,
+    required TagChanges changes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_tag_changes(changes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiTagsWriteTagsConstMeta,
+        argValues: [path, changes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagsWriteTagsConstMeta => const TaskConstMeta(
+    debugName: "write_tags",
+    argNames: ["path", "changes"],
+  );
+
+  @override
+  Future<List<TagWriteFailure>> crateApiTagsWriteTagsBatch({
+    required List<String> paths,
+    required TagChanges changes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(paths, serializer);
+          sse_encode_box_autoadd_tag_changes(changes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_tag_write_failure,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagsWriteTagsBatchConstMeta,
+        argValues: [paths, changes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagsWriteTagsBatchConstMeta => const TaskConstMeta(
+    debugName: "write_tags_batch",
+    argNames: ["paths", "changes"],
+  );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  Map<String, PlatformInt64> dco_decode_Map_String_i_64_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_i_64(raw).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  RustStreamSink<AudioRenderProgress>
+  dco_decode_StreamSink_audio_render_progress_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<AudioTranscodeEvent>
+  dco_decode_StreamSink_audio_transcode_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<LibrarySong>> dco_decode_StreamSink_list_library_song_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ScanStreamEvent> dco_decode_StreamSink_scan_stream_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<WatchEvent> dco_decode_StreamSink_watch_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AlbumPage dco_decode_album_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AlbumPage(
+      albums: dco_decode_list_library_album(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  AlbumPalette dco_decode_album_palette(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AlbumPalette(
+      dominant: dco_decode_u_32(arr[0]),
+      vibrant: dco_decode_u_32(arr[1]),
+      muted: dco_decode_u_32(arr[2]),
+      text: dco_decode_u_32(arr[3]),
+      secondaryText: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  AudioEventType dco_decode_audio_event_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return AudioEventType_StateChanged(state: dco_decode_String(raw[1]));
+      case 1:
+        return AudioEventType_Progress(
+          positionSecs: dco_decode_f_64(raw[1]),
+          durationSecs: dco_decode_opt_box_autoadd_f_64(raw[2]),
+          bufferLevel: dco_decode_f_32(raw[3]),
+        );
+      case 2:
+        return AudioEventType_TrackEnded(path: dco_decode_String(raw[1]));
+      case 3:
+        return AudioEventType_CrossfadeStarted(
+          fromPath: dco_decode_String(raw[1]),
+          toPath: dco_decode_String(raw[2]),
+        );
+      case 4:
+        return AudioEventType_Error(message: dco_decode_String(raw[1]));
+      case 5:
+        return AudioEventType_NextTrackReady(path: dco_decode_String(raw[1]));
+      case 6:
+        return AudioEventType_LyricsLineChanged(
+          path: dco_decode_String(raw[1]),
+          lineIndex: dco_decode_opt_box_autoadd_u_32(raw[2]),
+        );
+      case 7:
+        return AudioEventType_ChapterChanged(
+          path: dco_decode_String(raw[1]),
+          chapterIndex: dco_decode_opt_box_autoadd_u_32(raw[2]),
+        );
+      case 8:
+        return AudioEventType_Resumed(
+          path: dco_decode_String(raw[1]),
+          positionSecs: dco_decode_f_64(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
   AudioFileMetadata dco_decode_audio_file_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    if (arr.length != 29)
+      throw Exception('unexpected arr length: expect 29 but see ${arr.length}');
+    return AudioFileMetadata(
+      path: dco_decode_String(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      artist: dco_decode_opt_String(arr[2]),
+      album: dco_decode_opt_String(arr[3]),
+      albumArtist: dco_decode_opt_String(arr[4]),
+      trackNumber: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      trackTotal: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      discNumber: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      discTotal: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      year: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      genre: dco_decode_opt_String(arr[10]),
+      composer: dco_decode_opt_String(arr[11]),
+      compilation: dco_decode_bool(arr[12]),
+      durationSecs: dco_decode_opt_box_autoadd_u_64(arr[13]),
+      sampleRate: dco_decode_opt_box_autoadd_u_32(arr[14]),
+      bitDepth: dco_decode_opt_box_autoadd_u_32(arr[15]),
+      bitrateKbps: dco_decode_opt_box_autoadd_u_32(arr[16]),
+      channels: dco_decode_opt_box_autoadd_u_32(arr[17]),
+      hasLyrics: dco_decode_bool(arr[18]),
+      musicbrainzRecordingId: dco_decode_opt_String(arr[19]),
+      musicbrainzReleaseId: dco_decode_opt_String(arr[20]),
+      musicbrainzReleaseGroupId: dco_decode_opt_String(arr[21]),
+      musicbrainzArtistId: dco_decode_opt_String(arr[22]),
+      musicbrainzReleaseArtistId: dco_decode_opt_String(arr[23]),
+      albumArtPath: dco_decode_opt_String(arr[24]),
+      albumPalette: dco_decode_opt_box_autoadd_album_palette(arr[25]),
+      format: dco_decode_String(arr[26]),
+      lastModified: dco_decode_i_64(arr[27]),
+      range: dco_decode_opt_box_autoadd_track_range(arr[28]),
+    );
+  }
+
+  @protected
+  AudioOutputFormat dco_decode_audio_output_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AudioOutputFormat(
+      sampleFormat: dco_decode_output_sample_format_type(arr[0]),
+      bitsPerSample: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      sampleRate: dco_decode_u_32(arr[2]),
+      channels: dco_decode_u_32(arr[3]),
+      dither: dco_decode_dither_type(arr[4]),
+    );
+  }
+
+  @protected
+  AudioProgress dco_decode_audio_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AudioProgress(
+      positionSecs: dco_decode_f_64(arr[0]),
+      durationSecs: dco_decode_opt_box_autoadd_f_64(arr[1]),
+      bufferLevel: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  AudioRenderProgress dco_decode_audio_render_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AudioRenderProgress(
+      phase: dco_decode_render_phase_type(arr[0]),
+      trackIndex: dco_decode_u_32(arr[1]),
+      trackCount: dco_decode_u_32(arr[2]),
+      positionSecs: dco_decode_f_64(arr[3]),
+      totalSecs: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  AudioRenderSettings dco_decode_audio_render_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return AudioRenderSettings(
+      crossfadeSecs: dco_decode_f_32(arr[0]),
+      crossfadeCurve: dco_decode_crossfade_curve_type(arr[1]),
+      volume: dco_decode_f_32(arr[2]),
+      eqPreampDb: dco_decode_f_32(arr[3]),
+      eqBands: dco_decode_list_equalizer_band(arr[4]),
+      normalize: dco_decode_bool(arr[5]),
+      bitDepth: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  AudioTranscodeEvent dco_decode_audio_transcode_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AudioTranscodeEvent(
+      index: dco_decode_u_32(arr[0]),
+      status: dco_decode_transcode_status_type(arr[1]),
+      fraction: dco_decode_f_64(arr[2]),
+      message: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  AudioTranscodeJob dco_decode_audio_transcode_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AudioTranscodeJob(
+      inputPath: dco_decode_String(arr[0]),
+      outputPath: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  AudioTranscodeSettings dco_decode_audio_transcode_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AudioTranscodeSettings(
+      format: dco_decode_transcode_file_format(arr[0]),
+      sampleRate: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      bitDepth: dco_decode_u_32(arr[2]),
+      dither: dco_decode_dither_type(arr[3]),
+      opusBitrate: dco_decode_u_32(arr[4]),
+      copyTags: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AlbumPalette dco_decode_box_autoadd_album_palette(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_album_palette(raw);
+  }
+
+  @protected
+  AudioEventType dco_decode_box_autoadd_audio_event_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_event_type(raw);
+  }
+
+  @protected
+  AudioOutputFormat dco_decode_box_autoadd_audio_output_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_output_format(raw);
+  }
+
+  @protected
+  AudioProgress dco_decode_box_autoadd_audio_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_progress(raw);
+  }
+
+  @protected
+  AudioRenderSettings dco_decode_box_autoadd_audio_render_settings(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_render_settings(raw);
+  }
+
+  @protected
+  AudioTranscodeSettings dco_decode_box_autoadd_audio_transcode_settings(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_transcode_settings(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  GroupQuery dco_decode_box_autoadd_group_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_group_query(raw);
+  }
+
+  @protected
+  GroupingOptions dco_decode_box_autoadd_grouping_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_grouping_options(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  LibrarySong dco_decode_box_autoadd_library_song(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_library_song(raw);
+  }
+
+  @protected
+  Lyrics dco_decode_box_autoadd_lyrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_lyrics(raw);
+  }
+
+  @protected
+  Playlist dco_decode_box_autoadd_playlist(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_playlist(raw);
+  }
+
+  @protected
+  ScanOptions dco_decode_box_autoadd_scan_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scan_options(raw);
+  }
+
+  @protected
+  SmartPlaylist dco_decode_box_autoadd_smart_playlist(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_smart_playlist(raw);
+  }
+
+  @protected
+  SongQuery dco_decode_box_autoadd_song_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_song_query(raw);
+  }
+
+  @protected
+  TagChanges dco_decode_box_autoadd_tag_changes(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tag_changes(raw);
+  }
+
+  @protected
+  TrackRange dco_decode_box_autoadd_track_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_track_range(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_usize(raw);
+  }
+
+  @protected
+  Chapter dco_decode_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Chapter(
+      title: dco_decode_opt_String(arr[0]),
+      startSecs: dco_decode_f_64(arr[1]),
+      endSecs: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  CrossfadeCurveType dco_decode_crossfade_curve_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CrossfadeCurveType.values[raw as int];
+  }
+
+  @protected
+  DitherType dco_decode_dither_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DitherType.values[raw as int];
+  }
+
+  @protected
+  DuplicateFile dco_decode_duplicate_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return DuplicateFile(
+      path: dco_decode_String(arr[0]),
+      format: dco_decode_String(arr[1]),
+      lossless: dco_decode_bool(arr[2]),
+      bitrateKbps: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      sampleRate: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      bitDepth: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      durationSecs: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      similarity: dco_decode_f_32(arr[7]),
+    );
+  }
+
+  @protected
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DuplicateGroup(
+      files: dco_decode_list_duplicate_file(arr[0]),
+      keepPath: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  DuplicateReport dco_decode_duplicate_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DuplicateReport(
+      groups: dco_decode_list_duplicate_group(arr[0]),
+      failed: dco_decode_list_scan_failure(arr[1]),
+    );
+  }
+
+  @protected
+  EqualizerBand dco_decode_equalizer_band(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EqualizerBand(
+      frequencyHz: dco_decode_f_32(arr[0]),
+      gainDb: dco_decode_f_32(arr[1]),
+      q: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  GroupPage dco_decode_group_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GroupPage(
+      groups: dco_decode_list_library_group(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  GroupQuery dco_decode_group_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return GroupQuery(
+      search: dco_decode_opt_String(arr[0]),
+      folder: dco_decode_opt_String(arr[1]),
+      sort: dco_decode_group_sort_type(arr[2]),
+      descending: dco_decode_bool(arr[3]),
+      offset: dco_decode_u_32(arr[4]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  GroupSortType dco_decode_group_sort_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GroupSortType.values[raw as int];
+  }
+
+  @protected
+  GroupingOptions dco_decode_grouping_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GroupingOptions(
+      artistSeparators: dco_decode_list_String(arr[0]),
+      articles: dco_decode_list_String(arr[1]),
+      variousArtists: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  LibraryAlbum dco_decode_library_album(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return LibraryAlbum(
+      key: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      artist: dco_decode_opt_String(arr[2]),
+      compilation: dco_decode_bool(arr[3]),
+      year: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      songCount: dco_decode_u_32(arr[5]),
+      discCount: dco_decode_u_32(arr[6]),
+      durationSecs: dco_decode_u_64(arr[7]),
+      albumArtPath: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  LibraryFolder dco_decode_library_folder(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LibraryFolder(
+      path: dco_decode_String(arr[0]),
+      songCount: dco_decode_u_32(arr[1]),
+      addedAt: dco_decode_i_64(arr[2]),
+      scannedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  LibraryGroup dco_decode_library_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return LibraryGroup(
+      key: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      songCount: dco_decode_u_32(arr[2]),
+      albumCount: dco_decode_u_32(arr[3]),
+      durationSecs: dco_decode_u_64(arr[4]),
+      albumArtPath: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  LibrarySong dco_decode_library_song(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return LibrarySong(
+      id: dco_decode_i_64(arr[0]),
+      folder: dco_decode_String(arr[1]),
+      displayTitle: dco_decode_String(arr[2]),
+      dateAdded: dco_decode_i_64(arr[3]),
+      playCount: dco_decode_u_32(arr[4]),
+      lastPlayed: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      favorite: dco_decode_bool(arr[6]),
+      albumKey: dco_decode_opt_String(arr[7]),
+      artistKeys: dco_decode_list_String(arr[8]),
+      metadata: dco_decode_audio_file_metadata(arr[9]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AudioFileMetadata> dco_decode_list_audio_file_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_audio_file_metadata).toList();
+  }
+
+  @protected
+  List<AudioTranscodeJob> dco_decode_list_audio_transcode_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_audio_transcode_job).toList();
+  }
+
+  @protected
+  List<Chapter> dco_decode_list_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chapter).toList();
+  }
+
+  @protected
+  List<DuplicateFile> dco_decode_list_duplicate_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_file).toList();
+  }
+
+  @protected
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_group).toList();
+  }
+
+  @protected
+  List<EqualizerBand> dco_decode_list_equalizer_band(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_equalizer_band).toList();
+  }
+
+  @protected
+  List<LibraryAlbum> dco_decode_list_library_album(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_library_album).toList();
+  }
+
+  @protected
+  List<LibraryFolder> dco_decode_list_library_folder(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_library_folder).toList();
+  }
+
+  @protected
+  List<LibraryGroup> dco_decode_list_library_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_library_group).toList();
+  }
+
+  @protected
+  List<LibrarySong> dco_decode_list_library_song(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_library_song).toList();
+  }
+
+  @protected
+  List<LyricsLine> dco_decode_list_lyrics_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lyrics_line).toList();
+  }
+
+  @protected
+  List<LyricsWord> dco_decode_list_lyrics_word(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lyrics_word).toList();
+  }
+
+  @protected
+  List<PlaylistEntry> dco_decode_list_playlist_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_playlist_entry).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, PlatformInt64)> dco_decode_list_record_string_i_64(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_i_64).toList();
+  }
+
+  @protected
+  List<ScanFailure> dco_decode_list_scan_failure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_scan_failure).toList();
+  }
+
+  @protected
+  List<ScanMove> dco_decode_list_scan_move(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_scan_move).toList();
+  }
+
+  @protected
+  List<SmartCondition> dco_decode_list_smart_condition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_smart_condition).toList();
+  }
+
+  @protected
+  List<SmartRule> dco_decode_list_smart_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_smart_rule).toList();
+  }
+
+  @protected
+  List<TagField> dco_decode_list_tag_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag_field).toList();
+  }
+
+  @protected
+  List<TagWriteFailure> dco_decode_list_tag_write_failure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag_write_failure).toList();
+  }
+
+  @protected
+  List<UnresolvedEntry> dco_decode_list_unresolved_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_unresolved_entry).toList();
+  }
+
+  @protected
+  Lyrics dco_decode_lyrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Lyrics(
+      source: dco_decode_lyrics_source_type(arr[0]),
+      synced: dco_decode_bool(arr[1]),
+      lines: dco_decode_list_lyrics_line(arr[2]),
+    );
+  }
+
+  @protected
+  LyricsLine dco_decode_lyrics_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LyricsLine(
+      startMs: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      text: dco_decode_String(arr[1]),
+      words: dco_decode_list_lyrics_word(arr[2]),
+    );
+  }
+
+  @protected
+  LyricsSourceType dco_decode_lyrics_source_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LyricsSourceType.values[raw as int];
+  }
+
+  @protected
+  LyricsWord dco_decode_lyrics_word(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LyricsWord(
+      startMs: dco_decode_u_32(arr[0]),
+      text: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AlbumPalette? dco_decode_opt_box_autoadd_album_palette(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_album_palette(raw);
+  }
+
+  @protected
+  AudioEventType? dco_decode_opt_box_autoadd_audio_event_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_event_type(raw);
+  }
+
+  @protected
+  AudioOutputFormat? dco_decode_opt_box_autoadd_audio_output_format(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_output_format(raw);
+  }
+
+  @protected
+  AudioProgress? dco_decode_opt_box_autoadd_audio_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_progress(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  LibrarySong? dco_decode_opt_box_autoadd_library_song(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_library_song(raw);
+  }
+
+  @protected
+  Lyrics? dco_decode_opt_box_autoadd_lyrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_lyrics(raw);
+  }
+
+  @protected
+  ScanOptions? dco_decode_opt_box_autoadd_scan_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_scan_options(raw);
+  }
+
+  @protected
+  TrackRange? dco_decode_opt_box_autoadd_track_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_track_range(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  OutputSampleFormatType dco_decode_output_sample_format_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return OutputSampleFormatType.values[raw as int];
+  }
+
+  @protected
+  Playlist dco_decode_playlist(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Playlist(
+      name: dco_decode_opt_String(arr[0]),
+      entries: dco_decode_list_playlist_entry(arr[1]),
+    );
+  }
+
+  @protected
+  PlaylistEntry dco_decode_playlist_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PlaylistEntry(
+      location: dco_decode_String(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      artist: dco_decode_opt_String(arr[2]),
+      album: dco_decode_opt_String(arr[3]),
+      durationSecs: dco_decode_opt_box_autoadd_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  PlaylistImport dco_decode_playlist_import(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PlaylistImport(
+      name: dco_decode_opt_String(arr[0]),
+      paths: dco_decode_list_String(arr[1]),
+      unresolved: dco_decode_list_unresolved_entry(arr[2]),
+    );
+  }
+
+  @protected
+  (String, PlatformInt64) dco_decode_record_string_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_i_64(arr[1]));
+  }
+
+  @protected
+  RenderFileFormat dco_decode_render_file_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RenderFileFormat.values[raw as int];
+  }
+
+  @protected
+  RenderPhaseType dco_decode_render_phase_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RenderPhaseType.values[raw as int];
+  }
+
+  @protected
+  ScanEventType dco_decode_scan_event_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScanEventType.values[raw as int];
+  }
+
+  @protected
+  ScanFailure dco_decode_scan_failure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ScanFailure(
+      path: dco_decode_String(arr[0]),
+      reason: dco_decode_scan_failure_type(arr[1]),
+      detail: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ScanFailureType dco_decode_scan_failure_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScanFailureType.values[raw as int];
+  }
+
+  @protected
+  ScanMove dco_decode_scan_move(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ScanMove(
+      oldPath: dco_decode_String(arr[0]),
+      newPath: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ScanOptions dco_decode_scan_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ScanOptions(
+      extensions: dco_decode_list_String(arr[0]),
+      sniffContent: dco_decode_bool(arr[1]),
+      excludeGlobs: dco_decode_list_String(arr[2]),
+      honorMarkerFiles: dco_decode_bool(arr[3]),
+      minDurationSecs: dco_decode_u_32(arr[4]),
+      minFileSize: dco_decode_u_32(arr[5]),
+      maxDepth: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      followSymlinks: dco_decode_bool(arr[7]),
+      fingerprintIndex: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  ScanResult dco_decode_scan_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScanResult(
+      newOrModified: dco_decode_list_audio_file_metadata(arr[0]),
+      deletedPaths: dco_decode_list_String(arr[1]),
+      moved: dco_decode_list_scan_move(arr[2]),
+      failed: dco_decode_list_scan_failure(arr[3]),
+    );
+  }
+
+  @protected
+  ScanStreamEvent dco_decode_scan_stream_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ScanStreamEvent(
+      kind: dco_decode_scan_event_type(arr[0]),
+      files: dco_decode_list_audio_file_metadata(arr[1]),
+      failures: dco_decode_list_scan_failure(arr[2]),
+      deletedPaths: dco_decode_list_String(arr[3]),
+      moved: dco_decode_list_scan_move(arr[4]),
+      discovered: dco_decode_u_32(arr[5]),
+      processed: dco_decode_u_32(arr[6]),
+      failed: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  SmartCondition dco_decode_smart_condition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SmartCondition(
+      field: dco_decode_smart_field(arr[0]),
+      operator_: dco_decode_smart_operator(arr[1]),
+      text: dco_decode_String(arr[2]),
+      number: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  SmartField dco_decode_smart_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SmartField.values[raw as int];
+  }
+
+  @protected
+  SmartOperator dco_decode_smart_operator(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SmartOperator.values[raw as int];
+  }
+
+  @protected
+  SmartPlaylist dco_decode_smart_playlist(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SmartPlaylist(
+      rule: dco_decode_smart_rule(arr[0]),
+      sort: dco_decode_smart_sort_type(arr[1]),
+      descending: dco_decode_bool(arr[2]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      durationLimitSecs: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  SmartRule dco_decode_smart_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SmartRule(
+      matchAll: dco_decode_bool(arr[0]),
+      conditions: dco_decode_list_smart_condition(arr[1]),
+      groups: dco_decode_list_smart_rule(arr[2]),
+    );
+  }
+
+  @protected
+  SmartSortType dco_decode_smart_sort_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SmartSortType.values[raw as int];
+  }
+
+  @protected
+  SongPage dco_decode_song_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SongPage(
+      songs: dco_decode_list_library_song(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SongQuery dco_decode_song_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return SongQuery(
+      search: dco_decode_opt_String(arr[0]),
+      folder: dco_decode_opt_String(arr[1]),
+      artist: dco_decode_opt_String(arr[2]),
+      album: dco_decode_opt_String(arr[3]),
+      albumKey: dco_decode_opt_String(arr[4]),
+      genre: dco_decode_opt_String(arr[5]),
+      sort: dco_decode_song_sort_type(arr[6]),
+      descending: dco_decode_bool(arr[7]),
+      offset: dco_decode_u_32(arr[8]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[9]),
+    );
+  }
+
+  @protected
+  SongSortType dco_decode_song_sort_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SongSortType.values[raw as int];
+  }
+
+  @protected
+  TagChanges dco_decode_tag_changes(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return TagChanges(
+      title: dco_decode_opt_String(arr[0]),
+      artist: dco_decode_opt_String(arr[1]),
+      album: dco_decode_opt_String(arr[2]),
+      albumArtist: dco_decode_opt_String(arr[3]),
+      trackNumber: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      trackTotal: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      discNumber: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      discTotal: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      year: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      genre: dco_decode_opt_String(arr[9]),
+      lyrics: dco_decode_opt_String(arr[10]),
+      coverArt: dco_decode_opt_list_prim_u_8_strict(arr[11]),
+      remove: dco_decode_list_tag_field(arr[12]),
+    );
+  }
+
+  @protected
+  TagField dco_decode_tag_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TagField.values[raw as int];
+  }
+
+  @protected
+  TagWriteFailure dco_decode_tag_write_failure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TagWriteFailure(
+      path: dco_decode_String(arr[0]),
+      error: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  TrackRange dco_decode_track_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TrackRange(
+      sourcePath: dco_decode_String(arr[0]),
+      startSecs: dco_decode_f_64(arr[1]),
+      endSecs: dco_decode_opt_box_autoadd_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  TranscodeFileFormat dco_decode_transcode_file_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TranscodeFileFormat.values[raw as int];
+  }
+
+  @protected
+  TranscodeStatusType dco_decode_transcode_status_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TranscodeStatusType.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UnresolvedEntry dco_decode_unresolved_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return UnresolvedEntry(
+      index: dco_decode_u_32(arr[0]),
+      entry: dco_decode_playlist_entry(arr[1]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WatchEvent dco_decode_watch_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WatchEvent(
+      added: dco_decode_list_audio_file_metadata(arr[0]),
+      modified: dco_decode_list_audio_file_metadata(arr[1]),
+      removedPaths: dco_decode_list_String(arr[2]),
+      moved: dco_decode_list_scan_move(arr[3]),
+      failures: dco_decode_list_scan_failure(arr[4]),
+      rescanNeeded: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Map<String, PlatformInt64> sse_decode_Map_String_i_64_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_i_64(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  RustStreamSink<AudioRenderProgress>
+  sse_decode_StreamSink_audio_render_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<AudioTranscodeEvent>
+  sse_decode_StreamSink_audio_transcode_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<LibrarySong>> sse_decode_StreamSink_list_library_song_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ScanStreamEvent> sse_decode_StreamSink_scan_stream_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<WatchEvent> sse_decode_StreamSink_watch_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AlbumPage sse_decode_album_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_albums = sse_decode_list_library_album(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return AlbumPage(albums: var_albums, total: var_total);
+  }
+
+  @protected
+  AlbumPalette sse_decode_album_palette(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dominant = sse_decode_u_32(deserializer);
+    var var_vibrant = sse_decode_u_32(deserializer);
+    var var_muted = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_u_32(deserializer);
+    var var_secondaryText = sse_decode_u_32(deserializer);
+    return AlbumPalette(
+      dominant: var_dominant,
+      vibrant: var_vibrant,
+      muted: var_muted,
+      text: var_text,
+      secondaryText: var_secondaryText,
+    );
+  }
+
+  @protected
+  AudioEventType sse_decode_audio_event_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_state = sse_decode_String(deserializer);
+        return AudioEventType_StateChanged(state: var_state);
+      case 1:
+        var var_positionSecs = sse_decode_f_64(deserializer);
+        var var_durationSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
+        var var_bufferLevel = sse_decode_f_32(deserializer);
+        return AudioEventType_Progress(
+          positionSecs: var_positionSecs,
+          durationSecs: var_durationSecs,
+          bufferLevel: var_bufferLevel,
+        );
+      case 2:
+        var var_path = sse_decode_String(deserializer);
+        return AudioEventType_TrackEnded(path: var_path);
+      case 3:
+        var var_fromPath = sse_decode_String(deserializer);
+        var var_toPath = sse_decode_String(deserializer);
+        return AudioEventType_CrossfadeStarted(
+          fromPath: var_fromPath,
+          toPath: var_toPath,
+        );
+      case 4:
+        var var_message = sse_decode_String(deserializer);
+        return AudioEventType_Error(message: var_message);
+      case 5:
+        var var_path = sse_decode_String(deserializer);
+        return AudioEventType_NextTrackReady(path: var_path);
+      case 6:
+        var var_path = sse_decode_String(deserializer);
+        var var_lineIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+        return AudioEventType_LyricsLineChanged(
+          path: var_path,
+          lineIndex: var_lineIndex,
+        );
+      case 7:
+        var var_path = sse_decode_String(deserializer);
+        var var_chapterIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+        return AudioEventType_ChapterChanged(
+          path: var_path,
+          chapterIndex: var_chapterIndex,
+        );
+      case 8:
+        var var_path = sse_decode_String(deserializer);
+        var var_positionSecs = sse_decode_f_64(deserializer);
+        return AudioEventType_Resumed(
+          path: var_path,
+          positionSecs: var_positionSecs,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  AudioFileMetadata sse_decode_audio_file_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_albumArtist = sse_decode_opt_String(deserializer);
+    var var_trackNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_trackTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_discNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_discTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_year = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_genre = sse_decode_opt_String(deserializer);
+    var var_composer = sse_decode_opt_String(deserializer);
+    var var_compilation = sse_decode_bool(deserializer);
+    var var_durationSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bitDepth = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bitrateKbps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_channels = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_hasLyrics = sse_decode_bool(deserializer);
+    var var_musicbrainzRecordingId = sse_decode_opt_String(deserializer);
+    var var_musicbrainzReleaseId = sse_decode_opt_String(deserializer);
+    var var_musicbrainzReleaseGroupId = sse_decode_opt_String(deserializer);
+    var var_musicbrainzArtistId = sse_decode_opt_String(deserializer);
+    var var_musicbrainzReleaseArtistId = sse_decode_opt_String(deserializer);
+    var var_albumArtPath = sse_decode_opt_String(deserializer);
+    var var_albumPalette = sse_decode_opt_box_autoadd_album_palette(
+      deserializer,
+    );
+    var var_format = sse_decode_String(deserializer);
+    var var_lastModified = sse_decode_i_64(deserializer);
+    var var_range = sse_decode_opt_box_autoadd_track_range(deserializer);
     return AudioFileMetadata(
-      path: dco_decode_String(arr[0]),
-      title: dco_decode_opt_String(arr[1]),
-      artist: dco_decode_opt_String(arr[2]),
-      album: dco_decode_opt_String(arr[3]),
-      durationSecs: dco_decode_opt_box_autoadd_u_64(arr[4]),
-      format: dco_decode_String(arr[5]),
-      lastModified: dco_decode_i_64(arr[6]),
+      path: var_path,
+      title: var_title,
+      artist: var_artist,
+      album: var_album,
+      albumArtist: var_albumArtist,
+      trackNumber: var_trackNumber,
+      trackTotal: var_trackTotal,
+      discNumber: var_discNumber,
+      discTotal: var_discTotal,
+      year: var_year,
+      genre: var_genre,
+      composer: var_composer,
+      compilation: var_compilation,
+      durationSecs: var_durationSecs,
+      sampleRate: var_sampleRate,
+      bitDepth: var_bitDepth,
+      bitrateKbps: var_bitrateKbps,
+      channels: var_channels,
+      hasLyrics: var_hasLyrics,
+      musicbrainzRecordingId: var_musicbrainzRecordingId,
+      musicbrainzReleaseId: var_musicbrainzReleaseId,
+      musicbrainzReleaseGroupId: var_musicbrainzReleaseGroupId,
+      musicbrainzArtistId: var_musicbrainzArtistId,
+      musicbrainzReleaseArtistId: var_musicbrainzReleaseArtistId,
+      albumArtPath: var_albumArtPath,
+      albumPalette: var_albumPalette,
+      format: var_format,
+      lastModified: var_lastModified,
+      range: var_range,
+    );
+  }
+
+  @protected
+  AudioOutputFormat sse_decode_audio_output_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sampleFormat = sse_decode_output_sample_format_type(deserializer);
+    var var_bitsPerSample = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sampleRate = sse_decode_u_32(deserializer);
+    var var_channels = sse_decode_u_32(deserializer);
+    var var_dither = sse_decode_dither_type(deserializer);
+    return AudioOutputFormat(
+      sampleFormat: var_sampleFormat,
+      bitsPerSample: var_bitsPerSample,
+      sampleRate: var_sampleRate,
+      channels: var_channels,
+      dither: var_dither,
+    );
+  }
+
+  @protected
+  AudioProgress sse_decode_audio_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_positionSecs = sse_decode_f_64(deserializer);
+    var var_durationSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_bufferLevel = sse_decode_f_32(deserializer);
+    return AudioProgress(
+      positionSecs: var_positionSecs,
+      durationSecs: var_durationSecs,
+      bufferLevel: var_bufferLevel,
+    );
+  }
+
+  @protected
+  AudioRenderProgress sse_decode_audio_render_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_phase = sse_decode_render_phase_type(deserializer);
+    var var_trackIndex = sse_decode_u_32(deserializer);
+    var var_trackCount = sse_decode_u_32(deserializer);
+    var var_positionSecs = sse_decode_f_64(deserializer);
+    var var_totalSecs = sse_decode_f_64(deserializer);
+    return AudioRenderProgress(
+      phase: var_phase,
+      trackIndex: var_trackIndex,
+      trackCount: var_trackCount,
+      positionSecs: var_positionSecs,
+      totalSecs: var_totalSecs,
+    );
+  }
+
+  @protected
+  AudioRenderSettings sse_decode_audio_render_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_crossfadeSecs = sse_decode_f_32(deserializer);
+    var var_crossfadeCurve = sse_decode_crossfade_curve_type(deserializer);
+    var var_volume = sse_decode_f_32(deserializer);
+    var var_eqPreampDb = sse_decode_f_32(deserializer);
+    var var_eqBands = sse_decode_list_equalizer_band(deserializer);
+    var var_normalize = sse_decode_bool(deserializer);
+    var var_bitDepth = sse_decode_u_32(deserializer);
+    return AudioRenderSettings(
+      crossfadeSecs: var_crossfadeSecs,
+      crossfadeCurve: var_crossfadeCurve,
+      volume: var_volume,
+      eqPreampDb: var_eqPreampDb,
+      eqBands: var_eqBands,
+      normalize: var_normalize,
+      bitDepth: var_bitDepth,
+    );
+  }
+
+  @protected
+  AudioTranscodeEvent sse_decode_audio_transcode_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_status = sse_decode_transcode_status_type(deserializer);
+    var var_fraction = sse_decode_f_64(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    return AudioTranscodeEvent(
+      index: var_index,
+      status: var_status,
+      fraction: var_fraction,
+      message: var_message,
+    );
+  }
+
+  @protected
+  AudioTranscodeJob sse_decode_audio_transcode_job(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_inputPath = sse_decode_String(deserializer);
+    var var_outputPath = sse_decode_String(deserializer);
+    return AudioTranscodeJob(
+      inputPath: var_inputPath,
+      outputPath: var_outputPath,
+    );
+  }
+
+  @protected
+  AudioTranscodeSettings sse_decode_audio_transcode_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_transcode_file_format(deserializer);
+    var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bitDepth = sse_decode_u_32(deserializer);
+    var var_dither = sse_decode_dither_type(deserializer);
+    var var_opusBitrate = sse_decode_u_32(deserializer);
+    var var_copyTags = sse_decode_bool(deserializer);
+    return AudioTranscodeSettings(
+      format: var_format,
+      sampleRate: var_sampleRate,
+      bitDepth: var_bitDepth,
+      dither: var_dither,
+      opusBitrate: var_opusBitrate,
+      copyTags: var_copyTags,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AlbumPalette sse_decode_box_autoadd_album_palette(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_album_palette(deserializer));
+  }
+
+  @protected
+  AudioEventType sse_decode_box_autoadd_audio_event_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_event_type(deserializer));
+  }
+
+  @protected
+  AudioOutputFormat sse_decode_box_autoadd_audio_output_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_output_format(deserializer));
+  }
+
+  @protected
+  AudioProgress sse_decode_box_autoadd_audio_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_progress(deserializer));
+  }
+
+  @protected
+  AudioRenderSettings sse_decode_box_autoadd_audio_render_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_render_settings(deserializer));
+  }
+
+  @protected
+  AudioTranscodeSettings sse_decode_box_autoadd_audio_transcode_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_transcode_settings(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  GroupQuery sse_decode_box_autoadd_group_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_group_query(deserializer));
+  }
+
+  @protected
+  GroupingOptions sse_decode_box_autoadd_grouping_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_grouping_options(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  LibrarySong sse_decode_box_autoadd_library_song(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_library_song(deserializer));
+  }
+
+  @protected
+  Lyrics sse_decode_box_autoadd_lyrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_lyrics(deserializer));
+  }
+
+  @protected
+  Playlist sse_decode_box_autoadd_playlist(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_playlist(deserializer));
+  }
+
+  @protected
+  ScanOptions sse_decode_box_autoadd_scan_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scan_options(deserializer));
+  }
+
+  @protected
+  SmartPlaylist sse_decode_box_autoadd_smart_playlist(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_smart_playlist(deserializer));
+  }
+
+  @protected
+  SongQuery sse_decode_box_autoadd_song_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_song_query(deserializer));
+  }
+
+  @protected
+  TagChanges sse_decode_box_autoadd_tag_changes(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tag_changes(deserializer));
+  }
+
+  @protected
+  TrackRange sse_decode_box_autoadd_track_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_track_range(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_usize(deserializer));
+  }
+
+  @protected
+  Chapter sse_decode_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_startSecs = sse_decode_f_64(deserializer);
+    var var_endSecs = sse_decode_f_64(deserializer);
+    return Chapter(
+      title: var_title,
+      startSecs: var_startSecs,
+      endSecs: var_endSecs,
+    );
+  }
+
+  @protected
+  CrossfadeCurveType sse_decode_crossfade_curve_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CrossfadeCurveType.values[inner];
+  }
+
+  @protected
+  DitherType sse_decode_dither_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DitherType.values[inner];
+  }
+
+  @protected
+  DuplicateFile sse_decode_duplicate_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_format = sse_decode_String(deserializer);
+    var var_lossless = sse_decode_bool(deserializer);
+    var var_bitrateKbps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bitDepth = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_durationSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_similarity = sse_decode_f_32(deserializer);
+    return DuplicateFile(
+      path: var_path,
+      format: var_format,
+      lossless: var_lossless,
+      bitrateKbps: var_bitrateKbps,
+      sampleRate: var_sampleRate,
+      bitDepth: var_bitDepth,
+      durationSecs: var_durationSecs,
+      similarity: var_similarity,
+    );
+  }
+
+  @protected
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_files = sse_decode_list_duplicate_file(deserializer);
+    var var_keepPath = sse_decode_String(deserializer);
+    return DuplicateGroup(files: var_files, keepPath: var_keepPath);
+  }
+
+  @protected
+  DuplicateReport sse_decode_duplicate_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_groups = sse_decode_list_duplicate_group(deserializer);
+    var var_failed = sse_decode_list_scan_failure(deserializer);
+    return DuplicateReport(groups: var_groups, failed: var_failed);
+  }
+
+  @protected
+  EqualizerBand sse_decode_equalizer_band(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frequencyHz = sse_decode_f_32(deserializer);
+    var var_gainDb = sse_decode_f_32(deserializer);
+    var var_q = sse_decode_f_32(deserializer);
+    return EqualizerBand(
+      frequencyHz: var_frequencyHz,
+      gainDb: var_gainDb,
+      q: var_q,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  GroupPage sse_decode_group_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_groups = sse_decode_list_library_group(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return GroupPage(groups: var_groups, total: var_total);
+  }
+
+  @protected
+  GroupQuery sse_decode_group_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_search = sse_decode_opt_String(deserializer);
+    var var_folder = sse_decode_opt_String(deserializer);
+    var var_sort = sse_decode_group_sort_type(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return GroupQuery(
+      search: var_search,
+      folder: var_folder,
+      sort: var_sort,
+      descending: var_descending,
+      offset: var_offset,
+      limit: var_limit,
+    );
+  }
+
+  @protected
+  GroupSortType sse_decode_group_sort_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GroupSortType.values[inner];
+  }
+
+  @protected
+  GroupingOptions sse_decode_grouping_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_artistSeparators = sse_decode_list_String(deserializer);
+    var var_articles = sse_decode_list_String(deserializer);
+    var var_variousArtists = sse_decode_String(deserializer);
+    return GroupingOptions(
+      artistSeparators: var_artistSeparators,
+      articles: var_articles,
+      variousArtists: var_variousArtists,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  LibraryAlbum sse_decode_library_album(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_compilation = sse_decode_bool(deserializer);
+    var var_year = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_songCount = sse_decode_u_32(deserializer);
+    var var_discCount = sse_decode_u_32(deserializer);
+    var var_durationSecs = sse_decode_u_64(deserializer);
+    var var_albumArtPath = sse_decode_opt_String(deserializer);
+    return LibraryAlbum(
+      key: var_key,
+      name: var_name,
+      artist: var_artist,
+      compilation: var_compilation,
+      year: var_year,
+      songCount: var_songCount,
+      discCount: var_discCount,
+      durationSecs: var_durationSecs,
+      albumArtPath: var_albumArtPath,
+    );
+  }
+
+  @protected
+  LibraryFolder sse_decode_library_folder(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_songCount = sse_decode_u_32(deserializer);
+    var var_addedAt = sse_decode_i_64(deserializer);
+    var var_scannedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return LibraryFolder(
+      path: var_path,
+      songCount: var_songCount,
+      addedAt: var_addedAt,
+      scannedAt: var_scannedAt,
+    );
+  }
+
+  @protected
+  LibraryGroup sse_decode_library_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_songCount = sse_decode_u_32(deserializer);
+    var var_albumCount = sse_decode_u_32(deserializer);
+    var var_durationSecs = sse_decode_u_64(deserializer);
+    var var_albumArtPath = sse_decode_opt_String(deserializer);
+    return LibraryGroup(
+      key: var_key,
+      name: var_name,
+      songCount: var_songCount,
+      albumCount: var_albumCount,
+      durationSecs: var_durationSecs,
+      albumArtPath: var_albumArtPath,
+    );
+  }
+
+  @protected
+  LibrarySong sse_decode_library_song(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_folder = sse_decode_String(deserializer);
+    var var_displayTitle = sse_decode_String(deserializer);
+    var var_dateAdded = sse_decode_i_64(deserializer);
+    var var_playCount = sse_decode_u_32(deserializer);
+    var var_lastPlayed = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_favorite = sse_decode_bool(deserializer);
+    var var_albumKey = sse_decode_opt_String(deserializer);
+    var var_artistKeys = sse_decode_list_String(deserializer);
+    var var_metadata = sse_decode_audio_file_metadata(deserializer);
+    return LibrarySong(
+      id: var_id,
+      folder: var_folder,
+      displayTitle: var_displayTitle,
+      dateAdded: var_dateAdded,
+      playCount: var_playCount,
+      lastPlayed: var_lastPlayed,
+      favorite: var_favorite,
+      albumKey: var_albumKey,
+      artistKeys: var_artistKeys,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AudioFileMetadata> sse_decode_list_audio_file_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AudioFileMetadata>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audio_file_metadata(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AudioTranscodeJob> sse_decode_list_audio_transcode_job(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AudioTranscodeJob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audio_transcode_job(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Chapter> sse_decode_list_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Chapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateFile> sse_decode_list_duplicate_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DuplicateFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DuplicateGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EqualizerBand> sse_decode_list_equalizer_band(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EqualizerBand>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_equalizer_band(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibraryAlbum> sse_decode_list_library_album(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibraryAlbum>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_album(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibraryFolder> sse_decode_list_library_folder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibraryFolder>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_folder(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibraryGroup> sse_decode_list_library_group(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibraryGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibrarySong> sse_decode_list_library_song(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibrarySong>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_song(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LyricsLine> sse_decode_list_lyrics_line(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LyricsLine>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lyrics_line(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LyricsWord> sse_decode_list_lyrics_word(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LyricsWord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lyrics_word(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PlaylistEntry> sse_decode_list_playlist_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PlaylistEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_playlist_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, PlatformInt64)> sse_decode_list_record_string_i_64(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, PlatformInt64)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_i_64(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ScanFailure> sse_decode_list_scan_failure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ScanFailure>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_scan_failure(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ScanMove> sse_decode_list_scan_move(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ScanMove>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_scan_move(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SmartCondition> sse_decode_list_smart_condition(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SmartCondition>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_smart_condition(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SmartRule> sse_decode_list_smart_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SmartRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_smart_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TagField> sse_decode_list_tag_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TagField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TagWriteFailure> sse_decode_list_tag_write_failure(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TagWriteFailure>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag_write_failure(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UnresolvedEntry> sse_decode_list_unresolved_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UnresolvedEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_unresolved_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Lyrics sse_decode_lyrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_lyrics_source_type(deserializer);
+    var var_synced = sse_decode_bool(deserializer);
+    var var_lines = sse_decode_list_lyrics_line(deserializer);
+    return Lyrics(source: var_source, synced: var_synced, lines: var_lines);
+  }
+
+  @protected
+  LyricsLine sse_decode_lyrics_line(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_words = sse_decode_list_lyrics_word(deserializer);
+    return LyricsLine(startMs: var_startMs, text: var_text, words: var_words);
+  }
+
+  @protected
+  LyricsSourceType sse_decode_lyrics_source_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LyricsSourceType.values[inner];
+  }
+
+  @protected
+  LyricsWord sse_decode_lyrics_word(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return LyricsWord(startMs: var_startMs, text: var_text);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AlbumPalette? sse_decode_opt_box_autoadd_album_palette(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_album_palette(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AudioEventType? sse_decode_opt_box_autoadd_audio_event_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_event_type(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AudioOutputFormat? sse_decode_opt_box_autoadd_audio_output_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_output_format(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AudioProgress? sse_decode_opt_box_autoadd_audio_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_progress(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LibrarySong? sse_decode_opt_box_autoadd_library_song(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_library_song(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Lyrics? sse_decode_opt_box_autoadd_lyrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_lyrics(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ScanOptions? sse_decode_opt_box_autoadd_scan_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_scan_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TrackRange? sse_decode_opt_box_autoadd_track_range(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_track_range(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_usize(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutputSampleFormatType sse_decode_output_sample_format_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return OutputSampleFormatType.values[inner];
+  }
+
+  @protected
+  Playlist sse_decode_playlist(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_entries = sse_decode_list_playlist_entry(deserializer);
+    return Playlist(name: var_name, entries: var_entries);
+  }
+
+  @protected
+  PlaylistEntry sse_decode_playlist_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_location = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_durationSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return PlaylistEntry(
+      location: var_location,
+      title: var_title,
+      artist: var_artist,
+      album: var_album,
+      durationSecs: var_durationSecs,
+    );
+  }
+
+  @protected
+  PlaylistImport sse_decode_playlist_import(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_paths = sse_decode_list_String(deserializer);
+    var var_unresolved = sse_decode_list_unresolved_entry(deserializer);
+    return PlaylistImport(
+      name: var_name,
+      paths: var_paths,
+      unresolved: var_unresolved,
+    );
+  }
+
+  @protected
+  (String, PlatformInt64) sse_decode_record_string_i_64(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_i_64(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RenderFileFormat sse_decode_render_file_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RenderFileFormat.values[inner];
+  }
+
+  @protected
+  RenderPhaseType sse_decode_render_phase_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RenderPhaseType.values[inner];
+  }
+
+  @protected
+  ScanEventType sse_decode_scan_event_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ScanEventType.values[inner];
+  }
+
+  @protected
+  ScanFailure sse_decode_scan_failure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_reason = sse_decode_scan_failure_type(deserializer);
+    var var_detail = sse_decode_String(deserializer);
+    return ScanFailure(path: var_path, reason: var_reason, detail: var_detail);
+  }
+
+  @protected
+  ScanFailureType sse_decode_scan_failure_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ScanFailureType.values[inner];
+  }
+
+  @protected
+  ScanMove sse_decode_scan_move(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_oldPath = sse_decode_String(deserializer);
+    var var_newPath = sse_decode_String(deserializer);
+    return ScanMove(oldPath: var_oldPath, newPath: var_newPath);
+  }
+
+  @protected
+  ScanOptions sse_decode_scan_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_extensions = sse_decode_list_String(deserializer);
+    var var_sniffContent = sse_decode_bool(deserializer);
+    var var_excludeGlobs = sse_decode_list_String(deserializer);
+    var var_honorMarkerFiles = sse_decode_bool(deserializer);
+    var var_minDurationSecs = sse_decode_u_32(deserializer);
+    var var_minFileSize = sse_decode_u_32(deserializer);
+    var var_maxDepth = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_followSymlinks = sse_decode_bool(deserializer);
+    var var_fingerprintIndex = sse_decode_opt_String(deserializer);
+    return ScanOptions(
+      extensions: var_extensions,
+      sniffContent: var_sniffContent,
+      excludeGlobs: var_excludeGlobs,
+      honorMarkerFiles: var_honorMarkerFiles,
+      minDurationSecs: var_minDurationSecs,
+      minFileSize: var_minFileSize,
+      maxDepth: var_maxDepth,
+      followSymlinks: var_followSymlinks,
+      fingerprintIndex: var_fingerprintIndex,
+    );
+  }
+
+  @protected
+  ScanResult sse_decode_scan_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_newOrModified = sse_decode_list_audio_file_metadata(deserializer);
+    var var_deletedPaths = sse_decode_list_String(deserializer);
+    var var_moved = sse_decode_list_scan_move(deserializer);
+    var var_failed = sse_decode_list_scan_failure(deserializer);
+    return ScanResult(
+      newOrModified: var_newOrModified,
+      deletedPaths: var_deletedPaths,
+      moved: var_moved,
+      failed: var_failed,
+    );
+  }
+
+  @protected
+  ScanStreamEvent sse_decode_scan_stream_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_scan_event_type(deserializer);
+    var var_files = sse_decode_list_audio_file_metadata(deserializer);
+    var var_failures = sse_decode_list_scan_failure(deserializer);
+    var var_deletedPaths = sse_decode_list_String(deserializer);
+    var var_moved = sse_decode_list_scan_move(deserializer);
+    var var_discovered = sse_decode_u_32(deserializer);
+    var var_processed = sse_decode_u_32(deserializer);
+    var var_failed = sse_decode_u_32(deserializer);
+    return ScanStreamEvent(
+      kind: var_kind,
+      files: var_files,
+      failures: var_failures,
+      deletedPaths: var_deletedPaths,
+      moved: var_moved,
+      discovered: var_discovered,
+      processed: var_processed,
+      failed: var_failed,
+    );
+  }
+
+  @protected
+  SmartCondition sse_decode_smart_condition(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_smart_field(deserializer);
+    var var_operator_ = sse_decode_smart_operator(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_number = sse_decode_f_64(deserializer);
+    return SmartCondition(
+      field: var_field,
+      operator_: var_operator_,
+      text: var_text,
+      number: var_number,
+    );
+  }
+
+  @protected
+  SmartField sse_decode_smart_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SmartField.values[inner];
+  }
+
+  @protected
+  SmartOperator sse_decode_smart_operator(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SmartOperator.values[inner];
+  }
+
+  @protected
+  SmartPlaylist sse_decode_smart_playlist(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rule = sse_decode_smart_rule(deserializer);
+    var var_sort = sse_decode_smart_sort_type(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_durationLimitSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return SmartPlaylist(
+      rule: var_rule,
+      sort: var_sort,
+      descending: var_descending,
+      limit: var_limit,
+      durationLimitSecs: var_durationLimitSecs,
+    );
+  }
+
+  @protected
+  SmartRule sse_decode_smart_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_matchAll = sse_decode_bool(deserializer);
+    var var_conditions = sse_decode_list_smart_condition(deserializer);
+    var var_groups = sse_decode_list_smart_rule(deserializer);
+    return SmartRule(
+      matchAll: var_matchAll,
+      conditions: var_conditions,
+      groups: var_groups,
+    );
+  }
+
+  @protected
+  SmartSortType sse_decode_smart_sort_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SmartSortType.values[inner];
+  }
+
+  @protected
+  SongPage sse_decode_song_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_songs = sse_decode_list_library_song(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return SongPage(songs: var_songs, total: var_total);
+  }
+
+  @protected
+  SongQuery sse_decode_song_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_search = sse_decode_opt_String(deserializer);
+    var var_folder = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_albumKey = sse_decode_opt_String(deserializer);
+    var var_genre = sse_decode_opt_String(deserializer);
+    var var_sort = sse_decode_song_sort_type(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return SongQuery(
+      search: var_search,
+      folder: var_folder,
+      artist: var_artist,
+      album: var_album,
+      albumKey: var_albumKey,
+      genre: var_genre,
+      sort: var_sort,
+      descending: var_descending,
+      offset: var_offset,
+      limit: var_limit,
+    );
+  }
+
+  @protected
+  SongSortType sse_decode_song_sort_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SongSortType.values[inner];
+  }
+
+  @protected
+  TagChanges sse_decode_tag_changes(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_albumArtist = sse_decode_opt_String(deserializer);
+    var var_trackNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_trackTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_discNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_discTotal = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_year = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_genre = sse_decode_opt_String(deserializer);
+    var var_lyrics = sse_decode_opt_String(deserializer);
+    var var_coverArt = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_remove = sse_decode_list_tag_field(deserializer);
+    return TagChanges(
+      title: var_title,
+      artist: var_artist,
+      album: var_album,
+      albumArtist: var_albumArtist,
+      trackNumber: var_trackNumber,
+      trackTotal: var_trackTotal,
+      discNumber: var_discNumber,
+      discTotal: var_discTotal,
+      year: var_year,
+      genre: var_genre,
+      lyrics: var_lyrics,
+      coverArt: var_coverArt,
+      remove: var_remove,
+    );
+  }
+
+  @protected
+  TagField sse_decode_tag_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TagField.values[inner];
+  }
+
+  @protected
+  TagWriteFailure sse_decode_tag_write_failure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return TagWriteFailure(path: var_path, error: var_error);
+  }
+
+  @protected
+  TrackRange sse_decode_track_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sourcePath = sse_decode_String(deserializer);
+    var var_startSecs = sse_decode_f_64(deserializer);
+    var var_endSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return TrackRange(
+      sourcePath: var_sourcePath,
+      startSecs: var_startSecs,
+      endSecs: var_endSecs,
+    );
+  }
+
+  @protected
+  TranscodeFileFormat sse_decode_transcode_file_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TranscodeFileFormat.values[inner];
+  }
+
+  @protected
+  TranscodeStatusType sse_decode_transcode_status_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TranscodeStatusType.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UnresolvedEntry sse_decode_unresolved_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_entry = sse_decode_playlist_entry(deserializer);
+    return UnresolvedEntry(index: var_index, entry: var_entry);
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WatchEvent sse_decode_watch_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_added = sse_decode_list_audio_file_metadata(deserializer);
+    var var_modified = sse_decode_list_audio_file_metadata(deserializer);
+    var var_removedPaths = sse_decode_list_String(deserializer);
+    var var_moved = sse_decode_list_scan_move(deserializer);
+    var var_failures = sse_decode_list_scan_failure(deserializer);
+    var var_rescanNeeded = sse_decode_bool(deserializer);
+    return WatchEvent(
+      added: var_added,
+      modified: var_modified,
+      removedPaths: var_removedPaths,
+      moved: var_moved,
+      failures: var_failures,
+      rescanNeeded: var_rescanNeeded,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Map_String_i_64_None(
+    Map<String, PlatformInt64> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_i_64(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_audio_render_progress_Sse(
+    RustStreamSink<AudioRenderProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_audio_render_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_audio_transcode_event_Sse(
+    RustStreamSink<AudioTranscodeEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_audio_transcode_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_list_library_song_Sse(
+    RustStreamSink<List<LibrarySong>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(codec: SseCodec( decodeSuccessData: sse_decode_list_library_song, decodeErrorData: sse_decode_AnyhowException, )),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_scan_stream_event_Sse(
+    RustStreamSink<ScanStreamEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scan_stream_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  AudioProgress dco_decode_audio_progress(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return AudioProgress(
-      positionSecs: dco_decode_f_64(arr[0]),
-      durationSecs: dco_decode_opt_box_autoadd_f_64(arr[1]),
-      bufferLevel: dco_decode_f_32(arr[2]),
+  void sse_encode_StreamSink_watch_event_Sse(
+    RustStreamSink<WatchEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_watch_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  AudioEventType dco_decode_box_autoadd_audio_event_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_audio_event_type(raw);
+  void sse_encode_album_page(AlbumPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_library_album(self.albums, serializer);
+    sse_encode_u_32(self.total, serializer);
   }
 
   @protected
-  AudioProgress dco_decode_box_autoadd_audio_progress(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_audio_progress(raw);
+  void sse_encode_album_palette(AlbumPalette self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.dominant, serializer);
+    sse_encode_u_32(self.vibrant, serializer);
+    sse_encode_u_32(self.muted, serializer);
+    sse_encode_u_32(self.text, serializer);
+    sse_encode_u_32(self.secondaryText, serializer);
   }
 
   @protected
-  double dco_decode_box_autoadd_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_audio_event_type(
+    AudioEventType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case AudioEventType_StateChanged(state: final state):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(state, serializer);
+      case AudioEventType_Progress(
+        positionSecs: final positionSecs,
+        durationSecs: final durationSecs,
+        bufferLevel: final bufferLevel,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_f_64(positionSecs, serializer);
+        sse_encode_opt_box_autoadd_f_64(durationSecs, serializer);
+        sse_encode_f_32(bufferLevel, serializer);
+      case AudioEventType_TrackEnded(path: final path):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(path, serializer);
+      case AudioEventType_CrossfadeStarted(
+        fromPath: final fromPath,
+        toPath: final toPath,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(fromPath, serializer);
+        sse_encode_String(toPath, serializer);
+      case AudioEventType_Error(message: final message):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(message, serializer);
+      case AudioEventType_NextTrackReady(path: final path):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(path, serializer);
+      case AudioEventType_LyricsLineChanged(
+        path: final path,
+        lineIndex: final lineIndex,
+      ):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_opt_box_autoadd_u_32(lineIndex, serializer);
+      case AudioEventType_ChapterChanged(
+        path: final path,
+        chapterIndex: final chapterIndex,
+      ):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_opt_box_autoadd_u_32(chapterIndex, serializer);
+      case AudioEventType_Resumed(
+        path: final path,
+        positionSecs: final positionSecs,
+      ):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_f_64(positionSecs, serializer);
+    }
   }
 
   @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_audio_file_metadata(
+    AudioFileMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_String(self.albumArtist, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.trackNumber, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.trackTotal, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.discNumber, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.discTotal, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.year, serializer);
+    sse_encode_opt_String(self.genre, serializer);
+    sse_encode_opt_String(self.composer, serializer);
+    sse_encode_bool(self.compilation, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.durationSecs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitDepth, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitrateKbps, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.channels, serializer);
+    sse_encode_bool(self.hasLyrics, serializer);
+    sse_encode_opt_String(self.musicbrainzRecordingId, serializer);
+    sse_encode_opt_String(self.musicbrainzReleaseId, serializer);
+    sse_encode_opt_String(self.musicbrainzReleaseGroupId, serializer);
+    sse_encode_opt_String(self.musicbrainzArtistId, serializer);
+    sse_encode_opt_String(self.musicbrainzReleaseArtistId, serializer);
+    sse_encode_opt_String(self.albumArtPath, serializer);
+    sse_encode_opt_box_autoadd_album_palette(self.albumPalette, serializer);
+    sse_encode_String(self.format, serializer);
+    sse_encode_i_64(self.lastModified, serializer);
+    sse_encode_opt_box_autoadd_track_range(self.range, serializer);
   }
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_audio_output_format(
+    AudioOutputFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_output_sample_format_type(self.sampleFormat, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitsPerSample, serializer);
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_u_32(self.channels, serializer);
+    sse_encode_dither_type(self.dither, serializer);
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  void sse_encode_audio_progress(AudioProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.positionSecs, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.durationSecs, serializer);
+    sse_encode_f_32(self.bufferLevel, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_render_progress(
+    AudioRenderProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_render_phase_type(self.phase, serializer);
+    sse_encode_u_32(self.trackIndex, serializer);
+    sse_encode_u_32(self.trackCount, serializer);
+    sse_encode_f_64(self.positionSecs, serializer);
+    sse_encode_f_64(self.totalSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_render_settings(
+    AudioRenderSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.crossfadeSecs, serializer);
+    sse_encode_crossfade_curve_type(self.crossfadeCurve, serializer);
+    sse_encode_f_32(self.volume, serializer);
+    sse_encode_f_32(self.eqPreampDb, serializer);
+    sse_encode_list_equalizer_band(self.eqBands, serializer);
+    sse_encode_bool(self.normalize, serializer);
+    sse_encode_u_32(self.bitDepth, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_transcode_event(
+    AudioTranscodeEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_transcode_status_type(self.status, serializer);
+    sse_encode_f_64(self.fraction, serializer);
+    sse_encode_opt_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_transcode_job(
+    AudioTranscodeJob self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.inputPath, serializer);
+    sse_encode_String(self.outputPath, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_transcode_settings(
+    AudioTranscodeSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_transcode_file_format(self.format, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+    sse_encode_u_32(self.bitDepth, serializer);
+    sse_encode_dither_type(self.dither, serializer);
+    sse_encode_u_32(self.opusBitrate, serializer);
+    sse_encode_bool(self.copyTags, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_album_palette(
+    AlbumPalette self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_album_palette(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_event_type(
+    AudioEventType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_event_type(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_output_format(
+    AudioOutputFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_output_format(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_progress(
+    AudioProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_progress(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_render_settings(
+    AudioRenderSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_render_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_transcode_settings(
+    AudioTranscodeSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_transcode_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_group_query(
+    GroupQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_grouping_options(
+    GroupingOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_grouping_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_library_song(
+    LibrarySong self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_library_song(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_lyrics(Lyrics self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_lyrics(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_playlist(
+    Playlist self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_playlist(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scan_options(
+    ScanOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scan_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_smart_playlist(
+    SmartPlaylist self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_smart_playlist(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_song_query(
+    SongQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_song_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_tag_changes(
+    TagChanges self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tag_changes(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_usize(raw);
+  void sse_encode_box_autoadd_track_range(
+    TrackRange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_track_range(self, serializer);
   }
 
   @protected
-  CrossfadeCurveType dco_decode_crossfade_curve_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CrossfadeCurveType.values[raw as int];
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self, serializer);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_chapter(Chapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_f_64(self.startSecs, serializer);
+    sse_encode_f_64(self.endSecs, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_crossfade_curve_type(
+    CrossfadeCurveType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_dither_type(DitherType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<AudioFileMetadata> dco_decode_list_audio_file_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_audio_file_metadata).toList();
+  void sse_encode_duplicate_file(DuplicateFile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.format, serializer);
+    sse_encode_bool(self.lossless, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitrateKbps, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitDepth, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.durationSecs, serializer);
+    sse_encode_f_32(self.similarity, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_duplicate_group(
+    DuplicateGroup self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_duplicate_file(self.files, serializer);
+    sse_encode_String(self.keepPath, serializer);
   }
 
   @protected
-  List<(String, PlatformInt64)> dco_decode_list_record_string_i_64(
-    dynamic raw,
+  void sse_encode_duplicate_report(
+    DuplicateReport self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_i_64).toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_duplicate_group(self.groups, serializer);
+    sse_encode_list_scan_failure(self.failed, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_equalizer_band(EqualizerBand self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.frequencyHz, serializer);
+    sse_encode_f_32(self.gainDb, serializer);
+    sse_encode_f_32(self.q, serializer);
   }
 
   @protected
-  AudioEventType? dco_decode_opt_box_autoadd_audio_event_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_audio_event_type(raw);
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  AudioProgress? dco_decode_opt_box_autoadd_audio_progress(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_audio_progress(raw);
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  void sse_encode_group_page(GroupPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_library_group(self.groups, serializer);
+    sse_encode_u_32(self.total, serializer);
   }
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  void sse_encode_group_query(GroupQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.search, serializer);
+    sse_encode_opt_String(self.folder, serializer);
+    sse_encode_group_sort_type(self.sort, serializer);
+    sse_encode_bool(self.descending, serializer);
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  void sse_encode_group_sort_type(
+    GroupSortType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  void sse_encode_grouping_options(
+    GroupingOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.artistSeparators, serializer);
+    sse_encode_list_String(self.articles, serializer);
+    sse_encode_String(self.variousArtists, serializer);
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  (String, PlatformInt64) dco_decode_record_string_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_i_64(arr[1]));
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  ScanResult dco_decode_scan_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ScanResult(
-      newOrModified: dco_decode_list_audio_file_metadata(arr[0]),
-      deletedPaths: dco_decode_list_String(arr[1]),
-    );
+  void sse_encode_library_album(LibraryAlbum self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_bool(self.compilation, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.year, serializer);
+    sse_encode_u_32(self.songCount, serializer);
+    sse_encode_u_32(self.discCount, serializer);
+    sse_encode_u_64(self.durationSecs, serializer);
+    sse_encode_opt_String(self.albumArtPath, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_library_folder(LibraryFolder self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.songCount, serializer);
+    sse_encode_i_64(self.addedAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.scannedAt, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_library_group(LibraryGroup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.songCount, serializer);
+    sse_encode_u_32(self.albumCount, serializer);
+    sse_encode_u_64(self.durationSecs, serializer);
+    sse_encode_opt_String(self.albumArtPath, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_library_song(LibrarySong self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.folder, serializer);
+    sse_encode_String(self.displayTitle, serializer);
+    sse_encode_i_64(self.dateAdded, serializer);
+    sse_encode_u_32(self.playCount, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastPlayed, serializer);
+    sse_encode_bool(self.favorite, serializer);
+    sse_encode_opt_String(self.albumKey, serializer);
+    sse_encode_list_String(self.artistKeys, serializer);
+    sse_encode_audio_file_metadata(self.metadata, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_list_audio_file_metadata(
+    List<AudioFileMetadata> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audio_file_metadata(item, serializer);
+    }
   }
 
   @protected
-  Map<String, PlatformInt64> sse_decode_Map_String_i_64_None(
-    SseDeserializer deserializer,
+  void sse_encode_list_audio_transcode_job(
+    List<AudioTranscodeJob> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_i_64(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audio_transcode_job(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_chapter(List<Chapter> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chapter(item, serializer);
+    }
   }
 
   @protected
-  AudioEventType sse_decode_audio_event_type(SseDeserializer deserializer) {
+  void sse_encode_list_duplicate_file(
+    List<DuplicateFile> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_file(item, serializer);
+    }
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_state = sse_decode_String(deserializer);
-        return AudioEventType_StateChanged(state: var_state);
-      case 1:
-        var var_positionSecs = sse_decode_f_64(deserializer);
-        var var_durationSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
-        var var_bufferLevel = sse_decode_f_32(deserializer);
-        return AudioEventType_Progress(
-          positionSecs: var_positionSecs,
-          durationSecs: var_durationSecs,
-          bufferLevel: var_bufferLevel,
-        );
-      case 2:
-        var var_path = sse_decode_String(deserializer);
-        return AudioEventType_TrackEnded(path: var_path);
-      case 3:
-        var var_fromPath = sse_decode_String(deserializer);
-        var var_toPath = sse_decode_String(deserializer);
-        return AudioEventType_CrossfadeStarted(
-          fromPath: var_fromPath,
-          toPath: var_toPath,
-        );
-      case 4:
-        var var_message = sse_decode_String(deserializer);
-        return AudioEventType_Error(message: var_message);
-      case 5:
-        var var_path = sse_decode_String(deserializer);
-        return AudioEventType_NextTrackReady(path: var_path);
-      default:
-        throw UnimplementedError('');
+  @protected
+  void sse_encode_list_duplicate_group(
+    List<DuplicateGroup> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_group(item, serializer);
     }
   }
 
   @protected
-  AudioFileMetadata sse_decode_audio_file_metadata(
-    SseDeserializer deserializer,
+  void sse_encode_list_equalizer_band(
+    List<EqualizerBand> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_String(deserializer);
-    var var_title = sse_decode_opt_String(deserializer);
-    var var_artist = sse_decode_opt_String(deserializer);
-    var var_album = sse_decode_opt_String(deserializer);
-    var var_durationSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_format = sse_decode_String(deserializer);
-    var var_lastModified = sse_decode_i_64(deserializer);
-    return AudioFileMetadata(
-      path: var_path,
-      title: var_title,
-      artist: var_artist,
-      album: var_album,
-      durationSecs: var_durationSecs,
-      format: var_format,
-      lastModified: var_lastModified,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_equalizer_band(item, serializer);
+    }
   }
 
   @protected
-  AudioProgress sse_decode_audio_progress(SseDeserializer deserializer) {
+  void sse_encode_list_library_album(
+    List<LibraryAlbum> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_positionSecs = sse_decode_f_64(deserializer);
-    var var_durationSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_bufferLevel = sse_decode_f_32(deserializer);
-    return AudioProgress(
-      positionSecs: var_positionSecs,
-      durationSecs: var_durationSecs,
-      bufferLevel: var_bufferLevel,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_album(item, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_library_folder(
+    List<LibraryFolder> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_folder(item, serializer);
+    }
   }
 
   @protected
-  AudioEventType sse_decode_box_autoadd_audio_event_type(
-    SseDeserializer deserializer,
+  void sse_encode_list_library_group(
+    List<LibraryGroup> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_audio_event_type(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_group(item, serializer);
+    }
   }
 
   @protected
-  AudioProgress sse_decode_box_autoadd_audio_progress(
-    SseDeserializer deserializer,
+  void sse_encode_list_library_song(
+    List<LibrarySong> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_audio_progress(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_song(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+  void sse_encode_list_lyrics_line(
+    List<LyricsLine> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lyrics_line(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_lyrics_word(
+    List<LyricsWord> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lyrics_word(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_playlist_entry(
+    List<PlaylistEntry> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_playlist_entry(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+  void sse_encode_list_record_string_i_64(
+    List<(String, PlatformInt64)> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_usize(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_i_64(item, serializer);
+    }
   }
 
   @protected
-  CrossfadeCurveType sse_decode_crossfade_curve_type(
-    SseDeserializer deserializer,
+  void sse_encode_list_scan_failure(
+    List<ScanFailure> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return CrossfadeCurveType.values[inner];
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_scan_failure(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_list_scan_move(
+    List<ScanMove> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_scan_move(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_smart_condition(
+    List<SmartCondition> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_smart_condition(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_smart_rule(
+    List<SmartRule> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_smart_rule(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_tag_field(
+    List<TagField> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag_field(item, serializer);
+    }
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_list_tag_write_failure(
+    List<TagWriteFailure> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag_write_failure(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<AudioFileMetadata> sse_decode_list_audio_file_metadata(
-    SseDeserializer deserializer,
+  void sse_encode_list_unresolved_entry(
+    List<UnresolvedEntry> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AudioFileMetadata>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_audio_file_metadata(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_unresolved_entry(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_lyrics(Lyrics self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_lyrics_source_type(self.source, serializer);
+    sse_encode_bool(self.synced, serializer);
+    sse_encode_list_lyrics_line(self.lines, serializer);
   }
 
   @protected
-  List<(String, PlatformInt64)> sse_decode_list_record_string_i_64(
-    SseDeserializer deserializer,
+  void sse_encode_lyrics_line(LyricsLine self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_32(self.startMs, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_lyrics_word(self.words, serializer);
+  }
+
+  @protected
+  void sse_encode_lyrics_source_type(
+    LyricsSourceType self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, PlatformInt64)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_i_64(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_lyrics_word(LyricsWord self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.startMs, serializer);
+    sse_encode_String(self.text, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
   }
 
   @protected
-  AudioEventType? sse_decode_opt_box_autoadd_audio_event_type(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_album_palette(
+    AlbumPalette? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_audio_event_type(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_album_palette(self, serializer);
     }
   }
 
   @protected
-  AudioProgress? sse_decode_opt_box_autoadd_audio_progress(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_audio_event_type(
+    AudioEventType? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_audio_progress(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_event_type(self, serializer);
     }
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_audio_output_format(
+    AudioOutputFormat? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_32(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_output_format(self, serializer);
     }
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_audio_progress(
+    AudioProgress? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_progress(self, serializer);
     }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
     }
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
     }
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_usize(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
     }
   }
 
   @protected
-  (String, PlatformInt64) sse_decode_record_string_i_64(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_library_song(
+    LibrarySong? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_i_64(deserializer);
-    return (var_field0, var_field1);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_library_song(self, serializer);
+    }
   }
 
   @protected
-  ScanResult sse_decode_scan_result(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_lyrics(
+    Lyrics? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_newOrModified = sse_decode_list_audio_file_metadata(deserializer);
-    var var_deletedPaths = sse_decode_list_String(deserializer);
-    return ScanResult(
-      newOrModified: var_newOrModified,
-      deletedPaths: var_deletedPaths,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_lyrics(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_scan_options(
+    ScanOptions? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_scan_options(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_track_range(
+    TrackRange? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_track_range(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Map_String_i_64_None(
-    Map<String, PlatformInt64> self,
+  void sse_encode_opt_box_autoadd_usize(
+    BigInt? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_i_64(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_usize(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_audio_event_type(
-    AudioEventType self,
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case AudioEventType_StateChanged(state: final state):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(state, serializer);
-      case AudioEventType_Progress(
-        positionSecs: final positionSecs,
-        durationSecs: final durationSecs,
-        bufferLevel: final bufferLevel,
-      ):
-        sse_encode_i_32(1, serializer);
-        sse_encode_f_64(positionSecs, serializer);
-        sse_encode_opt_box_autoadd_f_64(durationSecs, serializer);
-        sse_encode_f_32(bufferLevel, serializer);
-      case AudioEventType_TrackEnded(path: final path):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(path, serializer);
-      case AudioEventType_CrossfadeStarted(
-        fromPath: final fromPath,
-        toPath: final toPath,
-      ):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(fromPath, serializer);
-        sse_encode_String(toPath, serializer);
-      case AudioEventType_Error(message: final message):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(message, serializer);
-      case AudioEventType_NextTrackReady(path: final path):
-        sse_encode_i_32(5, serializer);
-        sse_encode_String(path, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_audio_file_metadata(
-    AudioFileMetadata self,
+  void sse_encode_output_sample_format_type(
+    OutputSampleFormatType self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.path, serializer);
-    sse_encode_opt_String(self.title, serializer);
-    sse_encode_opt_String(self.artist, serializer);
-    sse_encode_opt_String(self.album, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.durationSecs, serializer);
-    sse_encode_String(self.format, serializer);
-    sse_encode_i_64(self.lastModified, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_audio_progress(AudioProgress self, SseSerializer serializer) {
+  void sse_encode_playlist(Playlist self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.positionSecs, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.durationSecs, serializer);
-    sse_encode_f_32(self.bufferLevel, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_list_playlist_entry(self.entries, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_playlist_entry(PlaylistEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.location, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.durationSecs, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_audio_event_type(
-    AudioEventType self,
+  void sse_encode_playlist_import(
+    PlaylistImport self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_audio_event_type(self, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_list_String(self.paths, serializer);
+    sse_encode_list_unresolved_entry(self.unresolved, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_audio_progress(
-    AudioProgress self,
+  void sse_encode_record_string_i_64(
+    (String, PlatformInt64) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_audio_progress(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self, serializer);
+    sse_encode_String(self.$1, serializer);
+    sse_encode_i_64(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+  void sse_encode_render_file_format(
+    RenderFileFormat self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_render_phase_type(
+    RenderPhaseType self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_scan_event_type(
+    ScanEventType self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_scan_failure(ScanFailure self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_scan_failure_type(self.reason, serializer);
+    sse_encode_String(self.detail, serializer);
   }
 
   @protected
-  void sse_encode_crossfade_curve_type(
-    CrossfadeCurveType self,
+  void sse_encode_scan_failure_type(
+    ScanFailureType self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1580,176 +7017,188 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_scan_move(ScanMove self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_String(self.oldPath, serializer);
+    sse_encode_String(self.newPath, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_scan_options(ScanOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_list_String(self.extensions, serializer);
+    sse_encode_bool(self.sniffContent, serializer);
+    sse_encode_list_String(self.excludeGlobs, serializer);
+    sse_encode_bool(self.honorMarkerFiles, serializer);
+    sse_encode_u_32(self.minDurationSecs, serializer);
+    sse_encode_u_32(self.minFileSize, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxDepth, serializer);
+    sse_encode_bool(self.followSymlinks, serializer);
+    sse_encode_opt_String(self.fingerprintIndex, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_scan_result(ScanResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_list_audio_file_metadata(self.newOrModified, serializer);
+    sse_encode_list_String(self.deletedPaths, serializer);
+    sse_encode_list_scan_move(self.moved, serializer);
+    sse_encode_list_scan_failure(self.failed, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_scan_stream_event(
+    ScanStreamEvent self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_scan_event_type(self.kind, serializer);
+    sse_encode_list_audio_file_metadata(self.files, serializer);
+    sse_encode_list_scan_failure(self.failures, serializer);
+    sse_encode_list_String(self.deletedPaths, serializer);
+    sse_encode_list_scan_move(self.moved, serializer);
+    sse_encode_u_32(self.discovered, serializer);
+    sse_encode_u_32(self.processed, serializer);
+    sse_encode_u_32(self.failed, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_smart_condition(
+    SmartCondition self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_smart_field(self.field, serializer);
+    sse_encode_smart_operator(self.operator_, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_f_64(self.number, serializer);
   }
 
   @protected
-  void sse_encode_list_audio_file_metadata(
-    List<AudioFileMetadata> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_smart_field(SmartField self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_audio_file_metadata(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_smart_operator(SmartOperator self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_record_string_i_64(
-    List<(String, PlatformInt64)> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_smart_playlist(SmartPlaylist self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_i_64(item, serializer);
-    }
+    sse_encode_smart_rule(self.rule, serializer);
+    sse_encode_smart_sort_type(self.sort, serializer);
+    sse_encode_bool(self.descending, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.durationLimitSecs, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_smart_rule(SmartRule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sse_encode_bool(self.matchAll, serializer);
+    sse_encode_list_smart_condition(self.conditions, serializer);
+    sse_encode_list_smart_rule(self.groups, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_audio_event_type(
-    AudioEventType? self,
+  void sse_encode_smart_sort_type(
+    SmartSortType self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_audio_event_type(self, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_audio_progress(
-    AudioProgress? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_song_page(SongPage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_audio_progress(self, serializer);
-    }
+    sse_encode_list_library_song(self.songs, serializer);
+    sse_encode_u_32(self.total, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
+  void sse_encode_song_query(SongQuery self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_32(self, serializer);
-    }
+    sse_encode_opt_String(self.search, serializer);
+    sse_encode_opt_String(self.folder, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_String(self.albumKey, serializer);
+    sse_encode_opt_String(self.genre, serializer);
+    sse_encode_song_sort_type(self.sort, serializer);
+    sse_encode_bool(self.descending, serializer);
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+  void sse_encode_song_sort_type(SongSortType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+  void sse_encode_tag_changes(TagChanges self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_String(self.albumArtist, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.trackNumber, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.trackTotal, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.discNumber, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.discTotal, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.year, serializer);
+    sse_encode_opt_String(self.genre, serializer);
+    sse_encode_opt_String(self.lyrics, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.coverArt, serializer);
+    sse_encode_list_tag_field(self.remove, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+  void sse_encode_tag_field(TagField self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_usize(
-    BigInt? self,
+  void sse_encode_tag_write_failure(
+    TagWriteFailure self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.error, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_usize(self, serializer);
-    }
+  @protected
+  void sse_encode_track_range(TrackRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sourcePath, serializer);
+    sse_encode_f_64(self.startSecs, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.endSecs, serializer);
   }
 
   @protected
-  void sse_encode_record_string_i_64(
-    (String, PlatformInt64) self,
+  void sse_encode_transcode_file_format(
+    TranscodeFileFormat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_i_64(self.$2, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_scan_result(ScanResult self, SseSerializer serializer) {
+  void sse_encode_transcode_status_type(
+    TranscodeStatusType self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_audio_file_metadata(self.newOrModified, serializer);
-    sse_encode_list_String(self.deletedPaths, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -1775,9 +7224,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_unresolved_entry(
+    UnresolvedEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_playlist_entry(self.entry, serializer);
+  }
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
+
+  @protected
+  void sse_encode_watch_event(WatchEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_audio_file_metadata(self.added, serializer);
+    sse_encode_list_audio_file_metadata(self.modified, serializer);
+    sse_encode_list_String(self.removedPaths, serializer);
+    sse_encode_list_scan_move(self.moved, serializer);
+    sse_encode_list_scan_failure(self.failures, serializer);
+    sse_encode_bool(self.rescanNeeded, serializer);
+  }
 }
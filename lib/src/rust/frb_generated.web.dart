@@ -22,379 +22,1569 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
   @protected
   Map<String, PlatformInt64> dco_decode_Map_String_i_64_None(dynamic raw);
 
+  @protected
+  RustStreamSink<AudioRenderProgress>
+  dco_decode_StreamSink_audio_render_progress_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<AudioTranscodeEvent>
+  dco_decode_StreamSink_audio_transcode_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<List<LibrarySong>> dco_decode_StreamSink_list_library_song_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ScanStreamEvent> dco_decode_StreamSink_scan_stream_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<WatchEvent> dco_decode_StreamSink_watch_event_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AlbumPage dco_decode_album_page(dynamic raw);
+
+  @protected
+  AlbumPalette dco_decode_album_palette(dynamic raw);
+
   @protected
   AudioEventType dco_decode_audio_event_type(dynamic raw);
 
   @protected
   AudioFileMetadata dco_decode_audio_file_metadata(dynamic raw);
 
+  @protected
+  AudioOutputFormat dco_decode_audio_output_format(dynamic raw);
+
   @protected
   AudioProgress dco_decode_audio_progress(dynamic raw);
 
+  @protected
+  AudioRenderProgress dco_decode_audio_render_progress(dynamic raw);
+
+  @protected
+  AudioRenderSettings dco_decode_audio_render_settings(dynamic raw);
+
+  @protected
+  AudioTranscodeEvent dco_decode_audio_transcode_event(dynamic raw);
+
+  @protected
+  AudioTranscodeJob dco_decode_audio_transcode_job(dynamic raw);
+
+  @protected
+  AudioTranscodeSettings dco_decode_audio_transcode_settings(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
   @protected
-  AudioEventType dco_decode_box_autoadd_audio_event_type(dynamic raw);
+  AlbumPalette dco_decode_box_autoadd_album_palette(dynamic raw);
+
+  @protected
+  AudioEventType dco_decode_box_autoadd_audio_event_type(dynamic raw);
+
+  @protected
+  AudioOutputFormat dco_decode_box_autoadd_audio_output_format(dynamic raw);
+
+  @protected
+  AudioProgress dco_decode_box_autoadd_audio_progress(dynamic raw);
+
+  @protected
+  AudioRenderSettings dco_decode_box_autoadd_audio_render_settings(dynamic raw);
+
+  @protected
+  AudioTranscodeSettings dco_decode_box_autoadd_audio_transcode_settings(
+    dynamic raw,
+  );
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  GroupQuery dco_decode_box_autoadd_group_query(dynamic raw);
+
+  @protected
+  GroupingOptions dco_decode_box_autoadd_grouping_options(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  LibrarySong dco_decode_box_autoadd_library_song(dynamic raw);
+
+  @protected
+  Lyrics dco_decode_box_autoadd_lyrics(dynamic raw);
+
+  @protected
+  Playlist dco_decode_box_autoadd_playlist(dynamic raw);
+
+  @protected
+  ScanOptions dco_decode_box_autoadd_scan_options(dynamic raw);
+
+  @protected
+  SmartPlaylist dco_decode_box_autoadd_smart_playlist(dynamic raw);
+
+  @protected
+  SongQuery dco_decode_box_autoadd_song_query(dynamic raw);
+
+  @protected
+  TagChanges dco_decode_box_autoadd_tag_changes(dynamic raw);
+
+  @protected
+  TrackRange dco_decode_box_autoadd_track_range(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw);
+
+  @protected
+  Chapter dco_decode_chapter(dynamic raw);
+
+  @protected
+  CrossfadeCurveType dco_decode_crossfade_curve_type(dynamic raw);
+
+  @protected
+  DitherType dco_decode_dither_type(dynamic raw);
+
+  @protected
+  DuplicateFile dco_decode_duplicate_file(dynamic raw);
+
+  @protected
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw);
+
+  @protected
+  DuplicateReport dco_decode_duplicate_report(dynamic raw);
+
+  @protected
+  EqualizerBand dco_decode_equalizer_band(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  GroupPage dco_decode_group_page(dynamic raw);
+
+  @protected
+  GroupQuery dco_decode_group_query(dynamic raw);
+
+  @protected
+  GroupSortType dco_decode_group_sort_type(dynamic raw);
+
+  @protected
+  GroupingOptions dco_decode_grouping_options(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  LibraryAlbum dco_decode_library_album(dynamic raw);
+
+  @protected
+  LibraryFolder dco_decode_library_folder(dynamic raw);
+
+  @protected
+  LibraryGroup dco_decode_library_group(dynamic raw);
+
+  @protected
+  LibrarySong dco_decode_library_song(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<AudioFileMetadata> dco_decode_list_audio_file_metadata(dynamic raw);
+
+  @protected
+  List<AudioTranscodeJob> dco_decode_list_audio_transcode_job(dynamic raw);
+
+  @protected
+  List<Chapter> dco_decode_list_chapter(dynamic raw);
+
+  @protected
+  List<DuplicateFile> dco_decode_list_duplicate_file(dynamic raw);
+
+  @protected
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw);
+
+  @protected
+  List<EqualizerBand> dco_decode_list_equalizer_band(dynamic raw);
+
+  @protected
+  List<LibraryAlbum> dco_decode_list_library_album(dynamic raw);
+
+  @protected
+  List<LibraryFolder> dco_decode_list_library_folder(dynamic raw);
+
+  @protected
+  List<LibraryGroup> dco_decode_list_library_group(dynamic raw);
+
+  @protected
+  List<LibrarySong> dco_decode_list_library_song(dynamic raw);
+
+  @protected
+  List<LyricsLine> dco_decode_list_lyrics_line(dynamic raw);
+
+  @protected
+  List<LyricsWord> dco_decode_list_lyrics_word(dynamic raw);
+
+  @protected
+  List<PlaylistEntry> dco_decode_list_playlist_entry(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<(String, PlatformInt64)> dco_decode_list_record_string_i_64(dynamic raw);
+
+  @protected
+  List<ScanFailure> dco_decode_list_scan_failure(dynamic raw);
+
+  @protected
+  List<ScanMove> dco_decode_list_scan_move(dynamic raw);
+
+  @protected
+  List<SmartCondition> dco_decode_list_smart_condition(dynamic raw);
+
+  @protected
+  List<SmartRule> dco_decode_list_smart_rule(dynamic raw);
+
+  @protected
+  List<TagField> dco_decode_list_tag_field(dynamic raw);
+
+  @protected
+  List<TagWriteFailure> dco_decode_list_tag_write_failure(dynamic raw);
+
+  @protected
+  List<UnresolvedEntry> dco_decode_list_unresolved_entry(dynamic raw);
+
+  @protected
+  Lyrics dco_decode_lyrics(dynamic raw);
+
+  @protected
+  LyricsLine dco_decode_lyrics_line(dynamic raw);
+
+  @protected
+  LyricsSourceType dco_decode_lyrics_source_type(dynamic raw);
+
+  @protected
+  LyricsWord dco_decode_lyrics_word(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  AlbumPalette? dco_decode_opt_box_autoadd_album_palette(dynamic raw);
+
+  @protected
+  AudioEventType? dco_decode_opt_box_autoadd_audio_event_type(dynamic raw);
+
+  @protected
+  AudioOutputFormat? dco_decode_opt_box_autoadd_audio_output_format(
+    dynamic raw,
+  );
+
+  @protected
+  AudioProgress? dco_decode_opt_box_autoadd_audio_progress(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  LibrarySong? dco_decode_opt_box_autoadd_library_song(dynamic raw);
+
+  @protected
+  Lyrics? dco_decode_opt_box_autoadd_lyrics(dynamic raw);
+
+  @protected
+  ScanOptions? dco_decode_opt_box_autoadd_scan_options(dynamic raw);
+
+  @protected
+  TrackRange? dco_decode_opt_box_autoadd_track_range(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  OutputSampleFormatType dco_decode_output_sample_format_type(dynamic raw);
+
+  @protected
+  Playlist dco_decode_playlist(dynamic raw);
+
+  @protected
+  PlaylistEntry dco_decode_playlist_entry(dynamic raw);
+
+  @protected
+  PlaylistImport dco_decode_playlist_import(dynamic raw);
+
+  @protected
+  (String, PlatformInt64) dco_decode_record_string_i_64(dynamic raw);
+
+  @protected
+  RenderFileFormat dco_decode_render_file_format(dynamic raw);
+
+  @protected
+  RenderPhaseType dco_decode_render_phase_type(dynamic raw);
+
+  @protected
+  ScanEventType dco_decode_scan_event_type(dynamic raw);
+
+  @protected
+  ScanFailure dco_decode_scan_failure(dynamic raw);
+
+  @protected
+  ScanFailureType dco_decode_scan_failure_type(dynamic raw);
+
+  @protected
+  ScanMove dco_decode_scan_move(dynamic raw);
+
+  @protected
+  ScanOptions dco_decode_scan_options(dynamic raw);
+
+  @protected
+  ScanResult dco_decode_scan_result(dynamic raw);
+
+  @protected
+  ScanStreamEvent dco_decode_scan_stream_event(dynamic raw);
+
+  @protected
+  SmartCondition dco_decode_smart_condition(dynamic raw);
+
+  @protected
+  SmartField dco_decode_smart_field(dynamic raw);
+
+  @protected
+  SmartOperator dco_decode_smart_operator(dynamic raw);
+
+  @protected
+  SmartPlaylist dco_decode_smart_playlist(dynamic raw);
+
+  @protected
+  SmartRule dco_decode_smart_rule(dynamic raw);
+
+  @protected
+  SmartSortType dco_decode_smart_sort_type(dynamic raw);
+
+  @protected
+  SongPage dco_decode_song_page(dynamic raw);
+
+  @protected
+  SongQuery dco_decode_song_query(dynamic raw);
+
+  @protected
+  SongSortType dco_decode_song_sort_type(dynamic raw);
+
+  @protected
+  TagChanges dco_decode_tag_changes(dynamic raw);
+
+  @protected
+  TagField dco_decode_tag_field(dynamic raw);
+
+  @protected
+  TagWriteFailure dco_decode_tag_write_failure(dynamic raw);
+
+  @protected
+  TrackRange dco_decode_track_range(dynamic raw);
+
+  @protected
+  TranscodeFileFormat dco_decode_transcode_file_format(dynamic raw);
+
+  @protected
+  TranscodeStatusType dco_decode_transcode_status_type(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  UnresolvedEntry dco_decode_unresolved_entry(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WatchEvent dco_decode_watch_event(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  Map<String, PlatformInt64> sse_decode_Map_String_i_64_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<AudioRenderProgress>
+  sse_decode_StreamSink_audio_render_progress_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<AudioTranscodeEvent>
+  sse_decode_StreamSink_audio_transcode_event_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<List<LibrarySong>> sse_decode_StreamSink_list_library_song_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ScanStreamEvent> sse_decode_StreamSink_scan_stream_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<WatchEvent> sse_decode_StreamSink_watch_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AlbumPage sse_decode_album_page(SseDeserializer deserializer);
+
+  @protected
+  AlbumPalette sse_decode_album_palette(SseDeserializer deserializer);
+
+  @protected
+  AudioEventType sse_decode_audio_event_type(SseDeserializer deserializer);
+
+  @protected
+  AudioFileMetadata sse_decode_audio_file_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioOutputFormat sse_decode_audio_output_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioProgress sse_decode_audio_progress(SseDeserializer deserializer);
+
+  @protected
+  AudioRenderProgress sse_decode_audio_render_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioRenderSettings sse_decode_audio_render_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioTranscodeEvent sse_decode_audio_transcode_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioTranscodeJob sse_decode_audio_transcode_job(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioTranscodeSettings sse_decode_audio_transcode_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AlbumPalette sse_decode_box_autoadd_album_palette(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioEventType sse_decode_box_autoadd_audio_event_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioOutputFormat sse_decode_box_autoadd_audio_output_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioProgress sse_decode_box_autoadd_audio_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioRenderSettings sse_decode_box_autoadd_audio_render_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioTranscodeSettings sse_decode_box_autoadd_audio_transcode_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  GroupQuery sse_decode_box_autoadd_group_query(SseDeserializer deserializer);
+
+  @protected
+  GroupingOptions sse_decode_box_autoadd_grouping_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  LibrarySong sse_decode_box_autoadd_library_song(SseDeserializer deserializer);
+
+  @protected
+  Lyrics sse_decode_box_autoadd_lyrics(SseDeserializer deserializer);
+
+  @protected
+  Playlist sse_decode_box_autoadd_playlist(SseDeserializer deserializer);
+
+  @protected
+  ScanOptions sse_decode_box_autoadd_scan_options(SseDeserializer deserializer);
+
+  @protected
+  SmartPlaylist sse_decode_box_autoadd_smart_playlist(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SongQuery sse_decode_box_autoadd_song_query(SseDeserializer deserializer);
+
+  @protected
+  TagChanges sse_decode_box_autoadd_tag_changes(SseDeserializer deserializer);
+
+  @protected
+  TrackRange sse_decode_box_autoadd_track_range(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  Chapter sse_decode_chapter(SseDeserializer deserializer);
+
+  @protected
+  CrossfadeCurveType sse_decode_crossfade_curve_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DitherType sse_decode_dither_type(SseDeserializer deserializer);
+
+  @protected
+  DuplicateFile sse_decode_duplicate_file(SseDeserializer deserializer);
+
+  @protected
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer);
+
+  @protected
+  DuplicateReport sse_decode_duplicate_report(SseDeserializer deserializer);
+
+  @protected
+  EqualizerBand sse_decode_equalizer_band(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  GroupPage sse_decode_group_page(SseDeserializer deserializer);
+
+  @protected
+  GroupQuery sse_decode_group_query(SseDeserializer deserializer);
+
+  @protected
+  GroupSortType sse_decode_group_sort_type(SseDeserializer deserializer);
+
+  @protected
+  GroupingOptions sse_decode_grouping_options(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  LibraryAlbum sse_decode_library_album(SseDeserializer deserializer);
+
+  @protected
+  LibraryFolder sse_decode_library_folder(SseDeserializer deserializer);
+
+  @protected
+  LibraryGroup sse_decode_library_group(SseDeserializer deserializer);
+
+  @protected
+  LibrarySong sse_decode_library_song(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AudioFileMetadata> sse_decode_list_audio_file_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AudioTranscodeJob> sse_decode_list_audio_transcode_job(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Chapter> sse_decode_list_chapter(SseDeserializer deserializer);
+
+  @protected
+  List<DuplicateFile> sse_decode_list_duplicate_file(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EqualizerBand> sse_decode_list_equalizer_band(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LibraryAlbum> sse_decode_list_library_album(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LibraryFolder> sse_decode_list_library_folder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LibraryGroup> sse_decode_list_library_group(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LibrarySong> sse_decode_list_library_song(SseDeserializer deserializer);
+
+  @protected
+  List<LyricsLine> sse_decode_list_lyrics_line(SseDeserializer deserializer);
+
+  @protected
+  List<LyricsWord> sse_decode_list_lyrics_word(SseDeserializer deserializer);
+
+  @protected
+  List<PlaylistEntry> sse_decode_list_playlist_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<(String, PlatformInt64)> sse_decode_list_record_string_i_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ScanFailure> sse_decode_list_scan_failure(SseDeserializer deserializer);
+
+  @protected
+  List<ScanMove> sse_decode_list_scan_move(SseDeserializer deserializer);
+
+  @protected
+  List<SmartCondition> sse_decode_list_smart_condition(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SmartRule> sse_decode_list_smart_rule(SseDeserializer deserializer);
+
+  @protected
+  List<TagField> sse_decode_list_tag_field(SseDeserializer deserializer);
+
+  @protected
+  List<TagWriteFailure> sse_decode_list_tag_write_failure(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<UnresolvedEntry> sse_decode_list_unresolved_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Lyrics sse_decode_lyrics(SseDeserializer deserializer);
+
+  @protected
+  LyricsLine sse_decode_lyrics_line(SseDeserializer deserializer);
+
+  @protected
+  LyricsSourceType sse_decode_lyrics_source_type(SseDeserializer deserializer);
+
+  @protected
+  LyricsWord sse_decode_lyrics_word(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AlbumPalette? sse_decode_opt_box_autoadd_album_palette(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioEventType? sse_decode_opt_box_autoadd_audio_event_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioOutputFormat? sse_decode_opt_box_autoadd_audio_output_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioProgress? sse_decode_opt_box_autoadd_audio_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  LibrarySong? sse_decode_opt_box_autoadd_library_song(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Lyrics? sse_decode_opt_box_autoadd_lyrics(SseDeserializer deserializer);
+
+  @protected
+  ScanOptions? sse_decode_opt_box_autoadd_scan_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TrackRange? sse_decode_opt_box_autoadd_track_range(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  OutputSampleFormatType sse_decode_output_sample_format_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Playlist sse_decode_playlist(SseDeserializer deserializer);
+
+  @protected
+  PlaylistEntry sse_decode_playlist_entry(SseDeserializer deserializer);
+
+  @protected
+  PlaylistImport sse_decode_playlist_import(SseDeserializer deserializer);
+
+  @protected
+  (String, PlatformInt64) sse_decode_record_string_i_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RenderFileFormat sse_decode_render_file_format(SseDeserializer deserializer);
+
+  @protected
+  RenderPhaseType sse_decode_render_phase_type(SseDeserializer deserializer);
+
+  @protected
+  ScanEventType sse_decode_scan_event_type(SseDeserializer deserializer);
+
+  @protected
+  ScanFailure sse_decode_scan_failure(SseDeserializer deserializer);
+
+  @protected
+  ScanFailureType sse_decode_scan_failure_type(SseDeserializer deserializer);
+
+  @protected
+  ScanMove sse_decode_scan_move(SseDeserializer deserializer);
+
+  @protected
+  ScanOptions sse_decode_scan_options(SseDeserializer deserializer);
+
+  @protected
+  ScanResult sse_decode_scan_result(SseDeserializer deserializer);
+
+  @protected
+  ScanStreamEvent sse_decode_scan_stream_event(SseDeserializer deserializer);
+
+  @protected
+  SmartCondition sse_decode_smart_condition(SseDeserializer deserializer);
+
+  @protected
+  SmartField sse_decode_smart_field(SseDeserializer deserializer);
+
+  @protected
+  SmartOperator sse_decode_smart_operator(SseDeserializer deserializer);
+
+  @protected
+  SmartPlaylist sse_decode_smart_playlist(SseDeserializer deserializer);
+
+  @protected
+  SmartRule sse_decode_smart_rule(SseDeserializer deserializer);
+
+  @protected
+  SmartSortType sse_decode_smart_sort_type(SseDeserializer deserializer);
+
+  @protected
+  SongPage sse_decode_song_page(SseDeserializer deserializer);
+
+  @protected
+  SongQuery sse_decode_song_query(SseDeserializer deserializer);
+
+  @protected
+  SongSortType sse_decode_song_sort_type(SseDeserializer deserializer);
+
+  @protected
+  TagChanges sse_decode_tag_changes(SseDeserializer deserializer);
+
+  @protected
+  TagField sse_decode_tag_field(SseDeserializer deserializer);
+
+  @protected
+  TagWriteFailure sse_decode_tag_write_failure(SseDeserializer deserializer);
+
+  @protected
+  TrackRange sse_decode_track_range(SseDeserializer deserializer);
+
+  @protected
+  TranscodeFileFormat sse_decode_transcode_file_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TranscodeStatusType sse_decode_transcode_status_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  UnresolvedEntry sse_decode_unresolved_entry(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  WatchEvent sse_decode_watch_event(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_i_64_None(
+    Map<String, PlatformInt64> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_audio_render_progress_Sse(
+    RustStreamSink<AudioRenderProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_audio_transcode_event_Sse(
+    RustStreamSink<AudioTranscodeEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_list_library_song_Sse(
+    RustStreamSink<List<LibrarySong>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_scan_stream_event_Sse(
+    RustStreamSink<ScanStreamEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_watch_event_Sse(
+    RustStreamSink<WatchEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_album_page(AlbumPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_album_palette(AlbumPalette self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_event_type(
+    AudioEventType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_file_metadata(
+    AudioFileMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_output_format(
+    AudioOutputFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_progress(AudioProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_render_progress(
+    AudioRenderProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_render_settings(
+    AudioRenderSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_transcode_event(
+    AudioTranscodeEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_transcode_job(
+    AudioTranscodeJob self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_transcode_settings(
+    AudioTranscodeSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_album_palette(
+    AlbumPalette self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_event_type(
+    AudioEventType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_output_format(
+    AudioOutputFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_progress(
+    AudioProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_render_settings(
+    AudioRenderSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_transcode_settings(
+    AudioTranscodeSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_group_query(
+    GroupQuery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_grouping_options(
+    GroupingOptions self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AudioProgress dco_decode_box_autoadd_audio_progress(dynamic raw);
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double dco_decode_box_autoadd_f_32(dynamic raw);
+  void sse_encode_box_autoadd_library_song(
+    LibrarySong self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw);
+  void sse_encode_box_autoadd_lyrics(Lyrics self, SseSerializer serializer);
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+  void sse_encode_box_autoadd_playlist(Playlist self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+  void sse_encode_box_autoadd_scan_options(
+    ScanOptions self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_box_autoadd_usize(dynamic raw);
+  void sse_encode_box_autoadd_smart_playlist(
+    SmartPlaylist self,
+    SseSerializer serializer,
+  );
 
   @protected
-  CrossfadeCurveType dco_decode_crossfade_curve_type(dynamic raw);
+  void sse_encode_box_autoadd_song_query(
+    SongQuery self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  void sse_encode_box_autoadd_tag_changes(
+    TagChanges self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double dco_decode_f_64(dynamic raw);
+  void sse_encode_box_autoadd_track_range(
+    TrackRange self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
 
   @protected
-  List<AudioFileMetadata> dco_decode_list_audio_file_metadata(dynamic raw);
+  void sse_encode_chapter(Chapter self, SseSerializer serializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_crossfade_curve_type(
+    CrossfadeCurveType self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<(String, PlatformInt64)> dco_decode_list_record_string_i_64(dynamic raw);
+  void sse_encode_dither_type(DitherType self, SseSerializer serializer);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_duplicate_file(DuplicateFile self, SseSerializer serializer);
 
   @protected
-  AudioEventType? dco_decode_opt_box_autoadd_audio_event_type(dynamic raw);
+  void sse_encode_duplicate_group(
+    DuplicateGroup self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AudioProgress? dco_decode_opt_box_autoadd_audio_progress(dynamic raw);
+  void sse_encode_duplicate_report(
+    DuplicateReport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+  void sse_encode_equalizer_band(EqualizerBand self, SseSerializer serializer);
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+  void sse_encode_f_32(double self, SseSerializer serializer);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+  void sse_encode_group_page(GroupPage self, SseSerializer serializer);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+  void sse_encode_group_query(GroupQuery self, SseSerializer serializer);
 
   @protected
-  (String, PlatformInt64) dco_decode_record_string_i_64(dynamic raw);
+  void sse_encode_group_sort_type(GroupSortType self, SseSerializer serializer);
 
   @protected
-  ScanResult dco_decode_scan_result(dynamic raw);
+  void sse_encode_grouping_options(
+    GroupingOptions self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_library_album(LibraryAlbum self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_library_folder(LibraryFolder self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_library_group(LibraryGroup self, SseSerializer serializer);
 
   @protected
-  Map<String, PlatformInt64> sse_decode_Map_String_i_64_None(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_library_song(LibrarySong self, SseSerializer serializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  AudioEventType sse_decode_audio_event_type(SseDeserializer deserializer);
+  void sse_encode_list_audio_file_metadata(
+    List<AudioFileMetadata> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AudioFileMetadata sse_decode_audio_file_metadata(
-    SseDeserializer deserializer,
+  void sse_encode_list_audio_transcode_job(
+    List<AudioTranscodeJob> self,
+    SseSerializer serializer,
   );
 
   @protected
-  AudioProgress sse_decode_audio_progress(SseDeserializer deserializer);
+  void sse_encode_list_chapter(List<Chapter> self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_list_duplicate_file(
+    List<DuplicateFile> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AudioEventType sse_decode_box_autoadd_audio_event_type(
-    SseDeserializer deserializer,
+  void sse_encode_list_duplicate_group(
+    List<DuplicateGroup> self,
+    SseSerializer serializer,
   );
 
   @protected
-  AudioProgress sse_decode_box_autoadd_audio_progress(
-    SseDeserializer deserializer,
+  void sse_encode_list_equalizer_band(
+    List<EqualizerBand> self,
+    SseSerializer serializer,
   );
 
   @protected
-  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+  void sse_encode_list_library_album(
+    List<LibraryAlbum> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+  void sse_encode_list_library_folder(
+    List<LibraryFolder> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  void sse_encode_list_library_group(
+    List<LibraryGroup> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  void sse_encode_list_library_song(
+    List<LibrarySong> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+  void sse_encode_list_lyrics_line(
+    List<LyricsLine> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  CrossfadeCurveType sse_decode_crossfade_curve_type(
-    SseDeserializer deserializer,
+  void sse_encode_list_lyrics_word(
+    List<LyricsWord> self,
+    SseSerializer serializer,
   );
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  void sse_encode_list_playlist_entry(
+    List<PlaylistEntry> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_list_record_string_i_64(
+    List<(String, PlatformInt64)> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_list_scan_failure(
+    List<ScanFailure> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  void sse_encode_list_scan_move(List<ScanMove> self, SseSerializer serializer);
 
   @protected
-  List<AudioFileMetadata> sse_decode_list_audio_file_metadata(
-    SseDeserializer deserializer,
+  void sse_encode_list_smart_condition(
+    List<SmartCondition> self,
+    SseSerializer serializer,
   );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  List<(String, PlatformInt64)> sse_decode_list_record_string_i_64(
-    SseDeserializer deserializer,
+  void sse_encode_list_smart_rule(
+    List<SmartRule> self,
+    SseSerializer serializer,
   );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_list_tag_field(List<TagField> self, SseSerializer serializer);
 
   @protected
-  AudioEventType? sse_decode_opt_box_autoadd_audio_event_type(
-    SseDeserializer deserializer,
+  void sse_encode_list_tag_write_failure(
+    List<TagWriteFailure> self,
+    SseSerializer serializer,
   );
 
   @protected
-  AudioProgress? sse_decode_opt_box_autoadd_audio_progress(
-    SseDeserializer deserializer,
+  void sse_encode_list_unresolved_entry(
+    List<UnresolvedEntry> self,
+    SseSerializer serializer,
   );
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+  void sse_encode_lyrics(Lyrics self, SseSerializer serializer);
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+  void sse_encode_lyrics_line(LyricsLine self, SseSerializer serializer);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  void sse_encode_lyrics_source_type(
+    LyricsSourceType self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+  void sse_encode_lyrics_word(LyricsWord self, SseSerializer serializer);
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  (String, PlatformInt64) sse_decode_record_string_i_64(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_album_palette(
+    AlbumPalette? self,
+    SseSerializer serializer,
   );
 
   @protected
-  ScanResult sse_decode_scan_result(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_audio_event_type(
+    AudioEventType? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_audio_output_format(
+    AudioOutputFormat? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_audio_progress(
+    AudioProgress? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_Map_String_i_64_None(
-    Map<String, PlatformInt64> self,
+  void sse_encode_opt_box_autoadd_library_song(
+    LibrarySong? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_lyrics(
+    Lyrics? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_audio_event_type(
-    AudioEventType self,
+  void sse_encode_opt_box_autoadd_scan_options(
+    ScanOptions? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_audio_file_metadata(
-    AudioFileMetadata self,
+  void sse_encode_opt_box_autoadd_track_range(
+    TrackRange? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_audio_progress(AudioProgress self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_audio_event_type(
-    AudioEventType self,
+  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_audio_progress(
-    AudioProgress self,
+  void sse_encode_output_sample_format_type(
+    OutputSampleFormatType self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+  void sse_encode_playlist(Playlist self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+  void sse_encode_playlist_entry(PlaylistEntry self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  void sse_encode_playlist_import(
+    PlaylistImport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+  void sse_encode_record_string_i_64(
+    (String, PlatformInt64) self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+  void sse_encode_render_file_format(
+    RenderFileFormat self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_crossfade_curve_type(
-    CrossfadeCurveType self,
+  void sse_encode_render_phase_type(
+    RenderPhaseType self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_scan_event_type(ScanEventType self, SseSerializer serializer);
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_scan_failure(ScanFailure self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_scan_failure_type(
+    ScanFailureType self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_scan_move(ScanMove self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_scan_options(ScanOptions self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_audio_file_metadata(
-    List<AudioFileMetadata> self,
-    SseSerializer serializer,
-  );
+  void sse_encode_scan_result(ScanResult self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_scan_stream_event(
+    ScanStreamEvent self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_record_string_i_64(
-    List<(String, PlatformInt64)> self,
+  void sse_encode_smart_condition(
+    SmartCondition self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_smart_field(SmartField self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_audio_event_type(
-    AudioEventType? self,
-    SseSerializer serializer,
-  );
+  void sse_encode_smart_operator(SmartOperator self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_audio_progress(
-    AudioProgress? self,
-    SseSerializer serializer,
-  );
+  void sse_encode_smart_playlist(SmartPlaylist self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+  void sse_encode_smart_rule(SmartRule self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+  void sse_encode_smart_sort_type(SmartSortType self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+  void sse_encode_song_page(SongPage self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+  void sse_encode_song_query(SongQuery self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+  void sse_encode_song_sort_type(SongSortType self, SseSerializer serializer);
 
   @protected
-  void sse_encode_record_string_i_64(
-    (String, PlatformInt64) self,
+  void sse_encode_tag_changes(TagChanges self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tag_field(TagField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tag_write_failure(
+    TagWriteFailure self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_scan_result(ScanResult self, SseSerializer serializer);
+  void sse_encode_track_range(TrackRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_transcode_file_format(
+    TranscodeFileFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transcode_status_type(
+    TranscodeStatusType self,
+    SseSerializer serializer,
+  );
 
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
@@ -408,6 +1598,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_unresolved_entry(
+    UnresolvedEntry self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 }
@@ -416,6 +1612,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+  @protected
+  void sse_encode_watch_event(WatchEvent self, SseSerializer serializer);
 }
 
 @JS('wasm_bindgen')
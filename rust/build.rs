@@ -1,10 +1,13 @@
 fn main() {
     println!("cargo:rustc-check-cfg=cfg(frb_expand)");
+    println!("cargo:rustc-check-cfg=cfg(native_audio_engine)");
     println!("cargo:rerun-if-changed=build.rs");
-    
-    // Link against C++ standard library on Android for cpal/oboe
+
+    // The audio engine is always built on desktop platforms. On Android it
+    // is opt-in through the `native_audio` feature (AAudio output via cpal).
     let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
-    if target_os == "android" {
-        println!("cargo:rustc-link-lib=c++_shared");
+    let native_audio = std::env::var_os("CARGO_FEATURE_NATIVE_AUDIO").is_some();
+    if target_os != "android" || native_audio {
+        println!("cargo:rustc-cfg=native_audio_engine");
     }
 }
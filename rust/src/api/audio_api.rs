@@ -1,21 +1,50 @@
 //! Flutter Rust Bridge API for audio engine control.
 //!
 //! This module provides the interface between Dart and the Rust audio engine.
-//! On Android, the native audio engine is disabled due to C++ linking issues,
-//! and all functions return appropriate error/stub values.
+//! On Android the engine is only compiled in when the `native_audio` feature is
+//! enabled; without it all functions return appropriate error/stub values.
 
-#[cfg(not(target_os = "android"))]
-use crate::audio::commands::{AudioEvent, PlaybackState};
-#[cfg(not(target_os = "android"))]
+#[cfg(native_audio_engine)]
+use crate::audio::commands::{AudioEvent, PlaybackState, TimeRange};
+#[cfg(native_audio_engine)]
+use crate::audio::crossfader::CrossfadeCurve;
+#[cfg(native_audio_engine)]
+use crate::audio::dither::DitherMode;
+#[cfg(native_audio_engine)]
 use crate::audio::engine::{create_audio_engine, AudioEngineHandle};
+#[cfg(native_audio_engine)]
+use crate::audio::output::OutputSampleFormat;
+#[cfg(native_audio_engine)]
+use crate::audio::{equalizer, render, transcode};
+use crate::frb_generated::StreamSink;
+#[cfg(native_audio_engine)]
 use once_cell::sync::OnceCell;
-#[cfg(not(target_os = "android"))]
+#[cfg(native_audio_engine)]
 use std::path::PathBuf;
+#[cfg(native_audio_engine)]
+use std::sync::atomic::{AtomicBool, Ordering};
 
-// Global audio engine handle (only used on non-Android platforms)
-#[cfg(not(target_os = "android"))]
+// Global audio engine handle (only used when the native engine is built)
+#[cfg(native_audio_engine)]
 static AUDIO_ENGINE: OnceCell<AudioEngineHandle> = OnceCell::new();
 
+// Offline render state; renders are independent of the playback engine
+#[cfg(native_audio_engine)]
+static RENDER_ACTIVE: AtomicBool = AtomicBool::new(false);
+#[cfg(native_audio_engine)]
+static RENDER_CANCEL: AtomicBool = AtomicBool::new(false);
+
+// Transcode batch state
+#[cfg(native_audio_engine)]
+static TRANSCODE_ACTIVE: AtomicBool = AtomicBool::new(false);
+#[cfg(native_audio_engine)]
+static TRANSCODE_CANCEL: AtomicBool = AtomicBool::new(false);
+
+// Error returned by every call when the engine is compiled out
+#[cfg(not(native_audio_engine))]
+const NATIVE_AUDIO_UNAVAILABLE: &str =
+    "Native audio engine not available (build with the `native_audio` feature)";
+
 // ============================================================================
 // SHARED TYPES (available on all platforms)
 // ============================================================================
@@ -40,6 +69,15 @@ pub enum AudioEventType {
     CrossfadeStarted { from_path: String, to_path: String },
     Error { message: String },
     NextTrackReady { path: String },
+    /// The line of the current track's synced lyrics to highlight; `None`
+    /// before the first line
+    LyricsLineChanged { path: String, line_index: Option<u32> },
+    /// Playback reached another chapter of the current track; `None` before
+    /// the first chapter
+    ChapterChanged { path: String, chapter_index: Option<u32> },
+    /// A long track started where it was left off; seek to zero to start
+    /// over
+    Resumed { path: String, position_secs: f64 },
 }
 
 /// Crossfade curve type for Dart.
@@ -51,6 +89,173 @@ pub enum CrossfadeCurveType {
     SCurve,
 }
 
+#[cfg(native_audio_engine)]
+impl From<CrossfadeCurveType> for CrossfadeCurve {
+    fn from(curve: CrossfadeCurveType) -> Self {
+        match curve {
+            CrossfadeCurveType::EqualPower => CrossfadeCurve::EqualPower,
+            CrossfadeCurveType::Linear => CrossfadeCurve::Linear,
+            CrossfadeCurveType::SquareRoot => CrossfadeCurve::SquareRoot,
+            CrossfadeCurveType::SCurve => CrossfadeCurve::SCurve,
+        }
+    }
+}
+
+/// Container format for rendered files.
+#[derive(Debug, Clone, Copy)]
+pub enum RenderFileFormat {
+    Wav,
+    Flac,
+}
+
+/// A peaking equalizer band.
+#[derive(Debug, Clone)]
+pub struct EqualizerBand {
+    pub frequency_hz: f32,
+    pub gain_db: f32,
+    pub q: f32,
+}
+
+/// How `audio_render_to_file` mixes and encodes a queue.
+#[derive(Debug, Clone)]
+pub struct AudioRenderSettings {
+    /// Crossfade between tracks in seconds (0 for gapless)
+    pub crossfade_secs: f32,
+    pub crossfade_curve: CrossfadeCurveType,
+    /// Output volume (0.0 to 1.0)
+    pub volume: f32,
+    pub eq_preamp_db: f32,
+    pub eq_bands: Vec<EqualizerBand>,
+    /// Normalize the peak of the mix to -1 dBFS
+    pub normalize: bool,
+    /// 16 or 24 (integer), or 32 (float, WAV only)
+    pub bit_depth: u32,
+}
+
+/// Stage of a running render.
+#[derive(Debug, Clone, Copy)]
+pub enum RenderPhaseType {
+    Mixing,
+    Normalizing,
+}
+
+/// Render progress for Dart.
+#[derive(Debug, Clone)]
+pub struct AudioRenderProgress {
+    pub phase: RenderPhaseType,
+    pub track_index: u32,
+    pub track_count: u32,
+    pub position_secs: f64,
+    /// Estimated length of the output in seconds
+    pub total_secs: f64,
+}
+
+/// Container format for transcoded files.
+#[derive(Debug, Clone, Copy)]
+pub enum TranscodeFileFormat {
+    Wav,
+    Flac,
+    /// Only available in builds with the `opus` feature
+    OggOpus,
+}
+
+/// Dither applied when reducing to 16 or 24 bit.
+#[derive(Debug, Clone, Copy)]
+pub enum DitherType {
+    None,
+    /// TPDF noise of +-1 LSB
+    Triangular,
+    /// TPDF noise with noise shaping towards high frequencies
+    NoiseShaped,
+}
+
+#[cfg(native_audio_engine)]
+impl From<DitherType> for DitherMode {
+    fn from(dither: DitherType) -> Self {
+        match dither {
+            DitherType::None => DitherMode::None,
+            DitherType::Triangular => DitherMode::Triangular,
+            DitherType::NoiseShaped => DitherMode::NoiseShaped,
+        }
+    }
+}
+
+#[cfg(native_audio_engine)]
+impl From<DitherMode> for DitherType {
+    fn from(mode: DitherMode) -> Self {
+        match mode {
+            DitherMode::None => DitherType::None,
+            DitherMode::Triangular => DitherType::Triangular,
+            DitherMode::NoiseShaped => DitherType::NoiseShaped,
+        }
+    }
+}
+
+/// Sample format of the output device.
+#[derive(Debug, Clone, Copy)]
+pub enum OutputSampleFormatType {
+    I16,
+    /// 24-bit samples in a 32-bit word
+    I24,
+    I32,
+    F32,
+}
+
+/// Output format negotiated with the device.
+#[derive(Debug, Clone)]
+pub struct AudioOutputFormat {
+    pub sample_format: OutputSampleFormatType,
+    /// Word length the mix is dithered to; `None` for float output
+    pub bits_per_sample: Option<u32>,
+    pub sample_rate: u32,
+    pub channels: u32,
+    /// Dither used for integer formats
+    pub dither: DitherType,
+}
+
+/// How `audio_transcode_files` converts files.
+#[derive(Debug, Clone)]
+pub struct AudioTranscodeSettings {
+    pub format: TranscodeFileFormat,
+    /// Output sample rate in Hz; `None` keeps the source rate
+    pub sample_rate: Option<u32>,
+    /// 16 or 24 (integer), or 32 (float, WAV only)
+    pub bit_depth: u32,
+    pub dither: DitherType,
+    /// Ogg/Opus bitrate in bits per second
+    pub opus_bitrate: u32,
+    /// Copy tags and cover art from the source file
+    pub copy_tags: bool,
+}
+
+/// One file of a transcode batch.
+#[derive(Debug, Clone)]
+pub struct AudioTranscodeJob {
+    pub input_path: String,
+    pub output_path: String,
+}
+
+/// Stage of a job in a transcode batch.
+#[derive(Debug, Clone, Copy)]
+pub enum TranscodeStatusType {
+    Started,
+    Progress,
+    Finished,
+    Failed,
+}
+
+/// Transcode batch event for Dart.
+#[derive(Debug, Clone)]
+pub struct AudioTranscodeEvent {
+    /// Index of the job in the submitted list
+    pub index: u32,
+    pub status: TranscodeStatusType,
+    /// Fraction of the job done (0.0 to 1.0)
+    pub fraction: f64,
+    /// Error message for `Failed`
+    pub message: Option<String>,
+}
+
 // ============================================================================
 // API FUNCTIONS
 // ============================================================================
@@ -58,16 +263,16 @@ pub enum CrossfadeCurveType {
 /// Check if native audio is available on this platform.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_is_native_available() -> bool {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     { true }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     { false }
 }
 
 /// Initialize the audio engine.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_init() -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         let handle = create_audio_engine()?;
         AUDIO_ENGINE
@@ -75,190 +280,285 @@ pub fn audio_init() -> Result<(), String> {
             .map_err(|_| "Audio engine already initialized".to_string())?;
         Ok(())
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
-        Err("Native audio engine not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Check if the audio engine is initialized.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_is_initialized() -> bool {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     { AUDIO_ENGINE.get().is_some() }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     { false }
 }
 
 /// Play an audio file.
 pub fn audio_play(path: String) -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .play(PathBuf::from(path))
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         let _ = path;
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
+    }
+}
+
+/// Play part of an audio file as a track, from `start_secs` to `end_secs`
+/// (or the end of the file), e.g. a track of a CUE sheet.
+pub fn audio_play_range(
+    path: String,
+    start_secs: f64,
+    end_secs: Option<f64>,
+) -> Result<(), String> {
+    #[cfg(native_audio_engine)]
+    {
+        AUDIO_ENGINE
+            .get()
+            .ok_or("Audio engine not initialized")?
+            .play_range(PathBuf::from(path), TimeRange { start_secs, end_secs })
+    }
+    #[cfg(not(native_audio_engine))]
+    {
+        let _ = (path, start_secs, end_secs);
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Queue the next track for gapless playback.
 pub fn audio_queue_next(path: String) -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .queue_next(PathBuf::from(path))
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         let _ = path;
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
+    }
+}
+
+/// Queue part of an audio file as the next track for gapless playback.
+///
+/// Tracks of the same file queued after each other play without a gap.
+pub fn audio_queue_next_range(
+    path: String,
+    start_secs: f64,
+    end_secs: Option<f64>,
+) -> Result<(), String> {
+    #[cfg(native_audio_engine)]
+    {
+        AUDIO_ENGINE
+            .get()
+            .ok_or("Audio engine not initialized")?
+            .queue_next_range(PathBuf::from(path), TimeRange { start_secs, end_secs })
+    }
+    #[cfg(not(native_audio_engine))]
+    {
+        let _ = (path, start_secs, end_secs);
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
+    }
+}
+
+/// Seek to the start of the next chapter of the current track.
+pub fn audio_next_chapter() -> Result<(), String> {
+    #[cfg(native_audio_engine)]
+    {
+        AUDIO_ENGINE
+            .get()
+            .ok_or("Audio engine not initialized")?
+            .next_chapter()
+    }
+    #[cfg(not(native_audio_engine))]
+    {
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
+    }
+}
+
+/// Seek to the start of the current chapter, or to the previous chapter
+/// within a few seconds of a chapter's start.
+pub fn audio_previous_chapter() -> Result<(), String> {
+    #[cfg(native_audio_engine)]
+    {
+        AUDIO_ENGINE
+            .get()
+            .ok_or("Audio engine not initialized")?
+            .previous_chapter()
+    }
+    #[cfg(not(native_audio_engine))]
+    {
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Pause playback.
 pub fn audio_pause() -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .pause()
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Resume playback after pause.
 pub fn audio_resume() -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .resume()
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Stop playback completely.
 pub fn audio_stop() -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .stop()
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Seek to a position in the current track.
 pub fn audio_seek(position_secs: f64) -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .seek(position_secs)
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         let _ = position_secs;
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Set the playback volume.
 pub fn audio_set_volume(volume: f32) -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .set_volume(volume)
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         let _ = volume;
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Configure crossfade settings.
 pub fn audio_set_crossfade(enabled: bool, duration_secs: f32) -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .set_crossfade(enabled, duration_secs)
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         let _ = (enabled, duration_secs);
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
+    }
+}
+
+/// Configure resuming long files such as podcasts and audiobooks.
+///
+/// The engine saves where playback of files at least `min_duration_secs`
+/// long is left off. With `auto_resume`, playing such a file starts there
+/// and sends a `Resumed` event. By default files of ten minutes or more
+/// resume automatically.
+pub fn audio_set_resume(auto_resume: bool, min_duration_secs: f64) -> Result<(), String> {
+    #[cfg(native_audio_engine)]
+    {
+        AUDIO_ENGINE
+            .get()
+            .ok_or("Audio engine not initialized")?
+            .set_resume(auto_resume, min_duration_secs)
+    }
+    #[cfg(not(native_audio_engine))]
+    {
+        let _ = (auto_resume, min_duration_secs);
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Skip to the next queued track.
 pub fn audio_skip_to_next() -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .skip_to_next()
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Set the playback speed.
 pub fn audio_set_playback_speed(speed: f32) -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .ok_or("Audio engine not initialized")?
             .set_playback_speed(speed)
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         let _ = speed;
-        Err("Native audio not available on Android".to_string())
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
     }
 }
 
 /// Get the current playback speed.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_get_playback_speed() -> Option<f32> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     { AUDIO_ENGINE.get().map(|h| h.get_playback_speed()) }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     { None }
 }
 
 /// Get the current playback state.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_get_state() -> String {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         let Some(handle) = AUDIO_ENGINE.get() else {
             return "uninitialized".to_string();
@@ -272,7 +572,7 @@ pub fn audio_get_state() -> String {
             PlaybackState::Stopped => "stopped".to_string(),
         }
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         "unavailable".to_string()
     }
@@ -281,7 +581,7 @@ pub fn audio_get_state() -> String {
 /// Get the current playback progress.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_get_progress() -> Option<AudioProgress> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE.get()?.get_progress().map(|p| AudioProgress {
             position_secs: p.position_secs,
@@ -289,7 +589,7 @@ pub fn audio_get_progress() -> Option<AudioProgress> {
             buffer_level: p.buffer_level,
         })
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         None
     }
@@ -298,7 +598,7 @@ pub fn audio_get_progress() -> Option<AudioProgress> {
 /// Poll for audio events (non-blocking).
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_poll_event() -> Option<AudioEventType> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         let handle = AUDIO_ENGINE.get()?;
         let event = handle.try_recv_event()?;
@@ -324,9 +624,24 @@ pub fn audio_poll_event() -> Option<AudioEventType> {
             }
             AudioEvent::Error { message } => AudioEventType::Error { message },
             AudioEvent::NextTrackReady { path } => AudioEventType::NextTrackReady { path },
+            AudioEvent::LyricsLineChanged { path, line_index } => {
+                AudioEventType::LyricsLineChanged {
+                    path,
+                    line_index: line_index.map(|index| index as u32),
+                }
+            }
+            AudioEvent::ChapterChanged { path, chapter_index } => {
+                AudioEventType::ChapterChanged {
+                    path,
+                    chapter_index: chapter_index.map(|index| index as u32),
+                }
+            }
+            AudioEvent::Resumed { path, position_secs } => {
+                AudioEventType::Resumed { path, position_secs }
+            }
         })
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         None
     }
@@ -340,23 +655,23 @@ pub fn audio_set_crossfade_curve(_curve: CrossfadeCurveType) -> Result<(), Strin
 /// Get the audio engine's sample rate.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_get_sample_rate() -> Option<u32> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     { AUDIO_ENGINE.get().map(|h| h.sample_rate()) }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     { None }
 }
 
 /// Get the current track path.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_get_current_path() -> Option<String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         AUDIO_ENGINE
             .get()
             .and_then(|h| h.get_current_path())
             .map(|p| p.to_string_lossy().to_string())
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     {
         None
     }
@@ -365,23 +680,229 @@ pub fn audio_get_current_path() -> Option<String> {
 /// Get the number of audio channels.
 #[flutter_rust_bridge::frb(sync)]
 pub fn audio_get_channels() -> Option<usize> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     { AUDIO_ENGINE.get().map(|h| h.channels()) }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
     { None }
 }
 
+/// Get the output format negotiated with the device.
+#[flutter_rust_bridge::frb(sync)]
+pub fn audio_get_output_format() -> Option<AudioOutputFormat> {
+    #[cfg(native_audio_engine)]
+    {
+        AUDIO_ENGINE.get().map(|h| {
+            let sample_format = h.sample_format();
+            AudioOutputFormat {
+                sample_format: match sample_format {
+                    OutputSampleFormat::I16 => OutputSampleFormatType::I16,
+                    OutputSampleFormat::I24 => OutputSampleFormatType::I24,
+                    OutputSampleFormat::I32 => OutputSampleFormatType::I32,
+                    OutputSampleFormat::F32 => OutputSampleFormatType::F32,
+                },
+                bits_per_sample: sample_format.bits(),
+                sample_rate: h.sample_rate(),
+                channels: h.channels() as u32,
+                dither: h.get_dither().into(),
+            }
+        })
+    }
+    #[cfg(not(native_audio_engine))]
+    {
+        None
+    }
+}
+
+/// Set the dither used when the output device takes integer samples.
+pub fn audio_set_dither(dither: DitherType) -> Result<(), String> {
+    #[cfg(native_audio_engine)]
+    {
+        AUDIO_ENGINE
+            .get()
+            .ok_or("Audio engine not initialized")?
+            .set_dither(dither.into())
+    }
+    #[cfg(not(native_audio_engine))]
+    {
+        let _ = dither;
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
+    }
+}
+
 /// Shutdown the audio engine.
 pub fn audio_shutdown() -> Result<(), String> {
-    #[cfg(not(target_os = "android"))]
+    #[cfg(native_audio_engine)]
     {
         if let Some(handle) = AUDIO_ENGINE.get() {
             handle.shutdown()?;
         }
         Ok(())
     }
-    #[cfg(target_os = "android")]
+    #[cfg(not(native_audio_engine))]
+    {
+        Ok(())
+    }
+}
+
+/// Render a queue to a WAV or FLAC file, faster than real time.
+///
+/// Tracks are mixed with the given crossfade, EQ, volume and normalization.
+/// Progress is streamed to Dart and the stream closes when the file is
+/// complete (or carries the error). Only one render can run at a time.
+pub fn audio_render_to_file(
+    paths: Vec<String>,
+    settings: AudioRenderSettings,
+    out_path: String,
+    format: RenderFileFormat,
+    sink: StreamSink<AudioRenderProgress>,
+) -> Result<(), String> {
+    #[cfg(native_audio_engine)]
+    {
+        if RENDER_ACTIVE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err("A render is already in progress".to_string());
+        }
+        RENDER_CANCEL.store(false, Ordering::Release);
+
+        let paths: Vec<PathBuf> = paths.into_iter().map(PathBuf::from).collect();
+        let settings = render::RenderSettings {
+            crossfade_secs: settings.crossfade_secs,
+            crossfade_curve: settings.crossfade_curve.into(),
+            volume: settings.volume,
+            eq_preamp_db: settings.eq_preamp_db,
+            eq_bands: settings
+                .eq_bands
+                .iter()
+                .map(|band| equalizer::EqBand {
+                    frequency_hz: band.frequency_hz,
+                    gain_db: band.gain_db,
+                    q: band.q,
+                })
+                .collect(),
+            normalize: settings.normalize,
+            bit_depth: settings.bit_depth,
+        };
+        let format = match format {
+            RenderFileFormat::Wav => render::RenderFormat::Wav,
+            RenderFileFormat::Flac => render::RenderFormat::Flac,
+        };
+
+        let result = render::render_to_file(
+            &paths,
+            &settings,
+            &PathBuf::from(out_path),
+            format,
+            &RENDER_CANCEL,
+            |progress| {
+                let _ = sink.add(AudioRenderProgress {
+                    phase: match progress.phase {
+                        render::RenderPhase::Mixing => RenderPhaseType::Mixing,
+                        render::RenderPhase::Normalizing => RenderPhaseType::Normalizing,
+                    },
+                    track_index: progress.track_index as u32,
+                    track_count: progress.track_count as u32,
+                    position_secs: progress.position_secs,
+                    total_secs: progress.total_secs,
+                });
+            },
+        );
+
+        RENDER_ACTIVE.store(false, Ordering::Release);
+        result.map_err(|e| e.to_string())
+    }
+    #[cfg(not(native_audio_engine))]
     {
+        let _ = (paths, settings, out_path, format, sink);
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
+    }
+}
+
+/// Cancel the running render, if any. Its partial output is deleted.
+#[flutter_rust_bridge::frb(sync)]
+pub fn audio_render_cancel() {
+    #[cfg(native_audio_engine)]
+    RENDER_CANCEL.store(true, Ordering::Release);
+}
+
+/// Transcode a batch of files to WAV, FLAC or Ogg/Opus.
+///
+/// Files are converted in parallel. Per-file progress and errors are
+/// streamed to Dart; a failed file does not stop the rest of the batch.
+/// The stream closes when every job has finished. Only one batch can run
+/// at a time.
+pub fn audio_transcode_files(
+    jobs: Vec<AudioTranscodeJob>,
+    settings: AudioTranscodeSettings,
+    sink: StreamSink<AudioTranscodeEvent>,
+) -> Result<(), String> {
+    #[cfg(native_audio_engine)]
+    {
+        if TRANSCODE_ACTIVE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err("A transcode is already in progress".to_string());
+        }
+        TRANSCODE_CANCEL.store(false, Ordering::Release);
+
+        let jobs: Vec<transcode::TranscodeJob> = jobs
+            .into_iter()
+            .map(|job| transcode::TranscodeJob {
+                input: PathBuf::from(job.input_path),
+                output: PathBuf::from(job.output_path),
+            })
+            .collect();
+        let settings = transcode::TranscodeSettings {
+            format: match settings.format {
+                TranscodeFileFormat::Wav => transcode::TranscodeFormat::Wav,
+                TranscodeFileFormat::Flac => transcode::TranscodeFormat::Flac,
+                TranscodeFileFormat::OggOpus => transcode::TranscodeFormat::OggOpus,
+            },
+            sample_rate: settings.sample_rate,
+            bit_depth: settings.bit_depth,
+            dither: settings.dither.into(),
+            opus_bitrate: settings.opus_bitrate,
+            copy_tags: settings.copy_tags,
+        };
+
+        transcode::transcode_batch(&jobs, &settings, &TRANSCODE_CANCEL, |event| {
+            let (index, status, fraction, message) = match event {
+                transcode::TranscodeEvent::Started { index } => {
+                    (index, TranscodeStatusType::Started, 0.0, None)
+                }
+                transcode::TranscodeEvent::Progress { index, fraction } => {
+                    (index, TranscodeStatusType::Progress, fraction, None)
+                }
+                transcode::TranscodeEvent::Finished { index } => {
+                    (index, TranscodeStatusType::Finished, 1.0, None)
+                }
+                transcode::TranscodeEvent::Failed { index, message } => {
+                    (index, TranscodeStatusType::Failed, 0.0, Some(message))
+                }
+            };
+            let _ = sink.add(AudioTranscodeEvent {
+                index: index as u32,
+                status,
+                fraction,
+                message,
+            });
+        });
+
+        TRANSCODE_ACTIVE.store(false, Ordering::Release);
         Ok(())
     }
+    #[cfg(not(native_audio_engine))]
+    {
+        let _ = (jobs, settings, sink);
+        Err(NATIVE_AUDIO_UNAVAILABLE.to_string())
+    }
+}
+
+/// Cancel the running transcode batch. Unfinished files are not written.
+#[flutter_rust_bridge::frb(sync)]
+pub fn audio_transcode_cancel() {
+    #[cfg(native_audio_engine)]
+    TRANSCODE_CANCEL.store(true, Ordering::Release);
 }
@@ -0,0 +1,25 @@
+//! Flutter Rust Bridge API for chapters of audiobooks and long mixes.
+//!
+//! Chapters come from MP4 chapter tracks and Nero `chpl` atoms, ID3v2
+//! CHAP/CTOC frames and Vorbis `CHAPTERxxx` comments. While a file plays,
+//! the engine reports the chapter reached with a `ChapterChanged` event, and
+//! `audio_next_chapter`/`audio_previous_chapter` seek between them.
+
+use crate::library::chapters;
+use std::path::Path;
+
+/// A chapter of an audio file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    pub title: Option<String>,
+    /// Where the chapter starts in the file
+    pub start_secs: f64,
+    /// Where the next chapter starts, or the end of the file
+    pub end_secs: f64,
+}
+
+/// The chapters of the file at `path` in playing order; empty if it has
+/// none.
+pub fn get_chapters(path: String) -> Result<Vec<Chapter>, String> {
+    chapters::read(Path::new(&path))
+}
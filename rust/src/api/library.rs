@@ -0,0 +1,352 @@
+//! Flutter Rust Bridge API for the persistent music library.
+//!
+//! The library lives in a SQLite database owned by Rust. Scans and the
+//! folder watch write into it directly, so Dart no longer passes the known
+//! files across the bridge, and reads songs, albums, artists, genres and
+//! folders a page at a time.
+
+use crate::api::scanner::{
+    scan_default_options, watch_stopped, AudioFileMetadata, ScanOptions, ScanSlot,
+    ScanStreamEvent, WatchEvent, SCAN_CANCEL, WATCH_GENERATION,
+};
+use crate::frb_generated::StreamSink;
+use crate::library::{scan, store, watch};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A song in the library.
+#[derive(Debug, Clone)]
+pub struct LibrarySong {
+    pub id: i64,
+    /// The music folder the song was found in
+    pub folder: String,
+    /// The tagged title, or one made from the file name
+    pub display_title: String,
+    /// When the song was first added, in seconds since the epoch
+    pub date_added: i64,
+    /// Times the song was played to the end
+    pub play_count: u32,
+    /// When the song was last played to the end
+    pub last_played: Option<i64>,
+    pub favorite: bool,
+    /// [`LibraryAlbum::key`] of its album
+    pub album_key: Option<String>,
+    /// [`LibraryGroup::key`]s of the artists it credits
+    pub artist_keys: Vec<String>,
+    pub metadata: AudioFileMetadata,
+}
+
+/// Order of songs returned by [`library_songs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SongSortType {
+    Title,
+    /// By artist, then album and track
+    Artist,
+    /// By album, then disc and track
+    Album,
+    /// By year, then album and track
+    Year,
+    Duration,
+    DateAdded,
+    Path,
+}
+
+/// Which songs [`library_songs`] returns.
+#[derive(Debug, Clone)]
+pub struct SongQuery {
+    /// Words that must all start a word of the title, artist, album, album
+    /// artist, genre or composer; case and accents are ignored
+    pub search: Option<String>,
+    /// Only songs found in this music folder
+    pub folder: Option<String>,
+    /// Only songs crediting this artist, by name or [`LibraryGroup::key`]
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    /// Only songs of the album with this [`LibraryAlbum::key`]
+    pub album_key: Option<String>,
+    pub genre: Option<String>,
+    pub sort: SongSortType,
+    pub descending: bool,
+    /// Matching songs to skip
+    pub offset: u32,
+    /// Most songs to return; all when unset
+    pub limit: Option<u32>,
+}
+
+/// One page of songs.
+#[derive(Debug, Clone)]
+pub struct SongPage {
+    pub songs: Vec<LibrarySong>,
+    /// Songs matching the query, across all pages
+    pub total: u32,
+}
+
+/// Order of albums, artists and genres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupSortType {
+    Name,
+    SongCount,
+    /// By the latest year of their songs
+    Year,
+    /// By when their latest song was added
+    DateAdded,
+}
+
+/// Which albums, artists or genres are returned.
+#[derive(Debug, Clone)]
+pub struct GroupQuery {
+    /// Words that must all start a word of the name; case and accents are
+    /// ignored
+    pub search: Option<String>,
+    /// Only count songs found in this music folder
+    pub folder: Option<String>,
+    pub sort: GroupSortType,
+    pub descending: bool,
+    pub offset: u32,
+    pub limit: Option<u32>,
+}
+
+/// An album in the library.
+#[derive(Debug, Clone)]
+pub struct LibraryAlbum {
+    /// Identifies the album for [`SongQuery::album_key`]
+    pub key: String,
+    pub name: String,
+    /// The album artist, or the track artist where none is tagged; the
+    /// compilation artist for compilations
+    pub artist: Option<String>,
+    /// Tagged as a compilation, or untagged songs by several artists in one
+    /// folder
+    pub compilation: bool,
+    pub year: Option<u32>,
+    pub song_count: u32,
+    pub disc_count: u32,
+    pub duration_secs: u64,
+    pub album_art_path: Option<String>,
+}
+
+/// One page of albums.
+#[derive(Debug, Clone)]
+pub struct AlbumPage {
+    pub albums: Vec<LibraryAlbum>,
+    pub total: u32,
+}
+
+/// An artist or genre in the library.
+#[derive(Debug, Clone)]
+pub struct LibraryGroup {
+    /// The normalized name; spellings of an artist's name share a key
+    pub key: String,
+    pub name: String,
+    pub song_count: u32,
+    pub album_count: u32,
+    pub duration_secs: u64,
+    /// Cover of one of its albums
+    pub album_art_path: Option<String>,
+}
+
+/// One page of artists or genres.
+#[derive(Debug, Clone)]
+pub struct GroupPage {
+    pub groups: Vec<LibraryGroup>,
+    pub total: u32,
+}
+
+/// How songs are grouped into albums and artists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupingOptions {
+    /// Separators artist tags are split on, ignoring ASCII case, e.g. ";"
+    /// or " feat. "
+    pub artist_separators: Vec<String>,
+    /// Leading articles ignored when sorting names; "Beatles, The" is read
+    /// as "The Beatles"
+    pub articles: Vec<String>,
+    /// Album artist of compilations, e.g. "Various Artists"
+    pub various_artists: String,
+}
+
+/// A music folder registered with the library.
+#[derive(Debug, Clone)]
+pub struct LibraryFolder {
+    pub path: String,
+    pub song_count: u32,
+    /// When the folder was added, in seconds since the epoch
+    pub added_at: i64,
+    /// When a scan of the folder last finished
+    pub scanned_at: Option<i64>,
+}
+
+/// Open the library database at `db_path`, creating it if needed.
+///
+/// Must be called before any other library function. Opening another
+/// database closes the previous one.
+pub fn library_open(db_path: String) -> Result<(), String> {
+    store::open_shared(Path::new(&db_path))
+}
+
+/// Register a music folder. Adding a registered folder does nothing.
+pub fn library_add_folder(path: String) -> Result<(), String> {
+    store::with_shared(|store| store.add_folder(&path))
+}
+
+/// Remove a music folder and its songs from the library.
+pub fn library_remove_folder(path: String) -> Result<(), String> {
+    store::with_shared(|store| store.remove_folder(&path))
+}
+
+pub fn library_folders() -> Result<Vec<LibraryFolder>, String> {
+    store::with_shared(|store| store.folders())
+}
+
+/// Scan `root_path` into the library, streaming progress like
+/// `scan_root_dir_stream`.
+///
+/// Known files come from the library, and every event is written to it
+/// before it is sent, so the stream only reports what changed. The folder
+/// is registered if it was not. Cancelled by `scan_cancel`; only one scan
+/// can run at a time.
+pub fn library_scan_folder(
+    root_path: String,
+    art_cache_dir: Option<String>,
+    options: Option<ScanOptions>,
+    sink: StreamSink<ScanStreamEvent>,
+) -> Result<(), String> {
+    let options = options.unwrap_or_else(scan_default_options);
+    let known_files = store::with_shared(|store| {
+        store.add_folder(&root_path)?;
+        store.known_files(&root_path)
+    })?;
+    let _scan = ScanSlot::take()?;
+
+    let mut write_error = None;
+    let cancel = &SCAN_CANCEL;
+    let result = scan::scan(&root_path, &known_files, art_cache_dir, &options, cancel, |event| {
+        if let Err(e) = store::with_shared(|store| store.apply_scan(&root_path, &event)) {
+            write_error.get_or_insert(e);
+        }
+        let _ = sink.add(event);
+    });
+    result?;
+    write_error.map_or(Ok(()), Err)
+}
+
+/// Watch the library's folders like `scan_watch`, writing changes into
+/// the library before they are sent. Changes the library could not store
+/// are sent as an error instead. Stopped by `scan_watch_stop`.
+pub fn library_watch(
+    art_cache_dir: Option<String>,
+    options: Option<ScanOptions>,
+    sink: StreamSink<WatchEvent>,
+) -> Result<(), String> {
+    let options = options.unwrap_or_else(scan_default_options);
+    let roots: Vec<String> =
+        store::with_shared(|store| store.folders())?.into_iter().map(|f| f.path).collect();
+    let generation = WATCH_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+    let closed = Arc::new(AtomicBool::new(false));
+    let stopped = watch_stopped(generation, &closed);
+    watch::spawn(roots, art_cache_dir, options, stopped, move |event| {
+        let sent = match store::with_shared(|store| store.apply_watch(&event)) {
+            Ok(()) => sink.add(event),
+            Err(e) => sink.add_error(e),
+        };
+        closed.store(sent.is_err(), Ordering::Release);
+    })
+}
+
+/// Songs matching `query`, one page at a time.
+pub fn library_songs(query: SongQuery) -> Result<SongPage, String> {
+    store::with_shared(|store| store.songs(&query))
+}
+
+/// Up to `limit` songs matching `query`, best match first.
+///
+/// Unlike the `search` of [`library_songs`], this ranks songs by how well
+/// their title, artist and album match, and tolerates typos: "bjrok"
+/// finds "Björk". Words of Chinese, Japanese and Korean titles are found
+/// without spaces.
+pub fn library_search(query: String, limit: u32) -> Result<Vec<LibrarySong>, String> {
+    store::with_shared(|store| store.search(&query, limit as usize))
+}
+
+/// Count a play of the song at `path`. Songs the audio engine plays to the
+/// end are counted already.
+pub fn library_record_play(path: String) -> Result<(), String> {
+    store::with_shared(|store| store.record_play(&path, None))
+}
+
+/// Mark the song at `path` as a favorite, or not.
+pub fn library_set_favorite(path: String, favorite: bool) -> Result<(), String> {
+    store::with_shared(|store| store.set_favorite(&path, favorite))
+}
+
+/// The library song at `path`, if any.
+pub fn library_song(path: String) -> Result<Option<LibrarySong>, String> {
+    store::with_shared(|store| store.song_by_path(&path))
+}
+
+pub fn library_albums(query: GroupQuery) -> Result<AlbumPage, String> {
+    store::with_shared(|store| store.albums(&query))
+}
+
+pub fn library_artists(query: GroupQuery) -> Result<GroupPage, String> {
+    store::with_shared(|store| store.artists(&query))
+}
+
+pub fn library_genres(query: GroupQuery) -> Result<GroupPage, String> {
+    store::with_shared(|store| store.genres(&query))
+}
+
+/// The options albums and artists are grouped by.
+pub fn library_grouping_options() -> Result<GroupingOptions, String> {
+    store::with_shared(|store| Ok(store.grouping_options().clone()))
+}
+
+/// Change how albums and artists are grouped, regrouping every song.
+pub fn library_set_grouping_options(options: GroupingOptions) -> Result<(), String> {
+    store::with_shared(|store| store.set_grouping_options(options))
+}
+
+/// All songs, by title.
+#[flutter_rust_bridge::frb(sync)]
+pub fn library_default_song_query() -> SongQuery {
+    SongQuery {
+        search: None,
+        folder: None,
+        artist: None,
+        album: None,
+        album_key: None,
+        genre: None,
+        sort: SongSortType::Title,
+        descending: false,
+        offset: 0,
+        limit: None,
+    }
+}
+
+/// All albums, artists or genres, by name.
+#[flutter_rust_bridge::frb(sync)]
+pub fn library_default_group_query() -> GroupQuery {
+    GroupQuery {
+        search: None,
+        folder: None,
+        sort: GroupSortType::Name,
+        descending: false,
+        offset: 0,
+        limit: None,
+    }
+}
+
+/// Split artists on ";", " / " and "feat." credits, and sort past "The",
+/// "A" and "An".
+#[flutter_rust_bridge::frb(sync)]
+pub fn library_default_grouping_options() -> GroupingOptions {
+    let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+    GroupingOptions {
+        artist_separators: strings(&[
+            ";", " / ", " (feat. ", " (ft. ", " feat. ", " feat ", " ft. ", " featuring ",
+        ]),
+        articles: strings(&["The", "A", "An"]),
+        various_artists: "Various Artists".to_string(),
+    }
+}
@@ -0,0 +1,59 @@
+//! Flutter Rust Bridge API for song lyrics.
+//!
+//! Lyrics come from a `.lrc` file beside the song or from its tags (ID3v2
+//! USLT/SYLT frames, Vorbis `LYRICS` comments, MP4 `©lyr` atoms). Synced
+//! lyrics win over plain text. While a song plays, the engine reports the
+//! line to highlight with a `LyricsLineChanged` event.
+
+use crate::library::lyrics;
+use std::path::Path;
+
+/// Where a song's lyrics were found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LyricsSourceType {
+    /// A `.lrc` file with the same name as the song
+    Sidecar,
+    /// An ID3v2 SYLT frame
+    SyncedTag,
+    /// A lyrics tag (USLT, `LYRICS`, `©lyr`), which may hold LRC text
+    Tag,
+}
+
+/// A word of an enhanced LRC line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LyricsWord {
+    pub start_ms: u32,
+    /// The word with any spacing that follows it
+    pub text: String,
+}
+
+/// A line of lyrics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LyricsLine {
+    /// When the line starts, for synced lyrics
+    pub start_ms: Option<u32>,
+    pub text: String,
+    /// Word timings, when the LRC file has them
+    pub words: Vec<LyricsWord>,
+}
+
+/// The lyrics of a song.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lyrics {
+    pub source: LyricsSourceType,
+    /// Every line has a start time, and lines are in time order
+    pub synced: bool,
+    pub lines: Vec<LyricsLine>,
+}
+
+/// Read the lyrics of the song at `path`, or `None` if it has none.
+pub fn get_lyrics(path: String) -> Result<Option<Lyrics>, String> {
+    lyrics::read(Path::new(&path))
+}
+
+/// The index of the line of synced `lyrics` that is active at
+/// `position_secs`, or `None` before the first line and for plain lyrics.
+#[flutter_rust_bridge::frb(sync)]
+pub fn lyrics_active_line(lyrics: Lyrics, position_secs: f64) -> Option<u32> {
+    lyrics::active_line(&lyrics, position_secs).map(|index| index as u32)
+}
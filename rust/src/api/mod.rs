@@ -1,3 +1,10 @@
 pub mod simple;
 pub mod scanner;
 pub mod audio_api;
+pub mod library;
+pub mod tags;
+pub mod lyrics;
+pub mod chapters;
+pub mod resume;
+pub mod playlists;
+pub mod smart_playlists;
@@ -0,0 +1,108 @@
+//! Flutter Rust Bridge API for playlist files.
+//!
+//! Reads and writes M3U/M3U8 (with `#EXTINF` durations and names), PLS and
+//! XSPF playlists, picking the format by extension. Relative entries are
+//! resolved against the playlist's folder. Importing matches entries to
+//! library songs by path, then by path ignoring case, then by file name or
+//! by title and artist, and reports the entries that matched nothing.
+
+use crate::library::{playlists, store};
+use std::path::Path;
+
+/// An entry of a playlist file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlaylistEntry {
+    /// Absolute path of the file, or the URL of a stream
+    pub location: String,
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub duration_secs: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Playlist {
+    /// The title the file gives, or its file name
+    pub name: Option<String>,
+    pub entries: Vec<PlaylistEntry>,
+}
+
+/// An entry of an imported playlist that no library song matched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnresolvedEntry {
+    /// Position of the entry in the playlist
+    pub index: u32,
+    pub entry: PlaylistEntry,
+}
+
+/// A playlist file matched to the library.
+#[derive(Debug, Clone)]
+pub struct PlaylistImport {
+    pub name: Option<String>,
+    /// Library paths of the entries that matched a song, in playlist order
+    pub paths: Vec<String>,
+    /// Entries that matched no song, e.g. streams and missing files
+    pub unresolved: Vec<UnresolvedEntry>,
+}
+
+/// Read the playlist file at `path`.
+pub fn playlist_read(path: String) -> Result<Playlist, String> {
+    playlists::read(Path::new(&path))
+}
+
+/// Read the playlist file at `path` and match its entries to library songs.
+pub fn playlist_import(path: String) -> Result<PlaylistImport, String> {
+    let playlist = playlists::read(Path::new(&path))?;
+    let tracks = store::with_shared(|store| store.playlist_tracks())?;
+    let (paths, unresolved) = playlists::resolve(&playlist, &tracks);
+    Ok(PlaylistImport {
+        name: playlist.name,
+        paths,
+        unresolved,
+    })
+}
+
+/// Write `playlist` to `path` in the format of its extension: `.m3u`,
+/// `.m3u8`, `.pls` or `.xspf`.
+///
+/// With `relative_paths`, entries are written relative to the playlist's
+/// folder where they can be. The file is replaced in one step.
+pub fn playlist_write(
+    path: String,
+    playlist: Playlist,
+    relative_paths: bool,
+) -> Result<(), String> {
+    playlists::write(Path::new(&path), &playlist, relative_paths)
+}
+
+/// Write the library songs at `song_paths` to `path` like
+/// [`playlist_write`], with their titles, artists, albums and durations.
+pub fn playlist_export(
+    path: String,
+    name: Option<String>,
+    song_paths: Vec<String>,
+    relative_paths: bool,
+) -> Result<(), String> {
+    let entries = store::with_shared(|store| {
+        song_paths
+            .into_iter()
+            .map(|song_path| {
+                let song = store.song_by_path(&song_path)?;
+                Ok(match song {
+                    Some(song) => PlaylistEntry {
+                        location: song_path,
+                        title: Some(song.display_title),
+                        artist: song.metadata.artist,
+                        album: song.metadata.album,
+                        duration_secs: song.metadata.duration_secs.map(|d| d as f64),
+                    },
+                    None => PlaylistEntry {
+                        location: song_path,
+                        ..Default::default()
+                    },
+                })
+            })
+            .collect::<rusqlite::Result<Vec<_>>>()
+    })?;
+    playlists::write(Path::new(&path), &Playlist { name, entries }, relative_paths)
+}
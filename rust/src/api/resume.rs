@@ -0,0 +1,17 @@
+//! Flutter Rust Bridge API for resuming long files.
+//!
+//! The engine saves where playback of podcasts, audiobooks and other long
+//! files is left off (see `audio_set_resume`), in the library so positions
+//! survive restarts. Positions near either end of a file are forgotten.
+
+use crate::library::store;
+
+/// Where playback of the file at `path` was left off, if it was saved.
+pub fn get_resume_position(path: String) -> Result<Option<f64>, String> {
+    store::with_shared(|store| store.resume_position(&path))
+}
+
+/// Forget where playback of the file at `path` was left off.
+pub fn clear_resume_position(path: String) -> Result<(), String> {
+    store::with_shared(|store| store.set_resume_position(&path, None))
+}
@@ -1,9 +1,50 @@
+use crate::frb_generated::StreamSink;
+use crate::library::{artwork, formats, palette, scan, watch};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
 
-use lofty::prelude::*;
-use lofty::probe::Probe;
-use rayon::prelude::*;
-use std::collections::{HashMap, HashSet};
-use walkdir::WalkDir;
+/// Folders of system sounds, voice notes and app data
+const DEFAULT_EXCLUDE_GLOBS: &[&str] = &[
+    "**/Alarms/**",
+    "**/Notifications/**",
+    "**/Ringtones/**",
+    "**/Android/data/**",
+    "**/Android/obb/**",
+    "**/WhatsApp*Voice Notes/**",
+];
+
+// Streamed scan state
+static SCAN_ACTIVE: AtomicBool = AtomicBool::new(false);
+pub(crate) static SCAN_CANCEL: AtomicBool = AtomicBool::new(false);
+
+/// The right to run the one streamed scan, given back when dropped so a
+/// scan that panics does not block every later one.
+pub(crate) struct ScanSlot(());
+
+impl ScanSlot {
+    /// Take the slot and reset the cancel flag, failing if a scan holds it.
+    pub(crate) fn take() -> Result<Self, String> {
+        if SCAN_ACTIVE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err("A scan is already in progress".to_string());
+        }
+        SCAN_CANCEL.store(false, Ordering::Release);
+        Ok(ScanSlot(()))
+    }
+}
+
+impl Drop for ScanSlot {
+    fn drop(&mut self) {
+        SCAN_ACTIVE.store(false, Ordering::Release);
+    }
+}
+
+// Bumped to stop the running watch
+pub(crate) static WATCH_GENERATION: AtomicU64 = AtomicU64::new(0);
 
 #[derive(Debug, Clone)]
 pub struct AudioFileMetadata {
@@ -11,111 +52,466 @@ pub struct AudioFileMetadata {
     pub title: Option<String>,
     pub artist: Option<String>,
     pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    pub track_total: Option<u32>,
+    pub disc_number: Option<u32>,
+    pub disc_total: Option<u32>,
+    pub year: Option<u32>,
+    pub genre: Option<String>,
+    pub composer: Option<String>,
+    /// Part of a various-artists compilation
+    pub compilation: bool,
     pub duration_secs: Option<u64>,
+    /// Sample rate in Hz
+    pub sample_rate: Option<u32>,
+    /// Bits per sample; only known for lossless formats
+    pub bit_depth: Option<u32>,
+    /// Audio bitrate in kbps
+    pub bitrate_kbps: Option<u32>,
+    pub channels: Option<u32>,
+    /// The tag carries unsynchronized lyrics
+    pub has_lyrics: bool,
+    pub musicbrainz_recording_id: Option<String>,
+    pub musicbrainz_release_id: Option<String>,
+    pub musicbrainz_release_group_id: Option<String>,
+    pub musicbrainz_artist_id: Option<String>,
+    pub musicbrainz_release_artist_id: Option<String>,
+    /// Cached cover thumbnail, from embedded art or a folder image
+    pub album_art_path: Option<String>,
+    /// Colors of the album art, computed alongside the thumbnail
+    pub album_palette: Option<AlbumPalette>,
     pub format: String,
     pub last_modified: i64,
+    /// For a track of a CUE sheet, the part of the file it plays
+    pub range: Option<TrackRange>,
+}
+
+/// Where a track of a CUE sheet lies in its audio file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackRange {
+    /// The audio file; the track's own path has its number appended
+    pub source_path: String,
+    pub start_secs: f64,
+    /// `None` for the last track, which plays to the end of the file
+    pub end_secs: Option<f64>,
+}
+
+/// Colors for theming the player around a piece of album art.
+///
+/// Each color is packed as `0xAARRGGBB`, so Dart can use `Color(value)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlbumPalette {
+    /// The color covering most of the image
+    pub dominant: u32,
+    /// A saturated accent color
+    pub vibrant: u32,
+    /// A desaturated color for backgrounds
+    pub muted: u32,
+    /// Text color readable on `dominant` (7:1 contrast where possible)
+    pub text: u32,
+    /// Dimmer text color with at least 4.5:1 contrast on `dominant`
+    pub secondary_text: u32,
 }
 
 #[derive(Debug, Clone)]
 pub struct ScanResult {
     pub new_or_modified: Vec<AudioFileMetadata>,
     pub deleted_paths: Vec<String>,
+    /// Known files found under a new path; only detected with a
+    /// fingerprint index
+    pub moved: Vec<ScanMove>,
+    /// Audio files that could not be read, with the reason
+    pub failed: Vec<ScanFailure>,
+}
+
+/// A known file that was renamed or moved, identified by its content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanMove {
+    pub old_path: String,
+    pub new_path: String,
+}
+
+/// Why a file could not be added to the library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanFailureType {
+    /// The file could not be opened or read
+    Io,
+    /// The file is not in a container format that can be read
+    UnsupportedContainer,
+    /// A tag in the file is malformed
+    BadTag,
+    /// The audio stream is damaged or truncated
+    Corrupt,
+    /// The file contains no audio
+    ZeroDuration,
+}
+
+/// An audio file the scanner found but could not read.
+#[derive(Debug, Clone)]
+pub struct ScanFailure {
+    pub path: String,
+    pub reason: ScanFailureType,
+    /// The underlying error message
+    pub detail: String,
+}
+
+/// Stage of a streamed scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanEventType {
+    /// Another batch of new or modified files
+    Batch,
+    /// The walk is complete; `deleted_paths` and `moved` are filled in
+    Finished,
+    /// The scan was cancelled; files already sent are valid
+    Cancelled,
+}
+
+/// Progress of a streamed scan.
+///
+/// Counts are cumulative. `processed` includes unchanged files that needed
+/// no work, so it approaches `discovered` as the scan goes on.
+#[derive(Debug, Clone)]
+pub struct ScanStreamEvent {
+    pub kind: ScanEventType,
+    /// New or modified files since the previous event
+    pub files: Vec<AudioFileMetadata>,
+    /// Files that could not be read since the previous event
+    pub failures: Vec<ScanFailure>,
+    /// Known files no longer on disk; only set on `Finished`
+    pub deleted_paths: Vec<String>,
+    /// Known files found under a new path; only set on `Finished`
+    pub moved: Vec<ScanMove>,
+    /// Audio files found so far
+    pub discovered: u32,
+    /// Audio files handled so far
+    pub processed: u32,
+    /// Audio files that could not be read
+    pub failed: u32,
+}
+
+/// Library changes picked up by [`scan_watch`] once a burst of file system
+/// activity has settled.
+#[derive(Debug, Clone)]
+pub struct WatchEvent {
+    /// Files that appeared
+    pub added: Vec<AudioFileMetadata>,
+    /// Files whose content or location changed
+    pub modified: Vec<AudioFileMetadata>,
+    /// Removed files and folders; songs at or below these paths, and the
+    /// CUE tracks of these files, are gone. Apply these before `added` and
+    /// `modified`, which may list a file's new tracks
+    pub removed_paths: Vec<String>,
+    /// Files that were renamed or moved; apply these before `modified`
+    pub moved: Vec<ScanMove>,
+    /// Changed files that could not be read
+    pub failures: Vec<ScanFailure>,
+    /// Notifications were lost, e.g. to a full event queue, so only a full
+    /// scan can catch up
+    pub rescan_needed: bool,
 }
 
-pub fn scan_root_dir(root_path: String, known_files: HashMap<String, i64>) -> ScanResult {
-    let files_on_disk: Vec<walkdir::DirEntry> = WalkDir::new(&root_path)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_file())
-        .collect();
-
-    let (to_process, found_paths_vec): (Vec<walkdir::DirEntry>, Vec<String>) = files_on_disk
-        .into_par_iter()
-        .fold(
-            || (Vec::new(), Vec::new()),
-            |(mut to_process, mut found_paths), entry| {
-                let path_str = entry.path().to_string_lossy().to_string();
-                let metadata = entry.metadata().ok();
-                let modified = metadata
-                    .and_then(|m| m.modified().ok())
-                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-                    .map(|d| d.as_secs() as i64)
-                    .unwrap_or(0);
-
-                let needs_processing = match known_files.get(&path_str) {
-                    Some(&known_timestamp) => modified > known_timestamp,
-                    None => true,
-                };
-
-                if needs_processing {
-                    to_process.push(entry);
-                }
-                found_paths.push(path_str);
-                (to_process, found_paths)
-            },
-        )
-        .reduce(
-            || (Vec::new(), Vec::new()),
-            |(mut a_proc, mut a_paths), (b_proc, b_paths)| {
-                a_proc.extend(b_proc);
-                a_paths.extend(b_paths);
-                (a_proc, a_paths)
-            },
+/// Files found by [`find_duplicates`].
+#[derive(Debug, Clone)]
+pub struct DuplicateReport {
+    pub groups: Vec<DuplicateGroup>,
+    /// Files that could not be read or decoded
+    pub failed: Vec<ScanFailure>,
+}
+
+/// Files holding the same recording.
+#[derive(Debug, Clone)]
+pub struct DuplicateGroup {
+    /// The copies, best quality first
+    pub files: Vec<DuplicateFile>,
+    /// The suggested copy to keep, the first of `files`
+    pub keep_path: String,
+}
+
+/// One copy of a recording, with what its quality was ranked by.
+#[derive(Debug, Clone)]
+pub struct DuplicateFile {
+    pub path: String,
+    pub format: String,
+    pub lossless: bool,
+    /// Audio bitrate in kbps
+    pub bitrate_kbps: Option<u32>,
+    /// Sample rate in Hz
+    pub sample_rate: Option<u32>,
+    /// Bits per sample; only known for lossless formats
+    pub bit_depth: Option<u32>,
+    pub duration_secs: Option<u64>,
+    /// Acoustic similarity to the copy to keep (0.0 to 1.0)
+    pub similarity: f32,
+}
+
+/// Which files the scanner picks up.
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    /// Lowercase extensions to read, without the dot
+    pub extensions: Vec<String>,
+    /// Also identify audio files with a wrong or missing extension from
+    /// their first bytes; slower, as other files have to be opened
+    pub sniff_content: bool,
+    /// Glob patterns, relative to the scanned folder, for files and folders
+    /// to skip (e.g. `**/Ringtones/**`); matched case-insensitively
+    pub exclude_globs: Vec<String>,
+    /// Skip folders containing a `.nomedia` or `.ignore` file
+    pub honor_marker_files: bool,
+    /// Skip audio shorter than this many seconds
+    pub min_duration_secs: u32,
+    /// Skip files smaller than this many bytes
+    pub min_file_size: u32,
+    /// Folder levels to descend below the scanned folder; 0 reads only the
+    /// files directly inside it. Unlimited when unset
+    pub max_depth: Option<u32>,
+    /// Follow symbolic links; links leading back into a folder being walked
+    /// are skipped
+    pub follow_symlinks: bool,
+    /// File the scanner keeps content fingerprints in between scans. When
+    /// set, renamed and moved files are reported in `moved` rather than as a
+    /// delete plus an add, and files whose content is unchanged are not
+    /// read again just because their mtime moved
+    pub fingerprint_index: Option<String>,
+}
+
+/// Scan options when none are given: every supported extension, phone
+/// system sounds and app data excluded, marker files honored.
+#[flutter_rust_bridge::frb(sync)]
+pub fn scan_default_options() -> ScanOptions {
+    ScanOptions {
+        extensions: formats::supported_extensions(),
+        sniff_content: false,
+        exclude_globs: DEFAULT_EXCLUDE_GLOBS.iter().map(|g| g.to_string()).collect(),
+        honor_marker_files: true,
+        min_duration_secs: 0,
+        min_file_size: 0,
+        max_depth: None,
+        follow_symlinks: false,
+        fingerprint_index: None,
+    }
+}
+
+/// Scan `root_path` for new, modified and deleted audio files.
+///
+/// When `art_cache_dir` is set, cover art is extracted into it as thumbnails
+/// and `album_art_path` points at them. Audio files that cannot be read are
+/// listed in `failed` rather than dropped. `options` defaults to
+/// [`scan_default_options`]; an invalid exclude pattern is an error.
+pub fn scan_root_dir(
+    root_path: String,
+    known_files: HashMap<String, i64>,
+    art_cache_dir: Option<String>,
+    options: Option<ScanOptions>,
+) -> Result<ScanResult, String> {
+    let options = options.unwrap_or_else(scan_default_options);
+    let never_cancelled = AtomicBool::new(false);
+    let mut result = ScanResult {
+        new_or_modified: Vec::new(),
+        deleted_paths: Vec::new(),
+        moved: Vec::new(),
+        failed: Vec::new(),
+    };
+    let cancel = &never_cancelled;
+    scan::scan(&root_path, &known_files, art_cache_dir, &options, cancel, |event| {
+        result.new_or_modified.extend(event.files);
+        result.failed.extend(event.failures);
+        result.deleted_paths.extend(event.deleted_paths);
+        result.moved.extend(event.moved);
+    })?;
+    Ok(result)
+}
+
+/// Scan like [`scan_root_dir`], streaming results as they are read.
+///
+/// New and modified files arrive in batches together with running counts,
+/// so large libraries show progress immediately. The stream ends with a
+/// `Finished` event carrying the deleted and moved paths, or `Cancelled`
+/// after [`scan_cancel`]. Only one streamed scan can run at a time.
+pub fn scan_root_dir_stream(
+    root_path: String,
+    known_files: HashMap<String, i64>,
+    art_cache_dir: Option<String>,
+    options: Option<ScanOptions>,
+    sink: StreamSink<ScanStreamEvent>,
+) -> Result<(), String> {
+    let options = options.unwrap_or_else(scan_default_options);
+    let _scan = ScanSlot::take()?;
+
+    scan::scan(&root_path, &known_files, art_cache_dir, &options, &SCAN_CANCEL, |event| {
+        let _ = sink.add(event);
+    })
+}
+
+/// Cancel the running streamed scan after its current batch.
+#[flutter_rust_bridge::frb(sync)]
+pub fn scan_cancel() {
+    SCAN_CANCEL.store(true, Ordering::Release);
+}
+
+/// Watch `roots` and stream library changes as they happen.
+///
+/// Bursts of activity, such as a folder being copied in, are reported once
+/// they settle, with changed files read like [`scan_root_dir`] reads them.
+/// The stream stays open until [`scan_watch_stop`] is called, Dart closes
+/// it, or another watch replaces it. Returns once the roots are watched,
+/// leaving the watch to a thread of its own; fails if a root cannot be
+/// watched.
+pub fn scan_watch(
+    roots: Vec<String>,
+    art_cache_dir: Option<String>,
+    options: Option<ScanOptions>,
+    sink: StreamSink<WatchEvent>,
+) -> Result<(), String> {
+    let options = options.unwrap_or_else(scan_default_options);
+    let generation = WATCH_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+    let closed = Arc::new(AtomicBool::new(false));
+    let stopped = watch_stopped(generation, &closed);
+    watch::spawn(roots, art_cache_dir, options, stopped, move |event| {
+        closed.store(sink.add(event).is_err(), Ordering::Release);
+    })
+}
+
+/// Whether the watch started as `generation` was replaced, stopped, or
+/// its stream `closed`.
+pub(crate) fn watch_stopped(
+    generation: u64,
+    closed: &Arc<AtomicBool>,
+) -> impl Fn() -> bool + Send + 'static {
+    let closed = Arc::clone(closed);
+    move || {
+        closed.load(Ordering::Acquire) || WATCH_GENERATION.load(Ordering::Acquire) != generation
+    }
+}
+
+/// Stop the running watch.
+#[flutter_rust_bridge::frb(sync)]
+pub fn scan_watch_stop() {
+    WATCH_GENERATION.fetch_add(1, Ordering::AcqRel);
+}
+
+/// Find files in `paths` that hold the same recording.
+///
+/// Files are compared by an acoustic fingerprint of their decoded audio,
+/// so copies in different formats or with different tags are found. Each
+/// group lists its copies lossless first, then by resolution or bitrate,
+/// and suggests the best one to keep. When `fingerprint_index` is set,
+/// fingerprints are kept in that file and only recomputed for changed
+/// files; it must not be the scanner's `ScanOptions::fingerprint_index`.
+pub fn find_duplicates(
+    paths: Vec<String>,
+    fingerprint_index: Option<String>,
+) -> Result<DuplicateReport, String> {
+    #[cfg(native_audio_engine)]
+    {
+        Ok(crate::library::duplicates::find(&paths, fingerprint_index.as_deref()))
+    }
+    #[cfg(not(native_audio_engine))]
+    {
+        let _ = (paths, fingerprint_index);
+        Err("Duplicate detection needs the native audio engine".to_string())
+    }
+}
+
+/// Palette of the image at `image_path`.
+///
+/// Thumbnails from the scanner's art cache return their precomputed palette
+/// without decoding the image.
+pub fn album_palette_from_image(image_path: String) -> Option<AlbumPalette> {
+    palette::for_image(Path::new(&image_path))
+}
+
+/// Palette of an audio file's embedded cover, or its folder image.
+pub fn album_palette_from_audio_file(audio_path: String) -> Option<AlbumPalette> {
+    let bytes = artwork::cover_bytes(Path::new(&audio_path))?;
+    palette::extract(&image::load_from_memory(&bytes).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{test_dir, write_silence};
+    use lofty::config::WriteOptions;
+    use lofty::prelude::*;
+    use lofty::tag::{ItemKey, Tag, TagType};
+
+    #[test]
+    fn test_scan_extracts_rich_metadata() {
+        let dir = test_dir("scanner");
+        let tagged = dir.join("tagged.wav");
+        let bare = dir.join("bare.wav");
+        write_silence(&tagged, 44100);
+        write_silence(&bare, 44100);
+
+        let mut tag = Tag::new(TagType::Id3v2);
+        tag.set_title("Song".to_string());
+        tag.set_artist("Artist".to_string());
+        tag.set_album("Album".to_string());
+        tag.insert_text(ItemKey::AlbumArtist, "Various Artists".to_string());
+        tag.set_track(3);
+        tag.set_track_total(12);
+        tag.set_disk(2);
+        tag.set_disk_total(2);
+        tag.insert_text(ItemKey::RecordingDate, "1997-05-21".to_string());
+        tag.set_genre("Jazz".to_string());
+        tag.insert_text(ItemKey::Composer, "Composer".to_string());
+        tag.insert_text(ItemKey::FlagCompilation, "1".to_string());
+        tag.insert_text(ItemKey::Lyrics, "La la la".to_string());
+        tag.insert_text(
+            ItemKey::MusicBrainzReleaseId,
+            "0b5d1f2c-3a4e-4f60-8d7a-9c1b2e3f4a5b".to_string(),
+        );
+        tag.save_to_path(&tagged, WriteOptions::default()).unwrap();
+
+        let result = scan_root_dir(dir.to_string_lossy().to_string(), HashMap::new(), None, None).unwrap();
+        assert_eq!(result.new_or_modified.len(), 2);
+        let find = |path: &Path| {
+            result
+                .new_or_modified
+                .iter()
+                .find(|m| m.path == path.to_string_lossy())
+                .unwrap()
+        };
+
+        let song = find(&tagged);
+        assert_eq!(song.title.as_deref(), Some("Song"));
+        assert_eq!(song.album_artist.as_deref(), Some("Various Artists"));
+        assert_eq!((song.track_number, song.track_total), (Some(3), Some(12)));
+        assert_eq!((song.disc_number, song.disc_total), (Some(2), Some(2)));
+        assert_eq!(song.year, Some(1997));
+        assert_eq!(song.genre.as_deref(), Some("Jazz"));
+        assert_eq!(song.composer.as_deref(), Some("Composer"));
+        assert!(song.compilation);
+        assert!(song.has_lyrics);
+        assert_eq!(
+            song.musicbrainz_release_id.as_deref(),
+            Some("0b5d1f2c-3a4e-4f60-8d7a-9c1b2e3f4a5b")
         );
+        assert_eq!(song.sample_rate, Some(44100));
+        assert_eq!(song.bit_depth, Some(16));
+        assert_eq!(song.channels, Some(2));
+        assert_eq!(song.bitrate_kbps, Some(1411));
+        assert_eq!(song.duration_secs, Some(1));
+
+        let bare = find(&bare);
+        assert_eq!(bare.title, None);
+        assert!(!bare.compilation && !bare.has_lyrics);
+        assert_eq!(bare.sample_rate, Some(44100));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_scan_slot_is_freed_after_a_panic() {
+        let slot = ScanSlot::take().unwrap();
+        assert!(ScanSlot::take().is_err());
+        drop(slot);
 
-    let new_or_modified: Vec<AudioFileMetadata> = to_process
-        .par_iter()
-        .filter_map(|entry: &walkdir::DirEntry| {
-            let path = entry.path();
-            let path_str = path.to_string_lossy().to_string();
-
-            let ext = path
-                .extension()
-                .and_then(|s: &std::ffi::OsStr| s.to_str())
-                .unwrap_or("")
-                .to_lowercase();
-            if !["mp3", "flac", "ogg", "m4a", "wav"].contains(&ext.as_str()) {
-                return None;
-            }
-
-            let tagged_file = Probe::open(path).ok()?.read().ok()?;
-            let tag = tagged_file.primary_tag();
-            let properties = tagged_file.properties();
-
-            let title = tag.and_then(|t: &lofty::tag::Tag| t.title().map(|s| s.to_string()));
-            let artist = tag.and_then(|t: &lofty::tag::Tag| t.artist().map(|s| s.to_string()));
-            let album = tag.and_then(|t: &lofty::tag::Tag| t.album().map(|s| s.to_string()));
-            let duration_secs = Some(properties.duration().as_secs());
-
-            let modified = entry
-                .metadata()
-                .ok()
-                .and_then(|m: std::fs::Metadata| m.modified().ok())
-                .and_then(|t: std::time::SystemTime| t.duration_since(std::time::UNIX_EPOCH).ok())
-                .map(|d: std::time::Duration| d.as_secs() as i64)
-                .unwrap_or(0);
-
-            Some(AudioFileMetadata {
-                path: path_str,
-                title,
-                artist,
-                album,
-                duration_secs,
-                format: ext,
-                last_modified: modified,
-            })
-        })
-        .collect();
-
-    let found_paths_set: HashSet<String> = found_paths_vec.into_iter().collect();
-    let deleted_paths: Vec<String> = known_files
-        .keys()
-        .filter(|k| !found_paths_set.contains(*k))
-        .cloned()
-        .collect();
-
-    ScanResult {
-        new_or_modified,
-        deleted_paths,
+        let panicked = std::panic::catch_unwind(|| {
+            let _slot = ScanSlot::take().unwrap();
+            panic!("decoder panic");
+        });
+        assert!(panicked.is_err());
+        assert!(ScanSlot::take().is_ok());
     }
 }
@@ -0,0 +1,205 @@
+//! Flutter Rust Bridge API for smart playlists.
+//!
+//! A smart playlist is a rule over the library's tags and play statistics,
+//! like "FLAC added in the last 30 days" or "never played rock over five
+//! minutes", with a sort order and limits. Rules nest into groups matching
+//! all or any of their conditions, and convert to JSON so Dart can store
+//! them. Watched playlists are kept in step with the library: only the songs
+//! a scan, the folder watch or a play changed are checked again.
+
+use crate::api::library::LibrarySong;
+use crate::frb_generated::StreamSink;
+use crate::library::{smart_playlists, store};
+use serde::{Deserialize, Serialize};
+
+/// A song property a condition tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmartField {
+    // Text
+    /// The tagged title, or the one made from the file name
+    Title,
+    /// The artist tag, or any artist it credits
+    Artist,
+    Album,
+    AlbumArtist,
+    Genre,
+    Composer,
+    /// The file format, e.g. "flac"
+    Format,
+    Path,
+
+    // Numbers
+    Year,
+    TrackNumber,
+    DiscNumber,
+    DurationSecs,
+    SampleRate,
+    BitDepth,
+    BitrateKbps,
+    Channels,
+    PlayCount,
+
+    // Dates, in seconds since the epoch
+    DateAdded,
+    LastPlayed,
+    /// When the file was last modified
+    LastModified,
+
+    // Flags
+    Favorite,
+    Compilation,
+    HasLyrics,
+}
+
+/// How a condition compares its field with its value.
+///
+/// Text comparisons ignore case and accents. A song missing the field only
+/// matches the negative operators: `IsNot`, `DoesNotContain`,
+/// `NotInTheLast` and `IsEmpty`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmartOperator {
+    /// Text or number equal to the value
+    Is,
+    IsNot,
+    Contains,
+    DoesNotContain,
+    StartsWith,
+    EndsWith,
+    GreaterThan,
+    LessThan,
+    /// Date before the time in [`SmartCondition::number`]
+    Before,
+    After,
+    /// Date within the last [`SmartCondition::number`] seconds
+    InTheLast,
+    NotInTheLast,
+    /// Flag set
+    IsTrue,
+    IsFalse,
+    /// Text, number or date missing
+    IsEmpty,
+    IsNotEmpty,
+}
+
+/// A test of one field of a song.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmartCondition {
+    pub field: SmartField,
+    pub operator: SmartOperator,
+    /// The value of text operators
+    #[serde(default)]
+    pub text: String,
+    /// The value of number and date operators: a time in seconds since the
+    /// epoch for `Before` and `After`, a span of seconds for `InTheLast`
+    #[serde(default)]
+    pub number: f64,
+}
+
+/// A group of conditions and nested groups.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmartRule {
+    /// Whether songs must match every condition and group, or any of them.
+    /// An empty group matches every song.
+    pub match_all: bool,
+    #[serde(default)]
+    pub conditions: Vec<SmartCondition>,
+    #[serde(default)]
+    pub groups: Vec<SmartRule>,
+}
+
+/// Order of the songs of a smart playlist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmartSortType {
+    Title,
+    /// By artist, then album and track
+    Artist,
+    /// By album, then disc and track
+    Album,
+    /// By year, then album and track
+    Year,
+    Duration,
+    DateAdded,
+    PlayCount,
+    LastPlayed,
+    /// Shuffled, in an order that stays the same as the library changes
+    Random,
+}
+
+/// A rule with the order and limits of the songs it selects.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmartPlaylist {
+    pub rule: SmartRule,
+    pub sort: SmartSortType,
+    #[serde(default)]
+    pub descending: bool,
+    /// Most songs to keep, in sort order
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// Most total duration to keep, in sort order
+    #[serde(default)]
+    pub duration_limit_secs: Option<u32>,
+}
+
+/// The songs of `playlist`, in its order.
+pub fn smart_playlist_songs(playlist: SmartPlaylist) -> Result<Vec<LibrarySong>, String> {
+    smart_playlists::validate(&playlist)?;
+    store::with_shared(|store| store.smart_playlist_songs(&playlist))
+}
+
+/// Stream the songs of `playlist` now and again whenever they change.
+///
+/// Rules on how long ago something happened are checked again on every
+/// change to the library, and every minute as time passes. Watching with a
+/// `key` already watched replaces that watch.
+pub fn smart_playlist_watch(
+    key: String,
+    playlist: SmartPlaylist,
+    sink: StreamSink<Vec<LibrarySong>>,
+) -> Result<(), String> {
+    smart_playlists::validate(&playlist)?;
+    store::start_shared_writer();
+    store::with_shared(|store| {
+        store.watch_smart_playlist(key, playlist, move |songs| sink.add(songs).is_ok())
+    })
+}
+
+/// Stop the watch started with `key`, closing its stream.
+pub fn smart_playlist_unwatch(key: String) -> Result<(), String> {
+    store::with_shared(|store| {
+        store.unwatch_smart_playlist(&key);
+        Ok(())
+    })
+}
+
+/// `playlist` as JSON, for storing.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smart_playlist_to_json(playlist: SmartPlaylist) -> String {
+    serde_json::to_string(&playlist).unwrap_or_default()
+}
+
+/// Read a playlist stored by [`smart_playlist_to_json`].
+#[flutter_rust_bridge::frb(sync)]
+pub fn smart_playlist_from_json(json: String) -> Result<SmartPlaylist, String> {
+    let playlist = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+    smart_playlists::validate(&playlist)?;
+    Ok(playlist)
+}
+
+/// All songs, by title.
+#[flutter_rust_bridge::frb(sync)]
+pub fn smart_playlist_default() -> SmartPlaylist {
+    SmartPlaylist {
+        rule: SmartRule {
+            match_all: true,
+            conditions: Vec::new(),
+            groups: Vec::new(),
+        },
+        sort: SmartSortType::Title,
+        descending: false,
+        limit: None,
+        duration_limit_secs: None,
+    }
+}
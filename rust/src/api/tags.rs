@@ -0,0 +1,82 @@
+//! Flutter Rust Bridge API for editing tags.
+//!
+//! Changes are written to a copy of the file that then replaces it, so a
+//! failed write never leaves a half-written file behind. The file's
+//! modification time always moves forward, so the folder watch and the next
+//! scan pick the change up.
+
+use crate::library::tags;
+use std::path::Path;
+
+/// A tag field that can be removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagField {
+    Title,
+    Artist,
+    Album,
+    AlbumArtist,
+    TrackNumber,
+    TrackTotal,
+    DiscNumber,
+    DiscTotal,
+    Year,
+    Genre,
+    Lyrics,
+    /// Every embedded picture
+    CoverArt,
+}
+
+/// Changes to a file's tags. Unset fields are left as they are.
+#[derive(Debug, Clone, Default)]
+pub struct TagChanges {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    pub track_total: Option<u32>,
+    pub disc_number: Option<u32>,
+    pub disc_total: Option<u32>,
+    pub year: Option<u32>,
+    pub genre: Option<String>,
+    /// Unsynchronized lyrics
+    pub lyrics: Option<String>,
+    /// A JPEG, PNG, GIF, BMP or TIFF image to embed as the front cover
+    pub cover_art: Option<Vec<u8>>,
+    /// Fields to remove, before the fields above are set
+    pub remove: Vec<TagField>,
+}
+
+/// A file [`write_tags_batch`] could not change.
+#[derive(Debug, Clone)]
+pub struct TagWriteFailure {
+    pub path: String,
+    pub error: String,
+}
+
+/// No changes, to set the fields to change on.
+#[flutter_rust_bridge::frb(sync)]
+pub fn tags_default_changes() -> TagChanges {
+    TagChanges::default()
+}
+
+/// Apply `changes` to the tags of the audio file at `path`.
+///
+/// Every tag in the file is changed, and the format's own tag (ID3v2 for
+/// MP3, Vorbis comments for FLAC and Ogg, MP4 atoms, APE for Monkey's Audio
+/// and WavPack) is created if it is missing.
+pub fn write_tags(path: String, changes: TagChanges) -> Result<(), String> {
+    tags::write(Path::new(&path), &changes)
+}
+
+/// Apply the same `changes` to every file in `paths`, e.g. the songs of an
+/// album. Files that fail are reported and the rest are still written.
+pub fn write_tags_batch(paths: Vec<String>, changes: TagChanges) -> Vec<TagWriteFailure> {
+    paths
+        .into_iter()
+        .filter_map(|path| match tags::write(Path::new(&path), &changes) {
+            Ok(()) => None,
+            Err(error) => Some(TagWriteFailure { path, error }),
+        })
+        .collect()
+}
@@ -3,6 +3,7 @@
 //! Commands are sent from Dart through lock-free channels to avoid
 //! blocking the audio thread.
 
+use crate::audio::dither::DitherMode;
 use std::path::PathBuf;
 
 /// Commands that can be sent to the audio engine.
@@ -11,10 +12,15 @@ pub enum AudioCommand {
     /// Load and play a track immediately
     Play {
         path: PathBuf,
+        /// Only this part of the file, for tracks of a CUE sheet
+        range: Option<TimeRange>,
+        /// Where to start, e.g. where the file was left off
+        start_secs: f64,
     },
     /// Queue a track for gapless playback (starts when current ends)
     QueueNext {
         path: PathBuf,
+        range: Option<TimeRange>,
     },
     /// Pause playback (maintains position)
     Pause,
@@ -39,18 +45,40 @@ pub enum AudioCommand {
     SetPlaybackSpeed {
         speed: f32,
     },
+    /// Set the dither used for integer output formats
+    SetDither {
+        mode: DitherMode,
+    },
     /// Trigger crossfade to next track immediately
     CrossfadeToNext,
     /// Skip to the next track (with crossfade if enabled)
     SkipToNext,
+    /// Seek to the start of the next chapter of the current track
+    NextChapter,
+    /// Seek to the start of the current chapter, or of the previous one
+    /// right after a chapter starts
+    PreviousChapter,
     /// Shutdown the audio engine
     Shutdown,
 }
 
+/// A part of a file played as a track of its own, e.g. a track of a CUE
+/// sheet.
+///
+/// Ranges are cut at whole frames of the file, so the tracks of one file
+/// queued after each other play back without a gap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeRange {
+    pub start_secs: f64,
+    /// `None` to play to the end of the file
+    pub end_secs: Option<f64>,
+}
+
 /// Current playback state reported back to Dart.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PlaybackState {
     /// Engine is idle, no track loaded
+    #[default]
     Idle,
     /// Track is loaded and playing
     Playing,
@@ -64,12 +92,6 @@ pub enum PlaybackState {
     Stopped,
 }
 
-impl Default for PlaybackState {
-    fn default() -> Self {
-        Self::Idle
-    }
-}
-
 /// Progress update sent to Dart via callbacks.
 #[derive(Debug, Clone, Copy)]
 pub struct PlaybackProgress {
@@ -105,4 +127,21 @@ pub enum AudioEvent {
     NextTrackReady {
         path: String,
     },
+    /// The active line of the current track's synced lyrics changed
+    LyricsLineChanged {
+        path: String,
+        /// `None` before the first line
+        line_index: Option<usize>,
+    },
+    /// Playback reached another chapter of the current track
+    ChapterChanged {
+        path: String,
+        /// Index into the track's chapters; `None` before the first
+        chapter_index: Option<usize>,
+    },
+    /// A track started where it was left off rather than at the beginning
+    Resumed {
+        path: String,
+        position_secs: f64,
+    },
 }
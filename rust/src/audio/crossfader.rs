@@ -6,10 +6,11 @@
 use std::f32::consts::FRAC_PI_2;
 
 /// Crossfade curve types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CrossfadeCurve {
     /// Equal power using sin/cos (recommended)
     /// Maintains constant perceived loudness
+    #[default]
     EqualPower,
     /// Linear fade (can sound "dipped" in the middle)
     Linear,
@@ -19,12 +20,6 @@ pub enum CrossfadeCurve {
     SCurve,
 }
 
-impl Default for CrossfadeCurve {
-    fn default() -> Self {
-        Self::EqualPower
-    }
-}
-
 /// Crossfader state machine.
 #[derive(Debug, Clone)]
 pub struct Crossfader {
@@ -249,7 +244,12 @@ impl Crossfader {
 
         // Process frame by frame for smooth gain transitions
         for frame in 0..frames {
-            let (gain_a, gain_b) = self.current_gains();
+            // Once the fade has completed the rest of the buffer is all B
+            let (gain_a, gain_b) = if completed {
+                (0.0, 1.0)
+            } else {
+                self.current_gains()
+            };
             
             for ch in 0..channels {
                 let idx = frame * channels + ch;
@@ -3,8 +3,10 @@
 //! Decoding happens in a separate thread to avoid blocking the audio callback.
 //! Decoded samples are written to a ring buffer for consumption by the audio thread.
 
+use crate::audio::commands::TimeRange;
 use crate::audio::resampler::{AudioResampler, DEFAULT_OUTPUT_SAMPLE_RATE};
 use crate::audio::source::{AudioSource, SourceInfo, SourceProducer};
+use crate::library::{chapters, lyrics};
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -59,6 +61,103 @@ pub struct ProbeResult {
     pub track_id: u32,
 }
 
+impl ProbeResult {
+    /// Seek the format reader to a position in seconds.
+    ///
+    /// Returns the number of frames (at the source sample rate) that must be
+    /// discarded from the decoded output to land exactly on the position.
+    pub fn seek(&mut self, position_secs: f64) -> Result<u64, DecoderError> {
+        let frame = (position_secs * self.source_info.original_sample_rate as f64).round() as u64;
+        self.seek_frame(frame)
+    }
+
+    /// Seek the format reader to a frame (at the source sample rate).
+    ///
+    /// Returns the number of frames that must be discarded from the decoded
+    /// output to land exactly on the frame.
+    pub fn seek_frame(&mut self, frame: u64) -> Result<u64, DecoderError> {
+        let sample_rate = self.source_info.original_sample_rate as f64;
+        let seeked = self
+            .format
+            .seek(
+                SeekMode::Accurate,
+                SeekTo::Time {
+                    time: Time::from(frame as f64 / sample_rate),
+                    track_id: Some(self.track_id),
+                },
+            )
+            .map_err(|e| DecoderError::DecodingFailed(format!("Seek failed: {}", e)))?;
+
+        // Reset decoder after seek
+        self.decoder.reset();
+
+        let time_base = self
+            .format
+            .tracks()
+            .iter()
+            .find(|t| t.id == self.track_id)
+            .and_then(|t| t.codec_params.time_base);
+        let actual_frame = match time_base {
+            Some(time_base) => {
+                let time = time_base.calc_time(seeked.actual_ts);
+                ((time.seconds as f64 + time.frac) * sample_rate).round() as u64
+            }
+            None => seeked.actual_ts,
+        };
+
+        Ok(frame.saturating_sub(actual_frame))
+    }
+
+    /// Decode the next packet of the audio track into interleaved f32
+    /// samples, replacing the contents of `buffer`.
+    ///
+    /// Returns `Ok(false)` at the end of the stream. Corrupted frames are
+    /// skipped, so a successful call may still leave `buffer` empty.
+    pub fn decode_next(&mut self, buffer: &mut Vec<f32>) -> Result<bool, DecoderError> {
+        buffer.clear();
+
+        loop {
+            // Get the next packet
+            let packet = match self.format.next_packet() {
+                Ok(packet) => packet,
+                Err(SymphoniaError::IoError(ref e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                    // End of stream
+                    return Ok(false);
+                }
+                Err(SymphoniaError::ResetRequired) => {
+                    // Reset decoder for gapless playback
+                    self.decoder.reset();
+                    continue;
+                }
+                Err(e) => {
+                    return Err(DecoderError::DecodingFailed(e.to_string()));
+                }
+            };
+
+            // Skip packets from other tracks
+            if packet.track_id() != self.track_id {
+                continue;
+            }
+
+            // Decode the packet
+            match self.decoder.decode(&packet) {
+                Ok(decoded) => {
+                    convert_to_interleaved_f32(&decoded, buffer);
+                    return Ok(true);
+                }
+                Err(SymphoniaError::DecodeError(e)) => {
+                    // Skip corrupted frames
+                    eprintln!("Decode error (skipping frame): {}", e);
+                    return Ok(true);
+                }
+                Err(e) => {
+                    return Err(DecoderError::DecodingFailed(e.to_string()));
+                }
+            }
+        }
+    }
+}
+
 /// Probe an audio file to get its metadata and prepare for decoding.
 pub fn probe_file(path: &Path) -> Result<ProbeResult, DecoderError> {
     let file = File::open(path)?;
@@ -110,12 +209,14 @@ pub fn probe_file(path: &Path) -> Result<ProbeResult, DecoderError> {
         0.0
     };
 
-    // Total samples at output sample rate
-    let total_samples = (duration_secs * DEFAULT_OUTPUT_SAMPLE_RATE as f64 * channels as f64) as u64;
+    // Total samples at output sample rate (rounded to whole frames, so exact
+    // durations don't lose a frame to floating point error)
+    let total_frames = (duration_secs * DEFAULT_OUTPUT_SAMPLE_RATE as f64).round() as u64;
+    let total_samples = total_frames * channels as u64;
 
     let decoder_opts = DecoderOptions::default();
     let decoder = symphonia::default::get_codecs()
-        .make(&codec_params, &decoder_opts)
+        .make(codec_params, &decoder_opts)
         .map_err(|e| DecoderError::UnsupportedFormat(e.to_string()))?;
 
     let source_info = SourceInfo {
@@ -125,6 +226,10 @@ pub fn probe_file(path: &Path) -> Result<ProbeResult, DecoderError> {
         channels,
         total_samples,
         duration_secs,
+        range: None,
+        chapters: Vec::new(),
+        lyrics: None,
+        start_secs: 0.0,
     };
 
     Ok(ProbeResult {
@@ -146,12 +251,64 @@ impl DecoderThread {
     ///
     /// Returns the audio source (for the audio thread) and the decoder thread handle.
     pub fn spawn(path: PathBuf, output_sample_rate: u32) -> Result<(AudioSource, Self), DecoderError> {
+        Self::spawn_at(path, output_sample_rate, 0.0)
+    }
+
+    /// Spawn a new decoder thread that starts at `start_secs` into the file.
+    pub fn spawn_at(
+        path: PathBuf,
+        output_sample_rate: u32,
+        start_secs: f64,
+    ) -> Result<(AudioSource, Self), DecoderError> {
+        Self::spawn_range(path, output_sample_rate, None, start_secs)
+    }
+
+    /// Spawn a new decoder thread that plays only `range` of the file,
+    /// starting `start_secs` into the range.
+    ///
+    /// The source reports positions and durations relative to the start of
+    /// the range. Decoding stops at the frame where the range ends, so the
+    /// next range of the same file continues without a gap or overlap.
+    pub fn spawn_range(
+        path: PathBuf,
+        output_sample_rate: u32,
+        range: Option<TimeRange>,
+        start_secs: f64,
+    ) -> Result<(AudioSource, Self), DecoderError> {
         // Probe the file first (on the calling thread)
-        let probe_result = probe_file(&path)?;
+        let mut probe_result = probe_file(&path)?;
+        let sample_rate = probe_result.source_info.original_sample_rate as f64;
+        let to_frame = |secs: f64| (secs.max(0.0) * sample_rate).round() as u64;
+
+        let range_start = range.map_or(0.0, |range| range.start_secs);
+        let first_frame = to_frame(range_start + start_secs);
+        let skip_frames = if first_frame > 0 {
+            probe_result.seek_frame(first_frame)?
+        } else {
+            0
+        };
+        let end_frame = range.and_then(|range| range.end_secs).map(to_frame);
+        let max_frames = end_frame.map(|end| end.saturating_sub(first_frame));
+
+        probe_result.source_info.chapters = chapters::read(&path).unwrap_or_default();
+        probe_result.source_info.lyrics = lyrics::read(&path).ok().flatten().filter(|l| l.synced);
+        if let Some(range) = range {
+            let info = &mut probe_result.source_info;
+            let end_secs = range.end_secs.unwrap_or(info.duration_secs);
+            info.duration_secs = (end_secs - range.start_secs).max(0.0);
+            let total_frames =
+                (info.duration_secs * DEFAULT_OUTPUT_SAMPLE_RATE as f64).round() as u64;
+            info.total_samples = total_frames * info.channels as u64;
+            info.range = Some(range);
+        }
+        probe_result.source_info.start_secs = start_secs;
         let source_info = probe_result.source_info.clone();
 
         // Create the source and producer
         let (source, producer) = AudioSource::new(source_info);
+        if start_secs > 0.0 {
+            source.set_position_secs(start_secs);
+        }
         let stop_signal = Arc::new(AtomicBool::new(false));
         let stop_signal_clone = Arc::clone(&stop_signal);
 
@@ -159,9 +316,16 @@ impl DecoderThread {
         let handle = thread::Builder::new()
             .name(format!("decoder-{}", path.display()))
             .spawn(move || {
-                decode_thread(probe_result, producer, output_sample_rate, stop_signal_clone)
+                decode_thread(
+                    probe_result,
+                    producer,
+                    output_sample_rate,
+                    skip_frames,
+                    max_frames,
+                    stop_signal_clone,
+                )
             })
-            .map_err(|e| DecoderError::IoError(e.into()))?;
+            .map_err(DecoderError::IoError)?;
 
         Ok((
             source,
@@ -205,17 +369,14 @@ impl Drop for DecoderThread {
 
 /// The main decoder loop running in a background thread.
 fn decode_thread(
-    probe_result: ProbeResult,
+    mut probe_result: ProbeResult,
     mut producer: SourceProducer,
     output_sample_rate: u32,
+    mut skip_frames: u64,
+    mut max_frames: Option<u64>,
     stop_signal: Arc<AtomicBool>,
 ) -> Result<(), DecoderError> {
-    let ProbeResult {
-        source_info,
-        mut format,
-        mut decoder,
-        track_id,
-    } = probe_result;
+    let source_info = probe_result.source_info.clone();
 
     // Create resampler if needed
     let needs_resampling = source_info.original_sample_rate != output_sample_rate;
@@ -248,80 +409,60 @@ fn decode_thread(
             break;
         }
 
-        // Get the next packet
-        let packet = match format.next_packet() {
-            Ok(packet) => packet,
-            Err(SymphoniaError::IoError(ref e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
-                // End of stream
-                break;
-            }
-            Err(SymphoniaError::ResetRequired) => {
-                // Reset decoder for gapless playback
-                decoder.reset();
-                continue;
-            }
-            Err(e) => {
-                return Err(DecoderError::DecodingFailed(e.to_string()));
-            }
-        };
-
-        // Skip packets from other tracks
-        if packet.track_id() != track_id {
+        if !probe_result.decode_next(&mut decode_buffer)? {
+            break;
+        }
+        if decode_buffer.is_empty() {
             continue;
         }
 
-        // Decode the packet
-        let decoded = match decoder.decode(&packet) {
-            Ok(decoded) => decoded,
-            Err(SymphoniaError::DecodeError(e)) => {
-                // Skip corrupted frames
-                eprintln!("Decode error (skipping frame): {}", e);
+        // Drop frames before the seek target
+        if skip_frames > 0 {
+            let frames = (decode_buffer.len() / source_info.channels) as u64;
+            let skipped = skip_frames.min(frames);
+            decode_buffer.drain(..skipped as usize * source_info.channels);
+            skip_frames -= skipped;
+            if decode_buffer.is_empty() {
                 continue;
             }
-            Err(e) => {
-                return Err(DecoderError::DecodingFailed(e.to_string()));
-            }
-        };
+        }
 
-        // Convert to interleaved f32
-        decode_buffer.clear();
-        convert_to_interleaved_f32(&decoded, &mut decode_buffer);
+        // Stop at the end of the range
+        let mut range_ended = false;
+        if let Some(ref mut remaining) = max_frames {
+            let frames = (decode_buffer.len() / source_info.channels) as u64;
+            if frames >= *remaining {
+                decode_buffer.truncate(*remaining as usize * source_info.channels);
+                range_ended = true;
+            }
+            *remaining -= frames.min(*remaining);
+        }
 
         // Resample if needed
         let output_samples = if let Some(ref mut resampler) = resampler {
             resample_buffer.clear();
-            resample_buffer.resize(
-                (decode_buffer.len() as f64 * output_sample_rate as f64 / source_info.original_sample_rate as f64 * 1.2) as usize + 256,
-                0.0,
-            );
-
-            let written = resampler
+            resampler
                 .process_interleaved(&decode_buffer, &mut resample_buffer)
                 .map_err(DecoderError::ResamplingFailed)?;
 
-            &resample_buffer[..written]
+            &resample_buffer[..]
         } else {
             &decode_buffer[..]
         };
 
-        // Write to ring buffer, waiting if necessary
-        let mut offset = 0;
-        while offset < output_samples.len() {
-            if stop_signal.load(Ordering::Acquire) || producer.should_stop() {
-                break;
-            }
-
-            let chunk = &output_samples[offset..];
-            let written = producer.write(chunk);
-            offset += written;
+        if !write_to_producer(&mut producer, output_samples, &stop_signal) || range_ended {
+            break;
+        }
+    }
 
-            if written == 0 {
-                // Buffer full - wait a bit
-                if !producer.wait_for_space(chunk.len().min(1024), 100) {
-                    // Timeout or stop signal
-                    break;
-                }
-            }
+    // Drain the samples still buffered in the resampler
+    if let Some(ref mut resampler) = resampler {
+        if !stop_signal.load(Ordering::Acquire) && !producer.should_stop() {
+            resample_buffer.clear();
+            resampler
+                .flush(&mut resample_buffer)
+                .map_err(DecoderError::ResamplingFailed)?;
+            write_to_producer(&mut producer, &resample_buffer, &stop_signal);
         }
     }
 
@@ -331,6 +472,33 @@ fn decode_thread(
     Ok(())
 }
 
+/// Write samples to the ring buffer, waiting for space as needed.
+///
+/// Returns `false` if decoding was stopped before everything was written.
+fn write_to_producer(
+    producer: &mut SourceProducer,
+    samples: &[f32],
+    stop_signal: &AtomicBool,
+) -> bool {
+    let mut offset = 0;
+    while offset < samples.len() {
+        if stop_signal.load(Ordering::Acquire) || producer.should_stop() {
+            return false;
+        }
+
+        let chunk = &samples[offset..];
+        let written = producer.write(chunk);
+        offset += written;
+
+        if written == 0 {
+            // Buffer full - wait for the consumer. A timeout just means
+            // it is slow (or paused); the samples must not be dropped.
+            producer.wait_for_space(chunk.len().min(1024), 100);
+        }
+    }
+    true
+}
+
 /// Convert an AudioBufferRef to interleaved f32 samples.
 fn convert_to_interleaved_f32(buffer: &AudioBufferRef, output: &mut Vec<f32>) {
     match buffer {
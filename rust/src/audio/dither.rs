@@ -0,0 +1,224 @@
+//! Dither for reducing float samples to integer PCM.
+//!
+//! Rounding to a lower bit depth produces quantization error that is
+//! correlated with the signal, which is audible as distortion on quiet
+//! passages. Adding triangular (TPDF) noise of +-1 LSB before rounding turns
+//! it into a constant, signal-independent noise floor.
+//!
+//! Noise shaping additionally feeds the quantization error back through a
+//! filter, moving the noise floor away from the midrange (where hearing is
+//! most sensitive) towards the top of the band.
+
+/// Error feedback filter for noise shaping (Wannamaker's 3-tap E-weighted
+/// filter). The resulting noise transfer function is about -12 dB at DC and
+/// +11 dB at Nyquist.
+const SHAPING_FILTER: [f32; 3] = [1.623, -0.982, 0.109];
+
+/// Dither applied before quantization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DitherMode {
+    /// Plain rounding
+    None,
+    /// Triangular probability density noise of +-1 LSB
+    #[default]
+    Triangular,
+    /// Triangular noise plus error feedback noise shaping
+    NoiseShaped,
+}
+
+impl DitherMode {
+    /// Stable numeric id, for storing the mode in an atomic.
+    pub fn to_u8(self) -> u8 {
+        match self {
+            DitherMode::None => 0,
+            DitherMode::Triangular => 1,
+            DitherMode::NoiseShaped => 2,
+        }
+    }
+
+    /// Inverse of [`to_u8`](Self::to_u8); unknown ids map to `None`.
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => DitherMode::Triangular,
+            2 => DitherMode::NoiseShaped,
+            _ => DitherMode::None,
+        }
+    }
+}
+
+/// Adds dither noise to float samples ahead of quantization.
+#[derive(Debug, Clone)]
+pub struct Ditherer {
+    mode: DitherMode,
+    /// One least significant bit of the target format, in float units
+    lsb: f32,
+    /// xorshift32 state
+    rng: u32,
+    channels: usize,
+    /// Recent quantization errors per channel, newest first (noise shaping)
+    errors: Vec<[f32; 3]>,
+}
+
+impl Ditherer {
+    /// Create a ditherer for interleaved integer output with `bits` bits per
+    /// sample.
+    pub fn new(mode: DitherMode, bits: u32, channels: usize) -> Self {
+        let channels = channels.max(1);
+        Self {
+            mode,
+            lsb: 1.0 / (1u64 << (bits - 1)) as f32,
+            rng: 0x9E37_79B9,
+            channels,
+            errors: vec![[0.0; 3]; channels],
+        }
+    }
+
+    /// Current dither mode.
+    #[inline]
+    pub fn mode(&self) -> DitherMode {
+        self.mode
+    }
+
+    /// Switch the dither mode. Does not allocate, so it is safe to call from
+    /// the audio callback.
+    pub fn set_mode(&mut self, mode: DitherMode) {
+        self.mode = mode;
+        for errors in &mut self.errors {
+            *errors = [0.0; 3];
+        }
+    }
+
+    /// Uniform random value in [0, 1).
+    #[inline]
+    fn next_uniform(&mut self) -> f32 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 17;
+        self.rng ^= self.rng << 5;
+        (self.rng >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Triangular noise of +-1 LSB.
+    #[inline]
+    fn next_triangular(&mut self) -> f32 {
+        // The difference of two uniform values has a triangular density
+        (self.next_uniform() - self.next_uniform()) * self.lsb
+    }
+
+    /// Add dither to an interleaved buffer in place.
+    ///
+    /// With [`DitherMode::NoiseShaped`] the samples are quantized here as
+    /// well (the error has to be known for the feedback), so they come out
+    /// as exact multiples of one LSB and the final rounding is a no-op.
+    pub fn process(&mut self, buffer: &mut [f32]) {
+        match self.mode {
+            DitherMode::None => {}
+            DitherMode::Triangular => {
+                for sample in buffer.iter_mut() {
+                    *sample += self.next_triangular();
+                }
+            }
+            DitherMode::NoiseShaped => {
+                let max = 1.0 - self.lsb;
+                for (index, sample) in buffer.iter_mut().enumerate() {
+                    let channel = index % self.channels;
+                    let errors = self.errors[channel];
+                    let feedback: f32 = SHAPING_FILTER
+                        .iter()
+                        .zip(errors.iter())
+                        .map(|(c, e)| c * e)
+                        .sum();
+
+                    let shaped = *sample - feedback;
+                    let dithered = shaped + self.next_triangular();
+                    let quantized = ((dithered / self.lsb).round() * self.lsb).clamp(-1.0, max);
+
+                    // Clipping produces errors far beyond the dither range;
+                    // feeding those back would make the filter ring
+                    let error = (quantized - shaped).clamp(-2.0 * self.lsb, 2.0 * self.lsb);
+                    self.errors[channel] = [error, errors[0], errors[1]];
+                    *sample = quantized;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::wav::quantize;
+
+    #[test]
+    fn test_no_dither_is_transparent() {
+        let mut ditherer = Ditherer::new(DitherMode::None, 16, 1);
+        let mut buffer = vec![0.1, -0.2, 0.3];
+        ditherer.process(&mut buffer);
+        assert_eq!(buffer, vec![0.1, -0.2, 0.3]);
+    }
+
+    #[test]
+    fn test_triangular_dither_decorrelates_quiet_signal() {
+        // A constant a quarter LSB above zero rounds to zero without dither;
+        // with TPDF dither the quantized mean tracks the true value
+        let lsb = 1.0 / 32768.0;
+        let mut ditherer = Ditherer::new(DitherMode::Triangular, 16, 1);
+        let mut buffer = vec![0.25 * lsb; 100_000];
+        ditherer.process(&mut buffer);
+
+        assert!(buffer.iter().all(|s| (s - 0.25 * lsb).abs() <= lsb));
+        let mean = buffer.iter().map(|&s| quantize(s, 16) as f64).sum::<f64>() / buffer.len() as f64;
+        assert!((mean - 0.25).abs() < 0.02, "mean {}", mean);
+    }
+
+    /// Energy of the total error `output - input` after a 16-sample moving
+    /// average, i.e. the part of the noise in the lower part of the band.
+    fn low_band_noise(input: &[f32], output: &[f32]) -> f64 {
+        let error: Vec<f64> = output.iter().zip(input).map(|(o, i)| (o - i) as f64).collect();
+        error
+            .windows(16)
+            .map(|w| (w.iter().sum::<f64>() / 16.0).powi(2))
+            .sum::<f64>()
+            / error.len() as f64
+    }
+
+    #[test]
+    fn test_noise_shaping_moves_noise_out_of_low_band() {
+        let input: Vec<f32> = (0..100_000)
+            .map(|i| (i as f32 * 0.01).sin() * 0.001)
+            .collect();
+
+        let mut flat = input.clone();
+        Ditherer::new(DitherMode::Triangular, 16, 1).process(&mut flat);
+        for sample in &mut flat {
+            *sample = quantize(*sample, 16) as f32 / 32768.0;
+        }
+
+        let mut shaped = input.clone();
+        Ditherer::new(DitherMode::NoiseShaped, 16, 1).process(&mut shaped);
+
+        // Shaped output is already on the 16-bit grid
+        assert!(shaped.iter().all(|&s| quantize(s, 16) as f32 / 32768.0 == s));
+        let flat_noise = low_band_noise(&input, &flat);
+        let shaped_noise = low_band_noise(&input, &shaped);
+        assert!(shaped_noise < flat_noise * 0.25, "{} vs {}", shaped_noise, flat_noise);
+    }
+
+    #[test]
+    fn test_noise_shaping_keeps_channels_apart() {
+        // Silence on the right must stay silent-ish even with a loud left
+        let mut buffer: Vec<f32> = (0..20_000)
+            .flat_map(|i| [(i as f32 * 0.05).sin() * 0.9, 0.0])
+            .collect();
+        Ditherer::new(DitherMode::NoiseShaped, 16, 2).process(&mut buffer);
+
+        let lsb = 1.0 / 32768.0;
+        assert!(buffer.iter().skip(1).step_by(2).all(|s| s.abs() <= 4.0 * lsb));
+    }
+
+    #[test]
+    fn test_mode_round_trips_through_u8() {
+        for mode in [DitherMode::None, DitherMode::Triangular, DitherMode::NoiseShaped] {
+            assert_eq!(DitherMode::from_u8(mode.to_u8()), mode);
+        }
+    }
+}
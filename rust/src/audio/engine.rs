@@ -1,23 +1,45 @@
-//! Core audio engine with cpal output and lock-free architecture.
+//! Core audio engine with lock-free architecture.
 //!
 //! The engine manages the audio output stream, handles commands from Dart,
 //! and coordinates decoding, resampling, and crossfading.
 
-use crate::audio::commands::{AudioCommand, AudioEvent, PlaybackProgress, PlaybackState};
+use crate::audio::commands::{
+    AudioCommand, AudioEvent, PlaybackProgress, PlaybackState, TimeRange,
+};
 use crate::audio::crossfader::Crossfader;
 use crate::audio::decoder::DecoderThread;
-use crate::audio::resampler::DEFAULT_OUTPUT_SAMPLE_RATE;
-use crate::audio::source::{AudioSource, SourceProvider};
+use crate::audio::dither::{DitherMode, Ditherer};
+use crate::audio::output::{AudioOutput, CpalOutput, OutputSampleFormat};
+use crate::audio::source::{AudioSource, SourceInfo, SourceProvider};
+use crate::library::chapters::active_chapter;
+use crate::library::{lyrics, store};
 
-use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use cpal::{SampleRate, StreamConfig};
 use crossbeam_channel::{bounded, Receiver, Sender};
 use parking_lot::Mutex;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use std::sync::Arc;
 use std::thread;
 
+/// Seconds into a chapter after which "previous chapter" restarts it
+/// rather than going back to the one before
+const CHAPTER_RESTART_SECS: f64 = 3.0;
+
+/// How far before a chapter's start playback may be and still count as in
+/// the chapter when skipping
+const CHAPTER_START_TOLERANCE_SECS: f64 = 0.01;
+
+/// Shortest file whose position is saved for resuming, until Dart sets one
+const DEFAULT_RESUME_MIN_DURATION_SECS: f64 = 600.0;
+
+/// How far playback of a long file moves between saves of its position
+const RESUME_SAVE_INTERVAL_SECS: f64 = 10.0;
+
+/// Positions this close to the start or end of a file, or this fraction of
+/// a short one, are not worth resuming
+const RESUME_MARGIN_SECS: f64 = 10.0;
+const RESUME_MARGIN_FRACTION: f64 = 0.05;
+
 /// Audio callback data shared between engine and audio thread.
 ///
 /// This struct contains only lock-free or atomic data to ensure
@@ -29,6 +51,8 @@ pub struct AudioCallbackData {
     playback_speed: std::sync::atomic::AtomicU32, // Using AtomicU32 for f32 bit pattern
     /// Pause state
     paused: AtomicBool,
+    /// Dither applied before integer output (`DitherMode` id)
+    dither: AtomicU8,
     /// Output channel count
     channels: usize,
     /// Crossfader state
@@ -57,6 +81,7 @@ impl AudioCallbackData {
             volume: std::sync::atomic::AtomicU32::new(1.0f32.to_bits()),
             playback_speed: std::sync::atomic::AtomicU32::new(1.0f32.to_bits()),
             paused: AtomicBool::new(false),
+            dither: AtomicU8::new(DitherMode::default().to_u8()),
             channels,
             crossfader: Mutex::new(Crossfader::disabled(sample_rate)),
             sources: Mutex::new(SourceProvider::new(sample_rate, channels)),
@@ -102,6 +127,16 @@ impl AudioCallbackData {
     pub fn set_paused(&self, paused: bool) {
         self.paused.store(paused, Ordering::Relaxed);
     }
+
+    #[inline]
+    pub fn get_dither(&self) -> DitherMode {
+        DitherMode::from_u8(self.dither.load(Ordering::Relaxed))
+    }
+
+    #[inline]
+    pub fn set_dither(&self, mode: DitherMode) {
+        self.dither.store(mode.to_u8(), Ordering::Relaxed);
+    }
 }
 
 /// Handle for controlling the audio engine from any thread.
@@ -120,11 +155,15 @@ pub struct AudioEngineHandle {
     sample_rate: u32,
     /// Number of channels
     channels: usize,
+    /// Sample format of the output device
+    sample_format: OutputSampleFormat,
     /// Active decoder threads (kept alive for the duration of playback)
     #[allow(dead_code)]
     decoders: Arc<Mutex<Vec<DecoderThread>>>,
     /// Shutdown flag
     shutdown: Arc<AtomicBool>,
+    /// Which files resume, shared with the command thread
+    resume_options: Arc<Mutex<ResumeOptions>>,
 }
 
 // AudioEngineHandle is Send + Sync because it only contains Arc, channels, and atomics
@@ -139,14 +178,50 @@ impl AudioEngineHandle {
             .map_err(|e| format!("Failed to send command: {}", e))
     }
 
-    /// Play a track.
+    /// Play a track, from where it was left off if it resumes.
+    ///
+    /// The position is read from the library here, on the caller's thread,
+    /// so the engine never waits for the library.
     pub fn play(&self, path: PathBuf) -> Result<(), String> {
-        self.send_command(AudioCommand::Play { path })
+        let options = *self.resume_options.lock();
+        let start_secs = resume_start_secs(&path, &options);
+        self.send_command(AudioCommand::Play {
+            path,
+            range: None,
+            start_secs,
+        })
+    }
+
+    /// Play part of a file as a track, e.g. a track of a CUE sheet.
+    pub fn play_range(&self, path: PathBuf, range: TimeRange) -> Result<(), String> {
+        self.send_command(AudioCommand::Play {
+            path,
+            range: Some(range),
+            start_secs: 0.0,
+        })
     }
 
     /// Queue the next track for gapless playback.
     pub fn queue_next(&self, path: PathBuf) -> Result<(), String> {
-        self.send_command(AudioCommand::QueueNext { path })
+        self.send_command(AudioCommand::QueueNext { path, range: None })
+    }
+
+    /// Queue part of a file as the next track for gapless playback.
+    pub fn queue_next_range(&self, path: PathBuf, range: TimeRange) -> Result<(), String> {
+        self.send_command(AudioCommand::QueueNext {
+            path,
+            range: Some(range),
+        })
+    }
+
+    /// Seek to the start of the next chapter.
+    pub fn next_chapter(&self) -> Result<(), String> {
+        self.send_command(AudioCommand::NextChapter)
+    }
+
+    /// Seek to the start of the current or previous chapter.
+    pub fn previous_chapter(&self) -> Result<(), String> {
+        self.send_command(AudioCommand::PreviousChapter)
     }
 
     /// Pause playback.
@@ -174,6 +249,15 @@ impl AudioEngineHandle {
         self.send_command(AudioCommand::SetVolume { volume })
     }
 
+    /// Configure where playback of long files resumes.
+    pub fn set_resume(&self, auto_resume: bool, min_duration_secs: f64) -> Result<(), String> {
+        *self.resume_options.lock() = ResumeOptions {
+            auto_resume,
+            min_duration_secs,
+        };
+        Ok(())
+    }
+
     /// Configure crossfade.
     pub fn set_crossfade(&self, enabled: bool, duration_secs: f32) -> Result<(), String> {
         self.send_command(AudioCommand::SetCrossfade {
@@ -197,6 +281,16 @@ impl AudioEngineHandle {
         self.callback_data.get_playback_speed()
     }
 
+    /// Set the dither used when reducing to an integer output format.
+    pub fn set_dither(&self, mode: DitherMode) -> Result<(), String> {
+        self.send_command(AudioCommand::SetDither { mode })
+    }
+
+    /// Get the current dither mode. It only has an effect on integer outputs.
+    pub fn get_dither(&self) -> DitherMode {
+        self.callback_data.get_dither()
+    }
+
     /// Get the current playback state.
     pub fn state(&self) -> PlaybackState {
         match self.state.load(Ordering::Relaxed) {
@@ -241,6 +335,11 @@ impl AudioEngineHandle {
         self.channels
     }
 
+    /// Get the sample format negotiated with the output device.
+    pub fn sample_format(&self) -> OutputSampleFormat {
+        self.sample_format
+    }
+
     /// Shutdown the engine.
     pub fn shutdown(&self) -> Result<(), String> {
         self.shutdown.store(true, Ordering::Release);
@@ -248,36 +347,20 @@ impl AudioEngineHandle {
     }
 }
 
-/// Initialize the audio engine and return a handle.
+/// Initialize the audio engine on the default output device and return a handle.
 /// 
-/// The actual cpal stream runs in a dedicated thread.
+/// The actual output stream runs in a dedicated thread.
 pub fn create_audio_engine() -> Result<AudioEngineHandle, String> {
-    // Get the default audio device
-    let host = cpal::default_host();
-    let device = host
-        .default_output_device()
-        .ok_or("No default output device")?;
-
-    // Get default config
-    let default_config = device
-        .default_output_config()
-        .map_err(|e| format!("Failed to get default config: {}", e))?;
-
-    let sample_rate = default_config.sample_rate().0;
-    let channels = default_config.channels() as usize;
-
-    // Prefer our default sample rate if supported
-    let target_sample_rate = if sample_rate == DEFAULT_OUTPUT_SAMPLE_RATE {
-        sample_rate
-    } else {
-        DEFAULT_OUTPUT_SAMPLE_RATE
-    };
+    create_audio_engine_with_output(Box::new(CpalOutput::open_default()?))
+}
 
-    let config = StreamConfig {
-        channels: channels as u16,
-        sample_rate: SampleRate(target_sample_rate),
-        buffer_size: cpal::BufferSize::Default,
-    };
+/// Initialize the audio engine on the given output backend.
+pub fn create_audio_engine_with_output(
+    output: Box<dyn AudioOutput>,
+) -> Result<AudioEngineHandle, String> {
+    let target_sample_rate = output.sample_rate();
+    let channels = output.channels();
+    let sample_format = output.sample_format();
 
     // Create finished tracks channel (from audio callback to command thread)
     let (finished_tx, finished_rx) = bounded::<AudioSource>(32);
@@ -308,34 +391,37 @@ pub fn create_audio_engine() -> Result<AudioEngineHandle, String> {
     // Callback data for command thread
     let callback_data_for_thread = Arc::clone(&callback_data);
 
-    // Spawn the audio thread (which owns the cpal stream)
+    // Resume options, set through the handle
+    let resume_options = Arc::new(Mutex::new(ResumeOptions::default()));
+    let resume_options_clone = Arc::clone(&resume_options);
+
+    // Spawn the audio thread (which owns the output stream)
     thread::Builder::new()
         .name("audio-engine".to_string())
         .spawn(move || {
+            // Integer outputs get the mix dithered down to their word length
+            let mut ditherer = sample_format
+                .bits()
+                .map(|bits| Ditherer::new(callback_data_clone.get_dither(), bits, channels));
+
             // Build the stream in this thread
-            let stream = match device.build_output_stream(
-                &config,
-                move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
-                    audio_callback(data, &callback_data_clone, &event_tx_clone);
-                },
-                |err| {
-                    eprintln!("Audio stream error: {}", err);
-                },
-                None,
-            ) {
+            let _stream = match output.start(Box::new(move |data: &mut [f32]| {
+                audio_callback(data, &callback_data_clone, &event_tx_clone);
+                if let Some(ditherer) = ditherer.as_mut() {
+                    let mode = callback_data_clone.get_dither();
+                    if ditherer.mode() != mode {
+                        ditherer.set_mode(mode);
+                    }
+                    ditherer.process(data);
+                }
+            })) {
                 Ok(s) => s,
                 Err(e) => {
-                    eprintln!("Failed to build audio stream: {}", e);
+                    eprintln!("{}", e);
                     return;
                 }
             };
 
-            // Start the stream
-            if let Err(e) = stream.play() {
-                eprintln!("Failed to start audio stream: {}", e);
-                return;
-            }
-
             // Run command processing loop
             command_processing_loop(
                 command_rx,
@@ -346,6 +432,7 @@ pub fn create_audio_engine() -> Result<AudioEngineHandle, String> {
                 decoders_clone,
                 target_sample_rate,
                 shutdown_clone,
+                resume_options_clone,
             );
 
             // Stream will be dropped here when the loop exits
@@ -359,8 +446,10 @@ pub fn create_audio_engine() -> Result<AudioEngineHandle, String> {
         state,
         sample_rate: target_sample_rate,
         channels,
+        sample_format,
         decoders,
         shutdown,
+        resume_options,
     })
 }
 
@@ -550,6 +639,7 @@ fn audio_callback(
 }
 
 /// Command processing loop running in the audio thread.
+#[allow(clippy::too_many_arguments)]
 fn command_processing_loop(
     command_rx: Receiver<AudioCommand>,
     finished_rx: Receiver<AudioSource>,
@@ -559,7 +649,11 @@ fn command_processing_loop(
     decoders: Arc<Mutex<Vec<DecoderThread>>>,
     sample_rate: u32,
     shutdown: Arc<AtomicBool>,
+    resume_options: Arc<Mutex<ResumeOptions>>,
 ) {
+    let mut lyrics_tracker = None;
+    let mut chapter_tracker = None;
+    let mut resume_tracker = None;
     loop {
         // Check shutdown flag
         if shutdown.load(Ordering::Acquire) {
@@ -568,6 +662,10 @@ fn command_processing_loop(
 
         // Check for finished tracks
         while let Ok(source) = finished_rx.try_recv() {
+            // Tracks skipped during a crossfade end here too, unplayed
+            if source.reached_end() {
+                record_play(&source.info);
+            }
             let path = source.info.path.to_string_lossy().to_string();
             let _ = event_tx.try_send(AudioEvent::TrackEnded { path });
         }
@@ -575,9 +673,12 @@ fn command_processing_loop(
         match command_rx.recv_timeout(std::time::Duration::from_millis(50)) {
             Ok(command) => {
                 match command {
-                    AudioCommand::Play { path } => {
+                    AudioCommand::Play { path, range, start_secs } => {
+                        finish_resume(&mut resume_tracker);
                         handle_play(
                             path,
+                            range,
+                            start_secs,
                             &callback_data,
                             &state,
                             &decoders,
@@ -585,9 +686,10 @@ fn command_processing_loop(
                             sample_rate,
                         );
                     }
-                    AudioCommand::QueueNext { path } => {
+                    AudioCommand::QueueNext { path, range } => {
                         handle_queue_next(
                             path,
+                            range,
                             &callback_data,
                             &decoders,
                             &event_tx,
@@ -610,11 +712,14 @@ fn command_processing_loop(
                         state.store(PlaybackState::Stopped as u8, Ordering::Relaxed);
                         let _ = event_tx.try_send(AudioEvent::StateChanged(PlaybackState::Stopped));
                     }
-                    AudioCommand::Seek { position_secs: _ } => {
-                        // Seeking requires restarting the decoder - complex to implement
-                        let _ = event_tx.try_send(AudioEvent::Error {
-                            message: "Seek not yet implemented".to_string(),
-                        });
+                    AudioCommand::Seek { position_secs } => {
+                        handle_seek(
+                            position_secs,
+                            &callback_data,
+                            &decoders,
+                            &event_tx,
+                            sample_rate,
+                        );
                     }
                     AudioCommand::SetVolume { volume } => {
                         callback_data.set_volume(volume.clamp(0.0, 1.0));
@@ -624,6 +729,9 @@ fn command_processing_loop(
                         crossfader.set_enabled(enabled);
                         crossfader.set_duration(duration_secs);
                     }
+                    AudioCommand::SetDither { mode } => {
+                        callback_data.set_dither(mode);
+                    }
                     AudioCommand::SetPlaybackSpeed { speed } => {
                         callback_data.set_playback_speed(speed);
                         // Reset fractional position when speed changes
@@ -632,6 +740,15 @@ fn command_processing_loop(
                     AudioCommand::CrossfadeToNext | AudioCommand::SkipToNext => {
                         handle_skip_to_next(&callback_data, &state, &event_tx);
                     }
+                    AudioCommand::NextChapter | AudioCommand::PreviousChapter => {
+                        handle_chapter_skip(
+                            matches!(command, AudioCommand::NextChapter),
+                            &callback_data,
+                            &decoders,
+                            &event_tx,
+                            sample_rate,
+                        );
+                    }
                     AudioCommand::Shutdown => {
                         // Stop everything and exit
                         callback_data.sources.lock().stop();
@@ -653,11 +770,253 @@ fn command_processing_loop(
 
         // Clean up finished decoders
         decoders.lock().retain(|d| d.is_running());
+
+        track_lyrics(&callback_data, &mut lyrics_tracker, &event_tx);
+        track_chapters(&callback_data, &mut chapter_tracker, &event_tx);
+        let options = *resume_options.lock();
+        track_resume(&callback_data, &options, &mut resume_tracker);
+    }
+}
+
+/// The current track and the line of its lyrics last reported.
+struct LyricsTracker {
+    path: PathBuf,
+    line: Option<usize>,
+}
+
+/// Report the line of the current track's lyrics that playback has reached.
+fn track_lyrics(
+    callback_data: &AudioCallbackData,
+    tracker: &mut Option<LyricsTracker>,
+    event_tx: &Sender<AudioEvent>,
+) {
+    let current = callback_data.sources.lock().current().map(|source| {
+        // Lyrics are timed against the whole file, not a CUE track in it
+        let line = (source.info.lyrics.as_ref())
+            .and_then(|lyrics| lyrics::active_line(lyrics, file_position_secs(source)));
+        (source.info.path.clone(), source.info.lyrics.is_some(), line)
+    });
+    let Some((path, has_lyrics, line)) = current else {
+        *tracker = None;
+        return;
+    };
+    let changed = match tracker {
+        Some(tracker) => tracker.path != path || tracker.line != line,
+        None => true,
+    };
+    if changed && has_lyrics {
+        let _ = event_tx.try_send(AudioEvent::LyricsLineChanged {
+            path: path.to_string_lossy().to_string(),
+            line_index: line,
+        });
+    }
+    *tracker = Some(LyricsTracker { path, line });
+}
+
+/// The current track and the chapter last reported.
+struct ChapterTracker {
+    path: PathBuf,
+    chapter: Option<usize>,
+}
+
+/// Report the chapter of the current track that playback has reached.
+fn track_chapters(
+    callback_data: &AudioCallbackData,
+    tracker: &mut Option<ChapterTracker>,
+    event_tx: &Sender<AudioEvent>,
+) {
+    let current = callback_data.sources.lock().current().map(|source| {
+        let chapter = active_chapter(&source.info.chapters, file_position_secs(source));
+        (source.info.path.clone(), !source.info.chapters.is_empty(), chapter)
+    });
+    let Some((path, has_chapters, chapter)) = current else {
+        *tracker = None;
+        return;
+    };
+    let changed = match tracker {
+        Some(tracker) => tracker.path != path || tracker.chapter != chapter,
+        None => true,
+    };
+    if changed && has_chapters {
+        let _ = event_tx.try_send(AudioEvent::ChapterChanged {
+            path: path.to_string_lossy().to_string(),
+            chapter_index: chapter,
+        });
     }
+    *tracker = Some(ChapterTracker { path, chapter });
+}
+
+/// Which files' positions are saved, and whether playing them resumes there.
+#[derive(Clone, Copy)]
+struct ResumeOptions {
+    auto_resume: bool,
+    min_duration_secs: f64,
 }
 
+impl Default for ResumeOptions {
+    fn default() -> Self {
+        Self {
+            auto_resume: true,
+            min_duration_secs: DEFAULT_RESUME_MIN_DURATION_SECS,
+        }
+    }
+}
+
+/// The current long track, where it was last seen and the position last
+/// saved for it, at first where it started.
+struct ResumeTracker {
+    path: PathBuf,
+    duration_secs: f64,
+    position_secs: f64,
+    saved_secs: f64,
+}
+
+/// Save where the current long track is left off: every so often while it
+/// plays, when it pauses, and when another track takes over.
+fn track_resume(
+    callback_data: &AudioCallbackData,
+    options: &ResumeOptions,
+    tracker: &mut Option<ResumeTracker>,
+) {
+    let paused = callback_data.is_paused();
+    let current = callback_data
+        .sources
+        .lock()
+        .current()
+        // Tracks of a CUE sheet share their file, so only whole files resume
+        .filter(|source| {
+            source.info.range.is_none() && source.info.duration_secs >= options.min_duration_secs
+        })
+        .map(|source| {
+            let info = &source.info;
+            (info.path.clone(), info.duration_secs, info.start_secs, source.position_secs())
+        });
+    if tracker
+        .as_ref()
+        .is_some_and(|t| current.as_ref().is_none_or(|(path, ..)| *path != t.path))
+    {
+        finish_resume(tracker);
+    }
+    let Some((path, duration_secs, start_secs, position_secs)) = current else {
+        return;
+    };
+    let tracker = tracker.get_or_insert_with(|| ResumeTracker {
+        path,
+        duration_secs,
+        position_secs,
+        saved_secs: start_secs,
+    });
+    tracker.position_secs = position_secs;
+    let moved_secs = (position_secs - tracker.saved_secs).abs();
+    if moved_secs >= RESUME_SAVE_INTERVAL_SECS || (paused && moved_secs > 0.0) {
+        save_resume_position(&tracker.path, position_secs, duration_secs);
+        tracker.saved_secs = position_secs;
+    }
+}
+
+/// Save the last position of the tracked track, if it moved, and stop
+/// tracking it.
+fn finish_resume(tracker: &mut Option<ResumeTracker>) {
+    if let Some(tracker) = tracker.take() {
+        if tracker.position_secs != tracker.saved_secs {
+            save_resume_position(&tracker.path, tracker.position_secs, tracker.duration_secs);
+        }
+    }
+}
+
+/// Save where playback of `path` was left off, forgetting positions too
+/// close to either end to be worth resuming.
+fn save_resume_position(path: &Path, position_secs: f64, duration_secs: f64) {
+    let margin = RESUME_MARGIN_SECS.min(duration_secs * RESUME_MARGIN_FRACTION);
+    let position_secs = Some(position_secs).filter(|&p| p >= margin && p <= duration_secs - margin);
+    // Positions are kept in the library, so nothing is saved until one is
+    // open
+    let path = path.to_string_lossy().to_string();
+    store::queue_shared_write(move |store| store.set_resume_position(&path, position_secs));
+}
+
+/// Count a play of a track that played to its end. Like resume positions,
+/// plays are only counted while a library is open.
+fn record_play(info: &SourceInfo) {
+    let path = info.path.to_string_lossy().to_string();
+    let start_secs = info.range.map(|range| range.start_secs);
+    store::queue_shared_write(move |store| store.record_play(&path, start_secs));
+}
+
+/// Where playing the whole file at `path` starts: where it was left off if
+/// it resumes automatically, else the beginning.
+fn resume_start_secs(path: &Path, options: &ResumeOptions) -> f64 {
+    if !options.auto_resume {
+        return 0.0;
+    }
+    // Saves still queued may be for this file
+    store::flush_shared_writes();
+    let path = path.to_string_lossy();
+    store::with_shared(|store| store.resume_position(&path))
+        .ok()
+        .flatten()
+        .unwrap_or(0.0)
+}
+
+/// Seek to the start of a chapter of the current track: the next one, or
+/// going back, the current one unless it has only just started.
+fn handle_chapter_skip(
+    forward: bool,
+    callback_data: &AudioCallbackData,
+    decoders: &Arc<Mutex<Vec<DecoderThread>>>,
+    event_tx: &Sender<AudioEvent>,
+    sample_rate: u32,
+) {
+    let target = {
+        let sources = callback_data.sources.lock();
+        let Some(source) = sources.current() else {
+            return;
+        };
+        let chapters = &source.info.chapters;
+        let position_secs = file_position_secs(source);
+        // A seek lands on the frame nearest the chapter start, which may be
+        // just before it
+        let active = active_chapter(chapters, position_secs + CHAPTER_START_TOLERANCE_SECS);
+        let index = match (forward, active) {
+            (true, active) => active.map_or(0, |i| i + 1),
+            (false, None) => return,
+            (false, Some(i)) => {
+                let elapsed = position_secs - chapters[i].start_secs;
+                if elapsed > CHAPTER_RESTART_SECS || i == 0 {
+                    i
+                } else {
+                    i - 1
+                }
+            }
+        };
+        let Some(chapter) = chapters.get(index) else {
+            return;
+        };
+        chapter.start_secs - source.info.range.map_or(0.0, |range| range.start_secs)
+    };
+    handle_seek(target, callback_data, decoders, event_tx, sample_rate);
+}
+
+/// Position of `source` in its file, which differs from its position for a
+/// track of a CUE sheet.
+fn file_position_secs(source: &AudioSource) -> f64 {
+    source.info.range.map_or(0.0, |range| range.start_secs) + source.position_secs()
+}
+
+/// Wait (up to ~1s) until a freshly spawned source has buffered enough to play.
+fn wait_for_initial_buffer(source: &AudioSource) {
+    let mut attempts = 0;
+    while !source.has_enough_buffer() && attempts < 100 {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        attempts += 1;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn handle_play(
     path: PathBuf,
+    range: Option<TimeRange>,
+    start_secs: f64,
     callback_data: &AudioCallbackData,
     state: &Arc<AtomicU8>,
     decoders: &Arc<Mutex<Vec<DecoderThread>>>,
@@ -673,17 +1032,13 @@ fn handle_play(
     callback_data.crossfader.lock().reset();
 
     // Spawn decoder
-    match DecoderThread::spawn(path.clone(), sample_rate) {
+    match DecoderThread::spawn_range(path.clone(), sample_rate, range, start_secs) {
         Ok((mut source, decoder_thread)) => {
-            // Wait for initial buffering
-            let mut attempts = 0;
-            while !source.has_enough_buffer() && attempts < 100 {
-                std::thread::sleep(std::time::Duration::from_millis(10));
-                attempts += 1;
-            }
+            wait_for_initial_buffer(&source);
 
             source.set_ready();
             source.set_playing();
+            let position_secs = source.position_secs();
 
             // Set the source
             callback_data.sources.lock().set_current(source);
@@ -695,6 +1050,12 @@ fn handle_play(
             // Update state
             state.store(PlaybackState::Playing as u8, Ordering::Relaxed);
             let _ = event_tx.try_send(AudioEvent::StateChanged(PlaybackState::Playing));
+            if start_secs > 0.0 {
+                let _ = event_tx.try_send(AudioEvent::Resumed {
+                    path: path.to_string_lossy().to_string(),
+                    position_secs,
+                });
+            }
         }
         Err(e) => {
             let _ = event_tx.try_send(AudioEvent::Error {
@@ -707,20 +1068,16 @@ fn handle_play(
 
 fn handle_queue_next(
     path: PathBuf,
+    range: Option<TimeRange>,
     callback_data: &AudioCallbackData,
     decoders: &Arc<Mutex<Vec<DecoderThread>>>,
     event_tx: &Sender<AudioEvent>,
     sample_rate: u32,
 ) {
     // Spawn decoder for next track
-    match DecoderThread::spawn(path.clone(), sample_rate) {
+    match DecoderThread::spawn_range(path.clone(), sample_rate, range, 0.0) {
         Ok((mut source, decoder_thread)) => {
-            // Wait for initial buffering
-            let mut attempts = 0;
-            while !source.has_enough_buffer() && attempts < 100 {
-                std::thread::sleep(std::time::Duration::from_millis(10));
-                attempts += 1;
-            }
+            wait_for_initial_buffer(&source);
 
             source.set_ready();
 
@@ -742,6 +1099,57 @@ fn handle_queue_next(
     }
 }
 
+fn handle_seek(
+    position_secs: f64,
+    callback_data: &AudioCallbackData,
+    decoders: &Arc<Mutex<Vec<DecoderThread>>>,
+    event_tx: &Sender<AudioEvent>,
+    sample_rate: u32,
+) {
+    let Some((path, range)) = callback_data
+        .sources
+        .lock()
+        .current()
+        .map(|source| (source.info.path.clone(), source.info.range))
+    else {
+        let _ = event_tx.try_send(AudioEvent::Error {
+            message: "Cannot seek: no track loaded".to_string(),
+        });
+        return;
+    };
+
+    // Seeking restarts the decoder at the new position; the queued next
+    // track is left untouched so gapless playback still works afterwards.
+    let position_secs = position_secs.max(0.0);
+    match DecoderThread::spawn_range(path.clone(), sample_rate, range, position_secs) {
+        Ok((mut source, decoder_thread)) => {
+            wait_for_initial_buffer(&source);
+
+            source.set_ready();
+            source.set_playing();
+
+            let progress = PlaybackProgress {
+                position_secs: source.position_secs(),
+                duration_secs: Some(source.info.duration_secs),
+                buffer_level: source.buffer_level(),
+            };
+
+            callback_data.crossfader.lock().reset();
+            callback_data.sources.lock().set_current(source);
+            *callback_data.speed_frac_pos.lock() = 0.0;
+
+            decoders.lock().push(decoder_thread);
+
+            let _ = event_tx.try_send(AudioEvent::Progress(progress));
+        }
+        Err(e) => {
+            let _ = event_tx.try_send(AudioEvent::Error {
+                message: format!("Failed to seek in {}: {}", path.display(), e),
+            });
+        }
+    }
+}
+
 fn handle_skip_to_next(
     callback_data: &AudioCallbackData,
     state: &Arc<AtomicU8>,
@@ -763,3 +1171,420 @@ fn handle_skip_to_next(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::output::{NullOutput, OfflineRenderer, WavFileOutput};
+    use crate::audio::resampler::DEFAULT_OUTPUT_SAMPLE_RATE;
+    use crate::audio::wav::{quantize, WavSampleFormat};
+    use crate::test_util::{assert_samples_eq, signal, test_dir, write_track};
+    use std::path::Path;
+    use std::time::{Duration, Instant};
+
+    const RATE: u32 = DEFAULT_OUTPUT_SAMPLE_RATE;
+    const CHANNELS: usize = 2;
+
+    /// Tests that open the shared library take turns
+    static LIBRARY: Mutex<()> = parking_lot::const_mutex(());
+
+    fn offline_engine(dir: &Path) -> (AudioEngineHandle, OfflineRenderer) {
+        offline_engine_with_format(dir, WavSampleFormat::Float32)
+    }
+
+    fn offline_engine_with_format(
+        dir: &Path,
+        format: WavSampleFormat,
+    ) -> (AudioEngineHandle, OfflineRenderer) {
+        let (output, renderer) =
+            WavFileOutput::create_with_format(&dir.join("out.wav"), RATE, CHANNELS, format).unwrap();
+        let handle = create_audio_engine_with_output(Box::new(output)).unwrap();
+
+        let start = Instant::now();
+        while !renderer.is_started() {
+            assert!(start.elapsed() < Duration::from_secs(5), "output never started");
+            thread::sleep(Duration::from_millis(1));
+        }
+        (handle, renderer)
+    }
+
+    fn wait_for(handle: &AudioEngineHandle, mut predicate: impl FnMut(&AudioEvent) -> bool) {
+        let start = Instant::now();
+        loop {
+            if let Some(event) = handle.try_recv_event() {
+                if let AudioEvent::Error { message } = &event {
+                    panic!("engine error: {}", message);
+                }
+                if predicate(&event) {
+                    return;
+                }
+            } else {
+                assert!(start.elapsed() < Duration::from_secs(5), "timed out waiting for event");
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+
+    fn wait_for_state(handle: &AudioEngineHandle, state: PlaybackState) {
+        wait_for(handle, |e| matches!(e, AudioEvent::StateChanged(s) if *s == state));
+    }
+
+    #[test]
+    fn test_offline_play_renders_track_exactly() {
+        let dir = test_dir("engine-play");
+        let track = signal(9600, 0.1);
+        write_track(&dir.join("a.wav"), RATE, &track);
+
+        let (handle, renderer) = offline_engine(&dir);
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for_state(&handle, PlaybackState::Playing);
+
+        let output = renderer.render(12_000).unwrap();
+        assert_samples_eq(&output[..track.len()], &track, 1e-5);
+        assert!(output[track.len()..].iter().all(|&s| s == 0.0));
+
+        wait_for(&handle, |e| matches!(e, AudioEvent::TrackEnded { .. }));
+
+        renderer.finish().unwrap();
+        let file_len = std::fs::metadata(dir.join("out.wav")).unwrap().len();
+        assert_eq!(file_len, 44 + 12_000 * CHANNELS as u64 * 4);
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_gapless_transition() {
+        let dir = test_dir("engine-gapless");
+        let track_a = signal(7000, 0.1);
+        let track_b = signal(5000, 0.5);
+        write_track(&dir.join("a.wav"), RATE, &track_a);
+        write_track(&dir.join("b.wav"), RATE, &track_b);
+
+        let (handle, renderer) = offline_engine(&dir);
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for_state(&handle, PlaybackState::Playing);
+        handle.queue_next(dir.join("b.wav")).unwrap();
+        wait_for(&handle, |e| matches!(e, AudioEvent::NextTrackReady { .. }));
+
+        let output = renderer.render(13_000).unwrap();
+        let mut expected = track_a.clone();
+        expected.extend_from_slice(&track_b);
+        expected.resize(13_000 * CHANNELS, 0.0);
+        assert_samples_eq(&output, &expected, 1e-5);
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_gapless_ranges_of_one_file() {
+        let dir = test_dir("engine-ranges");
+        let track = signal(12_000, 0.1);
+        write_track(&dir.join("a.wav"), RATE, &track);
+
+        let split = 4800.0 / RATE as f64;
+        let (handle, renderer) = offline_engine(&dir);
+        handle
+            .play_range(dir.join("a.wav"), TimeRange { start_secs: 0.0, end_secs: Some(split) })
+            .unwrap();
+        wait_for_state(&handle, PlaybackState::Playing);
+        let progress = handle.get_progress().unwrap();
+        assert!((progress.duration_secs.unwrap() - split).abs() < 1e-9);
+        handle
+            .queue_next_range(dir.join("a.wav"), TimeRange { start_secs: split, end_secs: None })
+            .unwrap();
+        wait_for(&handle, |e| matches!(e, AudioEvent::NextTrackReady { .. }));
+
+        let output = renderer.render(13_000).unwrap();
+        let mut expected = track.clone();
+        expected.resize(13_000 * CHANNELS, 0.0);
+        assert_samples_eq(&output, &expected, 1e-5);
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_crossfade() {
+        let dir = test_dir("engine-crossfade");
+        let track_a = signal(9600, 0.1);
+        let track_b = signal(9600, 0.5);
+        write_track(&dir.join("a.wav"), RATE, &track_a);
+        write_track(&dir.join("b.wav"), RATE, &track_b);
+
+        let fade_frames = 2400;
+        let (handle, renderer) = offline_engine(&dir);
+        handle
+            .set_crossfade(true, fade_frames as f32 / RATE as f32)
+            .unwrap();
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for_state(&handle, PlaybackState::Playing);
+        handle.queue_next(dir.join("b.wav")).unwrap();
+        wait_for(&handle, |e| matches!(e, AudioEvent::NextTrackReady { .. }));
+        handle.skip_to_next().unwrap();
+        wait_for_state(&handle, PlaybackState::Crossfading);
+
+        let output = renderer.render(9600).unwrap();
+
+        let mut expected = Vec::with_capacity(output.len());
+        for frame in 0..9600 {
+            let progress = (frame as f32 / fade_frames as f32).min(1.0);
+            let angle = progress * std::f32::consts::FRAC_PI_2;
+            let (gain_a, gain_b) = if frame < fade_frames {
+                (angle.cos(), angle.sin())
+            } else {
+                (0.0, 1.0)
+            };
+            for ch in 0..CHANNELS {
+                let idx = frame * CHANNELS + ch;
+                expected.push(track_a[idx] * gain_a + track_b[idx] * gain_b);
+            }
+        }
+        assert_samples_eq(&output, &expected, 1e-5);
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_seek() {
+        let dir = test_dir("engine-seek");
+        let track = signal(24_000, 0.1);
+        write_track(&dir.join("a.wav"), RATE, &track);
+
+        let (handle, renderer) = offline_engine(&dir);
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for_state(&handle, PlaybackState::Playing);
+        handle.seek(0.25).unwrap();
+        wait_for(&handle, |e| matches!(e, AudioEvent::Progress(_)));
+
+        let start = 12_000 * CHANNELS;
+        let output = renderer.render(2000).unwrap();
+        assert_samples_eq(&output, &track[start..start + 2000 * CHANNELS], 1e-5);
+
+        let progress = handle.get_progress().unwrap();
+        assert!((progress.position_secs - 14_000.0 / RATE as f64).abs() < 1e-6);
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_lyrics_line_events() {
+        let dir = test_dir("engine-lyrics");
+        write_track(&dir.join("a.wav"), RATE, &signal(RATE as usize, 0.1));
+        std::fs::write(dir.join("a.lrc"), "[00:00.00]One\n[00:00.50]Two\n").unwrap();
+
+        let lines = |handle: &AudioEngineHandle| {
+            let mut line = None;
+            wait_for(handle, |e| match e {
+                AudioEvent::LyricsLineChanged { line_index, .. } => {
+                    line = Some(*line_index);
+                    true
+                }
+                _ => false,
+            });
+            line.unwrap()
+        };
+
+        let (handle, renderer) = offline_engine(&dir);
+        handle.play(dir.join("a.wav")).unwrap();
+        assert_eq!(lines(&handle), Some(0));
+        renderer.render(RATE as usize * 3 / 4).unwrap();
+        assert_eq!(lines(&handle), Some(1));
+        handle.seek(0.25).unwrap();
+        assert_eq!(lines(&handle), Some(0));
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_chapter_skipping() {
+        use lofty::config::WriteOptions;
+        use lofty::tag::TagExt;
+
+        let dir = test_dir("engine-chapters");
+        let track = signal(RATE as usize, 0.1);
+        write_track(&dir.join("a.wav"), RATE, &track);
+        crate::library::chapters::test_chapter_tag(&[(0, 500, "One"), (500, 1000, "Two")])
+            .save_to_path(dir.join("a.wav"), WriteOptions::default())
+            .unwrap();
+
+        let chapters = |handle: &AudioEngineHandle| {
+            let mut chapter = None;
+            wait_for(handle, |e| match e {
+                AudioEvent::ChapterChanged { chapter_index, .. } => {
+                    chapter = Some(*chapter_index);
+                    true
+                }
+                _ => false,
+            });
+            chapter.unwrap()
+        };
+
+        let (handle, renderer) = offline_engine(&dir);
+        handle.play(dir.join("a.wav")).unwrap();
+        assert_eq!(chapters(&handle), Some(0));
+        handle.next_chapter().unwrap();
+        assert_eq!(chapters(&handle), Some(1));
+        let start = RATE as usize / 2 * CHANNELS;
+        let output = renderer.render(1000).unwrap();
+        assert_samples_eq(&output, &track[start..start + 1000 * CHANNELS], 1e-5);
+
+        // Early in a chapter, going back goes to the previous one
+        handle.previous_chapter().unwrap();
+        assert_eq!(chapters(&handle), Some(0));
+        let output = renderer.render(1000).unwrap();
+        assert_samples_eq(&output, &track[..1000 * CHANNELS], 1e-5);
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_resume_position() {
+        let _library = LIBRARY.lock();
+        let dir = test_dir("engine-resume");
+        store::open_shared(&dir.join("library.db")).unwrap();
+        let track = signal(RATE as usize, 0.1);
+        write_track(&dir.join("a.wav"), RATE, &track);
+        let path = dir.join("a.wav").to_string_lossy().to_string();
+        let saved = |expected: Option<f64>| {
+            let start = Instant::now();
+            loop {
+                let position = store::with_shared(|store| store.resume_position(&path)).unwrap();
+                if position == expected {
+                    return;
+                }
+                assert!(start.elapsed() < Duration::from_secs(5), "saved {:?}", position);
+                thread::sleep(Duration::from_millis(1));
+            }
+        };
+
+        let (handle, renderer) = offline_engine(&dir);
+        handle.set_resume(true, 0.5).unwrap();
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for_state(&handle, PlaybackState::Playing);
+        renderer.render(RATE as usize / 2).unwrap();
+        handle.pause().unwrap();
+        saved(Some(0.5));
+
+        // Stopping where playback resumed keeps the position. The second
+        // stop is handled once the first one's save would have been queued.
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for(&handle, |e| {
+            matches!(e, AudioEvent::Resumed { position_secs, .. } if *position_secs == 0.5)
+        });
+        handle.stop().unwrap();
+        handle.stop().unwrap();
+        wait_for_state(&handle, PlaybackState::Stopped);
+        wait_for_state(&handle, PlaybackState::Stopped);
+        store::flush_shared_writes();
+        let position = store::with_shared(|store| store.resume_position(&path)).unwrap();
+        assert_eq!(position, Some(0.5));
+
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for(&handle, |e| {
+            matches!(e, AudioEvent::Resumed { position_secs, .. } if *position_secs == 0.5)
+        });
+        let start = RATE as usize / 2 * CHANNELS;
+        let output = renderer.render(1000).unwrap();
+        assert_samples_eq(&output, &track[start..start + 1000 * CHANNELS], 1e-5);
+
+        // Starting over forgets the position
+        handle.seek(0.0).unwrap();
+        wait_for(&handle, |e| matches!(e, AudioEvent::Progress(_)));
+        handle.stop().unwrap();
+        saved(None);
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_plays_counted_at_track_end() {
+        use crate::api::scanner::ScanEventType;
+        use crate::library::store::tests::{event, song};
+
+        let _library = LIBRARY.lock();
+        let dir = test_dir("engine-plays");
+        store::open_shared(&dir.join("library.db")).unwrap();
+        let paths = ["a.wav", "b.wav"].map(|name| dir.join(name));
+        for (path, offset) in paths.iter().zip([0.1, 0.5]) {
+            write_track(path, RATE, &signal(9600, offset));
+        }
+        let songs = paths
+            .iter()
+            .map(|path| song(&path.to_string_lossy(), None, "Artist", "Album", "Pop"))
+            .collect();
+        let root = dir.to_string_lossy().to_string();
+        store::with_shared(|store| store.apply_scan(&root, &event(ScanEventType::Batch, songs)))
+            .unwrap();
+
+        // Skipping fades the first track out before its end, so only the
+        // second one, played to its end, counts
+        let (handle, renderer) = offline_engine(&dir);
+        handle.set_crossfade(true, 0.05).unwrap();
+        handle.play(paths[0].clone()).unwrap();
+        wait_for_state(&handle, PlaybackState::Playing);
+        handle.queue_next(paths[1].clone()).unwrap();
+        wait_for(&handle, |e| matches!(e, AudioEvent::NextTrackReady { .. }));
+        handle.skip_to_next().unwrap();
+        wait_for_state(&handle, PlaybackState::Crossfading);
+        renderer.render(12_000).unwrap();
+        let ended = paths[1].to_string_lossy().to_string();
+        wait_for(&handle, |e| matches!(e, AudioEvent::TrackEnded { path } if *path == ended));
+
+        store::flush_shared_writes();
+        let play_count = |path: &Path| {
+            let path = path.to_string_lossy();
+            store::with_shared(|store| store.song_by_path(&path)).unwrap().unwrap().play_count
+        };
+        assert_eq!(play_count(&paths[0]), 0);
+        assert_eq!(play_count(&paths[1]), 1);
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_offline_integer_output_is_dithered() {
+        let dir = test_dir("engine-dither");
+        let track = signal(4800, 0.1);
+        write_track(&dir.join("a.wav"), RATE, &track);
+
+        let (handle, renderer) = offline_engine_with_format(&dir, WavSampleFormat::Int16);
+        assert_eq!(handle.sample_format(), OutputSampleFormat::I16);
+        assert_eq!(handle.get_dither(), DitherMode::Triangular);
+        handle.set_dither(DitherMode::NoiseShaped).unwrap();
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for_state(&handle, PlaybackState::Playing);
+        assert_eq!(handle.get_dither(), DitherMode::NoiseShaped);
+
+        // Noise-shaped samples arrive on the 16-bit grid, close to the source
+        let lsb = 1.0 / 32768.0;
+        let output = renderer.render(4800).unwrap();
+        for (i, (&out, &expected)) in output.iter().zip(&track).enumerate() {
+            assert_eq!(quantize(out, 16) as f32 * lsb, out, "sample {} off the grid", i);
+            assert!((out - expected).abs() <= 8.0 * lsb, "sample {}: {} vs {}", i, out, expected);
+        }
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_null_output_plays_in_real_time() {
+        let dir = test_dir("engine-null");
+        write_track(&dir.join("a.wav"), RATE, &signal(2400, 0.1));
+
+        let handle = create_audio_engine_with_output(Box::new(NullOutput::default())).unwrap();
+        handle.play(dir.join("a.wav")).unwrap();
+        wait_for(&handle, |e| matches!(e, AudioEvent::TrackEnded { .. }));
+
+        handle.shutdown().unwrap();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,172 @@
+//! Parametric equalizer built from peaking biquad filters.
+//!
+//! Coefficients follow the RBJ Audio EQ Cookbook. Each band keeps separate
+//! filter state per channel so interleaved buffers can be processed in place.
+
+use std::f64::consts::PI;
+
+/// One peaking band of the equalizer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqBand {
+    /// Center frequency in Hz
+    pub frequency_hz: f32,
+    /// Boost (positive) or cut (negative) in dB
+    pub gain_db: f32,
+    /// Bandwidth as quality factor
+    pub q: f32,
+}
+
+/// Biquad coefficients, normalized so that `a0 == 1`.
+#[derive(Debug, Clone, Copy)]
+struct Coefficients {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+}
+
+impl Coefficients {
+    fn peaking(sample_rate: u32, band: &EqBand) -> Self {
+        // Keep the center frequency below Nyquist so the filter stays stable
+        let nyquist = sample_rate as f64 / 2.0;
+        let frequency = (band.frequency_hz as f64).clamp(1.0, nyquist * 0.99);
+        let q = (band.q as f64).max(0.01);
+
+        let a = 10f64.powf(band.gain_db as f64 / 40.0);
+        let w0 = 2.0 * PI * frequency / sample_rate as f64;
+        let alpha = w0.sin() / (2.0 * q);
+        let cos_w0 = w0.cos();
+
+        let a0 = 1.0 + alpha / a;
+        Self {
+            b0: (1.0 + alpha * a) / a0,
+            b1: (-2.0 * cos_w0) / a0,
+            b2: (1.0 - alpha * a) / a0,
+            a1: (-2.0 * cos_w0) / a0,
+            a2: (1.0 - alpha / a) / a0,
+        }
+    }
+}
+
+/// Transposed direct form II state for one channel.
+#[derive(Debug, Clone, Copy, Default)]
+struct BiquadState {
+    z1: f64,
+    z2: f64,
+}
+
+impl BiquadState {
+    #[inline]
+    fn process(&mut self, c: &Coefficients, input: f64) -> f64 {
+        let output = c.b0 * input + self.z1;
+        self.z1 = c.b1 * input - c.a1 * output + self.z2;
+        self.z2 = c.b2 * input - c.a2 * output;
+        output
+    }
+}
+
+/// Multi-band equalizer for interleaved audio.
+#[derive(Debug, Clone)]
+pub struct Equalizer {
+    channels: usize,
+    preamp: f32,
+    coefficients: Vec<Coefficients>,
+    /// Filter state, indexed by `band * channels + channel`
+    states: Vec<BiquadState>,
+}
+
+impl Equalizer {
+    /// Create an equalizer for the given bands.
+    ///
+    /// Bands with a gain of 0 dB are flat and are skipped entirely.
+    pub fn new(sample_rate: u32, channels: usize, preamp_db: f32, bands: &[EqBand]) -> Self {
+        let coefficients: Vec<Coefficients> = bands
+            .iter()
+            .filter(|band| band.gain_db.abs() > f32::EPSILON)
+            .map(|band| Coefficients::peaking(sample_rate, band))
+            .collect();
+        let states = vec![BiquadState::default(); coefficients.len() * channels];
+
+        Self {
+            channels,
+            preamp: 10f32.powf(preamp_db / 20.0),
+            coefficients,
+            states,
+        }
+    }
+
+    /// Whether processing would leave the signal untouched.
+    pub fn is_flat(&self) -> bool {
+        self.coefficients.is_empty() && (self.preamp - 1.0).abs() < f32::EPSILON
+    }
+
+    /// Filter an interleaved buffer in place.
+    pub fn process(&mut self, buffer: &mut [f32]) {
+        if self.is_flat() {
+            return;
+        }
+
+        for frame in buffer.chunks_exact_mut(self.channels) {
+            for (ch, sample) in frame.iter_mut().enumerate() {
+                let mut value = (*sample * self.preamp) as f64;
+                for (band, c) in self.coefficients.iter().enumerate() {
+                    value = self.states[band * self.channels + ch].process(c, value);
+                }
+                *sample = value as f32;
+            }
+        }
+    }
+
+    /// Clear the filter history.
+    pub fn reset(&mut self) {
+        self.states.fill(BiquadState::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 48000;
+
+    /// Peak amplitude of a steady-state sine after filtering.
+    fn sine_gain(eq: &mut Equalizer, frequency: f32) -> f32 {
+        let frames = RATE as usize;
+        let mut buffer: Vec<f32> = (0..frames)
+            .map(|i| (2.0 * std::f32::consts::PI * frequency * i as f32 / RATE as f32).sin() * 0.25)
+            .collect();
+        eq.process(&mut buffer);
+        // Skip the filter's settling time
+        buffer[frames / 2..].iter().fold(0.0f32, |peak, s| peak.max(s.abs())) / 0.25
+    }
+
+    #[test]
+    fn test_flat_equalizer_is_transparent() {
+        let mut eq = Equalizer::new(RATE, 2, 0.0, &[EqBand { frequency_hz: 1000.0, gain_db: 0.0, q: 1.0 }]);
+        assert!(eq.is_flat());
+
+        let mut buffer = vec![0.1, -0.2, 0.3, -0.4];
+        eq.process(&mut buffer);
+        assert_eq!(buffer, vec![0.1, -0.2, 0.3, -0.4]);
+    }
+
+    #[test]
+    fn test_peaking_band_boosts_center_frequency() {
+        let band = EqBand { frequency_hz: 1000.0, gain_db: 6.0, q: 1.0 };
+
+        let center = sine_gain(&mut Equalizer::new(RATE, 1, 0.0, &[band]), 1000.0);
+        assert!((center - 10f32.powf(6.0 / 20.0)).abs() < 0.02, "center gain {}", center);
+
+        let far = sine_gain(&mut Equalizer::new(RATE, 1, 0.0, &[band]), 50.0);
+        assert!((far - 1.0).abs() < 0.02, "off-band gain {}", far);
+    }
+
+    #[test]
+    fn test_preamp() {
+        let mut eq = Equalizer::new(RATE, 1, -6.0, &[]);
+        let mut buffer = vec![0.5];
+        eq.process(&mut buffer);
+        assert!((buffer[0] - 0.5 * 10f32.powf(-6.0 / 20.0)).abs() < 1e-6);
+    }
+}
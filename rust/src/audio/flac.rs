@@ -0,0 +1,199 @@
+//! Streaming FLAC writer for rendered engine output.
+//!
+//! Frames are encoded with flacenc as soon as a block of samples is
+//! available, so memory use does not grow with the length of the output.
+//! The STREAMINFO header is patched in [`FlacWriter::finalize`].
+
+use crate::audio::wav::quantize;
+
+use flacenc::bitsink::MemSink;
+use flacenc::component::{BitRepr, Stream, StreamInfo};
+use flacenc::config;
+use flacenc::error::{Verified, Verify};
+use flacenc::source::{Fill, FrameBuf};
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Frames per FLAC block (the flacenc and libFLAC default)
+const BLOCK_SIZE: usize = 4096;
+
+/// Bytes of PADDING after STREAMINFO, so tags can be added in place later
+/// (the libFLAC default)
+const PADDING_SIZE: usize = 8192;
+
+fn encode_error(e: impl std::fmt::Display) -> io::Error {
+    io::Error::other(format!("FLAC encoding failed: {}", e))
+}
+
+/// Streaming FLAC writer.
+pub struct FlacWriter {
+    writer: BufWriter<File>,
+    channels: usize,
+    bits_per_sample: u32,
+    config: Verified<config::Encoder>,
+    stream_info: StreamInfo,
+    frame_buf: FrameBuf,
+    /// Interleaved samples waiting for a full block
+    pending: Vec<i32>,
+    frames_encoded: usize,
+    sink: MemSink<u8>,
+}
+
+impl FlacWriter {
+    /// Create a new FLAC file, truncating any existing file at `path`.
+    ///
+    /// `bits_per_sample` must be 16 or 24.
+    pub fn create(
+        path: &Path,
+        sample_rate: u32,
+        channels: usize,
+        bits_per_sample: u32,
+    ) -> io::Result<Self> {
+        if bits_per_sample != 16 && bits_per_sample != 24 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Unsupported FLAC bit depth: {}", bits_per_sample),
+            ));
+        }
+
+        let config = config::Encoder::default()
+            .into_verified()
+            .map_err(|(_, e)| encode_error(e))?;
+        let stream_info = StreamInfo::new(sample_rate as usize, channels, bits_per_sample as usize)
+            .map_err(encode_error)?;
+        let frame_buf = FrameBuf::with_size(channels, BLOCK_SIZE).map_err(encode_error)?;
+
+        let mut writer = Self {
+            writer: BufWriter::new(File::create(path)?),
+            channels,
+            bits_per_sample,
+            config,
+            stream_info,
+            frame_buf,
+            pending: Vec::with_capacity(BLOCK_SIZE * channels),
+            frames_encoded: 0,
+            sink: MemSink::new(),
+        };
+        writer.write_header()?;
+        Ok(writer)
+    }
+
+    /// Write the `fLaC` marker, the STREAMINFO block and a PADDING block
+    /// at the current position.
+    fn write_header(&mut self) -> io::Result<()> {
+        let header = Stream::with_stream_info(self.stream_info.clone());
+        self.sink.clear();
+        header.write(&mut self.sink).map_err(encode_error)?;
+
+        let mut bytes = self.sink.as_slice().to_vec();
+        // Clear the "last metadata block" flag of STREAMINFO (after `fLaC`)
+        bytes[4] &= 0x7f;
+        // Last block, type 1 (PADDING), 24-bit length
+        bytes.push(0x81);
+        bytes.extend_from_slice(&(PADDING_SIZE as u32).to_be_bytes()[1..]);
+        bytes.resize(bytes.len() + PADDING_SIZE, 0);
+        self.writer.write_all(&bytes)
+    }
+
+    /// Number of interleaved channels in the file.
+    #[inline]
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Append interleaved samples.
+    pub fn write_samples(&mut self, samples: &[f32]) -> io::Result<()> {
+        let block_samples = BLOCK_SIZE * self.channels;
+        for &sample in samples {
+            self.pending.push(quantize(sample, self.bits_per_sample));
+            if self.pending.len() == block_samples {
+                self.encode_pending()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Encode everything in `pending` as one frame.
+    fn encode_pending(&mut self) -> io::Result<()> {
+        let frames = self.pending.len() / self.channels;
+        if frames == 0 {
+            return Ok(());
+        }
+
+        // Only the last block of a stream may be short
+        if frames != BLOCK_SIZE {
+            self.frame_buf = FrameBuf::with_size(self.channels, frames).map_err(encode_error)?;
+        }
+        self.frame_buf
+            .fill_interleaved(&self.pending)
+            .map_err(encode_error)?;
+
+        let frame = flacenc::encode_fixed_size_frame(
+            &self.config,
+            &self.frame_buf,
+            self.frames_encoded,
+            &self.stream_info,
+        )
+        .map_err(|e| encode_error(format!("{:?}", e)))?;
+        self.stream_info.update_frame_info(&frame);
+
+        self.sink.clear();
+        frame.write(&mut self.sink).map_err(encode_error)?;
+        self.writer.write_all(self.sink.as_slice())?;
+
+        self.frames_encoded += 1;
+        self.pending.clear();
+        Ok(())
+    }
+
+    /// Encode the last partial block, patch the header and flush to disk.
+    pub fn finalize(mut self) -> io::Result<()> {
+        self.encode_pending()?;
+
+        // A short last block must not lower the advertised block size
+        if self.frames_encoded != 1 {
+            self.stream_info
+                .set_block_sizes(BLOCK_SIZE, BLOCK_SIZE)
+                .map_err(encode_error)?;
+        }
+        if self.frames_encoded == 0 {
+            self.stream_info.set_frame_sizes(0, 0).map_err(encode_error)?;
+        }
+
+        self.writer.flush()?;
+        self.writer.seek(SeekFrom::Start(0))?;
+        self.write_header()?;
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::decoder::probe_file;
+    use crate::test_util::test_dir;
+
+    #[test]
+    fn test_flac_round_trip() {
+        let dir = test_dir("flac-writer");
+        let path = dir.join("round_trip.flac");
+
+        // More than one block, with a short last block
+        let frames = BLOCK_SIZE * 2 + 100;
+        let samples: Vec<f32> = (0..frames * 2)
+            .map(|i| ((i / 2) as f32 * 0.01).sin() * 0.5)
+            .collect();
+
+        let mut writer = FlacWriter::create(&path, 48000, 2, 16).unwrap();
+        writer.write_samples(&samples).unwrap();
+        writer.finalize().unwrap();
+
+        let probe = probe_file(&path).unwrap();
+        assert_eq!(probe.source_info.original_sample_rate, 48000);
+        assert_eq!(probe.source_info.channels, 2);
+        assert!((probe.source_info.duration_secs - frames as f64 / 48000.0).abs() < 1e-9);
+    }
+}
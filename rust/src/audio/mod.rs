@@ -8,17 +8,37 @@
 //! ## Components
 //!
 //! - `engine`: Core audio engine managing the output stream and mixing
+//! - `output`: Output backends (cpal device, null sink, offline WAV file)
 //! - `decoder`: Background thread decoder using symphonia
 //! - `resampler`: Sample rate conversion using rubato
 //! - `crossfader`: Equal-power crossfade implementation
+//! - `equalizer`: Parametric equalizer (peaking biquads)
+//! - `dither`: TPDF dither for integer output
 //! - `source`: Audio source abstraction for gapless playback
+//! - `render`: Offline rendering of a queue to WAV/FLAC
+//! - `wav`: WAV reader/writer for rendered output
+//! - `flac`: Streaming FLAC writer for rendered output
+//! - `opus`: Streaming Ogg/Opus writer (with the `opus` feature)
+//! - `transcode`: File conversion to WAV/FLAC/Ogg-Opus with batch jobs
 
 pub mod commands;
 pub mod crossfader;
 pub mod decoder;
+pub mod dither;
 pub mod engine;
+pub mod equalizer;
+pub mod flac;
+#[cfg(feature = "opus")]
+pub mod opus;
+pub mod output;
+pub mod render;
 pub mod resampler;
 pub mod source;
+pub mod transcode;
+pub mod wav;
 
 pub use commands::{AudioCommand, PlaybackState};
-pub use engine::{create_audio_engine, AudioEngineHandle};
+pub use engine::{create_audio_engine, create_audio_engine_with_output, AudioEngineHandle};
+pub use output::AudioOutput;
+pub use render::{render_to_file, RenderFormat, RenderProgress, RenderSettings};
+pub use transcode::{transcode_batch, transcode_file, TranscodeFormat, TranscodeJob, TranscodeSettings};
@@ -0,0 +1,216 @@
+//! Streaming Ogg/Opus writer (RFC 7845).
+//!
+//! Opus always runs at 48 kHz; callers resample first. Audio is encoded in
+//! 20 ms frames. The encoder's lookahead is written to the header as
+//! pre-skip and the final granule position trims the padding of the last
+//! frame, so decoders reproduce the exact input length.
+
+use audiopus::coder::Encoder;
+use audiopus::{Application, Bitrate, Channels, SampleRate};
+use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Opus sample rate
+pub const OPUS_SAMPLE_RATE: u32 = 48000;
+
+/// Frames per Opus packet (20 ms)
+const FRAME_SIZE: usize = 960;
+
+/// Largest packet libopus produces
+const MAX_PACKET_SIZE: usize = 4000;
+
+fn encode_error(e: impl std::fmt::Display) -> io::Error {
+    io::Error::other(format!("Opus encoding failed: {}", e))
+}
+
+/// Streaming Ogg/Opus writer.
+pub struct OggOpusWriter {
+    writer: PacketWriter<'static, BufWriter<File>>,
+    encoder: Encoder,
+    serial: u32,
+    channels: usize,
+    pre_skip: u64,
+    /// Interleaved samples waiting for a full frame
+    pending: Vec<f32>,
+    /// Frames received from the caller
+    frames_in: u64,
+    /// Frames passed to the encoder, including padding
+    frames_encoded: u64,
+    packet: Vec<u8>,
+}
+
+impl OggOpusWriter {
+    /// Create a new Ogg/Opus file, truncating any existing file at `path`.
+    ///
+    /// `channels` must be 1 or 2 and `bitrate` is in bits per second.
+    /// `input_sample_rate` is only informational (stored in the header).
+    pub fn create(
+        path: &Path,
+        channels: usize,
+        bitrate: u32,
+        input_sample_rate: u32,
+    ) -> io::Result<Self> {
+        let opus_channels = match channels {
+            1 => Channels::Mono,
+            2 => Channels::Stereo,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Ogg/Opus supports mono and stereo only, not {} channels", channels),
+                ))
+            }
+        };
+
+        let mut encoder = Encoder::new(SampleRate::Hz48000, opus_channels, Application::Audio)
+            .map_err(encode_error)?;
+        encoder
+            .set_bitrate(Bitrate::BitsPerSecond(bitrate as i32))
+            .map_err(encode_error)?;
+        let pre_skip = encoder.lookahead().map_err(encode_error)? as u64;
+
+        // Any value works as long as it is unique within the file
+        let serial = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0)
+            ^ std::process::id();
+
+        let mut writer = Self {
+            writer: PacketWriter::new(BufWriter::new(File::create(path)?)),
+            encoder,
+            serial,
+            channels,
+            pre_skip,
+            pending: Vec::with_capacity(FRAME_SIZE * channels),
+            frames_in: 0,
+            frames_encoded: 0,
+            packet: vec![0; MAX_PACKET_SIZE],
+        };
+        writer.write_headers(input_sample_rate)?;
+        Ok(writer)
+    }
+
+    /// Write the identification and comment headers, each on its own page.
+    fn write_headers(&mut self, input_sample_rate: u32) -> io::Result<()> {
+        let mut head = Vec::with_capacity(19);
+        head.extend_from_slice(b"OpusHead");
+        head.push(1); // version
+        head.push(self.channels as u8);
+        head.extend_from_slice(&(self.pre_skip as u16).to_le_bytes());
+        head.extend_from_slice(&input_sample_rate.to_le_bytes());
+        head.extend_from_slice(&0i16.to_le_bytes()); // output gain
+        head.push(0); // channel mapping family: mono/stereo
+        self.writer
+            .write_packet(head, self.serial, PacketWriteEndInfo::EndPage, 0)?;
+
+        let vendor = b"flick_player";
+        let mut tags = Vec::with_capacity(16 + vendor.len());
+        tags.extend_from_slice(b"OpusTags");
+        tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+        tags.extend_from_slice(vendor);
+        tags.extend_from_slice(&0u32.to_le_bytes()); // no user comments
+        self.writer
+            .write_packet(tags, self.serial, PacketWriteEndInfo::EndPage, 0)
+    }
+
+    /// Number of interleaved channels in the file.
+    #[inline]
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Append interleaved samples at 48 kHz.
+    pub fn write_samples(&mut self, samples: &[f32]) -> io::Result<()> {
+        self.frames_in += (samples.len() / self.channels) as u64;
+
+        let frame_samples = FRAME_SIZE * self.channels;
+        for chunk in samples.chunks(frame_samples) {
+            let take = (frame_samples - self.pending.len()).min(chunk.len());
+            self.pending.extend_from_slice(&chunk[..take]);
+            if self.pending.len() == frame_samples {
+                self.encode_pending(PacketWriteEndInfo::NormalPacket, None)?;
+            }
+            self.pending.extend_from_slice(&chunk[take..]);
+        }
+        Ok(())
+    }
+
+    /// Encode `pending` (padded with silence to a full frame) as one packet.
+    fn encode_pending(&mut self, end_info: PacketWriteEndInfo, granule: Option<u64>) -> io::Result<()> {
+        self.pending.resize(FRAME_SIZE * self.channels, 0.0);
+        let len = self
+            .encoder
+            .encode_float(&self.pending, &mut self.packet)
+            .map_err(encode_error)?;
+        self.pending.clear();
+        self.frames_encoded += FRAME_SIZE as u64;
+
+        let granule = granule.unwrap_or(self.frames_encoded);
+        self.writer
+            .write_packet(self.packet[..len].to_vec(), self.serial, end_info, granule)
+    }
+
+    /// Flush the encoder and end the stream.
+    pub fn finalize(mut self) -> io::Result<()> {
+        // Feed silence until the encoder has emitted the lookahead as well;
+        // the final granule position cuts the padding off again
+        let end = self.frames_in + self.pre_skip;
+        loop {
+            let last = self.frames_encoded + FRAME_SIZE as u64 >= end;
+            if last {
+                self.encode_pending(PacketWriteEndInfo::EndStream, Some(end))?;
+                break;
+            }
+            self.encode_pending(PacketWriteEndInfo::NormalPacket, None)?;
+        }
+
+        self.writer.inner_mut().flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+    use ogg::reading::PacketReader;
+
+    #[test]
+    fn test_opus_stream_layout() {
+        let dir = test_dir("opus-writer");
+        let path = dir.join("layout.opus");
+
+        let frames = FRAME_SIZE * 10 + 123;
+        let samples: Vec<f32> = (0..frames * 2)
+            .map(|i| ((i / 2) as f32 * 0.05).sin() * 0.5)
+            .collect();
+
+        let mut writer = OggOpusWriter::create(&path, 2, 128_000, 44100).unwrap();
+        let pre_skip = writer.pre_skip;
+        writer.write_samples(&samples).unwrap();
+        writer.finalize().unwrap();
+
+        let mut reader = PacketReader::new(File::open(&path).unwrap());
+        let head = reader.read_packet_expected().unwrap();
+        assert_eq!(&head.data[..8], b"OpusHead");
+        assert_eq!(head.data[9], 2);
+        assert_eq!(u16::from_le_bytes([head.data[10], head.data[11]]) as u64, pre_skip);
+        assert_eq!(u32::from_le_bytes(head.data[12..16].try_into().unwrap()), 44100);
+        let tags = reader.read_packet_expected().unwrap();
+        assert_eq!(&tags.data[..8], b"OpusTags");
+
+        let mut packets = 0;
+        let mut last = None;
+        while let Some(packet) = reader.read_packet().unwrap() {
+            packets += 1;
+            last = Some(packet);
+        }
+        let last = last.unwrap();
+        assert!(last.last_in_stream());
+        // The final granule position marks the exact end of the input
+        assert_eq!(last.absgp_page(), frames as u64 + pre_skip);
+        assert_eq!(packets as u64, (frames as u64 + pre_skip).div_ceil(FRAME_SIZE as u64));
+    }
+}
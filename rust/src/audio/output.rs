@@ -0,0 +1,538 @@
+//! Pluggable audio output backends.
+//!
+//! The engine renders interleaved `f32` buffers through a [`RenderCallback`]
+//! and does not care who pulls them. Backends report the
+//! [`OutputSampleFormat`] they hand to the device so the engine can dither
+//! to its word length first. Backends:
+//!
+//! - [`CpalOutput`]: the platform device via cpal (ALSA/PulseAudio on Linux,
+//!   CoreAudio on Apple platforms, WASAPI on Windows, AAudio on Android)
+//! - [`NullOutput`]: discards audio, pulling the callback on a timer as a
+//!   sound card would; useful on machines without audio hardware
+//! - [`WavFileOutput`]: renders on demand into a WAV file, driven by an
+//!   [`OfflineRenderer`]; used for headless tests with exact output
+
+use crate::audio::resampler::DEFAULT_OUTPUT_SAMPLE_RATE;
+use crate::audio::wav::{quantize, WavSampleFormat, WavWriter};
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{SampleFormat, SampleRate, SizedSample, StreamConfig, I24};
+use parking_lot::Mutex;
+use std::any::Any;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Callback that fills an interleaved output buffer.
+pub type RenderCallback = Box<dyn FnMut(&mut [f32]) + Send + 'static>;
+
+/// Sample encoding an output hands to its device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputSampleFormat {
+    I16,
+    /// 24-bit samples in a 32-bit word
+    I24,
+    I32,
+    F32,
+}
+
+impl OutputSampleFormat {
+    /// Word length the mix is reduced to, or `None` for float output.
+    ///
+    /// 32-bit integer output carries 24 significant bits: that is all the
+    /// precision of the `f32` mix, and what 24-bit DACs take from a 32-bit
+    /// container.
+    pub fn bits(self) -> Option<u32> {
+        match self {
+            Self::I16 => Some(16),
+            Self::I24 | Self::I32 => Some(24),
+            Self::F32 => None,
+        }
+    }
+
+    fn from_cpal(format: SampleFormat) -> Option<Self> {
+        match format {
+            SampleFormat::I16 => Some(Self::I16),
+            SampleFormat::I24 => Some(Self::I24),
+            SampleFormat::I32 => Some(Self::I32),
+            SampleFormat::F32 => Some(Self::F32),
+            _ => None,
+        }
+    }
+
+    fn to_cpal(self) -> SampleFormat {
+        match self {
+            Self::I16 => SampleFormat::I16,
+            Self::I24 => SampleFormat::I24,
+            Self::I32 => SampleFormat::I32,
+            Self::F32 => SampleFormat::F32,
+        }
+    }
+}
+
+/// Pick the sample format to open a device with.
+///
+/// The device's preferred format wins if we can produce it and it is
+/// available at the engine rate, so the host does not have to convert.
+/// Otherwise the best supported one is used: float, then the widest integer.
+/// An empty `supported` list means the device could not enumerate its
+/// configurations, in which case the preferred format is trusted.
+fn negotiate_sample_format(
+    preferred: SampleFormat,
+    supported: &[SampleFormat],
+) -> Option<OutputSampleFormat> {
+    if let Some(format) = OutputSampleFormat::from_cpal(preferred) {
+        if supported.is_empty() || supported.contains(&preferred) {
+            return Some(format);
+        }
+    }
+
+    [
+        OutputSampleFormat::F32,
+        OutputSampleFormat::I32,
+        OutputSampleFormat::I24,
+        OutputSampleFormat::I16,
+    ]
+    .into_iter()
+    .find(|format| supported.contains(&format.to_cpal()))
+}
+
+/// A running output. Dropping it stops the output.
+pub struct OutputStream {
+    _inner: Box<dyn Any>,
+}
+
+impl OutputStream {
+    fn new<T: Any>(inner: T) -> Self {
+        Self {
+            _inner: Box::new(inner),
+        }
+    }
+}
+
+/// An audio output the engine can drive.
+pub trait AudioOutput: Send {
+    /// Output sample rate in Hz.
+    fn sample_rate(&self) -> u32;
+
+    /// Number of interleaved output channels.
+    fn channels(&self) -> usize;
+
+    /// Sample format handed to the device. Integer formats get samples
+    /// already reduced to [`OutputSampleFormat::bits`] by the engine.
+    fn sample_format(&self) -> OutputSampleFormat {
+        OutputSampleFormat::F32
+    }
+
+    /// Start pulling audio from `render`.
+    ///
+    /// Called on the engine thread, which keeps the returned stream alive for
+    /// as long as audio should play.
+    fn start(self: Box<Self>, render: RenderCallback) -> Result<OutputStream, String>;
+}
+
+// ============================================================================
+// CPAL
+// ============================================================================
+
+/// Interleaved samples of the scratch mix buffer allocated up front for
+/// integer outputs; larger device periods are rendered in chunks of it.
+const CONVERT_BUFFER_SAMPLES: usize = 8192;
+
+/// A platform output device with the stream configuration the engine will use.
+pub struct CpalOutput {
+    device: cpal::Device,
+    config: StreamConfig,
+    sample_format: OutputSampleFormat,
+}
+
+impl CpalOutput {
+    /// Open the default output device of the default host.
+    pub fn open_default() -> Result<Self, String> {
+        let host = cpal::default_host();
+        let device = host
+            .default_output_device()
+            .ok_or("No default output device")?;
+
+        // Get default config
+        let default_config = device
+            .default_output_config()
+            .map_err(|e| format!("Failed to get default config: {}", e))?;
+
+        let channels = default_config.channels();
+
+        // All sources are resampled to the engine rate, so always ask for it
+        let config = StreamConfig {
+            channels,
+            sample_rate: SampleRate(DEFAULT_OUTPUT_SAMPLE_RATE),
+            buffer_size: cpal::BufferSize::Default,
+        };
+
+        let supported: Vec<SampleFormat> = device
+            .supported_output_configs()
+            .map(|configs| {
+                configs
+                    .filter(|c| {
+                        c.channels() == channels
+                            && c.min_sample_rate() <= config.sample_rate
+                            && c.max_sample_rate() >= config.sample_rate
+                    })
+                    .map(|c| c.sample_format())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let sample_format = negotiate_sample_format(default_config.sample_format(), &supported)
+            .ok_or_else(|| {
+                format!(
+                    "Unsupported output sample format: {}",
+                    default_config.sample_format()
+                )
+            })?;
+
+        Ok(Self {
+            device,
+            config,
+            sample_format,
+        })
+    }
+
+    /// Build a stream for an integer sample type, converting the `f32` mix
+    /// with `convert`.
+    fn build_converting_stream<T: SizedSample + Send + 'static>(
+        &self,
+        mut render: RenderCallback,
+        convert: fn(f32) -> T,
+    ) -> Result<cpal::Stream, cpal::BuildStreamError> {
+        // Whole frames, so every chunk starts on the first channel
+        let channels = self.config.channels as usize;
+        let mut mix = vec![0.0f32; CONVERT_BUFFER_SAMPLES / channels * channels];
+        self.device.build_output_stream(
+            &self.config,
+            move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+                for chunk in data.chunks_mut(mix.len()) {
+                    let mix = &mut mix[..chunk.len()];
+                    render(mix);
+                    for (out, &sample) in chunk.iter_mut().zip(mix.iter()) {
+                        *out = convert(sample);
+                    }
+                }
+            },
+            |err| {
+                eprintln!("Audio stream error: {}", err);
+            },
+            None,
+        )
+    }
+}
+
+impl AudioOutput for CpalOutput {
+    fn sample_rate(&self) -> u32 {
+        self.config.sample_rate.0
+    }
+
+    fn channels(&self) -> usize {
+        self.config.channels as usize
+    }
+
+    fn sample_format(&self) -> OutputSampleFormat {
+        self.sample_format
+    }
+
+    fn start(self: Box<Self>, mut render: RenderCallback) -> Result<OutputStream, String> {
+        // The engine has already dithered to the word length, so these
+        // conversions only round and clamp
+        let stream = match self.sample_format {
+            OutputSampleFormat::I16 => {
+                self.build_converting_stream(render, |s| quantize(s, 16) as i16)
+            }
+            OutputSampleFormat::I24 => {
+                self.build_converting_stream(render, |s| I24::new_unchecked(quantize(s, 24)))
+            }
+            OutputSampleFormat::I32 => {
+                self.build_converting_stream(render, |s| quantize(s, 24) << 8)
+            }
+            OutputSampleFormat::F32 => self.device.build_output_stream(
+                &self.config,
+                move |data: &mut [f32], _: &cpal::OutputCallbackInfo| render(data),
+                |err| {
+                    eprintln!("Audio stream error: {}", err);
+                },
+                None,
+            ),
+        }
+        .map_err(|e| format!("Failed to build audio stream: {}", e))?;
+
+        stream
+            .play()
+            .map_err(|e| format!("Failed to start audio stream: {}", e))?;
+
+        Ok(OutputStream::new(stream))
+    }
+}
+
+// ============================================================================
+// NULL
+// ============================================================================
+
+/// Output that discards audio while pulling it at real-time pace.
+pub struct NullOutput {
+    sample_rate: u32,
+    channels: usize,
+    period_frames: usize,
+}
+
+impl NullOutput {
+    /// Create a null output pulling `period_frames` frames per callback.
+    pub fn new(sample_rate: u32, channels: usize, period_frames: usize) -> Self {
+        Self {
+            sample_rate,
+            channels,
+            period_frames: period_frames.max(1),
+        }
+    }
+}
+
+impl Default for NullOutput {
+    /// 48kHz stereo with 10ms periods.
+    fn default() -> Self {
+        Self::new(DEFAULT_OUTPUT_SAMPLE_RATE, 2, DEFAULT_OUTPUT_SAMPLE_RATE as usize / 100)
+    }
+}
+
+/// Timer thread behind a started [`NullOutput`].
+struct NullStream {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for NullStream {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl AudioOutput for NullOutput {
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+
+    fn start(self: Box<Self>, mut render: RenderCallback) -> Result<OutputStream, String> {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_clone = Arc::clone(&stop);
+        let period = Duration::from_secs_f64(self.period_frames as f64 / self.sample_rate as f64);
+
+        let handle = thread::Builder::new()
+            .name("audio-null-output".to_string())
+            .spawn(move || {
+                let mut buffer = vec![0.0f32; self.period_frames * self.channels];
+                let mut deadline = Instant::now();
+
+                while !stop_clone.load(Ordering::Acquire) {
+                    render(&mut buffer);
+
+                    deadline += period;
+                    let now = Instant::now();
+                    if deadline > now {
+                        thread::sleep(deadline - now);
+                    } else {
+                        // We fell behind; don't try to catch up in a burst
+                        deadline = now;
+                    }
+                }
+            })
+            .map_err(|e| format!("Failed to spawn null output thread: {}", e))?;
+
+        Ok(OutputStream::new(NullStream {
+            stop,
+            handle: Some(handle),
+        }))
+    }
+}
+
+// ============================================================================
+// WAV FILE
+// ============================================================================
+
+/// Frames per render callback when rendering offline
+const OFFLINE_PERIOD_FRAMES: usize = 1024;
+
+/// State shared between a [`WavFileOutput`] and its [`OfflineRenderer`].
+struct OfflineState {
+    render: Option<RenderCallback>,
+    writer: Option<WavWriter>,
+    buffer: Vec<f32>,
+}
+
+/// Output that renders into a WAV file on demand.
+///
+/// Nothing is pulled on its own: audio is rendered only when
+/// [`OfflineRenderer::render`] is called, so the result is deterministic and
+/// not tied to wall-clock time.
+pub struct WavFileOutput {
+    sample_rate: u32,
+    channels: usize,
+    sample_format: OutputSampleFormat,
+    state: Arc<Mutex<OfflineState>>,
+}
+
+/// Drives a [`WavFileOutput`].
+#[derive(Clone)]
+pub struct OfflineRenderer {
+    channels: usize,
+    state: Arc<Mutex<OfflineState>>,
+}
+
+impl WavFileOutput {
+    /// Create a 32-bit float output file and the renderer that drives it.
+    pub fn create(
+        path: &Path,
+        sample_rate: u32,
+        channels: usize,
+    ) -> Result<(Self, OfflineRenderer), String> {
+        Self::create_with_format(path, sample_rate, channels, WavSampleFormat::Float32)
+    }
+
+    /// Create an output file storing samples as `format`.
+    ///
+    /// Integer formats behave like an integer device: the engine dithers the
+    /// mix to their word length.
+    pub fn create_with_format(
+        path: &Path,
+        sample_rate: u32,
+        channels: usize,
+        format: WavSampleFormat,
+    ) -> Result<(Self, OfflineRenderer), String> {
+        let writer = WavWriter::create_with_format(path, sample_rate, channels, format)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        let sample_format = match format {
+            WavSampleFormat::Int16 => OutputSampleFormat::I16,
+            WavSampleFormat::Int24 => OutputSampleFormat::I24,
+            WavSampleFormat::Float32 => OutputSampleFormat::F32,
+        };
+
+        let state = Arc::new(Mutex::new(OfflineState {
+            render: None,
+            writer: Some(writer),
+            buffer: Vec::new(),
+        }));
+
+        Ok((
+            Self {
+                sample_rate,
+                channels,
+                sample_format,
+                state: Arc::clone(&state),
+            },
+            OfflineRenderer { channels, state },
+        ))
+    }
+}
+
+impl AudioOutput for WavFileOutput {
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn channels(&self) -> usize {
+        self.channels
+    }
+
+    fn sample_format(&self) -> OutputSampleFormat {
+        self.sample_format
+    }
+
+    fn start(self: Box<Self>, render: RenderCallback) -> Result<OutputStream, String> {
+        self.state.lock().render = Some(render);
+        Ok(OutputStream::new(()))
+    }
+}
+
+impl OfflineRenderer {
+    /// Whether the engine has started the output yet.
+    pub fn is_started(&self) -> bool {
+        self.state.lock().render.is_some()
+    }
+
+    /// Render `frames` frames and append them to the file.
+    ///
+    /// Returns the rendered samples as well, for callers that want to
+    /// inspect the output directly.
+    pub fn render(&self, frames: usize) -> Result<Vec<f32>, String> {
+        let mut state = self.state.lock();
+        let OfflineState {
+            render,
+            writer,
+            buffer,
+        } = &mut *state;
+
+        let render = render.as_mut().ok_or("Output not started")?;
+        let writer = writer.as_mut().ok_or("Output already finished")?;
+
+        // Pull in device-sized periods like a real output would
+        buffer.clear();
+        buffer.resize(frames * self.channels, 0.0);
+        for period in buffer.chunks_mut(OFFLINE_PERIOD_FRAMES * self.channels) {
+            render(period);
+        }
+
+        writer
+            .write_samples(buffer)
+            .map_err(|e| format!("Failed to write output: {}", e))?;
+
+        Ok(buffer.clone())
+    }
+
+    /// Finish the WAV file. Further calls to [`render`](Self::render) fail.
+    pub fn finish(&self) -> Result<(), String> {
+        let writer = self
+            .state
+            .lock()
+            .writer
+            .take()
+            .ok_or("Output already finished")?;
+        writer
+            .finalize()
+            .map_err(|e| format!("Failed to finalize output: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_prefers_device_format() {
+        let all = [SampleFormat::I16, SampleFormat::I32, SampleFormat::F32];
+        assert_eq!(
+            negotiate_sample_format(SampleFormat::I16, &all),
+            Some(OutputSampleFormat::I16)
+        );
+        assert_eq!(
+            negotiate_sample_format(SampleFormat::I24, &[]),
+            Some(OutputSampleFormat::I24)
+        );
+    }
+
+    #[test]
+    fn test_negotiate_falls_back_to_best_supported() {
+        // Preferred format unusable or not available at the engine rate
+        assert_eq!(
+            negotiate_sample_format(SampleFormat::U16, &[SampleFormat::I16, SampleFormat::I32]),
+            Some(OutputSampleFormat::I32)
+        );
+        assert_eq!(
+            negotiate_sample_format(SampleFormat::I24, &[SampleFormat::I16, SampleFormat::F32]),
+            Some(OutputSampleFormat::F32)
+        );
+        assert_eq!(negotiate_sample_format(SampleFormat::U8, &[SampleFormat::U8]), None);
+    }
+}
@@ -0,0 +1,761 @@
+//! Offline rendering ("bouncing") of a queue to an audio file.
+//!
+//! Tracks are mixed through the same [`SourceProvider`] and [`Crossfader`]
+//! the engine uses for playback, followed by the equalizer and volume, so the
+//! file sounds like the queue would through the speakers. Samples are pulled
+//! as fast as the decoders can produce them instead of at device pace.
+//!
+//! With normalization enabled the mix is rendered twice over: once into a
+//! temporary float WAV while measuring the peak, then again from that file
+//! with the gain applied into the requested format.
+
+use crate::audio::crossfader::{CrossfadeCurve, Crossfader};
+use crate::audio::decoder::{probe_file, DecoderThread};
+use crate::audio::equalizer::{EqBand, Equalizer};
+use crate::audio::flac::FlacWriter;
+use crate::audio::resampler::DEFAULT_OUTPUT_SAMPLE_RATE;
+use crate::audio::source::{AudioSource, SourceProvider};
+use crate::audio::wav::{WavReader, WavSampleFormat, WavWriter};
+
+use std::ffi::OsString;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// Frames mixed per iteration
+const RENDER_BLOCK_FRAMES: usize = 4096;
+
+/// Peak level the mix is normalized to, in dBFS
+const NORMALIZE_TARGET_DBFS: f32 = -1.0;
+
+/// Seconds of rendered audio between progress reports
+const PROGRESS_INTERVAL_SECS: f64 = 1.0;
+
+/// Container format of a rendered file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    Wav,
+    Flac,
+}
+
+/// How a queue is mixed and encoded.
+#[derive(Debug, Clone)]
+pub struct RenderSettings {
+    /// Crossfade between consecutive tracks in seconds; 0 renders gapless
+    pub crossfade_secs: f32,
+    /// Crossfade curve
+    pub crossfade_curve: CrossfadeCurve,
+    /// Output volume (0.0 to 1.0)
+    pub volume: f32,
+    /// Equalizer preamp in dB
+    pub eq_preamp_db: f32,
+    /// Equalizer bands; empty for no EQ
+    pub eq_bands: Vec<EqBand>,
+    /// Scale the mix so its peak sits at -1 dBFS
+    pub normalize: bool,
+    /// 16 or 24 bit integer, or 32 for float (WAV only)
+    pub bit_depth: u32,
+}
+
+impl Default for RenderSettings {
+    fn default() -> Self {
+        Self {
+            crossfade_secs: 0.0,
+            crossfade_curve: CrossfadeCurve::default(),
+            volume: 1.0,
+            eq_preamp_db: 0.0,
+            eq_bands: Vec::new(),
+            normalize: false,
+            bit_depth: 16,
+        }
+    }
+}
+
+/// Stage of a render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderPhase {
+    /// Decoding and mixing the queue
+    Mixing,
+    /// Applying the normalization gain and encoding
+    Normalizing,
+}
+
+/// Progress report of a running render.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderProgress {
+    pub phase: RenderPhase,
+    /// Index of the track being mixed
+    pub track_index: usize,
+    pub track_count: usize,
+    /// Seconds of output rendered so far in this phase
+    pub position_secs: f64,
+    /// Estimated length of the output in seconds
+    pub total_secs: f64,
+}
+
+/// Render errors.
+#[derive(Debug)]
+pub enum RenderError {
+    NoTracks,
+    Cancelled,
+    InvalidSettings(String),
+    Decode(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::NoTracks => write!(f, "Nothing to render"),
+            RenderError::Cancelled => write!(f, "Render cancelled"),
+            RenderError::InvalidSettings(msg) => write!(f, "Invalid render settings: {}", msg),
+            RenderError::Decode(msg) => write!(f, "Decode error: {}", msg),
+            RenderError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for RenderError {
+    fn from(e: io::Error) -> Self {
+        RenderError::Io(e)
+    }
+}
+
+/// Encoder for the final file.
+enum FileSink {
+    Wav(WavWriter),
+    Flac(Box<FlacWriter>),
+}
+
+impl FileSink {
+    fn create(
+        path: &Path,
+        format: RenderFormat,
+        sample_rate: u32,
+        channels: usize,
+        bit_depth: u32,
+    ) -> Result<Self, RenderError> {
+        match (format, bit_depth) {
+            (RenderFormat::Wav, 16 | 24 | 32) => {
+                let sample_format = match bit_depth {
+                    16 => WavSampleFormat::Int16,
+                    24 => WavSampleFormat::Int24,
+                    _ => WavSampleFormat::Float32,
+                };
+                Ok(FileSink::Wav(WavWriter::create_with_format(
+                    path,
+                    sample_rate,
+                    channels,
+                    sample_format,
+                )?))
+            }
+            (RenderFormat::Flac, 16 | 24) => Ok(FileSink::Flac(Box::new(
+                FlacWriter::create(path, sample_rate, channels, bit_depth)?,
+            ))),
+            _ => Err(RenderError::InvalidSettings(format!(
+                "{}-bit output is not supported for {:?}",
+                bit_depth, format
+            ))),
+        }
+    }
+
+    fn write_samples(&mut self, samples: &[f32]) -> io::Result<()> {
+        match self {
+            FileSink::Wav(writer) => writer.write_samples(samples),
+            FileSink::Flac(writer) => writer.write_samples(samples),
+        }
+    }
+
+    fn finalize(self) -> io::Result<()> {
+        match self {
+            FileSink::Wav(writer) => writer.finalize(),
+            FileSink::Flac(writer) => writer.finalize(),
+        }
+    }
+}
+
+/// Render `paths` back to back into `out_path`.
+///
+/// The output uses the engine sample rate and the channel count of the first
+/// track; every track must have that channel count. `cancel` is polled
+/// between blocks. On failure or cancellation no output file is left behind.
+pub fn render_to_file(
+    paths: &[PathBuf],
+    settings: &RenderSettings,
+    out_path: &Path,
+    format: RenderFormat,
+    cancel: &AtomicBool,
+    mut on_progress: impl FnMut(RenderProgress),
+) -> Result<(), RenderError> {
+    if paths.is_empty() {
+        return Err(RenderError::NoTracks);
+    }
+    if settings.crossfade_secs.is_nan() || settings.crossfade_secs < 0.0 {
+        return Err(RenderError::InvalidSettings(format!(
+            "crossfade of {} seconds",
+            settings.crossfade_secs
+        )));
+    }
+
+    // Probe everything up front: bad files fail fast and the length is known
+    let mut durations = Vec::with_capacity(paths.len());
+    let mut channels = 0;
+    for path in paths {
+        let info = probe_file(path)
+            .map_err(|e| RenderError::Decode(format!("{}: {}", path.display(), e)))?
+            .source_info;
+        if channels == 0 {
+            channels = info.channels;
+        } else if info.channels != channels {
+            return Err(RenderError::Decode(format!(
+                "{}: {} channels, expected {}",
+                path.display(),
+                info.channels,
+                channels
+            )));
+        }
+        durations.push(info.duration_secs);
+    }
+
+    let total_secs = durations.iter().sum::<f64>()
+        - durations
+            .windows(2)
+            .map(|pair| (settings.crossfade_secs as f64).min(pair[0]).min(pair[1]))
+            .sum::<f64>();
+
+    let temp_path = {
+        let mut name = OsString::from(out_path.as_os_str());
+        name.push(".part");
+        PathBuf::from(name)
+    };
+
+    let result = render_passes(
+        paths,
+        settings,
+        out_path,
+        &temp_path,
+        format,
+        channels,
+        total_secs,
+        cancel,
+        &mut on_progress,
+    );
+
+    let _ = std::fs::remove_file(&temp_path);
+    if result.is_err() {
+        let _ = std::fs::remove_file(out_path);
+    }
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_passes(
+    paths: &[PathBuf],
+    settings: &RenderSettings,
+    out_path: &Path,
+    temp_path: &Path,
+    format: RenderFormat,
+    channels: usize,
+    total_secs: f64,
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(RenderProgress),
+) -> Result<(), RenderError> {
+    let sample_rate = DEFAULT_OUTPUT_SAMPLE_RATE;
+    let mut sink = FileSink::create(out_path, format, sample_rate, channels, settings.bit_depth)?;
+
+    let mut equalizer = Equalizer::new(sample_rate, channels, settings.eq_preamp_db, &settings.eq_bands);
+    let volume = settings.volume.clamp(0.0, 1.0);
+    let progress_interval = (PROGRESS_INTERVAL_SECS * sample_rate as f64) as u64;
+
+    // Without normalization the mix goes straight into the output file
+    let mut temp = if settings.normalize {
+        Some(WavWriter::create(temp_path, sample_rate, channels)?)
+    } else {
+        None
+    };
+
+    let mut peak = 0.0f32;
+    let mut rendered_frames = 0u64;
+    let mut next_report = 0u64;
+    mix_queue(paths, settings, channels, cancel, |block, track_index| {
+        equalizer.process(block);
+        for sample in block.iter_mut() {
+            *sample *= volume;
+            peak = peak.max(sample.abs());
+        }
+
+        match temp.as_mut() {
+            Some(temp) => temp.write_samples(block)?,
+            None => sink.write_samples(block)?,
+        }
+
+        rendered_frames += (block.len() / channels) as u64;
+        if rendered_frames >= next_report {
+            next_report = rendered_frames + progress_interval;
+            on_progress(RenderProgress {
+                phase: RenderPhase::Mixing,
+                track_index,
+                track_count: paths.len(),
+                position_secs: rendered_frames as f64 / sample_rate as f64,
+                total_secs,
+            });
+        }
+        Ok(())
+    })?;
+
+    let rendered_secs = rendered_frames as f64 / sample_rate as f64;
+    on_progress(RenderProgress {
+        phase: RenderPhase::Mixing,
+        track_index: paths.len() - 1,
+        track_count: paths.len(),
+        position_secs: rendered_secs,
+        total_secs: rendered_secs,
+    });
+
+    if let Some(temp) = temp {
+        temp.finalize()?;
+
+        let gain = if peak > 0.0 {
+            10f32.powf(NORMALIZE_TARGET_DBFS / 20.0) / peak
+        } else {
+            1.0
+        };
+
+        let mut reader = WavReader::open(temp_path)?;
+        let mut block = vec![0.0f32; RENDER_BLOCK_FRAMES * channels];
+        let mut written_frames = 0u64;
+        let mut next_report = 0u64;
+        loop {
+            if cancel.load(Ordering::Relaxed) {
+                return Err(RenderError::Cancelled);
+            }
+
+            let read = reader.read_samples(&mut block)?;
+            if read == 0 {
+                break;
+            }
+            for sample in &mut block[..read] {
+                *sample *= gain;
+            }
+            sink.write_samples(&block[..read])?;
+
+            written_frames += (read / channels) as u64;
+            if written_frames >= next_report {
+                next_report = written_frames + progress_interval;
+                on_progress(RenderProgress {
+                    phase: RenderPhase::Normalizing,
+                    track_index: paths.len() - 1,
+                    track_count: paths.len(),
+                    position_secs: written_frames as f64 / sample_rate as f64,
+                    total_secs: rendered_secs,
+                });
+            }
+        }
+    }
+
+    sink.finalize()?;
+    Ok(())
+}
+
+/// Spawn a decoder for `path` at the engine sample rate.
+fn spawn_source(
+    path: &Path,
+    channels: usize,
+    decoders: &mut Vec<(PathBuf, DecoderThread)>,
+) -> Result<AudioSource, RenderError> {
+    let (mut source, decoder) = DecoderThread::spawn(path.to_path_buf(), DEFAULT_OUTPUT_SAMPLE_RATE)
+        .map_err(|e| RenderError::Decode(format!("{}: {}", path.display(), e)))?;
+    if source.info.channels != channels {
+        return Err(RenderError::Decode(format!(
+            "{}: {} channels, expected {}",
+            path.display(),
+            source.info.channels,
+            channels
+        )));
+    }
+    source.set_ready();
+    decoders.push((path.to_path_buf(), decoder));
+    Ok(source)
+}
+
+/// Wait briefly for decoders to produce more samples.
+///
+/// Decoders that have exited are joined so a failed one surfaces as an error
+/// instead of leaving its source waiting forever.
+fn wait_for_decoders(decoders: &mut Vec<(PathBuf, DecoderThread)>) -> Result<(), RenderError> {
+    let mut i = 0;
+    while i < decoders.len() {
+        if decoders[i].1.is_running() {
+            i += 1;
+            continue;
+        }
+        let (path, decoder) = decoders.swap_remove(i);
+        decoder
+            .join()
+            .map_err(|e| RenderError::Decode(format!("{}: {}", path.display(), e)))?;
+    }
+    thread::sleep(Duration::from_millis(1));
+    Ok(())
+}
+
+/// Fill `output` from one source, padding with silence once it has finished.
+fn read_source(
+    source: &mut AudioSource,
+    output: &mut [f32],
+    decoders: &mut Vec<(PathBuf, DecoderThread)>,
+) -> Result<(), RenderError> {
+    let mut filled = 0;
+    while filled < output.len() {
+        let read = source.read(&mut output[filled..]);
+        filled += read;
+        if source.is_finished() {
+            output[filled..].fill(0.0);
+            break;
+        }
+        if read == 0 {
+            wait_for_decoders(decoders)?;
+        }
+    }
+    Ok(())
+}
+
+/// Decode and mix the queue, handing each mixed block to `emit` together
+/// with the index of the track it (mostly) belongs to.
+fn mix_queue(
+    paths: &[PathBuf],
+    settings: &RenderSettings,
+    channels: usize,
+    cancel: &AtomicBool,
+    mut emit: impl FnMut(&mut [f32], usize) -> Result<(), RenderError>,
+) -> Result<(), RenderError> {
+    let sample_rate = DEFAULT_OUTPUT_SAMPLE_RATE;
+    let mut decoders = Vec::new();
+    let mut provider = SourceProvider::new(sample_rate, channels);
+
+    let mut crossfader = if settings.crossfade_secs > 0.0 {
+        Crossfader::new(sample_rate, settings.crossfade_secs)
+    } else {
+        Crossfader::disabled(sample_rate)
+    };
+    crossfader.set_curve(settings.crossfade_curve);
+    let fade_frames = (crossfader.duration_secs() as f64 * sample_rate as f64).round() as u64;
+
+    let mut block = vec![0.0f32; RENDER_BLOCK_FRAMES * channels];
+    let mut buf_a = vec![0.0f32; block.len()];
+    let mut buf_b = vec![0.0f32; block.len()];
+
+    let mut first = spawn_source(&paths[0], channels, &mut decoders)?;
+    first.set_playing();
+    provider.set_current(first);
+    let mut next_index = 1;
+    let mut track_index = 0;
+
+    while provider.current().is_some() {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(RenderError::Cancelled);
+        }
+
+        // Keep the following track decoding ahead, as the engine does
+        if !provider.has_next() && next_index < paths.len() {
+            let source = spawn_source(&paths[next_index], channels, &mut decoders)?;
+            provider.queue_next(source);
+            next_index += 1;
+        }
+
+        if crossfader.is_active() && provider.has_next() {
+            if let Some(current) = provider.current_mut() {
+                read_source(current, &mut buf_a, &mut decoders)?;
+            }
+            if let Some(next) = provider.next_mut() {
+                read_source(next, &mut buf_b, &mut decoders)?;
+            }
+            crossfader
+                .mix(&buf_a, &buf_b, &mut block, channels)
+                .map_err(RenderError::Decode)?;
+
+            if !crossfader.is_active() {
+                provider.advance_to_next();
+                track_index += 1;
+            }
+            emit(&mut block, track_index)?;
+            continue;
+        }
+
+        // Stop short of the point where the fade into the next track begins
+        let mut wanted = block.len();
+        if crossfader.is_enabled() && provider.has_next() {
+            if let Some(current) = provider.current() {
+                let total_frames = current.info.total_samples / channels as u64;
+                let played_frames = current.position_samples() / channels as u64;
+                let until_fade = total_frames
+                    .saturating_sub(fade_frames)
+                    .saturating_sub(played_frames);
+                if until_fade == 0 {
+                    crossfader.start();
+                    continue;
+                }
+                wanted = wanted.min(until_fade as usize * channels);
+            }
+        }
+
+        // Gapless read; the provider moves on to the next track by itself
+        let mut filled = 0;
+        while filled < wanted {
+            let (read, finished) = provider.read(&mut block[filled..wanted]);
+            filled += read;
+            if finished.is_some() {
+                // Emit what we have so the following track gets queued
+                if provider.current().is_some() {
+                    track_index += 1;
+                }
+                break;
+            }
+            if read == 0 {
+                wait_for_decoders(&mut decoders)?;
+            }
+        }
+
+        if filled > 0 {
+            emit(&mut block[..filled], track_index)?;
+        }
+    }
+
+    // Surface errors from decoders that finished after their last read
+    for (path, decoder) in decoders {
+        if !decoder.is_running() {
+            decoder
+                .join()
+                .map_err(|e| RenderError::Decode(format!("{}: {}", path.display(), e)))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_samples_eq, signal, test_dir, write_track};
+    use std::f32::consts::FRAC_PI_2;
+
+    const RATE: u32 = DEFAULT_OUTPUT_SAMPLE_RATE;
+    const CHANNELS: usize = 2;
+
+    fn read_wav(path: &Path) -> Vec<f32> {
+        let mut reader = WavReader::open(path).unwrap();
+        let mut samples = Vec::new();
+        let mut block = vec![0.0; 4096];
+        loop {
+            let read = reader.read_samples(&mut block).unwrap();
+            if read == 0 {
+                return samples;
+            }
+            samples.extend_from_slice(&block[..read]);
+        }
+    }
+
+    /// Decode any supported file through the regular decoder thread.
+    fn decode(path: &Path) -> Vec<f32> {
+        let mut decoders = Vec::new();
+        let mut source = spawn_source(path, CHANNELS, &mut decoders).unwrap();
+        let mut samples = Vec::new();
+        let mut block = vec![0.0; 4096];
+        while !source.is_finished() {
+            let read = source.read(&mut block);
+            samples.extend_from_slice(&block[..read]);
+            if read == 0 {
+                wait_for_decoders(&mut decoders).unwrap();
+            }
+        }
+        samples
+    }
+
+    fn render(paths: &[PathBuf], settings: &RenderSettings, out: &Path, format: RenderFormat) {
+        let cancel = AtomicBool::new(false);
+        render_to_file(paths, settings, out, format, &cancel, |_| {}).unwrap();
+    }
+
+    fn float_settings() -> RenderSettings {
+        RenderSettings {
+            bit_depth: 32,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render_gapless_is_exact() {
+        let dir = test_dir("render-gapless");
+        // The middle track is longer than a source ring buffer, so its decoder
+        // has to wait for the mixer to catch up
+        let tracks = [signal(7000, 0.1), signal(300_000, 0.3), signal(5000, 0.5)];
+        let paths: Vec<PathBuf> = tracks
+            .iter()
+            .enumerate()
+            .map(|(i, track)| {
+                let path = dir.join(format!("{}.wav", i));
+                write_track(&path, RATE, track);
+                path
+            })
+            .collect();
+
+        // Stall once at the start, long enough for the queued decoder to fill
+        // its buffer and time out waiting for space
+        let out = dir.join("out.wav");
+        let cancel = AtomicBool::new(false);
+        let mut stalled = false;
+        render_to_file(&paths, &float_settings(), &out, RenderFormat::Wav, &cancel, |_| {
+            if !stalled {
+                stalled = true;
+                thread::sleep(Duration::from_millis(300));
+            }
+        })
+        .unwrap();
+
+        let expected: Vec<f32> = tracks.concat();
+        assert_samples_eq(&read_wav(&out), &expected, 0.0);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_render_crossfade() {
+        let dir = test_dir("render-crossfade");
+        let track_a = signal(9600, 0.1);
+        let track_b = signal(9600, 0.5);
+        write_track(&dir.join("a.wav"), RATE, &track_a);
+        write_track(&dir.join("b.wav"), RATE, &track_b);
+
+        let fade_frames = 2400;
+        let settings = RenderSettings {
+            crossfade_secs: fade_frames as f32 / RATE as f32,
+            ..float_settings()
+        };
+        let out = dir.join("out.wav");
+        render(&[dir.join("a.wav"), dir.join("b.wav")], &settings, &out, RenderFormat::Wav);
+
+        let fade_start = 9600 - fade_frames;
+        let mut expected = track_a[..fade_start * CHANNELS].to_vec();
+        for frame in 0..fade_frames {
+            let angle = frame as f32 / fade_frames as f32 * FRAC_PI_2;
+            for ch in 0..CHANNELS {
+                let a = track_a[(fade_start + frame) * CHANNELS + ch];
+                let b = track_b[frame * CHANNELS + ch];
+                expected.push(a * angle.cos() + b * angle.sin());
+            }
+        }
+        expected.extend_from_slice(&track_b[fade_frames * CHANNELS..]);
+        assert_samples_eq(&read_wav(&out), &expected, 1e-5);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_render_volume_and_normalize() {
+        let dir = test_dir("render-normalize");
+        let track = signal(4800, 0.1);
+        write_track(&dir.join("a.wav"), RATE, &track);
+
+        let out = dir.join("out.wav");
+        let settings = RenderSettings {
+            volume: 0.5,
+            ..float_settings()
+        };
+        render(&[dir.join("a.wav")], &settings, &out, RenderFormat::Wav);
+        let halved: Vec<f32> = track.iter().map(|s| s * 0.5).collect();
+        assert_samples_eq(&read_wav(&out), &halved, 1e-7);
+
+        let settings = RenderSettings {
+            normalize: true,
+            ..settings
+        };
+        render(&[dir.join("a.wav")], &settings, &out, RenderFormat::Wav);
+        let output = read_wav(&out);
+        let peak = output.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!((peak - 10f32.powf(-1.0 / 20.0)).abs() < 1e-6, "peak {}", peak);
+
+        // The temporary mix is cleaned up
+        assert!(!dir.join("out.wav.part").exists());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_render_flac() {
+        let dir = test_dir("render-flac");
+        let tracks = [signal(10_000, 0.1), signal(3000, 0.4)];
+        write_track(&dir.join("a.wav"), RATE, &tracks[0]);
+        write_track(&dir.join("b.wav"), RATE, &tracks[1]);
+
+        let out = dir.join("out.flac");
+        let settings = RenderSettings {
+            bit_depth: 16,
+            ..Default::default()
+        };
+        render(&[dir.join("a.wav"), dir.join("b.wav")], &settings, &out, RenderFormat::Flac);
+
+        let expected: Vec<f32> = tracks.concat();
+        assert_samples_eq(&decode(&out), &expected, 1.0 / 32768.0);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_render_cancel_and_errors() {
+        let dir = test_dir("render-cancel");
+        write_track(&dir.join("a.wav"), RATE, &signal(4800, 0.1));
+        let paths = [dir.join("a.wav")];
+        let out = dir.join("out.wav");
+
+        let cancel = AtomicBool::new(true);
+        let result = render_to_file(&paths, &float_settings(), &out, RenderFormat::Wav, &cancel, |_| {});
+        assert!(matches!(result, Err(RenderError::Cancelled)));
+        assert!(!out.exists());
+
+        let cancel = AtomicBool::new(false);
+        let settings = RenderSettings {
+            bit_depth: 32,
+            ..Default::default()
+        };
+        let result = render_to_file(&paths, &settings, &out, RenderFormat::Flac, &cancel, |_| {});
+        assert!(matches!(result, Err(RenderError::InvalidSettings(_))));
+
+        let result = render_to_file(&[], &settings, &out, RenderFormat::Wav, &cancel, |_| {});
+        assert!(matches!(result, Err(RenderError::NoTracks)));
+
+        let missing = [dir.join("missing.wav")];
+        let result = render_to_file(&missing, &settings, &out, RenderFormat::Wav, &cancel, |_| {});
+        assert!(matches!(result, Err(RenderError::Decode(_))));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_render_reports_progress() {
+        let dir = test_dir("render-progress");
+        write_track(&dir.join("a.wav"), RATE, &signal(RATE as usize * 3, 0.1));
+
+        let cancel = AtomicBool::new(false);
+        let mut reports = Vec::new();
+        render_to_file(
+            &[dir.join("a.wav")],
+            &float_settings(),
+            &dir.join("out.wav"),
+            RenderFormat::Wav,
+            &cancel,
+            |progress| reports.push(progress),
+        )
+        .unwrap();
+
+        assert!(reports.len() >= 3);
+        assert!(reports.windows(2).all(|w| w[0].position_secs <= w[1].position_secs));
+        let last = reports.last().unwrap();
+        assert_eq!(last.phase, RenderPhase::Mixing);
+        assert!((last.position_secs - 3.0).abs() < 1e-9);
+        assert!((last.total_secs - 3.0).abs() < 1e-9);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -9,6 +9,10 @@ use rubato::{FastFixedIn, PolynomialDegree, Resampler as RubatoResampler};
 pub const DEFAULT_OUTPUT_SAMPLE_RATE: u32 = 48000;
 
 /// Wrapper around rubato's resampler for real-time audio conversion.
+///
+/// rubato consumes fixed-size chunks, while decoders produce packets of
+/// arbitrary length, so input is buffered until a full chunk is available.
+/// Call [`AudioResampler::flush`] at the end of a stream to drain the rest.
 pub struct AudioResampler {
     resampler: FastFixedIn<f32>,
     input_rate: u32,
@@ -18,8 +22,16 @@ pub struct AudioResampler {
     input_buffers: Vec<Vec<f32>>,
     /// Pre-allocated output buffers (one per channel)
     output_buffers: Vec<Vec<f32>>,
-    /// Maximum frames we can process in one call
+    /// Frames per processing chunk
     max_input_frames: usize,
+    /// Interleaved input waiting for a full chunk
+    pending: Vec<f32>,
+    /// Output frames still to drop to compensate the filter delay
+    delay_frames: usize,
+    /// Input frames received since creation or the last reset
+    frames_in: u64,
+    /// Output frames emitted since creation or the last reset
+    frames_out: u64,
 }
 
 impl AudioResampler {
@@ -53,13 +65,14 @@ impl AudioResampler {
                 input_buffers: vec![vec![0.0; chunk_size]; channels],
                 output_buffers: vec![vec![0.0; chunk_size]; channels],
                 max_input_frames: chunk_size,
+                pending: Vec::new(),
+                delay_frames: 0,
+                frames_in: 0,
+                frames_out: 0,
             });
         }
 
         let resample_ratio = output_rate as f64 / input_rate as f64;
-        
-        // Calculate output size based on ratio
-        let max_output_frames = (chunk_size as f64 * resample_ratio * 1.1) as usize + 10;
 
         let resampler = FastFixedIn::new(
             resample_ratio,
@@ -69,6 +82,8 @@ impl AudioResampler {
             channels,
         )
         .map_err(|e| format!("Failed to create resampler: {}", e))?;
+        let max_output_frames = resampler.output_frames_max();
+        let delay_frames = resampler.output_delay();
 
         Ok(Self {
             resampler,
@@ -78,6 +93,10 @@ impl AudioResampler {
             input_buffers: vec![vec![0.0; chunk_size]; channels],
             output_buffers: vec![vec![0.0; max_output_frames]; channels],
             max_input_frames: chunk_size,
+            pending: Vec::with_capacity(chunk_size * channels),
+            delay_frames,
+            frames_in: 0,
+            frames_out: 0,
         })
     }
 
@@ -105,76 +124,118 @@ impl AudioResampler {
         self.channels
     }
 
-    /// Process a chunk of interleaved audio samples.
+    /// Process interleaved audio samples of any length.
     ///
     /// # Arguments
     /// * `input` - Interleaved input samples (e.g., [L, R, L, R, ...])
-    /// * `output` - Pre-allocated output buffer for interleaved samples
+    /// * `output` - Buffer the interleaved output samples are appended to
     ///
     /// # Returns
-    /// Number of output samples written (interleaved, so divide by channels for frames)
+    /// Number of output samples appended (interleaved, so divide by channels for frames)
     pub fn process_interleaved(
         &mut self,
         input: &[f32],
-        output: &mut [f32],
+        output: &mut Vec<f32>,
     ) -> Result<usize, String> {
         if !self.needs_resampling() {
             // Passthrough - just copy
-            let copy_len = input.len().min(output.len());
-            output[..copy_len].copy_from_slice(&input[..copy_len]);
-            return Ok(copy_len);
+            output.extend_from_slice(input);
+            return Ok(input.len());
         }
 
-        let input_frames = input.len() / self.channels;
-        
-        if input_frames == 0 {
+        let start = output.len();
+        self.frames_in += (input.len() / self.channels) as u64;
+        self.pending.extend_from_slice(input);
+
+        let chunk_samples = self.max_input_frames * self.channels;
+        let mut consumed = 0;
+        while self.pending.len() - consumed >= chunk_samples {
+            let chunk = consumed..consumed + chunk_samples;
+            self.deinterleave(chunk);
+            let (_, output_frames) = self
+                .resampler
+                .process_into_buffer(&self.input_buffers, &mut self.output_buffers, None)
+                .map_err(|e| format!("Resampling error: {}", e))?;
+            self.emit(output_frames, output);
+            consumed += chunk_samples;
+        }
+        self.pending.drain(..consumed);
+
+        Ok(output.len() - start)
+    }
+
+    /// Drain buffered input and the filter tail at the end of a stream.
+    ///
+    /// The total output length matches the input length scaled by the
+    /// sample rate ratio. Returns the number of samples appended.
+    pub fn flush(&mut self, output: &mut Vec<f32>) -> Result<usize, String> {
+        if !self.needs_resampling() {
             return Ok(0);
         }
 
-        // Deinterleave input into channel buffers
-        for (frame_idx, chunk) in input.chunks_exact(self.channels).enumerate() {
+        let start = output.len();
+        let expected_frames = (self.frames_in as f64 * self.output_rate as f64
+            / self.input_rate as f64)
+            .round() as u64;
+
+        while self.frames_out < expected_frames {
+            let pending_frames = self.pending.len() / self.channels;
+            self.deinterleave(0..self.pending.len());
+            let input_refs: Vec<&[f32]> = self
+                .input_buffers
+                .iter()
+                .map(|b| &b[..pending_frames])
+                .collect();
+            // A partial chunk is padded with silence, which also pushes the
+            // filter tail out once the real input is exhausted
+            let (_, output_frames) = self
+                .resampler
+                .process_partial_into_buffer(Some(&input_refs), &mut self.output_buffers, None)
+                .map_err(|e| format!("Resampling error: {}", e))?;
+            self.pending.clear();
+
+            let wanted = (expected_frames - self.frames_out) as usize + self.delay_frames;
+            self.emit(output_frames.min(wanted), output);
+        }
+
+        Ok(output.len() - start)
+    }
+
+    /// Copy a range of `pending` into the per-channel input buffers.
+    fn deinterleave(&mut self, range: std::ops::Range<usize>) {
+        for (frame_idx, chunk) in self.pending[range].chunks_exact(self.channels).enumerate() {
             for (ch, &sample) in chunk.iter().enumerate() {
-                if frame_idx < self.input_buffers[ch].len() {
-                    self.input_buffers[ch][frame_idx] = sample;
-                }
+                self.input_buffers[ch][frame_idx] = sample;
             }
         }
+    }
 
-        // Truncate input buffers to actual size
-        let input_refs: Vec<&[f32]> = self
-            .input_buffers
-            .iter()
-            .map(|b| &b[..input_frames.min(self.max_input_frames)])
-            .collect();
-
-        // Perform resampling
-        let (_, output_frames) = self
-            .resampler
-            .process_into_buffer(&input_refs, &mut self.output_buffers, None)
-            .map_err(|e| format!("Resampling error: {}", e))?;
-
-        // Interleave output
-        let output_samples = output_frames * self.channels;
-        if output_samples > output.len() {
-            return Err(format!(
-                "Output buffer too small: need {}, have {}",
-                output_samples,
-                output.len()
-            ));
-        }
+    /// Interleave `frames` of the output buffers into `output`, dropping
+    /// the leading filter delay.
+    fn emit(&mut self, frames: usize, output: &mut Vec<f32>) {
+        let skip = self.delay_frames.min(frames);
+        self.delay_frames -= skip;
 
-        for frame_idx in 0..output_frames {
+        output.reserve((frames - skip) * self.channels);
+        for frame_idx in skip..frames {
             for ch in 0..self.channels {
-                output[frame_idx * self.channels + ch] = self.output_buffers[ch][frame_idx];
+                output.push(self.output_buffers[ch][frame_idx]);
             }
         }
-
-        Ok(output_samples)
+        self.frames_out += (frames - skip) as u64;
     }
 
     /// Reset the resampler state (call between tracks).
     pub fn reset(&mut self) {
         self.resampler.reset();
+        self.pending.clear();
+        self.delay_frames = if self.needs_resampling() {
+            self.resampler.output_delay()
+        } else {
+            0
+        };
+        self.frames_in = 0;
+        self.frames_out = 0;
     }
 
     /// Get the latency introduced by resampling in samples.
@@ -206,7 +267,7 @@ mod tests {
         assert!(!resampler.needs_resampling());
 
         let input: Vec<f32> = (0..200).map(|i| i as f32 / 200.0).collect();
-        let mut output = vec![0.0; 200];
+        let mut output = Vec::new();
 
         let written = resampler.process_interleaved(&input, &mut output).unwrap();
         assert_eq!(written, 200);
@@ -218,4 +279,25 @@ mod tests {
         let resampler = AudioResampler::new(44100, 48000, 2, 1024).unwrap();
         assert!(resampler.needs_resampling());
     }
+
+    #[test]
+    fn test_arbitrary_packet_sizes() {
+        // Packets shorter and longer than a chunk, as an MP3 decoder produces
+        let mut resampler = AudioResampler::new(44100, 48000, 2, 1024).unwrap();
+        let mut output = Vec::new();
+
+        let mut frames_in = 0;
+        for packet_frames in [1152, 300, 5000, 7] {
+            let input = vec![0.25f32; packet_frames * 2];
+            resampler.process_interleaved(&input, &mut output).unwrap();
+            frames_in += packet_frames;
+        }
+        resampler.flush(&mut output).unwrap();
+
+        let expected = (frames_in as f64 * 48000.0 / 44100.0).round() as usize;
+        assert_eq!(output.len(), expected * 2);
+        // DC input stays DC away from the edges
+        let mid = output.len() / 2;
+        assert!((output[mid] - 0.25).abs() < 1e-3);
+    }
 }
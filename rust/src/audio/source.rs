@@ -3,6 +3,9 @@
 //! The source provider manages multiple audio sources and handles
 //! seamless transitions between them.
 
+use crate::api::chapters::Chapter;
+use crate::api::lyrics::Lyrics;
+use crate::audio::commands::TimeRange;
 use ringbuf::traits::{Consumer, Observer, Producer, Split};
 use ringbuf::HeapRb;
 use std::path::PathBuf;
@@ -28,6 +31,17 @@ pub struct SourceInfo {
     pub total_samples: u64,
     /// Total duration in seconds
     pub duration_secs: f64,
+    /// The part of the file played; positions and durations are relative
+    /// to its start
+    pub range: Option<TimeRange>,
+    /// Chapters of the file, timed against the whole file; only read for
+    /// playback
+    pub chapters: Vec<Chapter>,
+    /// Synced lyrics of the file, timed against the whole file; only read
+    /// for playback
+    pub lyrics: Option<Lyrics>,
+    /// Where playback of the source started, after a seek or when resuming
+    pub start_secs: f64,
 }
 
 /// State of an audio source.
@@ -62,6 +76,9 @@ pub struct AudioSource {
     position: Arc<AtomicU64>,
     /// Flag to signal the decoder to stop
     stop_signal: Arc<AtomicBool>,
+    /// Whether playback reached the end of the source, rather than leaving
+    /// it for another
+    ended: bool,
 }
 
 /// Handle given to the decoder thread to write samples.
@@ -98,6 +115,7 @@ impl AudioSource {
             decoder_finished: Arc::clone(&decoder_finished),
             position: Arc::clone(&position),
             stop_signal: Arc::clone(&stop_signal),
+            ended: false,
         };
 
         let producer = SourceProducer {
@@ -129,6 +147,12 @@ impl AudioSource {
         self.state = SourceState::Playing;
     }
 
+    /// Whether playback reached the end of this source. Sources skipped or
+    /// faded out before their end did not.
+    pub fn reached_end(&self) -> bool {
+        self.ended
+    }
+
     /// Check if the source has finished (all samples consumed and decoder done).
     pub fn is_finished(&self) -> bool {
         self.state == SourceState::Finished
@@ -150,6 +174,13 @@ impl AudioSource {
         frames as f64 / self.info.output_sample_rate as f64
     }
 
+    /// Set the playback position (used when a source starts mid-file).
+    pub fn set_position_secs(&self, position_secs: f64) {
+        let frames = (position_secs * self.info.output_sample_rate as f64) as u64;
+        self.position
+            .store(frames * self.info.channels as u64, Ordering::Relaxed);
+    }
+
     /// Get the buffer fill level (0.0 to 1.0).
     #[inline]
     pub fn buffer_level(&self) -> f32 {
@@ -355,7 +386,7 @@ impl SourceProvider {
     ///
     /// # Returns
     /// * Number of samples read
-    /// * Whether a track transition occurred
+    /// * The source that finished during this read, if any
     #[inline]
     pub fn read(&mut self, output: &mut [f32]) -> (usize, Option<AudioSource>) {
         if self.current.is_none() {
@@ -373,6 +404,7 @@ impl SourceProvider {
 
         // If we didn't get enough samples and source is finished, transition
         if finished {
+            self.current.as_mut().unwrap().ended = true;
             // Fill remaining with silence or next track
             if self.next.is_some() {
                 // Gapless transition!
@@ -385,8 +417,10 @@ impl SourceProvider {
                 
                 return (read + next_read, old_source);
             } else {
-                // No next track - fill with silence
+                // No next track - fill with silence and hand the finished
+                // source back so the end of the track gets reported
                 output[read..].fill(0.0);
+                return (read, self.current.take());
             }
         }
 
@@ -0,0 +1,560 @@
+//! File-to-file transcoding on top of the decoder pipeline.
+//!
+//! Anything symphonia can decode is resampled with [`AudioResampler`] if
+//! needed, dithered down to the target bit depth and written as WAV, FLAC
+//! or Ogg/Opus. Tags (including cover art) can be carried over with lofty.
+//!
+//! [`transcode_batch`] runs a list of jobs on the rayon pool and reports
+//! per-file progress and errors through a callback.
+
+use crate::audio::decoder::probe_file;
+use crate::audio::dither::{DitherMode, Ditherer};
+use crate::audio::flac::FlacWriter;
+#[cfg(feature = "opus")]
+use crate::audio::opus::{OggOpusWriter, OPUS_SAMPLE_RATE};
+use crate::audio::resampler::AudioResampler;
+use crate::audio::wav::{WavSampleFormat, WavWriter};
+
+use lofty::config::WriteOptions;
+use lofty::file::TaggedFile;
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use rayon::prelude::*;
+use std::ffi::OsString;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Frames per resampler chunk
+const RESAMPLE_CHUNK_FRAMES: usize = 4096;
+
+/// Fraction of a file between progress reports
+const PROGRESS_STEP: f64 = 0.01;
+
+/// Container format of a transcoded file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscodeFormat {
+    Wav,
+    Flac,
+    /// Requires the `opus` feature
+    OggOpus,
+}
+
+/// How files are converted.
+#[derive(Debug, Clone)]
+pub struct TranscodeSettings {
+    pub format: TranscodeFormat,
+    /// Output sample rate; `None` keeps the source rate. Ogg/Opus is always 48 kHz.
+    pub sample_rate: Option<u32>,
+    /// 16 or 24 bit integer, or 32 for float (WAV only); ignored for Ogg/Opus
+    pub bit_depth: u32,
+    /// Dither used when reducing to 16 or 24 bit
+    pub dither: DitherMode,
+    /// Ogg/Opus bitrate in bits per second
+    pub opus_bitrate: u32,
+    /// Copy tags and cover art from the source file
+    pub copy_tags: bool,
+}
+
+impl Default for TranscodeSettings {
+    fn default() -> Self {
+        Self {
+            format: TranscodeFormat::Flac,
+            sample_rate: None,
+            bit_depth: 16,
+            dither: DitherMode::default(),
+            opus_bitrate: 128_000,
+            copy_tags: true,
+        }
+    }
+}
+
+/// One input/output pair of a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscodeJob {
+    pub input: PathBuf,
+    pub output: PathBuf,
+}
+
+/// Progress report of a batch, per job.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TranscodeEvent {
+    Started { index: usize },
+    /// Fraction of the job done (0.0 to 1.0)
+    Progress { index: usize, fraction: f64 },
+    Finished { index: usize },
+    Failed { index: usize, message: String },
+}
+
+/// Transcoding errors.
+#[derive(Debug)]
+pub enum TranscodeError {
+    Cancelled,
+    InvalidSettings(String),
+    Decode(String),
+    Tags(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for TranscodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TranscodeError::Cancelled => write!(f, "Transcode cancelled"),
+            TranscodeError::InvalidSettings(msg) => write!(f, "Invalid transcode settings: {}", msg),
+            TranscodeError::Decode(msg) => write!(f, "Decode error: {}", msg),
+            TranscodeError::Tags(msg) => write!(f, "Tag copy failed: {}", msg),
+            TranscodeError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for TranscodeError {
+    fn from(e: io::Error) -> Self {
+        TranscodeError::Io(e)
+    }
+}
+
+/// Encoder for the output file.
+enum TranscodeSink {
+    Wav(WavWriter),
+    Flac(Box<FlacWriter>),
+    #[cfg(feature = "opus")]
+    OggOpus(Box<OggOpusWriter>),
+}
+
+impl TranscodeSink {
+    fn create(
+        path: &Path,
+        settings: &TranscodeSettings,
+        sample_rate: u32,
+        source_sample_rate: u32,
+        channels: usize,
+    ) -> Result<Self, TranscodeError> {
+        match (settings.format, settings.bit_depth) {
+            (TranscodeFormat::Wav, 16 | 24 | 32) => {
+                let sample_format = match settings.bit_depth {
+                    16 => WavSampleFormat::Int16,
+                    24 => WavSampleFormat::Int24,
+                    _ => WavSampleFormat::Float32,
+                };
+                Ok(TranscodeSink::Wav(WavWriter::create_with_format(
+                    path,
+                    sample_rate,
+                    channels,
+                    sample_format,
+                )?))
+            }
+            (TranscodeFormat::Flac, 16 | 24) => Ok(TranscodeSink::Flac(Box::new(
+                FlacWriter::create(path, sample_rate, channels, settings.bit_depth)?,
+            ))),
+            #[cfg(feature = "opus")]
+            (TranscodeFormat::OggOpus, _) => {
+                let writer = OggOpusWriter::create(path, channels, settings.opus_bitrate, source_sample_rate)
+                    .map_err(|e| match e.kind() {
+                        io::ErrorKind::InvalidInput => TranscodeError::InvalidSettings(e.to_string()),
+                        _ => TranscodeError::Io(e),
+                    })?;
+                Ok(TranscodeSink::OggOpus(Box::new(writer)))
+            }
+            #[cfg(not(feature = "opus"))]
+            (TranscodeFormat::OggOpus, _) => {
+                let _ = source_sample_rate;
+                Err(TranscodeError::InvalidSettings(
+                    "Ogg/Opus encoding not available (build with the `opus` feature)".to_string(),
+                ))
+            }
+            (format, bit_depth) => Err(TranscodeError::InvalidSettings(format!(
+                "{}-bit output is not supported for {:?}",
+                bit_depth, format
+            ))),
+        }
+    }
+
+    /// Whether samples are rounded to integers, so dither applies.
+    fn is_integer_pcm(&self, bit_depth: u32) -> bool {
+        match self {
+            TranscodeSink::Wav(_) | TranscodeSink::Flac(_) => bit_depth < 32,
+            #[cfg(feature = "opus")]
+            TranscodeSink::OggOpus(_) => false,
+        }
+    }
+
+    fn write_samples(&mut self, samples: &[f32]) -> io::Result<()> {
+        match self {
+            TranscodeSink::Wav(writer) => writer.write_samples(samples),
+            TranscodeSink::Flac(writer) => writer.write_samples(samples),
+            #[cfg(feature = "opus")]
+            TranscodeSink::OggOpus(writer) => writer.write_samples(samples),
+        }
+    }
+
+    fn finalize(self) -> io::Result<()> {
+        match self {
+            TranscodeSink::Wav(writer) => writer.finalize(),
+            TranscodeSink::Flac(writer) => writer.finalize(),
+            #[cfg(feature = "opus")]
+            TranscodeSink::OggOpus(writer) => writer.finalize(),
+        }
+    }
+}
+
+/// Output sample rate for a source rate under `settings`.
+fn output_sample_rate(settings: &TranscodeSettings, source_rate: u32) -> u32 {
+    #[cfg(feature = "opus")]
+    if settings.format == TranscodeFormat::OggOpus {
+        return OPUS_SAMPLE_RATE;
+    }
+    settings.sample_rate.unwrap_or(source_rate)
+}
+
+/// Convert `input` into `output`.
+///
+/// `on_progress` receives the fraction of the file done. `cancel` is polled
+/// between packets. The file is written next to `output` and moved into
+/// place when complete, so an existing file is only replaced on success.
+pub fn transcode_file(
+    input: &Path,
+    output: &Path,
+    settings: &TranscodeSettings,
+    cancel: &AtomicBool,
+    mut on_progress: impl FnMut(f64),
+) -> Result<(), TranscodeError> {
+    if let Some(rate) = settings.sample_rate {
+        if !(8000..=384_000).contains(&rate) {
+            return Err(TranscodeError::InvalidSettings(format!(
+                "sample rate of {} Hz",
+                rate
+            )));
+        }
+    }
+    if settings.format == TranscodeFormat::OggOpus && !(6000..=510_000).contains(&settings.opus_bitrate) {
+        return Err(TranscodeError::InvalidSettings(format!(
+            "Opus bitrate of {} bit/s",
+            settings.opus_bitrate
+        )));
+    }
+
+    let temp_path = {
+        let mut name = OsString::from(output.as_os_str());
+        name.push(".part");
+        PathBuf::from(name)
+    };
+
+    let result = transcode_into(input, &temp_path, settings, cancel, &mut on_progress)
+        .and_then(|()| Ok(std::fs::rename(&temp_path, output)?));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
+fn transcode_into(
+    input: &Path,
+    temp_path: &Path,
+    settings: &TranscodeSettings,
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(f64),
+) -> Result<(), TranscodeError> {
+    let mut probe = probe_file(input).map_err(|e| TranscodeError::Decode(e.to_string()))?;
+    let source_rate = probe.source_info.original_sample_rate;
+    let channels = probe.source_info.channels;
+    let sample_rate = output_sample_rate(settings, source_rate);
+
+    let mut sink = TranscodeSink::create(temp_path, settings, sample_rate, source_rate, channels)?;
+    let mut resampler = if sample_rate != source_rate {
+        Some(
+            AudioResampler::new(source_rate, sample_rate, channels, RESAMPLE_CHUNK_FRAMES)
+                .map_err(TranscodeError::Decode)?,
+        )
+    } else {
+        None
+    };
+    let mut ditherer = sink
+        .is_integer_pcm(settings.bit_depth)
+        .then(|| Ditherer::new(settings.dither, settings.bit_depth, channels));
+
+    let total_frames = (probe.source_info.duration_secs * source_rate as f64).max(1.0);
+    let mut decoded_frames = 0u64;
+    let mut next_report = 0.0;
+
+    let mut decode_buffer = Vec::new();
+    let mut resample_buffer = Vec::new();
+    let mut write = |samples: &mut [f32], sink: &mut TranscodeSink| -> io::Result<()> {
+        if let Some(ditherer) = ditherer.as_mut() {
+            ditherer.process(samples);
+        }
+        sink.write_samples(samples)
+    };
+
+    on_progress(0.0);
+    loop {
+        if cancel.load(Ordering::Acquire) {
+            return Err(TranscodeError::Cancelled);
+        }
+        if !probe
+            .decode_next(&mut decode_buffer)
+            .map_err(|e| TranscodeError::Decode(e.to_string()))?
+        {
+            break;
+        }
+
+        decoded_frames += (decode_buffer.len() / channels) as u64;
+        match resampler.as_mut() {
+            Some(resampler) => {
+                resample_buffer.clear();
+                resampler
+                    .process_interleaved(&decode_buffer, &mut resample_buffer)
+                    .map_err(TranscodeError::Decode)?;
+                write(&mut resample_buffer, &mut sink)?;
+            }
+            None => write(&mut decode_buffer, &mut sink)?,
+        }
+
+        let fraction = (decoded_frames as f64 / total_frames).min(1.0);
+        if fraction >= next_report {
+            next_report = fraction + PROGRESS_STEP;
+            on_progress(fraction);
+        }
+    }
+
+    if let Some(resampler) = resampler.as_mut() {
+        resample_buffer.clear();
+        resampler
+            .flush(&mut resample_buffer)
+            .map_err(TranscodeError::Decode)?;
+        write(&mut resample_buffer, &mut sink)?;
+    }
+    sink.finalize()?;
+
+    if settings.copy_tags {
+        copy_tags(input, temp_path)?;
+    }
+    on_progress(1.0);
+    Ok(())
+}
+
+/// Read a file's tags, detecting its type from the content.
+fn read_tagged(path: &Path) -> Result<TaggedFile, TranscodeError> {
+    let tag_error = |e: lofty::error::LoftyError| TranscodeError::Tags(e.to_string());
+    Probe::open(path)
+        .map_err(tag_error)?
+        .guess_file_type()?
+        .read()
+        .map_err(tag_error)
+}
+
+/// Copy the primary tag of `source` to `target`, mapped to the tag format
+/// `target` prefers. Does nothing if `source` has no tags.
+fn copy_tags(source: &Path, target: &Path) -> Result<(), TranscodeError> {
+    let source_file = read_tagged(source)?;
+    let Some(tag) = source_file.primary_tag().or_else(|| source_file.first_tag()) else {
+        return Ok(());
+    };
+
+    // The target still has its `.part` name, hence the content sniffing
+    let target_file = read_tagged(target)?;
+    let mut tag = tag.clone();
+    tag.re_map(target_file.primary_tag_type());
+    tag.save_to_path(target, WriteOptions::default())
+        .map_err(|e| TranscodeError::Tags(e.to_string()))
+}
+
+/// Convert a batch of files in parallel on the rayon pool.
+///
+/// Events for different jobs interleave; each job reports `Started`, then
+/// `Progress`, then `Finished` or `Failed`. Once `cancel` is set, running
+/// jobs stop and queued ones fail with [`TranscodeError::Cancelled`].
+/// Returns one result per job, in order.
+pub fn transcode_batch(
+    jobs: &[TranscodeJob],
+    settings: &TranscodeSettings,
+    cancel: &AtomicBool,
+    on_event: impl Fn(TranscodeEvent) + Sync,
+) -> Vec<Result<(), TranscodeError>> {
+    jobs.par_iter()
+        .enumerate()
+        .map(|(index, job)| {
+            if cancel.load(Ordering::Acquire) {
+                let error = TranscodeError::Cancelled;
+                on_event(TranscodeEvent::Failed { index, message: error.to_string() });
+                return Err(error);
+            }
+
+            on_event(TranscodeEvent::Started { index });
+            let result = transcode_file(&job.input, &job.output, settings, cancel, |fraction| {
+                on_event(TranscodeEvent::Progress { index, fraction })
+            })
+            .map_err(|e| match e {
+                TranscodeError::Decode(msg) => {
+                    TranscodeError::Decode(format!("{}: {}", job.input.display(), msg))
+                }
+                other => other,
+            });
+
+            match &result {
+                Ok(()) => on_event(TranscodeEvent::Finished { index }),
+                Err(e) => on_event(TranscodeEvent::Failed { index, message: e.to_string() }),
+            }
+            result
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+    use std::sync::Mutex;
+
+    fn write_sine(path: &Path, sample_rate: u32, frames: usize) {
+        let samples: Vec<f32> = (0..frames * 2)
+            .map(|i| {
+                let t = (i / 2) as f32 / sample_rate as f32;
+                (2.0 * std::f32::consts::PI * 440.0 * t).sin() * 0.5
+            })
+            .collect();
+        let mut writer = WavWriter::create_with_format(path, sample_rate, 2, WavSampleFormat::Int16).unwrap();
+        writer.write_samples(&samples).unwrap();
+        writer.finalize().unwrap();
+    }
+
+    fn decode(path: &Path) -> (u32, Vec<f32>) {
+        let mut probe = probe_file(path).unwrap();
+        let mut samples = Vec::new();
+        let mut buffer = Vec::new();
+        while probe.decode_next(&mut buffer).unwrap() {
+            samples.extend_from_slice(&buffer);
+        }
+        (probe.source_info.original_sample_rate, samples)
+    }
+
+    #[test]
+    fn test_transcode_resamples_to_flac_and_wav() {
+        let dir = test_dir("transcode-resample");
+        let input = dir.join("in.wav");
+        write_sine(&input, 44100, 44100);
+
+        let settings = TranscodeSettings {
+            format: TranscodeFormat::Wav,
+            sample_rate: Some(48000),
+            bit_depth: 24,
+            copy_tags: false,
+            ..Default::default()
+        };
+        let output = dir.join("out.wav");
+        transcode_file(&input, &output, &settings, &AtomicBool::new(false), |_| {}).unwrap();
+
+        let (rate, samples) = decode(&output);
+        assert_eq!(rate, 48000);
+        assert_eq!(samples.len(), 48000 * 2);
+        // The sine survives resampling at the same level
+        let peak = samples[1000..samples.len() - 1000]
+            .iter()
+            .fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!((peak - 0.5).abs() < 0.01, "peak {}", peak);
+        assert!(!dir.join("out.wav.part").exists());
+
+        let flac = dir.join("out.flac");
+        let settings = TranscodeSettings {
+            format: TranscodeFormat::Flac,
+            sample_rate: None,
+            bit_depth: 16,
+            copy_tags: false,
+            ..Default::default()
+        };
+        transcode_file(&input, &flac, &settings, &AtomicBool::new(false), |_| {}).unwrap();
+        let probe = probe_file(&flac).unwrap();
+        assert_eq!(probe.source_info.original_sample_rate, 44100);
+        assert!((probe.source_info.duration_secs - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transcode_copies_tags() {
+        let dir = test_dir("transcode-tags");
+        let input = dir.join("in.wav");
+        write_sine(&input, 48000, 4800);
+
+        let mut tag = lofty::tag::Tag::new(lofty::tag::TagType::Id3v2);
+        tag.set_title("Song".to_string());
+        tag.set_artist("Artist".to_string());
+        tag.save_to_path(&input, WriteOptions::default()).unwrap();
+
+        let output = dir.join("out.flac");
+        let settings = TranscodeSettings::default();
+        transcode_file(&input, &output, &settings, &AtomicBool::new(false), |_| {}).unwrap();
+
+        let tagged = lofty::read_from_path(&output).unwrap();
+        let tag = tagged.primary_tag().unwrap();
+        assert_eq!(tag.title().as_deref(), Some("Song"));
+        assert_eq!(tag.artist().as_deref(), Some("Artist"));
+    }
+
+    #[test]
+    fn test_transcode_errors_leave_no_output() {
+        let dir = test_dir("transcode-errors");
+        let input = dir.join("in.wav");
+        write_sine(&input, 48000, 4800);
+        let output = dir.join("out.flac");
+
+        let settings = TranscodeSettings {
+            bit_depth: 32,
+            ..Default::default()
+        };
+        let result = transcode_file(&input, &output, &settings, &AtomicBool::new(false), |_| {});
+        assert!(matches!(result, Err(TranscodeError::InvalidSettings(_))));
+
+        let result = transcode_file(&input, &output, &TranscodeSettings::default(), &AtomicBool::new(true), |_| {});
+        assert!(matches!(result, Err(TranscodeError::Cancelled)));
+
+        let result = transcode_file(&dir.join("missing.wav"), &output, &TranscodeSettings::default(), &AtomicBool::new(false), |_| {});
+        assert!(matches!(result, Err(TranscodeError::Decode(_))));
+
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_batch_reports_per_file_events() {
+        let dir = test_dir("transcode-batch");
+        let mut jobs = Vec::new();
+        for i in 0..3 {
+            let input = dir.join(format!("in{}.wav", i));
+            write_sine(&input, 48000, 4800);
+            jobs.push(TranscodeJob {
+                input,
+                output: dir.join(format!("out{}.flac", i)),
+            });
+        }
+        jobs.push(TranscodeJob {
+            input: dir.join("missing.wav"),
+            output: dir.join("out_missing.flac"),
+        });
+
+        let events = Mutex::new(Vec::new());
+        let results = transcode_batch(&jobs, &TranscodeSettings::default(), &AtomicBool::new(false), |event| {
+            events.lock().unwrap().push(event)
+        });
+
+        assert!(results[..3].iter().all(|r| r.is_ok()));
+        assert!(matches!(&results[3], Err(TranscodeError::Decode(msg)) if msg.contains("missing.wav")));
+
+        let events = events.into_inner().unwrap();
+        for (index, job) in jobs.iter().enumerate().take(3) {
+            let of_job: Vec<_> = events.iter().filter(|e| event_index(e) == index).collect();
+            assert_eq!(of_job.first(), Some(&&TranscodeEvent::Started { index }));
+            assert_eq!(of_job.last(), Some(&&TranscodeEvent::Finished { index }));
+            assert!(of_job.contains(&&TranscodeEvent::Progress { index, fraction: 1.0 }));
+            assert!(job.output.exists());
+        }
+        assert!(events.iter().any(|e| matches!(e, TranscodeEvent::Failed { index: 3, .. })));
+    }
+
+    fn event_index(event: &TranscodeEvent) -> usize {
+        match event {
+            TranscodeEvent::Started { index }
+            | TranscodeEvent::Progress { index, .. }
+            | TranscodeEvent::Finished { index }
+            | TranscodeEvent::Failed { index, .. } => *index,
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Minimal RIFF/WAVE writer (and reader) for rendered engine output.
+//!
+//! By default samples are stored as interleaved 32-bit IEEE float, which is
+//! exactly what the engine produces, so a rendered file round-trips without
+//! any loss. 16- and 24-bit integer PCM are available for exports.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Size of the RIFF header up to (and including) the `data` chunk header.
+const HEADER_SIZE: u32 = 44;
+
+/// WAVE_FORMAT_PCM
+const FORMAT_PCM: u16 = 1;
+
+/// WAVE_FORMAT_IEEE_FLOAT
+const FORMAT_IEEE_FLOAT: u16 = 3;
+
+/// Sample encoding of a WAV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WavSampleFormat {
+    /// 16-bit signed integer PCM
+    Int16,
+    /// 24-bit signed integer PCM
+    Int24,
+    /// 32-bit IEEE float
+    Float32,
+}
+
+impl WavSampleFormat {
+    /// Bytes used by one sample.
+    pub fn bytes_per_sample(self) -> usize {
+        match self {
+            Self::Int16 => 2,
+            Self::Int24 => 3,
+            Self::Float32 => 4,
+        }
+    }
+}
+
+/// Streaming WAV writer.
+///
+/// The header is written with placeholder sizes and patched in
+/// [`WavWriter::finalize`], so the total length does not need to be known
+/// up front.
+pub struct WavWriter {
+    writer: BufWriter<File>,
+    channels: usize,
+    format: WavSampleFormat,
+    data_bytes: u32,
+}
+
+impl WavWriter {
+    /// Create a new 32-bit float WAV file, truncating any existing file at `path`.
+    pub fn create(path: &Path, sample_rate: u32, channels: usize) -> io::Result<Self> {
+        Self::create_with_format(path, sample_rate, channels, WavSampleFormat::Float32)
+    }
+
+    /// Create a new WAV file storing samples as `format`.
+    ///
+    /// Integer formats are quantized by rounding and clamped to full scale.
+    pub fn create_with_format(
+        path: &Path,
+        sample_rate: u32,
+        channels: usize,
+        format: WavSampleFormat,
+    ) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        let bytes_per_sample = format.bytes_per_sample() as u16;
+        let format_tag = match format {
+            WavSampleFormat::Float32 => FORMAT_IEEE_FLOAT,
+            WavSampleFormat::Int16 | WavSampleFormat::Int24 => FORMAT_PCM,
+        };
+        let block_align = bytes_per_sample * channels as u16;
+        let byte_rate = sample_rate * block_align as u32;
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&(HEADER_SIZE - 8).to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        writer.write_all(&format_tag.to_le_bytes())?;
+        writer.write_all(&(channels as u16).to_le_bytes())?;
+        writer.write_all(&sample_rate.to_le_bytes())?;
+        writer.write_all(&byte_rate.to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&(bytes_per_sample * 8).to_le_bytes())?;
+
+        writer.write_all(b"data")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+
+        Ok(Self {
+            writer,
+            channels,
+            format,
+            data_bytes: 0,
+        })
+    }
+
+    /// Number of interleaved channels in the file.
+    #[inline]
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Sample encoding of the file.
+    #[inline]
+    pub fn format(&self) -> WavSampleFormat {
+        self.format
+    }
+
+    /// Number of complete frames written so far.
+    pub fn frames_written(&self) -> u64 {
+        self.data_bytes as u64 / (self.format.bytes_per_sample() * self.channels) as u64
+    }
+
+    /// Append interleaved samples.
+    pub fn write_samples(&mut self, samples: &[f32]) -> io::Result<()> {
+        for &sample in samples {
+            match self.format {
+                WavSampleFormat::Float32 => self.writer.write_all(&sample.to_le_bytes())?,
+                WavSampleFormat::Int16 => {
+                    let value = quantize(sample, 16) as i16;
+                    self.writer.write_all(&value.to_le_bytes())?;
+                }
+                WavSampleFormat::Int24 => {
+                    let value = quantize(sample, 24);
+                    self.writer.write_all(&value.to_le_bytes()[..3])?;
+                }
+            }
+        }
+        let bytes = samples.len() * self.format.bytes_per_sample();
+        self.data_bytes = self.data_bytes.saturating_add(bytes as u32);
+        Ok(())
+    }
+
+    /// Patch the chunk sizes and flush the file to disk.
+    pub fn finalize(mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        let file = self.writer.get_mut();
+
+        file.seek(SeekFrom::Start(4))?;
+        file.write_all(&(HEADER_SIZE - 8 + self.data_bytes).to_le_bytes())?;
+        file.seek(SeekFrom::Start(40))?;
+        file.write_all(&self.data_bytes.to_le_bytes())?;
+        file.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Reader for 32-bit float WAV files as produced by [`WavWriter::create`].
+pub struct WavReader {
+    reader: BufReader<File>,
+    sample_rate: u32,
+    channels: usize,
+    remaining_bytes: u64,
+}
+
+impl WavReader {
+    /// Open a 32-bit float WAV file and position it at the first sample.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let invalid = |what: &str| io::Error::new(io::ErrorKind::InvalidData, what.to_string());
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut riff = [0u8; 12];
+        reader.read_exact(&mut riff)?;
+        if &riff[0..4] != b"RIFF" || &riff[8..12] != b"WAVE" {
+            return Err(invalid("Not a RIFF/WAVE file"));
+        }
+
+        let mut format = None;
+        loop {
+            let mut chunk = [0u8; 8];
+            reader.read_exact(&mut chunk)?;
+            let size = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+
+            match &chunk[0..4] {
+                b"fmt " => {
+                    let mut fmt = vec![0u8; size as usize];
+                    reader.read_exact(&mut fmt)?;
+                    if fmt.len() < 16 {
+                        return Err(invalid("Truncated fmt chunk"));
+                    }
+                    let tag = u16::from_le_bytes([fmt[0], fmt[1]]);
+                    let channels = u16::from_le_bytes([fmt[2], fmt[3]]) as usize;
+                    let sample_rate = u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]);
+                    let bits = u16::from_le_bytes([fmt[14], fmt[15]]);
+                    if tag != FORMAT_IEEE_FLOAT || bits != 32 || channels == 0 {
+                        return Err(invalid("Only 32-bit float WAV files are supported"));
+                    }
+                    format = Some((sample_rate, channels));
+                }
+                b"data" => {
+                    let (sample_rate, channels) =
+                        format.ok_or_else(|| invalid("data chunk before fmt chunk"))?;
+                    return Ok(Self {
+                        reader,
+                        sample_rate,
+                        channels,
+                        remaining_bytes: size as u64,
+                    });
+                }
+                _ => {
+                    // Chunks are padded to an even size
+                    reader.seek(SeekFrom::Current(size as i64 + (size & 1) as i64))?;
+                }
+            }
+        }
+    }
+
+    /// Sample rate of the file in Hz.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Number of interleaved channels in the file.
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Read interleaved samples into `output`.
+    ///
+    /// Returns the number of samples read, which is 0 at the end of the data.
+    pub fn read_samples(&mut self, output: &mut [f32]) -> io::Result<usize> {
+        let count = output.len().min((self.remaining_bytes / 4) as usize);
+        let mut bytes = [0u8; 4];
+        for sample in &mut output[..count] {
+            self.reader.read_exact(&mut bytes)?;
+            *sample = f32::from_le_bytes(bytes);
+        }
+        self.remaining_bytes -= count as u64 * 4;
+        Ok(count)
+    }
+}
+
+/// Convert a float sample to a signed integer of `bits` bits, rounding to
+/// the nearest step and clamping to full scale.
+#[inline]
+pub fn quantize(sample: f32, bits: u32) -> i32 {
+    let max = ((1i64 << (bits - 1)) - 1) as f64;
+    let scaled = (sample as f64 * (max + 1.0)).round();
+    scaled.clamp(-(max + 1.0), max) as i32
+}
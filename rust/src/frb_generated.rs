@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1169394639;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1313141232;
 
 // Section: executor
 
@@ -45,6 +45,76 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__scanner__album_palette_from_audio_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "album_palette_from_audio_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_audio_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::scanner::album_palette_from_audio_file(api_audio_path),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__album_palette_from_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "album_palette_from_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_image_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::scanner::album_palette_from_image(api_image_path),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__audio_api__audio_get_channels_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -104,6 +174,36 @@ fn wire__crate__api__audio_api__audio_get_current_path_impl(
         },
     )
 }
+fn wire__crate__api__audio_api__audio_get_output_format_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_get_output_format",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::audio_api::audio_get_output_format())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__audio_api__audio_get_playback_speed_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -310,6 +410,38 @@ fn wire__crate__api__audio_api__audio_is_native_available_impl(
         },
     )
 }
+fn wire__crate__api__audio_api__audio_next_chapter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_next_chapter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_next_chapter()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__audio_api__audio_pause_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -375,6 +507,45 @@ fn wire__crate__api__audio_api__audio_play_impl(
         },
     )
 }
+fn wire__crate__api__audio_api__audio_play_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_play_range",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_start_secs = <f64>::sse_decode(&mut deserializer);
+            let api_end_secs = <Option<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_play_range(
+                        api_path,
+                        api_start_secs,
+                        api_end_secs,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__audio_api__audio_poll_event_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -404,7 +575,7 @@ fn wire__crate__api__audio_api__audio_poll_event_impl(
         },
     )
 }
-fn wire__crate__api__audio_api__audio_queue_next_impl(
+fn wire__crate__api__audio_api__audio_previous_chapter_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -412,7 +583,7 @@ fn wire__crate__api__audio_api__audio_queue_next_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_queue_next",
+            debug_name: "audio_previous_chapter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -426,18 +597,17 @@ fn wire__crate__api__audio_api__audio_queue_next_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_queue_next(api_path)?;
+                    let output_ok = crate::api::audio_api::audio_previous_chapter()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio_api__audio_resume_impl(
+fn wire__crate__api__audio_api__audio_queue_next_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -445,7 +615,7 @@ fn wire__crate__api__audio_api__audio_resume_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_resume",
+            debug_name: "audio_queue_next",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -459,17 +629,18 @@ fn wire__crate__api__audio_api__audio_resume_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_resume()?;
+                    let output_ok = crate::api::audio_api::audio_queue_next(api_path)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio_api__audio_seek_impl(
+fn wire__crate__api__audio_api__audio_queue_next_range_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -477,7 +648,7 @@ fn wire__crate__api__audio_api__audio_seek_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_seek",
+            debug_name: "audio_queue_next_range",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -491,28 +662,33 @@ fn wire__crate__api__audio_api__audio_seek_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_position_secs = <f64>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_start_secs = <f64>::sse_decode(&mut deserializer);
+            let api_end_secs = <Option<f64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_seek(api_position_secs)?;
+                    let output_ok = crate::api::audio_api::audio_queue_next_range(
+                        api_path,
+                        api_start_secs,
+                        api_end_secs,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio_api__audio_set_crossfade_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__audio_api__audio_render_cancel_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_set_crossfade",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "audio_render_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -524,20 +700,17 @@ fn wire__crate__api__audio_api__audio_set_crossfade_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_enabled = <bool>::sse_decode(&mut deserializer);
-            let api_duration_secs = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::audio_api::audio_set_crossfade(api_enabled, api_duration_secs)?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::audio_api::audio_render_cancel();
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__audio_api__audio_set_crossfade_curve_impl(
+fn wire__crate__api__audio_api__audio_render_to_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -545,7 +718,7 @@ fn wire__crate__api__audio_api__audio_set_crossfade_curve_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_set_crossfade_curve",
+            debug_name: "audio_render_to_file",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -559,19 +732,33 @@ fn wire__crate__api__audio_api__audio_set_crossfade_curve_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api__curve =
-                <crate::api::audio_api::CrossfadeCurveType>::sse_decode(&mut deserializer);
+            let api_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_settings =
+                <crate::api::audio_api::AudioRenderSettings>::sse_decode(&mut deserializer);
+            let api_out_path = <String>::sse_decode(&mut deserializer);
+            let api_format =
+                <crate::api::audio_api::RenderFileFormat>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::audio_api::AudioRenderProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_set_crossfade_curve(api__curve)?;
+                    let output_ok = crate::api::audio_api::audio_render_to_file(
+                        api_paths,
+                        api_settings,
+                        api_out_path,
+                        api_format,
+                        api_sink,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio_api__audio_set_playback_speed_impl(
+fn wire__crate__api__audio_api__audio_resume_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -579,7 +766,7 @@ fn wire__crate__api__audio_api__audio_set_playback_speed_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_set_playback_speed",
+            debug_name: "audio_resume",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -593,18 +780,17 @@ fn wire__crate__api__audio_api__audio_set_playback_speed_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_speed = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_set_playback_speed(api_speed)?;
+                    let output_ok = crate::api::audio_api::audio_resume()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio_api__audio_set_volume_impl(
+fn wire__crate__api__audio_api__audio_seek_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -612,7 +798,7 @@ fn wire__crate__api__audio_api__audio_set_volume_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_set_volume",
+            debug_name: "audio_seek",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -626,18 +812,18 @@ fn wire__crate__api__audio_api__audio_set_volume_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_volume = <f32>::sse_decode(&mut deserializer);
+            let api_position_secs = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_set_volume(api_volume)?;
+                    let output_ok = crate::api::audio_api::audio_seek(api_position_secs)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio_api__audio_shutdown_impl(
+fn wire__crate__api__audio_api__audio_set_crossfade_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -645,7 +831,7 @@ fn wire__crate__api__audio_api__audio_shutdown_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_shutdown",
+            debug_name: "audio_set_crossfade",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -659,17 +845,20 @@ fn wire__crate__api__audio_api__audio_shutdown_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_duration_secs = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_shutdown()?;
+                    let output_ok =
+                        crate::api::audio_api::audio_set_crossfade(api_enabled, api_duration_secs)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio_api__audio_skip_to_next_impl(
+fn wire__crate__api__audio_api__audio_set_crossfade_curve_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -677,7 +866,7 @@ fn wire__crate__api__audio_api__audio_skip_to_next_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_skip_to_next",
+            debug_name: "audio_set_crossfade_curve",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -691,17 +880,19 @@ fn wire__crate__api__audio_api__audio_skip_to_next_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api__curve =
+                <crate::api::audio_api::CrossfadeCurveType>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_skip_to_next()?;
+                    let output_ok = crate::api::audio_api::audio_set_crossfade_curve(api__curve)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__audio_api__audio_stop_impl(
+fn wire__crate__api__audio_api__audio_set_dither_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -709,7 +900,7 @@ fn wire__crate__api__audio_api__audio_stop_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "audio_stop",
+            debug_name: "audio_set_dither",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -723,26 +914,28 @@ fn wire__crate__api__audio_api__audio_stop_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dither = <crate::api::audio_api::DitherType>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::audio_api::audio_stop()?;
+                    let output_ok = crate::api::audio_api::audio_set_dither(api_dither)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__audio_api__audio_set_playback_speed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "audio_set_playback_speed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -754,16 +947,18 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_speed = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_set_playback_speed(api_speed)?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__audio_api__audio_set_resume_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -771,7 +966,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "audio_set_resume",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -785,19 +980,22 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_auto_resume = <bool>::sse_decode(&mut deserializer);
+            let api_min_duration_secs = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_set_resume(
+                        api_auto_resume,
+                        api_min_duration_secs,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__scanner__scan_root_dir_impl(
+fn wire__crate__api__audio_api__audio_set_volume_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -805,7 +1003,7 @@ fn wire__crate__api__scanner__scan_root_dir_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "scan_root_dir",
+            debug_name: "audio_set_volume",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -819,678 +1017,6417 @@ fn wire__crate__api__scanner__scan_root_dir_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_root_path = <String>::sse_decode(&mut deserializer);
-            let api_known_files =
-                <std::collections::HashMap<String, i64>>::sse_decode(&mut deserializer);
+            let api_volume = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::scanner::scan_root_dir(
-                        api_root_path,
-                        api_known_files,
-                    ))?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_set_volume(api_volume)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for std::collections::HashMap<String, i64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, i64)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for crate::api::audio_api::AudioEventType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_state = <String>::sse_decode(deserializer);
-                return crate::api::audio_api::AudioEventType::StateChanged { state: var_state };
-            }
-            1 => {
-                let mut var_positionSecs = <f64>::sse_decode(deserializer);
-                let mut var_durationSecs = <Option<f64>>::sse_decode(deserializer);
-                let mut var_bufferLevel = <f32>::sse_decode(deserializer);
-                return crate::api::audio_api::AudioEventType::Progress {
-                    position_secs: var_positionSecs,
-                    duration_secs: var_durationSecs,
-                    buffer_level: var_bufferLevel,
-                };
-            }
-            2 => {
-                let mut var_path = <String>::sse_decode(deserializer);
-                return crate::api::audio_api::AudioEventType::TrackEnded { path: var_path };
+fn wire__crate__api__audio_api__audio_shutdown_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_shutdown",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_shutdown()?;
+                    Ok(output_ok)
+                })())
             }
-            3 => {
-                let mut var_fromPath = <String>::sse_decode(deserializer);
-                let mut var_toPath = <String>::sse_decode(deserializer);
-                return crate::api::audio_api::AudioEventType::CrossfadeStarted {
-                    from_path: var_fromPath,
-                    to_path: var_toPath,
-                };
+        },
+    )
+}
+fn wire__crate__api__audio_api__audio_skip_to_next_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_skip_to_next",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_skip_to_next()?;
+                    Ok(output_ok)
+                })())
             }
-            4 => {
-                let mut var_message = <String>::sse_decode(deserializer);
-                return crate::api::audio_api::AudioEventType::Error {
-                    message: var_message,
-                };
+        },
+    )
+}
+fn wire__crate__api__audio_api__audio_stop_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_stop",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_stop()?;
+                    Ok(output_ok)
+                })())
             }
-            5 => {
-                let mut var_path = <String>::sse_decode(deserializer);
-                return crate::api::audio_api::AudioEventType::NextTrackReady { path: var_path };
+        },
+    )
+}
+fn wire__crate__api__audio_api__audio_transcode_cancel_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_transcode_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::audio_api::audio_transcode_cancel();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__audio_api__audio_transcode_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_transcode_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_jobs =
+                <Vec<crate::api::audio_api::AudioTranscodeJob>>::sse_decode(&mut deserializer);
+            let api_settings =
+                <crate::api::audio_api::AudioTranscodeSettings>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::audio_api::AudioTranscodeEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::audio_api::audio_transcode_files(
+                        api_jobs,
+                        api_settings,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
-            _ => {
-                unimplemented!("");
+        },
+    )
+}
+fn wire__crate__api__resume__clear_resume_position_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_resume_position",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::resume::clear_resume_position(api_path)?;
+                    Ok(output_ok)
+                })())
             }
+        },
+    )
+}
+fn wire__crate__api__scanner__find_duplicates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_duplicates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_fingerprint_index = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::scanner::find_duplicates(api_paths, api_fingerprint_index)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chapters__get_chapters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_chapters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chapters::get_chapters(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lyrics__get_lyrics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_lyrics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::lyrics::get_lyrics(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__resume__get_resume_position_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_resume_position",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::resume::get_resume_position(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_add_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_add_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_add_folder(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_albums_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_albums",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::library::GroupQuery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_albums(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_artists_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_artists",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::library::GroupQuery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_artists(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_default_group_query_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_default_group_query",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::library::library_default_group_query())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__library__library_default_grouping_options_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_default_grouping_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::library::library_default_grouping_options())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__library__library_default_song_query_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_default_song_query",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::library::library_default_song_query())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__library__library_folders_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_folders",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_folders()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_genres_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_genres",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::library::GroupQuery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_genres(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_grouping_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_grouping_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_grouping_options()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_open_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_open",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_db_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_open(api_db_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_record_play_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_record_play",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_record_play(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_remove_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_remove_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_remove_folder(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_scan_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_scan_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_path = <String>::sse_decode(&mut deserializer);
+            let api_art_cache_dir = <Option<String>>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::scanner::ScanOptions>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::scanner::ScanStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_scan_folder(
+                        api_root_path,
+                        api_art_cache_dir,
+                        api_options,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_search_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_search",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_search(api_query, api_limit)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_set_favorite_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_set_favorite",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_favorite = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::library::library_set_favorite(api_path, api_favorite)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_set_grouping_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_set_grouping_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_options = <crate::api::library::GroupingOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_set_grouping_options(api_options)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_song_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_song",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_song(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_songs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_songs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::library::SongQuery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_songs(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__library__library_watch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "library_watch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_art_cache_dir = <Option<String>>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::scanner::ScanOptions>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::scanner::WatchEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::library::library_watch(
+                        api_art_cache_dir,
+                        api_options,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__lyrics__lyrics_active_line_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lyrics_active_line",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_lyrics = <crate::api::lyrics::Lyrics>::sse_decode(&mut deserializer);
+            let api_position_secs = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::lyrics::lyrics_active_line(
+                    api_lyrics,
+                    api_position_secs,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__playlists__playlist_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "playlist_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::playlists::Playlist::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__playlists__playlist_entry_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "playlist_entry_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::playlists::PlaylistEntry::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__playlists__playlist_export_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "playlist_export",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_song_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_relative_paths = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::playlists::playlist_export(
+                        api_path,
+                        api_name,
+                        api_song_paths,
+                        api_relative_paths,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__playlists__playlist_import_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "playlist_import",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::playlists::playlist_import(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__playlists__playlist_read_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "playlist_read",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::playlists::playlist_read(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__playlists__playlist_write_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "playlist_write",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_playlist = <crate::api::playlists::Playlist>::sse_decode(&mut deserializer);
+            let api_relative_paths = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::playlists::playlist_write(
+                        api_path,
+                        api_playlist,
+                        api_relative_paths,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__scan_cancel_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::scanner::scan_cancel();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__scanner__scan_default_options_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_default_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::scanner::scan_default_options())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__scanner__scan_root_dir_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_root_dir",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_path = <String>::sse_decode(&mut deserializer);
+            let api_known_files =
+                <std::collections::HashMap<String, i64>>::sse_decode(&mut deserializer);
+            let api_art_cache_dir = <Option<String>>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::scanner::ScanOptions>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::scanner::scan_root_dir(
+                        api_root_path,
+                        api_known_files,
+                        api_art_cache_dir,
+                        api_options,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__scan_root_dir_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_root_dir_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_path = <String>::sse_decode(&mut deserializer);
+            let api_known_files =
+                <std::collections::HashMap<String, i64>>::sse_decode(&mut deserializer);
+            let api_art_cache_dir = <Option<String>>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::scanner::ScanOptions>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::scanner::ScanStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::scanner::scan_root_dir_stream(
+                        api_root_path,
+                        api_known_files,
+                        api_art_cache_dir,
+                        api_options,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__scan_watch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_watch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_roots = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_art_cache_dir = <Option<String>>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::api::scanner::ScanOptions>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::scanner::WatchEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::scanner::scan_watch(
+                        api_roots,
+                        api_art_cache_dir,
+                        api_options,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__scan_watch_stop_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_watch_stop",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::scanner::scan_watch_stop();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__smart_playlists__smart_playlist_default_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "smart_playlist_default",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::smart_playlists::smart_playlist_default())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__smart_playlists__smart_playlist_from_json_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "smart_playlist_from_json",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::smart_playlists::smart_playlist_from_json(api_json)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__smart_playlists__smart_playlist_songs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "smart_playlist_songs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_playlist =
+                <crate::api::smart_playlists::SmartPlaylist>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::smart_playlists::smart_playlist_songs(api_playlist)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__smart_playlists__smart_playlist_to_json_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "smart_playlist_to_json",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_playlist =
+                <crate::api::smart_playlists::SmartPlaylist>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::smart_playlists::smart_playlist_to_json(api_playlist),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__smart_playlists__smart_playlist_unwatch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "smart_playlist_unwatch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::smart_playlists::smart_playlist_unwatch(api_key)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__smart_playlists__smart_playlist_watch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "smart_playlist_watch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_playlist =
+                <crate::api::smart_playlists::SmartPlaylist>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                Vec<crate::api::library::LibrarySong>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::smart_playlists::smart_playlist_watch(
+                        api_key,
+                        api_playlist,
+                        api_sink,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__tag_changes_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tag_changes_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::tags::TagChanges::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__tags_default_changes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tags_default_changes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::tags::tags_default_changes())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__tags__write_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_changes = <crate::api::tags::TagChanges>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::tags::write_tags(api_path, api_changes)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tags__write_tags_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_tags_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_changes = <crate::api::tags::TagChanges>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::tags::write_tags_batch(
+                        api_paths,
+                        api_changes,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, i64)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::audio_api::AudioRenderProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::audio_api::AudioTranscodeEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::api::library::LibrarySong>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::scanner::ScanStreamEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::scanner::WatchEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::library::AlbumPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_albums = <Vec<crate::api::library::LibraryAlbum>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::library::AlbumPage {
+            albums: var_albums,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::AlbumPalette {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dominant = <u32>::sse_decode(deserializer);
+        let mut var_vibrant = <u32>::sse_decode(deserializer);
+        let mut var_muted = <u32>::sse_decode(deserializer);
+        let mut var_text = <u32>::sse_decode(deserializer);
+        let mut var_secondaryText = <u32>::sse_decode(deserializer);
+        return crate::api::scanner::AlbumPalette {
+            dominant: var_dominant,
+            vibrant: var_vibrant,
+            muted: var_muted,
+            text: var_text,
+            secondary_text: var_secondaryText,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::AudioEventType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_state = <String>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::StateChanged { state: var_state };
+            }
+            1 => {
+                let mut var_positionSecs = <f64>::sse_decode(deserializer);
+                let mut var_durationSecs = <Option<f64>>::sse_decode(deserializer);
+                let mut var_bufferLevel = <f32>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::Progress {
+                    position_secs: var_positionSecs,
+                    duration_secs: var_durationSecs,
+                    buffer_level: var_bufferLevel,
+                };
+            }
+            2 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::TrackEnded { path: var_path };
+            }
+            3 => {
+                let mut var_fromPath = <String>::sse_decode(deserializer);
+                let mut var_toPath = <String>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::CrossfadeStarted {
+                    from_path: var_fromPath,
+                    to_path: var_toPath,
+                };
+            }
+            4 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::Error {
+                    message: var_message,
+                };
+            }
+            5 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::NextTrackReady { path: var_path };
+            }
+            6 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                let mut var_lineIndex = <Option<u32>>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::LyricsLineChanged {
+                    path: var_path,
+                    line_index: var_lineIndex,
+                };
+            }
+            7 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                let mut var_chapterIndex = <Option<u32>>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::ChapterChanged {
+                    path: var_path,
+                    chapter_index: var_chapterIndex,
+                };
+            }
+            8 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                let mut var_positionSecs = <f64>::sse_decode(deserializer);
+                return crate::api::audio_api::AudioEventType::Resumed {
+                    path: var_path,
+                    position_secs: var_positionSecs,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scanner::AudioFileMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumArtist = <Option<String>>::sse_decode(deserializer);
+        let mut var_trackNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_trackTotal = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discTotal = <Option<u32>>::sse_decode(deserializer);
+        let mut var_year = <Option<u32>>::sse_decode(deserializer);
+        let mut var_genre = <Option<String>>::sse_decode(deserializer);
+        let mut var_composer = <Option<String>>::sse_decode(deserializer);
+        let mut var_compilation = <bool>::sse_decode(deserializer);
+        let mut var_durationSecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_sampleRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bitDepth = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bitrateKbps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_channels = <Option<u32>>::sse_decode(deserializer);
+        let mut var_hasLyrics = <bool>::sse_decode(deserializer);
+        let mut var_musicbrainzRecordingId = <Option<String>>::sse_decode(deserializer);
+        let mut var_musicbrainzReleaseId = <Option<String>>::sse_decode(deserializer);
+        let mut var_musicbrainzReleaseGroupId = <Option<String>>::sse_decode(deserializer);
+        let mut var_musicbrainzArtistId = <Option<String>>::sse_decode(deserializer);
+        let mut var_musicbrainzReleaseArtistId = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumArtPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumPalette =
+            <Option<crate::api::scanner::AlbumPalette>>::sse_decode(deserializer);
+        let mut var_format = <String>::sse_decode(deserializer);
+        let mut var_lastModified = <i64>::sse_decode(deserializer);
+        let mut var_range = <Option<crate::api::scanner::TrackRange>>::sse_decode(deserializer);
+        return crate::api::scanner::AudioFileMetadata {
+            path: var_path,
+            title: var_title,
+            artist: var_artist,
+            album: var_album,
+            album_artist: var_albumArtist,
+            track_number: var_trackNumber,
+            track_total: var_trackTotal,
+            disc_number: var_discNumber,
+            disc_total: var_discTotal,
+            year: var_year,
+            genre: var_genre,
+            composer: var_composer,
+            compilation: var_compilation,
+            duration_secs: var_durationSecs,
+            sample_rate: var_sampleRate,
+            bit_depth: var_bitDepth,
+            bitrate_kbps: var_bitrateKbps,
+            channels: var_channels,
+            has_lyrics: var_hasLyrics,
+            musicbrainz_recording_id: var_musicbrainzRecordingId,
+            musicbrainz_release_id: var_musicbrainzReleaseId,
+            musicbrainz_release_group_id: var_musicbrainzReleaseGroupId,
+            musicbrainz_artist_id: var_musicbrainzArtistId,
+            musicbrainz_release_artist_id: var_musicbrainzReleaseArtistId,
+            album_art_path: var_albumArtPath,
+            album_palette: var_albumPalette,
+            format: var_format,
+            last_modified: var_lastModified,
+            range: var_range,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::AudioOutputFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sampleFormat =
+            <crate::api::audio_api::OutputSampleFormatType>::sse_decode(deserializer);
+        let mut var_bitsPerSample = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sampleRate = <u32>::sse_decode(deserializer);
+        let mut var_channels = <u32>::sse_decode(deserializer);
+        let mut var_dither = <crate::api::audio_api::DitherType>::sse_decode(deserializer);
+        return crate::api::audio_api::AudioOutputFormat {
+            sample_format: var_sampleFormat,
+            bits_per_sample: var_bitsPerSample,
+            sample_rate: var_sampleRate,
+            channels: var_channels,
+            dither: var_dither,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::AudioProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_positionSecs = <f64>::sse_decode(deserializer);
+        let mut var_durationSecs = <Option<f64>>::sse_decode(deserializer);
+        let mut var_bufferLevel = <f32>::sse_decode(deserializer);
+        return crate::api::audio_api::AudioProgress {
+            position_secs: var_positionSecs,
+            duration_secs: var_durationSecs,
+            buffer_level: var_bufferLevel,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::AudioRenderProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phase = <crate::api::audio_api::RenderPhaseType>::sse_decode(deserializer);
+        let mut var_trackIndex = <u32>::sse_decode(deserializer);
+        let mut var_trackCount = <u32>::sse_decode(deserializer);
+        let mut var_positionSecs = <f64>::sse_decode(deserializer);
+        let mut var_totalSecs = <f64>::sse_decode(deserializer);
+        return crate::api::audio_api::AudioRenderProgress {
+            phase: var_phase,
+            track_index: var_trackIndex,
+            track_count: var_trackCount,
+            position_secs: var_positionSecs,
+            total_secs: var_totalSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::AudioRenderSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_crossfadeSecs = <f32>::sse_decode(deserializer);
+        let mut var_crossfadeCurve =
+            <crate::api::audio_api::CrossfadeCurveType>::sse_decode(deserializer);
+        let mut var_volume = <f32>::sse_decode(deserializer);
+        let mut var_eqPreampDb = <f32>::sse_decode(deserializer);
+        let mut var_eqBands = <Vec<crate::api::audio_api::EqualizerBand>>::sse_decode(deserializer);
+        let mut var_normalize = <bool>::sse_decode(deserializer);
+        let mut var_bitDepth = <u32>::sse_decode(deserializer);
+        return crate::api::audio_api::AudioRenderSettings {
+            crossfade_secs: var_crossfadeSecs,
+            crossfade_curve: var_crossfadeCurve,
+            volume: var_volume,
+            eq_preamp_db: var_eqPreampDb,
+            eq_bands: var_eqBands,
+            normalize: var_normalize,
+            bit_depth: var_bitDepth,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::AudioTranscodeEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_status = <crate::api::audio_api::TranscodeStatusType>::sse_decode(deserializer);
+        let mut var_fraction = <f64>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        return crate::api::audio_api::AudioTranscodeEvent {
+            index: var_index,
+            status: var_status,
+            fraction: var_fraction,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::AudioTranscodeJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPath = <String>::sse_decode(deserializer);
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        return crate::api::audio_api::AudioTranscodeJob {
+            input_path: var_inputPath,
+            output_path: var_outputPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::AudioTranscodeSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_format = <crate::api::audio_api::TranscodeFileFormat>::sse_decode(deserializer);
+        let mut var_sampleRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bitDepth = <u32>::sse_decode(deserializer);
+        let mut var_dither = <crate::api::audio_api::DitherType>::sse_decode(deserializer);
+        let mut var_opusBitrate = <u32>::sse_decode(deserializer);
+        let mut var_copyTags = <bool>::sse_decode(deserializer);
+        return crate::api::audio_api::AudioTranscodeSettings {
+            format: var_format,
+            sample_rate: var_sampleRate,
+            bit_depth: var_bitDepth,
+            dither: var_dither,
+            opus_bitrate: var_opusBitrate,
+            copy_tags: var_copyTags,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::chapters::Chapter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_startSecs = <f64>::sse_decode(deserializer);
+        let mut var_endSecs = <f64>::sse_decode(deserializer);
+        return crate::api::chapters::Chapter {
+            title: var_title,
+            start_secs: var_startSecs,
+            end_secs: var_endSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::CrossfadeCurveType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio_api::CrossfadeCurveType::EqualPower,
+            1 => crate::api::audio_api::CrossfadeCurveType::Linear,
+            2 => crate::api::audio_api::CrossfadeCurveType::SquareRoot,
+            3 => crate::api::audio_api::CrossfadeCurveType::SCurve,
+            _ => unreachable!("Invalid variant for CrossfadeCurveType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::DitherType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio_api::DitherType::None,
+            1 => crate::api::audio_api::DitherType::Triangular,
+            2 => crate::api::audio_api::DitherType::NoiseShaped,
+            _ => unreachable!("Invalid variant for DitherType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::DuplicateFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_format = <String>::sse_decode(deserializer);
+        let mut var_lossless = <bool>::sse_decode(deserializer);
+        let mut var_bitrateKbps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sampleRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bitDepth = <Option<u32>>::sse_decode(deserializer);
+        let mut var_durationSecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_similarity = <f32>::sse_decode(deserializer);
+        return crate::api::scanner::DuplicateFile {
+            path: var_path,
+            format: var_format,
+            lossless: var_lossless,
+            bitrate_kbps: var_bitrateKbps,
+            sample_rate: var_sampleRate,
+            bit_depth: var_bitDepth,
+            duration_secs: var_durationSecs,
+            similarity: var_similarity,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::DuplicateGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_files = <Vec<crate::api::scanner::DuplicateFile>>::sse_decode(deserializer);
+        let mut var_keepPath = <String>::sse_decode(deserializer);
+        return crate::api::scanner::DuplicateGroup {
+            files: var_files,
+            keep_path: var_keepPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::DuplicateReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_groups = <Vec<crate::api::scanner::DuplicateGroup>>::sse_decode(deserializer);
+        let mut var_failed = <Vec<crate::api::scanner::ScanFailure>>::sse_decode(deserializer);
+        return crate::api::scanner::DuplicateReport {
+            groups: var_groups,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::EqualizerBand {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frequencyHz = <f32>::sse_decode(deserializer);
+        let mut var_gainDb = <f32>::sse_decode(deserializer);
+        let mut var_q = <f32>::sse_decode(deserializer);
+        return crate::api::audio_api::EqualizerBand {
+            frequency_hz: var_frequencyHz,
+            gain_db: var_gainDb,
+            q: var_q,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::library::GroupPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_groups = <Vec<crate::api::library::LibraryGroup>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::library::GroupPage {
+            groups: var_groups,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::GroupQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_search = <Option<String>>::sse_decode(deserializer);
+        let mut var_folder = <Option<String>>::sse_decode(deserializer);
+        let mut var_sort = <crate::api::library::GroupSortType>::sse_decode(deserializer);
+        let mut var_descending = <bool>::sse_decode(deserializer);
+        let mut var_offset = <u32>::sse_decode(deserializer);
+        let mut var_limit = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::library::GroupQuery {
+            search: var_search,
+            folder: var_folder,
+            sort: var_sort,
+            descending: var_descending,
+            offset: var_offset,
+            limit: var_limit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::GroupSortType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::library::GroupSortType::Name,
+            1 => crate::api::library::GroupSortType::SongCount,
+            2 => crate::api::library::GroupSortType::Year,
+            3 => crate::api::library::GroupSortType::DateAdded,
+            _ => unreachable!("Invalid variant for GroupSortType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::GroupingOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_artistSeparators = <Vec<String>>::sse_decode(deserializer);
+        let mut var_articles = <Vec<String>>::sse_decode(deserializer);
+        let mut var_variousArtists = <String>::sse_decode(deserializer);
+        return crate::api::library::GroupingOptions {
+            artist_separators: var_artistSeparators,
+            articles: var_articles,
+            various_artists: var_variousArtists,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::library::LibraryAlbum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_compilation = <bool>::sse_decode(deserializer);
+        let mut var_year = <Option<u32>>::sse_decode(deserializer);
+        let mut var_songCount = <u32>::sse_decode(deserializer);
+        let mut var_discCount = <u32>::sse_decode(deserializer);
+        let mut var_durationSecs = <u64>::sse_decode(deserializer);
+        let mut var_albumArtPath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::library::LibraryAlbum {
+            key: var_key,
+            name: var_name,
+            artist: var_artist,
+            compilation: var_compilation,
+            year: var_year,
+            song_count: var_songCount,
+            disc_count: var_discCount,
+            duration_secs: var_durationSecs,
+            album_art_path: var_albumArtPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::LibraryFolder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_songCount = <u32>::sse_decode(deserializer);
+        let mut var_addedAt = <i64>::sse_decode(deserializer);
+        let mut var_scannedAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::library::LibraryFolder {
+            path: var_path,
+            song_count: var_songCount,
+            added_at: var_addedAt,
+            scanned_at: var_scannedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::LibraryGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_songCount = <u32>::sse_decode(deserializer);
+        let mut var_albumCount = <u32>::sse_decode(deserializer);
+        let mut var_durationSecs = <u64>::sse_decode(deserializer);
+        let mut var_albumArtPath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::library::LibraryGroup {
+            key: var_key,
+            name: var_name,
+            song_count: var_songCount,
+            album_count: var_albumCount,
+            duration_secs: var_durationSecs,
+            album_art_path: var_albumArtPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::LibrarySong {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_folder = <String>::sse_decode(deserializer);
+        let mut var_displayTitle = <String>::sse_decode(deserializer);
+        let mut var_dateAdded = <i64>::sse_decode(deserializer);
+        let mut var_playCount = <u32>::sse_decode(deserializer);
+        let mut var_lastPlayed = <Option<i64>>::sse_decode(deserializer);
+        let mut var_favorite = <bool>::sse_decode(deserializer);
+        let mut var_albumKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_artistKeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_metadata = <crate::api::scanner::AudioFileMetadata>::sse_decode(deserializer);
+        return crate::api::library::LibrarySong {
+            id: var_id,
+            folder: var_folder,
+            display_title: var_displayTitle,
+            date_added: var_dateAdded,
+            play_count: var_playCount,
+            last_played: var_lastPlayed,
+            favorite: var_favorite,
+            album_key: var_albumKey,
+            artist_keys: var_artistKeys,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scanner::AudioFileMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scanner::AudioFileMetadata>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::audio_api::AudioTranscodeJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::audio_api::AudioTranscodeJob>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chapters::Chapter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chapters::Chapter>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scanner::DuplicateFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scanner::DuplicateFile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scanner::DuplicateGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scanner::DuplicateGroup>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::audio_api::EqualizerBand> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::audio_api::EqualizerBand>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::library::LibraryAlbum> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::library::LibraryAlbum>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::library::LibraryFolder> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::library::LibraryFolder>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::library::LibraryGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::library::LibraryGroup>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::library::LibrarySong> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::library::LibrarySong>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::lyrics::LyricsLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::lyrics::LyricsLine>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::lyrics::LyricsWord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::lyrics::LyricsWord>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::playlists::PlaylistEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::playlists::PlaylistEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, i64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, i64)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scanner::ScanFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scanner::ScanFailure>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scanner::ScanMove> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scanner::ScanMove>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::smart_playlists::SmartCondition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::smart_playlists::SmartCondition>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::smart_playlists::SmartRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::smart_playlists::SmartRule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::tags::TagField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::tags::TagField>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::tags::TagWriteFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::tags::TagWriteFailure>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::playlists::UnresolvedEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::playlists::UnresolvedEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::lyrics::Lyrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <crate::api::lyrics::LyricsSourceType>::sse_decode(deserializer);
+        let mut var_synced = <bool>::sse_decode(deserializer);
+        let mut var_lines = <Vec<crate::api::lyrics::LyricsLine>>::sse_decode(deserializer);
+        return crate::api::lyrics::Lyrics {
+            source: var_source,
+            synced: var_synced,
+            lines: var_lines,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lyrics::LyricsLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_words = <Vec<crate::api::lyrics::LyricsWord>>::sse_decode(deserializer);
+        return crate::api::lyrics::LyricsLine {
+            start_ms: var_startMs,
+            text: var_text,
+            words: var_words,
+        };
+    }
+}
+
+impl SseDecode for crate::api::lyrics::LyricsSourceType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::lyrics::LyricsSourceType::Sidecar,
+            1 => crate::api::lyrics::LyricsSourceType::SyncedTag,
+            2 => crate::api::lyrics::LyricsSourceType::Tag,
+            _ => unreachable!("Invalid variant for LyricsSourceType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::lyrics::LyricsWord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <u32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        return crate::api::lyrics::LyricsWord {
+            start_ms: var_startMs,
+            text: var_text,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::scanner::AlbumPalette> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::scanner::AlbumPalette>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::audio_api::AudioEventType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::audio_api::AudioEventType>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::audio_api::AudioOutputFormat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::audio_api::AudioOutputFormat>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::audio_api::AudioProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::audio_api::AudioProgress>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::library::LibrarySong> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::library::LibrarySong>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::lyrics::Lyrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::lyrics::Lyrics>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::scanner::ScanOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::scanner::ScanOptions>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::scanner::TrackRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::scanner::TrackRange>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::audio_api::OutputSampleFormatType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio_api::OutputSampleFormatType::I16,
+            1 => crate::api::audio_api::OutputSampleFormatType::I24,
+            2 => crate::api::audio_api::OutputSampleFormatType::I32,
+            3 => crate::api::audio_api::OutputSampleFormatType::F32,
+            _ => unreachable!("Invalid variant for OutputSampleFormatType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::playlists::Playlist {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_entries = <Vec<crate::api::playlists::PlaylistEntry>>::sse_decode(deserializer);
+        return crate::api::playlists::Playlist {
+            name: var_name,
+            entries: var_entries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::playlists::PlaylistEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_location = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_durationSecs = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::playlists::PlaylistEntry {
+            location: var_location,
+            title: var_title,
+            artist: var_artist,
+            album: var_album,
+            duration_secs: var_durationSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::playlists::PlaylistImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_paths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_unresolved =
+            <Vec<crate::api::playlists::UnresolvedEntry>>::sse_decode(deserializer);
+        return crate::api::playlists::PlaylistImport {
+            name: var_name,
+            paths: var_paths,
+            unresolved: var_unresolved,
+        };
+    }
+}
+
+impl SseDecode for (String, i64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <i64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::audio_api::RenderFileFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio_api::RenderFileFormat::Wav,
+            1 => crate::api::audio_api::RenderFileFormat::Flac,
+            _ => unreachable!("Invalid variant for RenderFileFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::RenderPhaseType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio_api::RenderPhaseType::Mixing,
+            1 => crate::api::audio_api::RenderPhaseType::Normalizing,
+            _ => unreachable!("Invalid variant for RenderPhaseType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanEventType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scanner::ScanEventType::Batch,
+            1 => crate::api::scanner::ScanEventType::Finished,
+            2 => crate::api::scanner::ScanEventType::Cancelled,
+            _ => unreachable!("Invalid variant for ScanEventType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_reason = <crate::api::scanner::ScanFailureType>::sse_decode(deserializer);
+        let mut var_detail = <String>::sse_decode(deserializer);
+        return crate::api::scanner::ScanFailure {
+            path: var_path,
+            reason: var_reason,
+            detail: var_detail,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanFailureType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scanner::ScanFailureType::Io,
+            1 => crate::api::scanner::ScanFailureType::UnsupportedContainer,
+            2 => crate::api::scanner::ScanFailureType::BadTag,
+            3 => crate::api::scanner::ScanFailureType::Corrupt,
+            4 => crate::api::scanner::ScanFailureType::ZeroDuration,
+            _ => unreachable!("Invalid variant for ScanFailureType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanMove {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_oldPath = <String>::sse_decode(deserializer);
+        let mut var_newPath = <String>::sse_decode(deserializer);
+        return crate::api::scanner::ScanMove {
+            old_path: var_oldPath,
+            new_path: var_newPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_extensions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_sniffContent = <bool>::sse_decode(deserializer);
+        let mut var_excludeGlobs = <Vec<String>>::sse_decode(deserializer);
+        let mut var_honorMarkerFiles = <bool>::sse_decode(deserializer);
+        let mut var_minDurationSecs = <u32>::sse_decode(deserializer);
+        let mut var_minFileSize = <u32>::sse_decode(deserializer);
+        let mut var_maxDepth = <Option<u32>>::sse_decode(deserializer);
+        let mut var_followSymlinks = <bool>::sse_decode(deserializer);
+        let mut var_fingerprintIndex = <Option<String>>::sse_decode(deserializer);
+        return crate::api::scanner::ScanOptions {
+            extensions: var_extensions,
+            sniff_content: var_sniffContent,
+            exclude_globs: var_excludeGlobs,
+            honor_marker_files: var_honorMarkerFiles,
+            min_duration_secs: var_minDurationSecs,
+            min_file_size: var_minFileSize,
+            max_depth: var_maxDepth,
+            follow_symlinks: var_followSymlinks,
+            fingerprint_index: var_fingerprintIndex,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_newOrModified =
+            <Vec<crate::api::scanner::AudioFileMetadata>>::sse_decode(deserializer);
+        let mut var_deletedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_moved = <Vec<crate::api::scanner::ScanMove>>::sse_decode(deserializer);
+        let mut var_failed = <Vec<crate::api::scanner::ScanFailure>>::sse_decode(deserializer);
+        return crate::api::scanner::ScanResult {
+            new_or_modified: var_newOrModified,
+            deleted_paths: var_deletedPaths,
+            moved: var_moved,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::ScanStreamEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::scanner::ScanEventType>::sse_decode(deserializer);
+        let mut var_files = <Vec<crate::api::scanner::AudioFileMetadata>>::sse_decode(deserializer);
+        let mut var_failures = <Vec<crate::api::scanner::ScanFailure>>::sse_decode(deserializer);
+        let mut var_deletedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_moved = <Vec<crate::api::scanner::ScanMove>>::sse_decode(deserializer);
+        let mut var_discovered = <u32>::sse_decode(deserializer);
+        let mut var_processed = <u32>::sse_decode(deserializer);
+        let mut var_failed = <u32>::sse_decode(deserializer);
+        return crate::api::scanner::ScanStreamEvent {
+            kind: var_kind,
+            files: var_files,
+            failures: var_failures,
+            deleted_paths: var_deletedPaths,
+            moved: var_moved,
+            discovered: var_discovered,
+            processed: var_processed,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::smart_playlists::SmartCondition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::api::smart_playlists::SmartField>::sse_decode(deserializer);
+        let mut var_operator_ =
+            <crate::api::smart_playlists::SmartOperator>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_number = <f64>::sse_decode(deserializer);
+        return crate::api::smart_playlists::SmartCondition {
+            field: var_field,
+            operator: var_operator_,
+            text: var_text,
+            number: var_number,
+        };
+    }
+}
+
+impl SseDecode for crate::api::smart_playlists::SmartField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::smart_playlists::SmartField::Title,
+            1 => crate::api::smart_playlists::SmartField::Artist,
+            2 => crate::api::smart_playlists::SmartField::Album,
+            3 => crate::api::smart_playlists::SmartField::AlbumArtist,
+            4 => crate::api::smart_playlists::SmartField::Genre,
+            5 => crate::api::smart_playlists::SmartField::Composer,
+            6 => crate::api::smart_playlists::SmartField::Format,
+            7 => crate::api::smart_playlists::SmartField::Path,
+            8 => crate::api::smart_playlists::SmartField::Year,
+            9 => crate::api::smart_playlists::SmartField::TrackNumber,
+            10 => crate::api::smart_playlists::SmartField::DiscNumber,
+            11 => crate::api::smart_playlists::SmartField::DurationSecs,
+            12 => crate::api::smart_playlists::SmartField::SampleRate,
+            13 => crate::api::smart_playlists::SmartField::BitDepth,
+            14 => crate::api::smart_playlists::SmartField::BitrateKbps,
+            15 => crate::api::smart_playlists::SmartField::Channels,
+            16 => crate::api::smart_playlists::SmartField::PlayCount,
+            17 => crate::api::smart_playlists::SmartField::DateAdded,
+            18 => crate::api::smart_playlists::SmartField::LastPlayed,
+            19 => crate::api::smart_playlists::SmartField::LastModified,
+            20 => crate::api::smart_playlists::SmartField::Favorite,
+            21 => crate::api::smart_playlists::SmartField::Compilation,
+            22 => crate::api::smart_playlists::SmartField::HasLyrics,
+            _ => unreachable!("Invalid variant for SmartField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::smart_playlists::SmartOperator {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::smart_playlists::SmartOperator::Is,
+            1 => crate::api::smart_playlists::SmartOperator::IsNot,
+            2 => crate::api::smart_playlists::SmartOperator::Contains,
+            3 => crate::api::smart_playlists::SmartOperator::DoesNotContain,
+            4 => crate::api::smart_playlists::SmartOperator::StartsWith,
+            5 => crate::api::smart_playlists::SmartOperator::EndsWith,
+            6 => crate::api::smart_playlists::SmartOperator::GreaterThan,
+            7 => crate::api::smart_playlists::SmartOperator::LessThan,
+            8 => crate::api::smart_playlists::SmartOperator::Before,
+            9 => crate::api::smart_playlists::SmartOperator::After,
+            10 => crate::api::smart_playlists::SmartOperator::InTheLast,
+            11 => crate::api::smart_playlists::SmartOperator::NotInTheLast,
+            12 => crate::api::smart_playlists::SmartOperator::IsTrue,
+            13 => crate::api::smart_playlists::SmartOperator::IsFalse,
+            14 => crate::api::smart_playlists::SmartOperator::IsEmpty,
+            15 => crate::api::smart_playlists::SmartOperator::IsNotEmpty,
+            _ => unreachable!("Invalid variant for SmartOperator: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::smart_playlists::SmartPlaylist {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rule = <crate::api::smart_playlists::SmartRule>::sse_decode(deserializer);
+        let mut var_sort = <crate::api::smart_playlists::SmartSortType>::sse_decode(deserializer);
+        let mut var_descending = <bool>::sse_decode(deserializer);
+        let mut var_limit = <Option<u32>>::sse_decode(deserializer);
+        let mut var_durationLimitSecs = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::smart_playlists::SmartPlaylist {
+            rule: var_rule,
+            sort: var_sort,
+            descending: var_descending,
+            limit: var_limit,
+            duration_limit_secs: var_durationLimitSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::smart_playlists::SmartRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_matchAll = <bool>::sse_decode(deserializer);
+        let mut var_conditions =
+            <Vec<crate::api::smart_playlists::SmartCondition>>::sse_decode(deserializer);
+        let mut var_groups =
+            <Vec<crate::api::smart_playlists::SmartRule>>::sse_decode(deserializer);
+        return crate::api::smart_playlists::SmartRule {
+            match_all: var_matchAll,
+            conditions: var_conditions,
+            groups: var_groups,
+        };
+    }
+}
+
+impl SseDecode for crate::api::smart_playlists::SmartSortType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::smart_playlists::SmartSortType::Title,
+            1 => crate::api::smart_playlists::SmartSortType::Artist,
+            2 => crate::api::smart_playlists::SmartSortType::Album,
+            3 => crate::api::smart_playlists::SmartSortType::Year,
+            4 => crate::api::smart_playlists::SmartSortType::Duration,
+            5 => crate::api::smart_playlists::SmartSortType::DateAdded,
+            6 => crate::api::smart_playlists::SmartSortType::PlayCount,
+            7 => crate::api::smart_playlists::SmartSortType::LastPlayed,
+            8 => crate::api::smart_playlists::SmartSortType::Random,
+            _ => unreachable!("Invalid variant for SmartSortType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::SongPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_songs = <Vec<crate::api::library::LibrarySong>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::library::SongPage {
+            songs: var_songs,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::SongQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_search = <Option<String>>::sse_decode(deserializer);
+        let mut var_folder = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_genre = <Option<String>>::sse_decode(deserializer);
+        let mut var_sort = <crate::api::library::SongSortType>::sse_decode(deserializer);
+        let mut var_descending = <bool>::sse_decode(deserializer);
+        let mut var_offset = <u32>::sse_decode(deserializer);
+        let mut var_limit = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::library::SongQuery {
+            search: var_search,
+            folder: var_folder,
+            artist: var_artist,
+            album: var_album,
+            album_key: var_albumKey,
+            genre: var_genre,
+            sort: var_sort,
+            descending: var_descending,
+            offset: var_offset,
+            limit: var_limit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::library::SongSortType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::library::SongSortType::Title,
+            1 => crate::api::library::SongSortType::Artist,
+            2 => crate::api::library::SongSortType::Album,
+            3 => crate::api::library::SongSortType::Year,
+            4 => crate::api::library::SongSortType::Duration,
+            5 => crate::api::library::SongSortType::DateAdded,
+            6 => crate::api::library::SongSortType::Path,
+            _ => unreachable!("Invalid variant for SongSortType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::tags::TagChanges {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_artist = <Option<String>>::sse_decode(deserializer);
+        let mut var_album = <Option<String>>::sse_decode(deserializer);
+        let mut var_albumArtist = <Option<String>>::sse_decode(deserializer);
+        let mut var_trackNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_trackTotal = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discNumber = <Option<u32>>::sse_decode(deserializer);
+        let mut var_discTotal = <Option<u32>>::sse_decode(deserializer);
+        let mut var_year = <Option<u32>>::sse_decode(deserializer);
+        let mut var_genre = <Option<String>>::sse_decode(deserializer);
+        let mut var_lyrics = <Option<String>>::sse_decode(deserializer);
+        let mut var_coverArt = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_remove = <Vec<crate::api::tags::TagField>>::sse_decode(deserializer);
+        return crate::api::tags::TagChanges {
+            title: var_title,
+            artist: var_artist,
+            album: var_album,
+            album_artist: var_albumArtist,
+            track_number: var_trackNumber,
+            track_total: var_trackTotal,
+            disc_number: var_discNumber,
+            disc_total: var_discTotal,
+            year: var_year,
+            genre: var_genre,
+            lyrics: var_lyrics,
+            cover_art: var_coverArt,
+            remove: var_remove,
+        };
+    }
+}
+
+impl SseDecode for crate::api::tags::TagField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::tags::TagField::Title,
+            1 => crate::api::tags::TagField::Artist,
+            2 => crate::api::tags::TagField::Album,
+            3 => crate::api::tags::TagField::AlbumArtist,
+            4 => crate::api::tags::TagField::TrackNumber,
+            5 => crate::api::tags::TagField::TrackTotal,
+            6 => crate::api::tags::TagField::DiscNumber,
+            7 => crate::api::tags::TagField::DiscTotal,
+            8 => crate::api::tags::TagField::Year,
+            9 => crate::api::tags::TagField::Genre,
+            10 => crate::api::tags::TagField::Lyrics,
+            11 => crate::api::tags::TagField::CoverArt,
+            _ => unreachable!("Invalid variant for TagField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::tags::TagWriteFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::tags::TagWriteFailure {
+            path: var_path,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scanner::TrackRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourcePath = <String>::sse_decode(deserializer);
+        let mut var_startSecs = <f64>::sse_decode(deserializer);
+        let mut var_endSecs = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::scanner::TrackRange {
+            source_path: var_sourcePath,
+            start_secs: var_startSecs,
+            end_secs: var_endSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::TranscodeFileFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio_api::TranscodeFileFormat::Wav,
+            1 => crate::api::audio_api::TranscodeFileFormat::Flac,
+            2 => crate::api::audio_api::TranscodeFileFormat::OggOpus,
+            _ => unreachable!("Invalid variant for TranscodeFileFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::audio_api::TranscodeStatusType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audio_api::TranscodeStatusType::Started,
+            1 => crate::api::audio_api::TranscodeStatusType::Progress,
+            2 => crate::api::audio_api::TranscodeStatusType::Finished,
+            3 => crate::api::audio_api::TranscodeStatusType::Failed,
+            _ => unreachable!("Invalid variant for TranscodeStatusType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::playlists::UnresolvedEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_entry = <crate::api::playlists::PlaylistEntry>::sse_decode(deserializer);
+        return crate::api::playlists::UnresolvedEntry {
+            index: var_index,
+            entry: var_entry,
+        };
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::scanner::WatchEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_added = <Vec<crate::api::scanner::AudioFileMetadata>>::sse_decode(deserializer);
+        let mut var_modified =
+            <Vec<crate::api::scanner::AudioFileMetadata>>::sse_decode(deserializer);
+        let mut var_removedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_moved = <Vec<crate::api::scanner::ScanMove>>::sse_decode(deserializer);
+        let mut var_failures = <Vec<crate::api::scanner::ScanFailure>>::sse_decode(deserializer);
+        let mut var_rescanNeeded = <bool>::sse_decode(deserializer);
+        return crate::api::scanner::WatchEvent {
+            added: var_added,
+            modified: var_modified,
+            removed_paths: var_removedPaths,
+            moved: var_moved,
+            failures: var_failures,
+            rescan_needed: var_rescanNeeded,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__scanner__album_palette_from_audio_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__scanner__album_palette_from_image_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => {
+            wire__crate__api__audio_api__audio_next_chapter_impl(port, ptr, rust_vec_len, data_len)
+        }
+        14 => wire__crate__api__audio_api__audio_pause_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__audio_api__audio_play_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__audio_api__audio_play_range_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__audio_api__audio_previous_chapter_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__audio_api__audio_queue_next_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__audio_api__audio_queue_next_range_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__audio_api__audio_render_to_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__audio_api__audio_resume_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__audio_api__audio_seek_impl(port, ptr, rust_vec_len, data_len),
+        25 => {
+            wire__crate__api__audio_api__audio_set_crossfade_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => wire__crate__api__audio_api__audio_set_crossfade_curve_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__audio_api__audio_set_dither_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__audio_api__audio_set_playback_speed_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__audio_api__audio_set_resume_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__audio_api__audio_set_volume_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__audio_api__audio_shutdown_impl(port, ptr, rust_vec_len, data_len),
+        32 => {
+            wire__crate__api__audio_api__audio_skip_to_next_impl(port, ptr, rust_vec_len, data_len)
+        }
+        33 => wire__crate__api__audio_api__audio_stop_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__audio_api__audio_transcode_files_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => {
+            wire__crate__api__resume__clear_resume_position_impl(port, ptr, rust_vec_len, data_len)
+        }
+        37 => wire__crate__api__scanner__find_duplicates_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__chapters__get_chapters_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__lyrics__get_lyrics_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__resume__get_resume_position_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__library__library_add_folder_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__library__library_albums_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__library__library_artists_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__library__library_folders_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__library__library_genres_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__library__library_grouping_options_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => wire__crate__api__library__library_open_impl(port, ptr, rust_vec_len, data_len),
+        53 => {
+            wire__crate__api__library__library_record_play_impl(port, ptr, rust_vec_len, data_len)
+        }
+        54 => {
+            wire__crate__api__library__library_remove_folder_impl(port, ptr, rust_vec_len, data_len)
+        }
+        55 => {
+            wire__crate__api__library__library_scan_folder_impl(port, ptr, rust_vec_len, data_len)
+        }
+        56 => wire__crate__api__library__library_search_impl(port, ptr, rust_vec_len, data_len),
+        57 => {
+            wire__crate__api__library__library_set_favorite_impl(port, ptr, rust_vec_len, data_len)
+        }
+        58 => wire__crate__api__library__library_set_grouping_options_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        59 => wire__crate__api__library__library_song_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__library__library_songs_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__library__library_watch_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__playlists__playlist_default_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__playlists__playlist_entry_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => wire__crate__api__playlists__playlist_export_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__playlists__playlist_import_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__playlists__playlist_read_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__playlists__playlist_write_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__scanner__scan_root_dir_impl(port, ptr, rust_vec_len, data_len),
+        72 => {
+            wire__crate__api__scanner__scan_root_dir_stream_impl(port, ptr, rust_vec_len, data_len)
+        }
+        73 => wire__crate__api__scanner__scan_watch_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__smart_playlists__smart_playlist_songs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        79 => wire__crate__api__smart_playlists__smart_playlist_unwatch_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        80 => wire__crate__api__smart_playlists__smart_playlist_watch_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => wire__crate__api__tags__tag_changes_default_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__tags__write_tags_impl(port, ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__tags__write_tags_batch_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        3 => wire__crate__api__audio_api__audio_get_channels_impl(ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__audio_api__audio_get_current_path_impl(ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__audio_api__audio_get_output_format_impl(ptr, rust_vec_len, data_len),
+        6 => {
+            wire__crate__api__audio_api__audio_get_playback_speed_impl(ptr, rust_vec_len, data_len)
+        }
+        7 => wire__crate__api__audio_api__audio_get_progress_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__audio_api__audio_get_sample_rate_impl(ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__audio_api__audio_get_state_impl(ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__audio_api__audio_init_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__audio_api__audio_is_initialized_impl(ptr, rust_vec_len, data_len),
+        12 => {
+            wire__crate__api__audio_api__audio_is_native_available_impl(ptr, rust_vec_len, data_len)
+        }
+        17 => wire__crate__api__audio_api__audio_poll_event_impl(ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__audio_api__audio_render_cancel_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__audio_api__audio_transcode_cancel_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        46 => {
+            wire__crate__api__library__library_default_group_query_impl(ptr, rust_vec_len, data_len)
+        }
+        47 => wire__crate__api__library__library_default_grouping_options_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => {
+            wire__crate__api__library__library_default_song_query_impl(ptr, rust_vec_len, data_len)
+        }
+        62 => wire__crate__api__lyrics__lyrics_active_line_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__scanner__scan_cancel_impl(ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__scanner__scan_default_options_impl(ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__scanner__scan_watch_stop_impl(ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__smart_playlists__smart_playlist_default_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => wire__crate__api__smart_playlists__smart_playlist_from_json_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        78 => wire__crate__api__smart_playlists__smart_playlist_to_json_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => wire__crate__api__tags__tags_default_changes_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::AlbumPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.albums.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::AlbumPage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::AlbumPage>
+    for crate::api::library::AlbumPage
+{
+    fn into_into_dart(self) -> crate::api::library::AlbumPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::AlbumPalette {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.dominant.into_into_dart().into_dart(),
+            self.vibrant.into_into_dart().into_dart(),
+            self.muted.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.secondary_text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::AlbumPalette
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::AlbumPalette>
+    for crate::api::scanner::AlbumPalette
+{
+    fn into_into_dart(self) -> crate::api::scanner::AlbumPalette {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioEventType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::audio_api::AudioEventType::StateChanged { state } => {
+                [0.into_dart(), state.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::audio_api::AudioEventType::Progress {
+                position_secs,
+                duration_secs,
+                buffer_level,
+            } => [
+                1.into_dart(),
+                position_secs.into_into_dart().into_dart(),
+                duration_secs.into_into_dart().into_dart(),
+                buffer_level.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::audio_api::AudioEventType::TrackEnded { path } => {
+                [2.into_dart(), path.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::audio_api::AudioEventType::CrossfadeStarted { from_path, to_path } => [
+                3.into_dart(),
+                from_path.into_into_dart().into_dart(),
+                to_path.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::audio_api::AudioEventType::Error { message } => {
+                [4.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::audio_api::AudioEventType::NextTrackReady { path } => {
+                [5.into_dart(), path.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::audio_api::AudioEventType::LyricsLineChanged { path, line_index } => [
+                6.into_dart(),
+                path.into_into_dart().into_dart(),
+                line_index.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::audio_api::AudioEventType::ChapterChanged {
+                path,
+                chapter_index,
+            } => [
+                7.into_dart(),
+                path.into_into_dart().into_dart(),
+                chapter_index.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::audio_api::AudioEventType::Resumed {
+                path,
+                position_secs,
+            } => [
+                8.into_dart(),
+                path.into_into_dart().into_dart(),
+                position_secs.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::AudioEventType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioEventType>
+    for crate::api::audio_api::AudioEventType
+{
+    fn into_into_dart(self) -> crate::api::audio_api::AudioEventType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::AudioFileMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.album_artist.into_into_dart().into_dart(),
+            self.track_number.into_into_dart().into_dart(),
+            self.track_total.into_into_dart().into_dart(),
+            self.disc_number.into_into_dart().into_dart(),
+            self.disc_total.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+            self.genre.into_into_dart().into_dart(),
+            self.composer.into_into_dart().into_dart(),
+            self.compilation.into_into_dart().into_dart(),
+            self.duration_secs.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+            self.bitrate_kbps.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.has_lyrics.into_into_dart().into_dart(),
+            self.musicbrainz_recording_id.into_into_dart().into_dart(),
+            self.musicbrainz_release_id.into_into_dart().into_dart(),
+            self.musicbrainz_release_group_id
+                .into_into_dart()
+                .into_dart(),
+            self.musicbrainz_artist_id.into_into_dart().into_dart(),
+            self.musicbrainz_release_artist_id
+                .into_into_dart()
+                .into_dart(),
+            self.album_art_path.into_into_dart().into_dart(),
+            self.album_palette.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.last_modified.into_into_dart().into_dart(),
+            self.range.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::AudioFileMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::AudioFileMetadata>
+    for crate::api::scanner::AudioFileMetadata
+{
+    fn into_into_dart(self) -> crate::api::scanner::AudioFileMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioOutputFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sample_format.into_into_dart().into_dart(),
+            self.bits_per_sample.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.dither.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::AudioOutputFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioOutputFormat>
+    for crate::api::audio_api::AudioOutputFormat
+{
+    fn into_into_dart(self) -> crate::api::audio_api::AudioOutputFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.position_secs.into_into_dart().into_dart(),
+            self.duration_secs.into_into_dart().into_dart(),
+            self.buffer_level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::AudioProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioProgress>
+    for crate::api::audio_api::AudioProgress
+{
+    fn into_into_dart(self) -> crate::api::audio_api::AudioProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioRenderProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase.into_into_dart().into_dart(),
+            self.track_index.into_into_dart().into_dart(),
+            self.track_count.into_into_dart().into_dart(),
+            self.position_secs.into_into_dart().into_dart(),
+            self.total_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::AudioRenderProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioRenderProgress>
+    for crate::api::audio_api::AudioRenderProgress
+{
+    fn into_into_dart(self) -> crate::api::audio_api::AudioRenderProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioRenderSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.crossfade_secs.into_into_dart().into_dart(),
+            self.crossfade_curve.into_into_dart().into_dart(),
+            self.volume.into_into_dart().into_dart(),
+            self.eq_preamp_db.into_into_dart().into_dart(),
+            self.eq_bands.into_into_dart().into_dart(),
+            self.normalize.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::AudioRenderSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioRenderSettings>
+    for crate::api::audio_api::AudioRenderSettings
+{
+    fn into_into_dart(self) -> crate::api::audio_api::AudioRenderSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioTranscodeEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.fraction.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::AudioTranscodeEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioTranscodeEvent>
+    for crate::api::audio_api::AudioTranscodeEvent
+{
+    fn into_into_dart(self) -> crate::api::audio_api::AudioTranscodeEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioTranscodeJob {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.input_path.into_into_dart().into_dart(),
+            self.output_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::AudioTranscodeJob
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioTranscodeJob>
+    for crate::api::audio_api::AudioTranscodeJob
+{
+    fn into_into_dart(self) -> crate::api::audio_api::AudioTranscodeJob {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioTranscodeSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.format.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+            self.dither.into_into_dart().into_dart(),
+            self.opus_bitrate.into_into_dart().into_dart(),
+            self.copy_tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::AudioTranscodeSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioTranscodeSettings>
+    for crate::api::audio_api::AudioTranscodeSettings
+{
+    fn into_into_dart(self) -> crate::api::audio_api::AudioTranscodeSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chapters::Chapter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.start_secs.into_into_dart().into_dart(),
+            self.end_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::chapters::Chapter {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chapters::Chapter>
+    for crate::api::chapters::Chapter
+{
+    fn into_into_dart(self) -> crate::api::chapters::Chapter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::CrossfadeCurveType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::EqualPower => 0.into_dart(),
+            Self::Linear => 1.into_dart(),
+            Self::SquareRoot => 2.into_dart(),
+            Self::SCurve => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::CrossfadeCurveType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::CrossfadeCurveType>
+    for crate::api::audio_api::CrossfadeCurveType
+{
+    fn into_into_dart(self) -> crate::api::audio_api::CrossfadeCurveType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::DitherType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Triangular => 1.into_dart(),
+            Self::NoiseShaped => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::DitherType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::DitherType>
+    for crate::api::audio_api::DitherType
+{
+    fn into_into_dart(self) -> crate::api::audio_api::DitherType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::DuplicateFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.lossless.into_into_dart().into_dart(),
+            self.bitrate_kbps.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+            self.duration_secs.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::DuplicateFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::DuplicateFile>
+    for crate::api::scanner::DuplicateFile
+{
+    fn into_into_dart(self) -> crate::api::scanner::DuplicateFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::DuplicateGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.files.into_into_dart().into_dart(),
+            self.keep_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::DuplicateGroup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::DuplicateGroup>
+    for crate::api::scanner::DuplicateGroup
+{
+    fn into_into_dart(self) -> crate::api::scanner::DuplicateGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::DuplicateReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.groups.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::DuplicateReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::DuplicateReport>
+    for crate::api::scanner::DuplicateReport
+{
+    fn into_into_dart(self) -> crate::api::scanner::DuplicateReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::EqualizerBand {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frequency_hz.into_into_dart().into_dart(),
+            self.gain_db.into_into_dart().into_dart(),
+            self.q.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::EqualizerBand
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::EqualizerBand>
+    for crate::api::audio_api::EqualizerBand
+{
+    fn into_into_dart(self) -> crate::api::audio_api::EqualizerBand {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::GroupPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.groups.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::GroupPage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::GroupPage>
+    for crate::api::library::GroupPage
+{
+    fn into_into_dart(self) -> crate::api::library::GroupPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::GroupQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.search.into_into_dart().into_dart(),
+            self.folder.into_into_dart().into_dart(),
+            self.sort.into_into_dart().into_dart(),
+            self.descending.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::GroupQuery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::GroupQuery>
+    for crate::api::library::GroupQuery
+{
+    fn into_into_dart(self) -> crate::api::library::GroupQuery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::GroupSortType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Name => 0.into_dart(),
+            Self::SongCount => 1.into_dart(),
+            Self::Year => 2.into_dart(),
+            Self::DateAdded => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::GroupSortType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::GroupSortType>
+    for crate::api::library::GroupSortType
+{
+    fn into_into_dart(self) -> crate::api::library::GroupSortType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::GroupingOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.artist_separators.into_into_dart().into_dart(),
+            self.articles.into_into_dart().into_dart(),
+            self.various_artists.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::GroupingOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::GroupingOptions>
+    for crate::api::library::GroupingOptions
+{
+    fn into_into_dart(self) -> crate::api::library::GroupingOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::LibraryAlbum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.compilation.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+            self.song_count.into_into_dart().into_dart(),
+            self.disc_count.into_into_dart().into_dart(),
+            self.duration_secs.into_into_dart().into_dart(),
+            self.album_art_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::LibraryAlbum
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::LibraryAlbum>
+    for crate::api::library::LibraryAlbum
+{
+    fn into_into_dart(self) -> crate::api::library::LibraryAlbum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::LibraryFolder {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.song_count.into_into_dart().into_dart(),
+            self.added_at.into_into_dart().into_dart(),
+            self.scanned_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::LibraryFolder
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::LibraryFolder>
+    for crate::api::library::LibraryFolder
+{
+    fn into_into_dart(self) -> crate::api::library::LibraryFolder {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::LibraryGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.song_count.into_into_dart().into_dart(),
+            self.album_count.into_into_dart().into_dart(),
+            self.duration_secs.into_into_dart().into_dart(),
+            self.album_art_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::LibraryGroup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::LibraryGroup>
+    for crate::api::library::LibraryGroup
+{
+    fn into_into_dart(self) -> crate::api::library::LibraryGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::LibrarySong {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.folder.into_into_dart().into_dart(),
+            self.display_title.into_into_dart().into_dart(),
+            self.date_added.into_into_dart().into_dart(),
+            self.play_count.into_into_dart().into_dart(),
+            self.last_played.into_into_dart().into_dart(),
+            self.favorite.into_into_dart().into_dart(),
+            self.album_key.into_into_dart().into_dart(),
+            self.artist_keys.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::LibrarySong
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::LibrarySong>
+    for crate::api::library::LibrarySong
+{
+    fn into_into_dart(self) -> crate::api::library::LibrarySong {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::Lyrics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.synced.into_into_dart().into_dart(),
+            self.lines.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::lyrics::Lyrics {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::Lyrics> for crate::api::lyrics::Lyrics {
+    fn into_into_dart(self) -> crate::api::lyrics::Lyrics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::LyricsLine {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.words.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::lyrics::LyricsLine
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::LyricsLine>
+    for crate::api::lyrics::LyricsLine
+{
+    fn into_into_dart(self) -> crate::api::lyrics::LyricsLine {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::LyricsSourceType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Sidecar => 0.into_dart(),
+            Self::SyncedTag => 1.into_dart(),
+            Self::Tag => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::lyrics::LyricsSourceType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::LyricsSourceType>
+    for crate::api::lyrics::LyricsSourceType
+{
+    fn into_into_dart(self) -> crate::api::lyrics::LyricsSourceType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lyrics::LyricsWord {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::lyrics::LyricsWord
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lyrics::LyricsWord>
+    for crate::api::lyrics::LyricsWord
+{
+    fn into_into_dart(self) -> crate::api::lyrics::LyricsWord {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::OutputSampleFormatType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::I16 => 0.into_dart(),
+            Self::I24 => 1.into_dart(),
+            Self::I32 => 2.into_dart(),
+            Self::F32 => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::OutputSampleFormatType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::OutputSampleFormatType>
+    for crate::api::audio_api::OutputSampleFormatType
+{
+    fn into_into_dart(self) -> crate::api::audio_api::OutputSampleFormatType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::playlists::Playlist {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::playlists::Playlist
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::playlists::Playlist>
+    for crate::api::playlists::Playlist
+{
+    fn into_into_dart(self) -> crate::api::playlists::Playlist {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::playlists::PlaylistEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.location.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.duration_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::playlists::PlaylistEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::playlists::PlaylistEntry>
+    for crate::api::playlists::PlaylistEntry
+{
+    fn into_into_dart(self) -> crate::api::playlists::PlaylistEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::playlists::PlaylistImport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.paths.into_into_dart().into_dart(),
+            self.unresolved.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::playlists::PlaylistImport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::playlists::PlaylistImport>
+    for crate::api::playlists::PlaylistImport
+{
+    fn into_into_dart(self) -> crate::api::playlists::PlaylistImport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::RenderFileFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Wav => 0.into_dart(),
+            Self::Flac => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::RenderFileFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::RenderFileFormat>
+    for crate::api::audio_api::RenderFileFormat
+{
+    fn into_into_dart(self) -> crate::api::audio_api::RenderFileFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::RenderPhaseType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mixing => 0.into_dart(),
+            Self::Normalizing => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::RenderPhaseType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::RenderPhaseType>
+    for crate::api::audio_api::RenderPhaseType
+{
+    fn into_into_dart(self) -> crate::api::audio_api::RenderPhaseType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanEventType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Batch => 0.into_dart(),
+            Self::Finished => 1.into_dart(),
+            Self::Cancelled => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanEventType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanEventType>
+    for crate::api::scanner::ScanEventType
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanEventType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanFailure {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanFailure
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanFailure>
+    for crate::api::scanner::ScanFailure
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanFailure {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanFailureType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Io => 0.into_dart(),
+            Self::UnsupportedContainer => 1.into_dart(),
+            Self::BadTag => 2.into_dart(),
+            Self::Corrupt => 3.into_dart(),
+            Self::ZeroDuration => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanFailureType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanFailureType>
+    for crate::api::scanner::ScanFailureType
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanFailureType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanMove {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.old_path.into_into_dart().into_dart(),
+            self.new_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scanner::ScanMove {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanMove>
+    for crate::api::scanner::ScanMove
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanMove {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.extensions.into_into_dart().into_dart(),
+            self.sniff_content.into_into_dart().into_dart(),
+            self.exclude_globs.into_into_dart().into_dart(),
+            self.honor_marker_files.into_into_dart().into_dart(),
+            self.min_duration_secs.into_into_dart().into_dart(),
+            self.min_file_size.into_into_dart().into_dart(),
+            self.max_depth.into_into_dart().into_dart(),
+            self.follow_symlinks.into_into_dart().into_dart(),
+            self.fingerprint_index.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanOptions>
+    for crate::api::scanner::ScanOptions
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.new_or_modified.into_into_dart().into_dart(),
+            self.deleted_paths.into_into_dart().into_dart(),
+            self.moved.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanResult>
+    for crate::api::scanner::ScanResult
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanStreamEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.files.into_into_dart().into_dart(),
+            self.failures.into_into_dart().into_dart(),
+            self.deleted_paths.into_into_dart().into_dart(),
+            self.moved.into_into_dart().into_dart(),
+            self.discovered.into_into_dart().into_dart(),
+            self.processed.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::ScanStreamEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanStreamEvent>
+    for crate::api::scanner::ScanStreamEvent
+{
+    fn into_into_dart(self) -> crate::api::scanner::ScanStreamEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::smart_playlists::SmartCondition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.operator.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.number.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::smart_playlists::SmartCondition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::smart_playlists::SmartCondition>
+    for crate::api::smart_playlists::SmartCondition
+{
+    fn into_into_dart(self) -> crate::api::smart_playlists::SmartCondition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::smart_playlists::SmartField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::Artist => 1.into_dart(),
+            Self::Album => 2.into_dart(),
+            Self::AlbumArtist => 3.into_dart(),
+            Self::Genre => 4.into_dart(),
+            Self::Composer => 5.into_dart(),
+            Self::Format => 6.into_dart(),
+            Self::Path => 7.into_dart(),
+            Self::Year => 8.into_dart(),
+            Self::TrackNumber => 9.into_dart(),
+            Self::DiscNumber => 10.into_dart(),
+            Self::DurationSecs => 11.into_dart(),
+            Self::SampleRate => 12.into_dart(),
+            Self::BitDepth => 13.into_dart(),
+            Self::BitrateKbps => 14.into_dart(),
+            Self::Channels => 15.into_dart(),
+            Self::PlayCount => 16.into_dart(),
+            Self::DateAdded => 17.into_dart(),
+            Self::LastPlayed => 18.into_dart(),
+            Self::LastModified => 19.into_dart(),
+            Self::Favorite => 20.into_dart(),
+            Self::Compilation => 21.into_dart(),
+            Self::HasLyrics => 22.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::smart_playlists::SmartField
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::smart_playlists::SmartField>
+    for crate::api::smart_playlists::SmartField
+{
+    fn into_into_dart(self) -> crate::api::smart_playlists::SmartField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::smart_playlists::SmartOperator {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Is => 0.into_dart(),
+            Self::IsNot => 1.into_dart(),
+            Self::Contains => 2.into_dart(),
+            Self::DoesNotContain => 3.into_dart(),
+            Self::StartsWith => 4.into_dart(),
+            Self::EndsWith => 5.into_dart(),
+            Self::GreaterThan => 6.into_dart(),
+            Self::LessThan => 7.into_dart(),
+            Self::Before => 8.into_dart(),
+            Self::After => 9.into_dart(),
+            Self::InTheLast => 10.into_dart(),
+            Self::NotInTheLast => 11.into_dart(),
+            Self::IsTrue => 12.into_dart(),
+            Self::IsFalse => 13.into_dart(),
+            Self::IsEmpty => 14.into_dart(),
+            Self::IsNotEmpty => 15.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::smart_playlists::SmartOperator
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::smart_playlists::SmartOperator>
+    for crate::api::smart_playlists::SmartOperator
+{
+    fn into_into_dart(self) -> crate::api::smart_playlists::SmartOperator {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::smart_playlists::SmartPlaylist {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rule.into_into_dart().into_dart(),
+            self.sort.into_into_dart().into_dart(),
+            self.descending.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+            self.duration_limit_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::smart_playlists::SmartPlaylist
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::smart_playlists::SmartPlaylist>
+    for crate::api::smart_playlists::SmartPlaylist
+{
+    fn into_into_dart(self) -> crate::api::smart_playlists::SmartPlaylist {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::smart_playlists::SmartRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.match_all.into_into_dart().into_dart(),
+            self.conditions.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::smart_playlists::SmartRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::smart_playlists::SmartRule>
+    for crate::api::smart_playlists::SmartRule
+{
+    fn into_into_dart(self) -> crate::api::smart_playlists::SmartRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::smart_playlists::SmartSortType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::Artist => 1.into_dart(),
+            Self::Album => 2.into_dart(),
+            Self::Year => 3.into_dart(),
+            Self::Duration => 4.into_dart(),
+            Self::DateAdded => 5.into_dart(),
+            Self::PlayCount => 6.into_dart(),
+            Self::LastPlayed => 7.into_dart(),
+            Self::Random => 8.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::smart_playlists::SmartSortType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::smart_playlists::SmartSortType>
+    for crate::api::smart_playlists::SmartSortType
+{
+    fn into_into_dart(self) -> crate::api::smart_playlists::SmartSortType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::SongPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.songs.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::library::SongPage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::SongPage>
+    for crate::api::library::SongPage
+{
+    fn into_into_dart(self) -> crate::api::library::SongPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::SongQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.search.into_into_dart().into_dart(),
+            self.folder.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.album_key.into_into_dart().into_dart(),
+            self.genre.into_into_dart().into_dart(),
+            self.sort.into_into_dart().into_dart(),
+            self.descending.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::SongQuery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::SongQuery>
+    for crate::api::library::SongQuery
+{
+    fn into_into_dart(self) -> crate::api::library::SongQuery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::library::SongSortType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::Artist => 1.into_dart(),
+            Self::Album => 2.into_dart(),
+            Self::Year => 3.into_dart(),
+            Self::Duration => 4.into_dart(),
+            Self::DateAdded => 5.into_dart(),
+            Self::Path => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::library::SongSortType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::library::SongSortType>
+    for crate::api::library::SongSortType
+{
+    fn into_into_dart(self) -> crate::api::library::SongSortType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::tags::TagChanges {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.artist.into_into_dart().into_dart(),
+            self.album.into_into_dart().into_dart(),
+            self.album_artist.into_into_dart().into_dart(),
+            self.track_number.into_into_dart().into_dart(),
+            self.track_total.into_into_dart().into_dart(),
+            self.disc_number.into_into_dart().into_dart(),
+            self.disc_total.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+            self.genre.into_into_dart().into_dart(),
+            self.lyrics.into_into_dart().into_dart(),
+            self.cover_art.into_into_dart().into_dart(),
+            self.remove.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::tags::TagChanges {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::tags::TagChanges>
+    for crate::api::tags::TagChanges
+{
+    fn into_into_dart(self) -> crate::api::tags::TagChanges {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::tags::TagField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::Artist => 1.into_dart(),
+            Self::Album => 2.into_dart(),
+            Self::AlbumArtist => 3.into_dart(),
+            Self::TrackNumber => 4.into_dart(),
+            Self::TrackTotal => 5.into_dart(),
+            Self::DiscNumber => 6.into_dart(),
+            Self::DiscTotal => 7.into_dart(),
+            Self::Year => 8.into_dart(),
+            Self::Genre => 9.into_dart(),
+            Self::Lyrics => 10.into_dart(),
+            Self::CoverArt => 11.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::tags::TagField {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::tags::TagField> for crate::api::tags::TagField {
+    fn into_into_dart(self) -> crate::api::tags::TagField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::tags::TagWriteFailure {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::tags::TagWriteFailure
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::tags::TagWriteFailure>
+    for crate::api::tags::TagWriteFailure
+{
+    fn into_into_dart(self) -> crate::api::tags::TagWriteFailure {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::TrackRange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_path.into_into_dart().into_dart(),
+            self.start_secs.into_into_dart().into_dart(),
+            self.end_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::TrackRange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::TrackRange>
+    for crate::api::scanner::TrackRange
+{
+    fn into_into_dart(self) -> crate::api::scanner::TrackRange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::TranscodeFileFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Wav => 0.into_dart(),
+            Self::Flac => 1.into_dart(),
+            Self::OggOpus => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::TranscodeFileFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::TranscodeFileFormat>
+    for crate::api::audio_api::TranscodeFileFormat
+{
+    fn into_into_dart(self) -> crate::api::audio_api::TranscodeFileFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audio_api::TranscodeStatusType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Started => 0.into_dart(),
+            Self::Progress => 1.into_dart(),
+            Self::Finished => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audio_api::TranscodeStatusType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::TranscodeStatusType>
+    for crate::api::audio_api::TranscodeStatusType
+{
+    fn into_into_dart(self) -> crate::api::audio_api::TranscodeStatusType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::playlists::UnresolvedEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.entry.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::playlists::UnresolvedEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::playlists::UnresolvedEntry>
+    for crate::api::playlists::UnresolvedEntry
+{
+    fn into_into_dart(self) -> crate::api::playlists::UnresolvedEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::WatchEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.added.into_into_dart().into_dart(),
+            self.modified.into_into_dart().into_dart(),
+            self.removed_paths.into_into_dart().into_dart(),
+            self.moved.into_into_dart().into_dart(),
+            self.failures.into_into_dart().into_dart(),
+            self.rescan_needed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::WatchEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::WatchEvent>
+    for crate::api::scanner::WatchEvent
+{
+    fn into_into_dart(self) -> crate::api::scanner::WatchEvent {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for std::collections::HashMap<String, i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, i64)>>::sse_encode(self.into_iter().collect(), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::audio_api::AudioRenderProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::audio_api::AudioTranscodeEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        Vec<crate::api::library::LibrarySong>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::scanner::ScanStreamEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::scanner::WatchEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::AlbumPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::library::LibraryAlbum>>::sse_encode(self.albums, serializer);
+        <u32>::sse_encode(self.total, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scanner::AlbumPalette {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.dominant, serializer);
+        <u32>::sse_encode(self.vibrant, serializer);
+        <u32>::sse_encode(self.muted, serializer);
+        <u32>::sse_encode(self.text, serializer);
+        <u32>::sse_encode(self.secondary_text, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::AudioEventType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::audio_api::AudioEventType::StateChanged { state } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(state, serializer);
+            }
+            crate::api::audio_api::AudioEventType::Progress {
+                position_secs,
+                duration_secs,
+                buffer_level,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <f64>::sse_encode(position_secs, serializer);
+                <Option<f64>>::sse_encode(duration_secs, serializer);
+                <f32>::sse_encode(buffer_level, serializer);
+            }
+            crate::api::audio_api::AudioEventType::TrackEnded { path } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(path, serializer);
+            }
+            crate::api::audio_api::AudioEventType::CrossfadeStarted { from_path, to_path } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(from_path, serializer);
+                <String>::sse_encode(to_path, serializer);
+            }
+            crate::api::audio_api::AudioEventType::Error { message } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::audio_api::AudioEventType::NextTrackReady { path } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(path, serializer);
+            }
+            crate::api::audio_api::AudioEventType::LyricsLineChanged { path, line_index } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(path, serializer);
+                <Option<u32>>::sse_encode(line_index, serializer);
+            }
+            crate::api::audio_api::AudioEventType::ChapterChanged {
+                path,
+                chapter_index,
+            } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(path, serializer);
+                <Option<u32>>::sse_encode(chapter_index, serializer);
+            }
+            crate::api::audio_api::AudioEventType::Resumed {
+                path,
+                position_secs,
+            } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(path, serializer);
+                <f64>::sse_encode(position_secs, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::scanner::AudioFileMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.album, serializer);
+        <Option<String>>::sse_encode(self.album_artist, serializer);
+        <Option<u32>>::sse_encode(self.track_number, serializer);
+        <Option<u32>>::sse_encode(self.track_total, serializer);
+        <Option<u32>>::sse_encode(self.disc_number, serializer);
+        <Option<u32>>::sse_encode(self.disc_total, serializer);
+        <Option<u32>>::sse_encode(self.year, serializer);
+        <Option<String>>::sse_encode(self.genre, serializer);
+        <Option<String>>::sse_encode(self.composer, serializer);
+        <bool>::sse_encode(self.compilation, serializer);
+        <Option<u64>>::sse_encode(self.duration_secs, serializer);
+        <Option<u32>>::sse_encode(self.sample_rate, serializer);
+        <Option<u32>>::sse_encode(self.bit_depth, serializer);
+        <Option<u32>>::sse_encode(self.bitrate_kbps, serializer);
+        <Option<u32>>::sse_encode(self.channels, serializer);
+        <bool>::sse_encode(self.has_lyrics, serializer);
+        <Option<String>>::sse_encode(self.musicbrainz_recording_id, serializer);
+        <Option<String>>::sse_encode(self.musicbrainz_release_id, serializer);
+        <Option<String>>::sse_encode(self.musicbrainz_release_group_id, serializer);
+        <Option<String>>::sse_encode(self.musicbrainz_artist_id, serializer);
+        <Option<String>>::sse_encode(self.musicbrainz_release_artist_id, serializer);
+        <Option<String>>::sse_encode(self.album_art_path, serializer);
+        <Option<crate::api::scanner::AlbumPalette>>::sse_encode(self.album_palette, serializer);
+        <String>::sse_encode(self.format, serializer);
+        <i64>::sse_encode(self.last_modified, serializer);
+        <Option<crate::api::scanner::TrackRange>>::sse_encode(self.range, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::AudioOutputFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::audio_api::OutputSampleFormatType>::sse_encode(self.sample_format, serializer);
+        <Option<u32>>::sse_encode(self.bits_per_sample, serializer);
+        <u32>::sse_encode(self.sample_rate, serializer);
+        <u32>::sse_encode(self.channels, serializer);
+        <crate::api::audio_api::DitherType>::sse_encode(self.dither, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::AudioProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.position_secs, serializer);
+        <Option<f64>>::sse_encode(self.duration_secs, serializer);
+        <f32>::sse_encode(self.buffer_level, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::AudioRenderProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::audio_api::RenderPhaseType>::sse_encode(self.phase, serializer);
+        <u32>::sse_encode(self.track_index, serializer);
+        <u32>::sse_encode(self.track_count, serializer);
+        <f64>::sse_encode(self.position_secs, serializer);
+        <f64>::sse_encode(self.total_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::AudioRenderSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.crossfade_secs, serializer);
+        <crate::api::audio_api::CrossfadeCurveType>::sse_encode(self.crossfade_curve, serializer);
+        <f32>::sse_encode(self.volume, serializer);
+        <f32>::sse_encode(self.eq_preamp_db, serializer);
+        <Vec<crate::api::audio_api::EqualizerBand>>::sse_encode(self.eq_bands, serializer);
+        <bool>::sse_encode(self.normalize, serializer);
+        <u32>::sse_encode(self.bit_depth, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::AudioTranscodeEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <crate::api::audio_api::TranscodeStatusType>::sse_encode(self.status, serializer);
+        <f64>::sse_encode(self.fraction, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::AudioTranscodeJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.input_path, serializer);
+        <String>::sse_encode(self.output_path, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::AudioTranscodeSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::audio_api::TranscodeFileFormat>::sse_encode(self.format, serializer);
+        <Option<u32>>::sse_encode(self.sample_rate, serializer);
+        <u32>::sse_encode(self.bit_depth, serializer);
+        <crate::api::audio_api::DitherType>::sse_encode(self.dither, serializer);
+        <u32>::sse_encode(self.opus_bitrate, serializer);
+        <bool>::sse_encode(self.copy_tags, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::chapters::Chapter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.title, serializer);
+        <f64>::sse_encode(self.start_secs, serializer);
+        <f64>::sse_encode(self.end_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::CrossfadeCurveType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio_api::CrossfadeCurveType::EqualPower => 0,
+                crate::api::audio_api::CrossfadeCurveType::Linear => 1,
+                crate::api::audio_api::CrossfadeCurveType::SquareRoot => 2,
+                crate::api::audio_api::CrossfadeCurveType::SCurve => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::audio_api::DitherType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio_api::DitherType::None => 0,
+                crate::api::audio_api::DitherType::Triangular => 1,
+                crate::api::audio_api::DitherType::NoiseShaped => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::scanner::DuplicateFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.format, serializer);
+        <bool>::sse_encode(self.lossless, serializer);
+        <Option<u32>>::sse_encode(self.bitrate_kbps, serializer);
+        <Option<u32>>::sse_encode(self.sample_rate, serializer);
+        <Option<u32>>::sse_encode(self.bit_depth, serializer);
+        <Option<u64>>::sse_encode(self.duration_secs, serializer);
+        <f32>::sse_encode(self.similarity, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scanner::DuplicateGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::scanner::DuplicateFile>>::sse_encode(self.files, serializer);
+        <String>::sse_encode(self.keep_path, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scanner::DuplicateReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::scanner::DuplicateGroup>>::sse_encode(self.groups, serializer);
+        <Vec<crate::api::scanner::ScanFailure>>::sse_encode(self.failed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audio_api::EqualizerBand {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.frequency_hz, serializer);
+        <f32>::sse_encode(self.gain_db, serializer);
+        <f32>::sse_encode(self.q, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::library::GroupPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::library::LibraryGroup>>::sse_encode(self.groups, serializer);
+        <u32>::sse_encode(self.total, serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::GroupQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.search, serializer);
+        <Option<String>>::sse_encode(self.folder, serializer);
+        <crate::api::library::GroupSortType>::sse_encode(self.sort, serializer);
+        <bool>::sse_encode(self.descending, serializer);
+        <u32>::sse_encode(self.offset, serializer);
+        <Option<u32>>::sse_encode(self.limit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::GroupSortType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::library::GroupSortType::Name => 0,
+                crate::api::library::GroupSortType::SongCount => 1,
+                crate::api::library::GroupSortType::Year => 2,
+                crate::api::library::GroupSortType::DateAdded => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::library::GroupingOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.artist_separators, serializer);
+        <Vec<String>>::sse_encode(self.articles, serializer);
+        <String>::sse_encode(self.various_artists, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::library::LibraryAlbum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <bool>::sse_encode(self.compilation, serializer);
+        <Option<u32>>::sse_encode(self.year, serializer);
+        <u32>::sse_encode(self.song_count, serializer);
+        <u32>::sse_encode(self.disc_count, serializer);
+        <u64>::sse_encode(self.duration_secs, serializer);
+        <Option<String>>::sse_encode(self.album_art_path, serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::LibraryFolder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u32>::sse_encode(self.song_count, serializer);
+        <i64>::sse_encode(self.added_at, serializer);
+        <Option<i64>>::sse_encode(self.scanned_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::LibraryGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u32>::sse_encode(self.song_count, serializer);
+        <u32>::sse_encode(self.album_count, serializer);
+        <u64>::sse_encode(self.duration_secs, serializer);
+        <Option<String>>::sse_encode(self.album_art_path, serializer);
+    }
+}
+
+impl SseEncode for crate::api::library::LibrarySong {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.folder, serializer);
+        <String>::sse_encode(self.display_title, serializer);
+        <i64>::sse_encode(self.date_added, serializer);
+        <u32>::sse_encode(self.play_count, serializer);
+        <Option<i64>>::sse_encode(self.last_played, serializer);
+        <bool>::sse_encode(self.favorite, serializer);
+        <Option<String>>::sse_encode(self.album_key, serializer);
+        <Vec<String>>::sse_encode(self.artist_keys, serializer);
+        <crate::api::scanner::AudioFileMetadata>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::scanner::AudioFileMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scanner::AudioFileMetadata>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::audio_api::AudioTranscodeJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::audio_api::AudioTranscodeJob>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::chapters::Chapter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chapters::Chapter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::scanner::DuplicateFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scanner::DuplicateFile>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for crate::api::scanner::AudioFileMetadata {
+impl SseEncode for Vec<crate::api::scanner::DuplicateGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_path = <String>::sse_decode(deserializer);
-        let mut var_title = <Option<String>>::sse_decode(deserializer);
-        let mut var_artist = <Option<String>>::sse_decode(deserializer);
-        let mut var_album = <Option<String>>::sse_decode(deserializer);
-        let mut var_durationSecs = <Option<u64>>::sse_decode(deserializer);
-        let mut var_format = <String>::sse_decode(deserializer);
-        let mut var_lastModified = <i64>::sse_decode(deserializer);
-        return crate::api::scanner::AudioFileMetadata {
-            path: var_path,
-            title: var_title,
-            artist: var_artist,
-            album: var_album,
-            duration_secs: var_durationSecs,
-            format: var_format,
-            last_modified: var_lastModified,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scanner::DuplicateGroup>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::audio_api::AudioProgress {
+impl SseEncode for Vec<crate::api::audio_api::EqualizerBand> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_positionSecs = <f64>::sse_decode(deserializer);
-        let mut var_durationSecs = <Option<f64>>::sse_decode(deserializer);
-        let mut var_bufferLevel = <f32>::sse_decode(deserializer);
-        return crate::api::audio_api::AudioProgress {
-            position_secs: var_positionSecs,
-            duration_secs: var_durationSecs,
-            buffer_level: var_bufferLevel,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::audio_api::EqualizerBand>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for Vec<crate::api::library::LibraryAlbum> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::library::LibraryAlbum>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::audio_api::CrossfadeCurveType {
+impl SseEncode for Vec<crate::api::library::LibraryFolder> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::audio_api::CrossfadeCurveType::EqualPower,
-            1 => crate::api::audio_api::CrossfadeCurveType::Linear,
-            2 => crate::api::audio_api::CrossfadeCurveType::SquareRoot,
-            3 => crate::api::audio_api::CrossfadeCurveType::SCurve,
-            _ => unreachable!("Invalid variant for CrossfadeCurveType: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::library::LibraryFolder>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for f32 {
+impl SseEncode for Vec<crate::api::library::LibraryGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::library::LibraryGroup>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode for Vec<crate::api::library::LibrarySong> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::library::LibrarySong>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::api::lyrics::LyricsLine> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::lyrics::LyricsLine>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for Vec<crate::api::lyrics::LyricsWord> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::lyrics::LyricsWord>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for Vec<crate::api::playlists::PlaylistEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::playlists::PlaylistEntry>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::scanner::AudioFileMetadata> {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::scanner::AudioFileMetadata>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<(String, i64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, i64)>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<(String, i64)> {
+impl SseEncode for Vec<crate::api::scanner::ScanFailure> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, i64)>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scanner::ScanFailure>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::api::scanner::ScanMove> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scanner::ScanMove>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::audio_api::AudioEventType> {
+impl SseEncode for Vec<crate::api::smart_playlists::SmartCondition> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::audio_api::AudioEventType>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::smart_playlists::SmartCondition>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::audio_api::AudioProgress> {
+impl SseEncode for Vec<crate::api::smart_playlists::SmartRule> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::audio_api::AudioProgress>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::smart_playlists::SmartRule>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<f32> {
+impl SseEncode for Vec<crate::api::tags::TagField> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::tags::TagField>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<f64> {
+impl SseEncode for Vec<crate::api::tags::TagWriteFailure> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f64>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::tags::TagWriteFailure>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<u32> {
+impl SseEncode for Vec<crate::api::playlists::UnresolvedEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::playlists::UnresolvedEntry>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<u64> {
+impl SseEncode for crate::api::lyrics::Lyrics {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::lyrics::LyricsSourceType>::sse_encode(self.source, serializer);
+        <bool>::sse_encode(self.synced, serializer);
+        <Vec<crate::api::lyrics::LyricsLine>>::sse_encode(self.lines, serializer);
     }
 }
 
-impl SseDecode for Option<usize> {
+impl SseEncode for crate::api::lyrics::LyricsLine {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<usize>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u32>>::sse_encode(self.start_ms, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Vec<crate::api::lyrics::LyricsWord>>::sse_encode(self.words, serializer);
     }
 }
 
-impl SseDecode for (String, i64) {
+impl SseEncode for crate::api::lyrics::LyricsSourceType {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <i64>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::lyrics::LyricsSourceType::Sidecar => 0,
+                crate::api::lyrics::LyricsSourceType::SyncedTag => 1,
+                crate::api::lyrics::LyricsSourceType::Tag => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::api::scanner::ScanResult {
+impl SseEncode for crate::api::lyrics::LyricsWord {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_newOrModified =
-            <Vec<crate::api::scanner::AudioFileMetadata>>::sse_decode(deserializer);
-        let mut var_deletedPaths = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::scanner::ScanResult {
-            new_or_modified: var_newOrModified,
-            deleted_paths: var_deletedPaths,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start_ms, serializer);
+        <String>::sse_encode(self.text, serializer);
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::scanner::AlbumPalette> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::scanner::AlbumPalette>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::audio_api::AudioEventType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::audio_api::AudioEventType>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Option<crate::api::audio_api::AudioOutputFormat> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::audio_api::AudioOutputFormat>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Option<crate::api::audio_api::AudioProgress> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::audio_api::AudioProgress>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        10 => wire__crate__api__audio_api__audio_pause_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__audio_api__audio_play_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__audio_api__audio_queue_next_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__audio_api__audio_resume_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__audio_api__audio_seek_impl(port, ptr, rust_vec_len, data_len),
-        16 => {
-            wire__crate__api__audio_api__audio_set_crossfade_impl(port, ptr, rust_vec_len, data_len)
-        }
-        17 => wire__crate__api__audio_api__audio_set_crossfade_curve_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        18 => wire__crate__api__audio_api__audio_set_playback_speed_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        19 => wire__crate__api__audio_api__audio_set_volume_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__audio_api__audio_shutdown_impl(port, ptr, rust_vec_len, data_len),
-        21 => {
-            wire__crate__api__audio_api__audio_skip_to_next_impl(port, ptr, rust_vec_len, data_len)
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
         }
-        22 => wire__crate__api__audio_api__audio_stop_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__scanner__scan_root_dir_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__audio_api__audio_get_channels_impl(ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__audio_api__audio_get_current_path_impl(ptr, rust_vec_len, data_len),
-        3 => {
-            wire__crate__api__audio_api__audio_get_playback_speed_impl(ptr, rust_vec_len, data_len)
-        }
-        4 => wire__crate__api__audio_api__audio_get_progress_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__audio_api__audio_get_sample_rate_impl(ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__audio_api__audio_get_state_impl(ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__audio_api__audio_init_impl(ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__audio_api__audio_is_initialized_impl(ptr, rust_vec_len, data_len),
-        9 => {
-            wire__crate__api__audio_api__audio_is_native_available_impl(ptr, rust_vec_len, data_len)
+impl SseEncode for Option<crate::api::library::LibrarySong> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::library::LibrarySong>::sse_encode(value, serializer);
         }
-        12 => wire__crate__api__audio_api__audio_poll_event_impl(ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioEventType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::audio_api::AudioEventType::StateChanged { state } => {
-                [0.into_dart(), state.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::audio_api::AudioEventType::Progress {
-                position_secs,
-                duration_secs,
-                buffer_level,
-            } => [
-                1.into_dart(),
-                position_secs.into_into_dart().into_dart(),
-                duration_secs.into_into_dart().into_dart(),
-                buffer_level.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::audio_api::AudioEventType::TrackEnded { path } => {
-                [2.into_dart(), path.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::audio_api::AudioEventType::CrossfadeStarted { from_path, to_path } => [
-                3.into_dart(),
-                from_path.into_into_dart().into_dart(),
-                to_path.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::api::audio_api::AudioEventType::Error { message } => {
-                [4.into_dart(), message.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::audio_api::AudioEventType::NextTrackReady { path } => {
-                [5.into_dart(), path.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
+impl SseEncode for Option<crate::api::lyrics::Lyrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::lyrics::Lyrics>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::audio_api::AudioEventType
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioEventType>
-    for crate::api::audio_api::AudioEventType
-{
-    fn into_into_dart(self) -> crate::api::audio_api::AudioEventType {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scanner::AudioFileMetadata {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.path.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.artist.into_into_dart().into_dart(),
-            self.album.into_into_dart().into_dart(),
-            self.duration_secs.into_into_dart().into_dart(),
-            self.format.into_into_dart().into_dart(),
-            self.last_modified.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::scanner::ScanOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::scanner::ScanOptions>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scanner::AudioFileMetadata
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::AudioFileMetadata>
-    for crate::api::scanner::AudioFileMetadata
-{
-    fn into_into_dart(self) -> crate::api::scanner::AudioFileMetadata {
-        self
+
+impl SseEncode for Option<crate::api::scanner::TrackRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::scanner::TrackRange>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::audio_api::AudioProgress {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.position_secs.into_into_dart().into_dart(),
-            self.duration_secs.into_into_dart().into_dart(),
-            self.buffer_level.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::audio_api::AudioProgress
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::AudioProgress>
-    for crate::api::audio_api::AudioProgress
-{
-    fn into_into_dart(self) -> crate::api::audio_api::AudioProgress {
-        self
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::audio_api::CrossfadeCurveType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::EqualPower => 0.into_dart(),
-            Self::Linear => 1.into_dart(),
-            Self::SquareRoot => 2.into_dart(),
-            Self::SCurve => 3.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::audio_api::CrossfadeCurveType
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::audio_api::CrossfadeCurveType>
-    for crate::api::audio_api::CrossfadeCurveType
-{
-    fn into_into_dart(self) -> crate::api::audio_api::CrossfadeCurveType {
-        self
+
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::scanner::ScanResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.new_or_modified.into_into_dart().into_dart(),
-            self.deleted_paths.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::audio_api::OutputSampleFormatType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio_api::OutputSampleFormatType::I16 => 0,
+                crate::api::audio_api::OutputSampleFormatType::I24 => 1,
+                crate::api::audio_api::OutputSampleFormatType::I32 => 2,
+                crate::api::audio_api::OutputSampleFormatType::F32 => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::scanner::ScanResult
-{
+
+impl SseEncode for crate::api::playlists::Playlist {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Vec<crate::api::playlists::PlaylistEntry>>::sse_encode(self.entries, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::ScanResult>
-    for crate::api::scanner::ScanResult
-{
-    fn into_into_dart(self) -> crate::api::scanner::ScanResult {
-        self
+
+impl SseEncode for crate::api::playlists::PlaylistEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.location, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.album, serializer);
+        <Option<f64>>::sse_encode(self.duration_secs, serializer);
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, i64> {
+impl SseEncode for crate::api::playlists::PlaylistImport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, i64)>>::sse_encode(self.into_iter().collect(), serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Vec<String>>::sse_encode(self.paths, serializer);
+        <Vec<crate::api::playlists::UnresolvedEntry>>::sse_encode(self.unresolved, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for (String, i64) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <String>::sse_encode(self.0, serializer);
+        <i64>::sse_encode(self.1, serializer);
     }
 }
 
-impl SseEncode for crate::api::audio_api::AudioEventType {
+impl SseEncode for crate::api::audio_api::RenderFileFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::audio_api::AudioEventType::StateChanged { state } => {
-                <i32>::sse_encode(0, serializer);
-                <String>::sse_encode(state, serializer);
-            }
-            crate::api::audio_api::AudioEventType::Progress {
-                position_secs,
-                duration_secs,
-                buffer_level,
-            } => {
-                <i32>::sse_encode(1, serializer);
-                <f64>::sse_encode(position_secs, serializer);
-                <Option<f64>>::sse_encode(duration_secs, serializer);
-                <f32>::sse_encode(buffer_level, serializer);
-            }
-            crate::api::audio_api::AudioEventType::TrackEnded { path } => {
-                <i32>::sse_encode(2, serializer);
-                <String>::sse_encode(path, serializer);
-            }
-            crate::api::audio_api::AudioEventType::CrossfadeStarted { from_path, to_path } => {
-                <i32>::sse_encode(3, serializer);
-                <String>::sse_encode(from_path, serializer);
-                <String>::sse_encode(to_path, serializer);
-            }
-            crate::api::audio_api::AudioEventType::Error { message } => {
-                <i32>::sse_encode(4, serializer);
-                <String>::sse_encode(message, serializer);
-            }
-            crate::api::audio_api::AudioEventType::NextTrackReady { path } => {
-                <i32>::sse_encode(5, serializer);
-                <String>::sse_encode(path, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio_api::RenderFileFormat::Wav => 0,
+                crate::api::audio_api::RenderFileFormat::Flac => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::scanner::AudioFileMetadata {
+impl SseEncode for crate::api::audio_api::RenderPhaseType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.path, serializer);
-        <Option<String>>::sse_encode(self.title, serializer);
-        <Option<String>>::sse_encode(self.artist, serializer);
-        <Option<String>>::sse_encode(self.album, serializer);
-        <Option<u64>>::sse_encode(self.duration_secs, serializer);
-        <String>::sse_encode(self.format, serializer);
-        <i64>::sse_encode(self.last_modified, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio_api::RenderPhaseType::Mixing => 0,
+                crate::api::audio_api::RenderPhaseType::Normalizing => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::audio_api::AudioProgress {
+impl SseEncode for crate::api::scanner::ScanEventType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <f64>::sse_encode(self.position_secs, serializer);
-        <Option<f64>>::sse_encode(self.duration_secs, serializer);
-        <f32>::sse_encode(self.buffer_level, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::scanner::ScanEventType::Batch => 0,
+                crate::api::scanner::ScanEventType::Finished => 1,
+                crate::api::scanner::ScanEventType::Cancelled => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::scanner::ScanFailure {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.path, serializer);
+        <crate::api::scanner::ScanFailureType>::sse_encode(self.reason, serializer);
+        <String>::sse_encode(self.detail, serializer);
     }
 }
 
-impl SseEncode for crate::api::audio_api::CrossfadeCurveType {
+impl SseEncode for crate::api::scanner::ScanFailureType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::api::audio_api::CrossfadeCurveType::EqualPower => 0,
-                crate::api::audio_api::CrossfadeCurveType::Linear => 1,
-                crate::api::audio_api::CrossfadeCurveType::SquareRoot => 2,
-                crate::api::audio_api::CrossfadeCurveType::SCurve => 3,
+                crate::api::scanner::ScanFailureType::Io => 0,
+                crate::api::scanner::ScanFailureType::UnsupportedContainer => 1,
+                crate::api::scanner::ScanFailureType::BadTag => 2,
+                crate::api::scanner::ScanFailureType::Corrupt => 3,
+                crate::api::scanner::ScanFailureType::ZeroDuration => 4,
                 _ => {
                     unimplemented!("");
                 }
@@ -1500,167 +7437,312 @@ impl SseEncode for crate::api::audio_api::CrossfadeCurveType {
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for crate::api::scanner::ScanMove {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.old_path, serializer);
+        <String>::sse_encode(self.new_path, serializer);
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for crate::api::scanner::ScanOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <Vec<String>>::sse_encode(self.extensions, serializer);
+        <bool>::sse_encode(self.sniff_content, serializer);
+        <Vec<String>>::sse_encode(self.exclude_globs, serializer);
+        <bool>::sse_encode(self.honor_marker_files, serializer);
+        <u32>::sse_encode(self.min_duration_secs, serializer);
+        <u32>::sse_encode(self.min_file_size, serializer);
+        <Option<u32>>::sse_encode(self.max_depth, serializer);
+        <bool>::sse_encode(self.follow_symlinks, serializer);
+        <Option<String>>::sse_encode(self.fingerprint_index, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::scanner::ScanResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <Vec<crate::api::scanner::AudioFileMetadata>>::sse_encode(self.new_or_modified, serializer);
+        <Vec<String>>::sse_encode(self.deleted_paths, serializer);
+        <Vec<crate::api::scanner::ScanMove>>::sse_encode(self.moved, serializer);
+        <Vec<crate::api::scanner::ScanFailure>>::sse_encode(self.failed, serializer);
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::api::scanner::ScanStreamEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <crate::api::scanner::ScanEventType>::sse_encode(self.kind, serializer);
+        <Vec<crate::api::scanner::AudioFileMetadata>>::sse_encode(self.files, serializer);
+        <Vec<crate::api::scanner::ScanFailure>>::sse_encode(self.failures, serializer);
+        <Vec<String>>::sse_encode(self.deleted_paths, serializer);
+        <Vec<crate::api::scanner::ScanMove>>::sse_encode(self.moved, serializer);
+        <u32>::sse_encode(self.discovered, serializer);
+        <u32>::sse_encode(self.processed, serializer);
+        <u32>::sse_encode(self.failed, serializer);
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::smart_playlists::SmartCondition {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <crate::api::smart_playlists::SmartField>::sse_encode(self.field, serializer);
+        <crate::api::smart_playlists::SmartOperator>::sse_encode(self.operator, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <f64>::sse_encode(self.number, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::scanner::AudioFileMetadata> {
+impl SseEncode for crate::api::smart_playlists::SmartField {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::scanner::AudioFileMetadata>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::smart_playlists::SmartField::Title => 0,
+                crate::api::smart_playlists::SmartField::Artist => 1,
+                crate::api::smart_playlists::SmartField::Album => 2,
+                crate::api::smart_playlists::SmartField::AlbumArtist => 3,
+                crate::api::smart_playlists::SmartField::Genre => 4,
+                crate::api::smart_playlists::SmartField::Composer => 5,
+                crate::api::smart_playlists::SmartField::Format => 6,
+                crate::api::smart_playlists::SmartField::Path => 7,
+                crate::api::smart_playlists::SmartField::Year => 8,
+                crate::api::smart_playlists::SmartField::TrackNumber => 9,
+                crate::api::smart_playlists::SmartField::DiscNumber => 10,
+                crate::api::smart_playlists::SmartField::DurationSecs => 11,
+                crate::api::smart_playlists::SmartField::SampleRate => 12,
+                crate::api::smart_playlists::SmartField::BitDepth => 13,
+                crate::api::smart_playlists::SmartField::BitrateKbps => 14,
+                crate::api::smart_playlists::SmartField::Channels => 15,
+                crate::api::smart_playlists::SmartField::PlayCount => 16,
+                crate::api::smart_playlists::SmartField::DateAdded => 17,
+                crate::api::smart_playlists::SmartField::LastPlayed => 18,
+                crate::api::smart_playlists::SmartField::LastModified => 19,
+                crate::api::smart_playlists::SmartField::Favorite => 20,
+                crate::api::smart_playlists::SmartField::Compilation => 21,
+                crate::api::smart_playlists::SmartField::HasLyrics => 22,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::smart_playlists::SmartOperator {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::smart_playlists::SmartOperator::Is => 0,
+                crate::api::smart_playlists::SmartOperator::IsNot => 1,
+                crate::api::smart_playlists::SmartOperator::Contains => 2,
+                crate::api::smart_playlists::SmartOperator::DoesNotContain => 3,
+                crate::api::smart_playlists::SmartOperator::StartsWith => 4,
+                crate::api::smart_playlists::SmartOperator::EndsWith => 5,
+                crate::api::smart_playlists::SmartOperator::GreaterThan => 6,
+                crate::api::smart_playlists::SmartOperator::LessThan => 7,
+                crate::api::smart_playlists::SmartOperator::Before => 8,
+                crate::api::smart_playlists::SmartOperator::After => 9,
+                crate::api::smart_playlists::SmartOperator::InTheLast => 10,
+                crate::api::smart_playlists::SmartOperator::NotInTheLast => 11,
+                crate::api::smart_playlists::SmartOperator::IsTrue => 12,
+                crate::api::smart_playlists::SmartOperator::IsFalse => 13,
+                crate::api::smart_playlists::SmartOperator::IsEmpty => 14,
+                crate::api::smart_playlists::SmartOperator::IsNotEmpty => 15,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<(String, i64)> {
+impl SseEncode for crate::api::smart_playlists::SmartPlaylist {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, i64)>::sse_encode(item, serializer);
-        }
+        <crate::api::smart_playlists::SmartRule>::sse_encode(self.rule, serializer);
+        <crate::api::smart_playlists::SmartSortType>::sse_encode(self.sort, serializer);
+        <bool>::sse_encode(self.descending, serializer);
+        <Option<u32>>::sse_encode(self.limit, serializer);
+        <Option<u32>>::sse_encode(self.duration_limit_secs, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::smart_playlists::SmartRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <bool>::sse_encode(self.match_all, serializer);
+        <Vec<crate::api::smart_playlists::SmartCondition>>::sse_encode(self.conditions, serializer);
+        <Vec<crate::api::smart_playlists::SmartRule>>::sse_encode(self.groups, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::audio_api::AudioEventType> {
+impl SseEncode for crate::api::smart_playlists::SmartSortType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::audio_api::AudioEventType>::sse_encode(value, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::smart_playlists::SmartSortType::Title => 0,
+                crate::api::smart_playlists::SmartSortType::Artist => 1,
+                crate::api::smart_playlists::SmartSortType::Album => 2,
+                crate::api::smart_playlists::SmartSortType::Year => 3,
+                crate::api::smart_playlists::SmartSortType::Duration => 4,
+                crate::api::smart_playlists::SmartSortType::DateAdded => 5,
+                crate::api::smart_playlists::SmartSortType::PlayCount => 6,
+                crate::api::smart_playlists::SmartSortType::LastPlayed => 7,
+                crate::api::smart_playlists::SmartSortType::Random => 8,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Option<crate::api::audio_api::AudioProgress> {
+impl SseEncode for crate::api::library::SongPage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::audio_api::AudioProgress>::sse_encode(value, serializer);
-        }
+        <Vec<crate::api::library::LibrarySong>>::sse_encode(self.songs, serializer);
+        <u32>::sse_encode(self.total, serializer);
     }
 }
 
-impl SseEncode for Option<f32> {
+impl SseEncode for crate::api::library::SongQuery {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f32>::sse_encode(value, serializer);
-        }
+        <Option<String>>::sse_encode(self.search, serializer);
+        <Option<String>>::sse_encode(self.folder, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.album, serializer);
+        <Option<String>>::sse_encode(self.album_key, serializer);
+        <Option<String>>::sse_encode(self.genre, serializer);
+        <crate::api::library::SongSortType>::sse_encode(self.sort, serializer);
+        <bool>::sse_encode(self.descending, serializer);
+        <u32>::sse_encode(self.offset, serializer);
+        <Option<u32>>::sse_encode(self.limit, serializer);
     }
 }
 
-impl SseEncode for Option<f64> {
+impl SseEncode for crate::api::library::SongSortType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f64>::sse_encode(value, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::library::SongSortType::Title => 0,
+                crate::api::library::SongSortType::Artist => 1,
+                crate::api::library::SongSortType::Album => 2,
+                crate::api::library::SongSortType::Year => 3,
+                crate::api::library::SongSortType::Duration => 4,
+                crate::api::library::SongSortType::DateAdded => 5,
+                crate::api::library::SongSortType::Path => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Option<u32> {
+impl SseEncode for crate::api::tags::TagChanges {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.artist, serializer);
+        <Option<String>>::sse_encode(self.album, serializer);
+        <Option<String>>::sse_encode(self.album_artist, serializer);
+        <Option<u32>>::sse_encode(self.track_number, serializer);
+        <Option<u32>>::sse_encode(self.track_total, serializer);
+        <Option<u32>>::sse_encode(self.disc_number, serializer);
+        <Option<u32>>::sse_encode(self.disc_total, serializer);
+        <Option<u32>>::sse_encode(self.year, serializer);
+        <Option<String>>::sse_encode(self.genre, serializer);
+        <Option<String>>::sse_encode(self.lyrics, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.cover_art, serializer);
+        <Vec<crate::api::tags::TagField>>::sse_encode(self.remove, serializer);
     }
 }
 
-impl SseEncode for Option<u64> {
+impl SseEncode for crate::api::tags::TagField {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::tags::TagField::Title => 0,
+                crate::api::tags::TagField::Artist => 1,
+                crate::api::tags::TagField::Album => 2,
+                crate::api::tags::TagField::AlbumArtist => 3,
+                crate::api::tags::TagField::TrackNumber => 4,
+                crate::api::tags::TagField::TrackTotal => 5,
+                crate::api::tags::TagField::DiscNumber => 6,
+                crate::api::tags::TagField::DiscTotal => 7,
+                crate::api::tags::TagField::Year => 8,
+                crate::api::tags::TagField::Genre => 9,
+                crate::api::tags::TagField::Lyrics => 10,
+                crate::api::tags::TagField::CoverArt => 11,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Option<usize> {
+impl SseEncode for crate::api::tags::TagWriteFailure {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <usize>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for (String, i64) {
+impl SseEncode for crate::api::scanner::TrackRange {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.0, serializer);
-        <i64>::sse_encode(self.1, serializer);
+        <String>::sse_encode(self.source_path, serializer);
+        <f64>::sse_encode(self.start_secs, serializer);
+        <Option<f64>>::sse_encode(self.end_secs, serializer);
     }
 }
 
-impl SseEncode for crate::api::scanner::ScanResult {
+impl SseEncode for crate::api::audio_api::TranscodeFileFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::api::scanner::AudioFileMetadata>>::sse_encode(self.new_or_modified, serializer);
-        <Vec<String>>::sse_encode(self.deleted_paths, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio_api::TranscodeFileFormat::Wav => 0,
+                crate::api::audio_api::TranscodeFileFormat::Flac => 1,
+                crate::api::audio_api::TranscodeFileFormat::OggOpus => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::audio_api::TranscodeStatusType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::audio_api::TranscodeStatusType::Started => 0,
+                crate::api::audio_api::TranscodeStatusType::Progress => 1,
+                crate::api::audio_api::TranscodeStatusType::Finished => 2,
+                crate::api::audio_api::TranscodeStatusType::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -1690,6 +7772,14 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::playlists::UnresolvedEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <crate::api::playlists::PlaylistEntry>::sse_encode(self.entry, serializer);
+    }
+}
+
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1700,6 +7790,18 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::scanner::WatchEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::scanner::AudioFileMetadata>>::sse_encode(self.added, serializer);
+        <Vec<crate::api::scanner::AudioFileMetadata>>::sse_encode(self.modified, serializer);
+        <Vec<String>>::sse_encode(self.removed_paths, serializer);
+        <Vec<crate::api::scanner::ScanMove>>::sse_encode(self.moved, serializer);
+        <Vec<crate::api::scanner::ScanFailure>>::sse_encode(self.failures, serializer);
+        <bool>::sse_encode(self.rescan_needed, serializer);
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
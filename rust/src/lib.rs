@@ -1,7 +1,12 @@
 pub mod api;
+pub mod library;
 
-// Audio engine is only available on non-Android platforms due to C++ linking issues with cpal/oboe
-#[cfg(not(target_os = "android"))]
+// The audio engine is built on desktop platforms and, when the `native_audio`
+// feature is enabled, on Android (see build.rs for the `native_audio_engine` cfg).
+#[cfg(native_audio_engine)]
 pub mod audio;
 
 mod frb_generated;
+
+#[cfg(test)]
+pub(crate) mod test_util;
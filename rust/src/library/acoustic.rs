@@ -0,0 +1,284 @@
+//! Acoustic fingerprints for finding the same recording in different files.
+//!
+//! Unlike the content fingerprints in `fingerprint`, these are computed from
+//! the decoded audio, so they survive re-encoding, a different container or
+//! sample rate, and retagging. The approach follows Chromaprint: the first
+//! two minutes are downmixed to mono at 11025 Hz, split into overlapping
+//! frames whose spectrum is folded into a 12-bin chroma vector, and each
+//! frame is reduced to 32 bits comparing chroma bins with their neighbours
+//! and with the previous frame. Two fingerprints are compared by the share
+//! of equal bits at the best alignment.
+
+use crate::audio::decoder::{probe_file, DecoderError};
+use crate::audio::resampler::AudioResampler;
+use std::path::Path;
+
+/// Sample rate the audio is analysed at
+pub const SAMPLE_RATE: u32 = 11025;
+
+/// Samples per analysis frame
+const FRAME_SIZE: usize = 4096;
+
+/// Samples between the starts of consecutive frames
+const HOP_SIZE: usize = FRAME_SIZE / 3;
+
+/// Seconds of audio fingerprinted, after leading silence
+const MAX_DURATION_SECS: usize = 120;
+
+/// Frequency range folded into the chroma bins, in Hz
+const MIN_FREQ: f32 = 28.0;
+const MAX_FREQ: f32 = 3520.0;
+
+/// Samples below this level count as leading silence
+const SILENCE: f32 = 1.0 / 1024.0;
+
+/// Frames two fingerprints may be shifted by when aligning them (about
+/// ten seconds), e.g. for a copy with a longer lead-in
+const MAX_OFFSET: isize = 80;
+
+/// Fingerprint the first two minutes of audio in the file at `path`.
+pub fn fingerprint(path: &Path) -> Result<Vec<u32>, DecoderError> {
+    let mut probe = probe_file(path)?;
+    let channels = probe.source_info.channels.max(1);
+    let mut resampler =
+        AudioResampler::new(probe.source_info.original_sample_rate, SAMPLE_RATE, 1, 1024)
+            .map_err(DecoderError::ResamplingFailed)?;
+
+    let limit = MAX_DURATION_SECS * SAMPLE_RATE as usize;
+    let mut decoded = Vec::new();
+    let mut mono = Vec::new();
+    let mut samples = Vec::with_capacity(limit);
+    let mut start = None;
+    while probe.decode_next(&mut decoded)? {
+        mono.clear();
+        mono.extend(
+            decoded
+                .chunks_exact(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32),
+        );
+        let checked = samples.len();
+        resampler
+            .process_interleaved(&mono, &mut samples)
+            .map_err(DecoderError::ResamplingFailed)?;
+        if start.is_none() {
+            start = samples[checked..]
+                .iter()
+                .position(|s| s.abs() > SILENCE)
+                .map(|i| checked + i);
+        }
+        if start.is_some_and(|start| samples.len() - start >= limit) {
+            break;
+        }
+    }
+    resampler
+        .flush(&mut samples)
+        .map_err(DecoderError::ResamplingFailed)?;
+
+    let start = start.unwrap_or(samples.len());
+    let end = samples.len().min(start + limit);
+    Ok(from_samples(&samples[start..end]))
+}
+
+/// Fingerprint mono samples at [`SAMPLE_RATE`].
+pub fn from_samples(samples: &[f32]) -> Vec<u32> {
+    let chroma = chroma_frames(samples);
+
+    // Smooth each bin over three frames, so single noisy frames matter less
+    let smoothed: Vec<[f32; 12]> = chroma
+        .windows(3)
+        .map(|w| std::array::from_fn(|b| (w[0][b] + w[1][b] + w[2][b]) / 3.0))
+        .collect();
+
+    smoothed
+        .windows(2)
+        .map(|w| {
+            let (previous, current) = (&w[0], &w[1]);
+            let mut bits = 0u32;
+            for b in 0..12 {
+                bits |= u32::from(current[b] > previous[b]) << b;
+                bits |= u32::from(current[b] > current[(b + 1) % 12]) << (12 + b);
+            }
+            for b in 0..8 {
+                bits |= u32::from(current[b] > current[(b + 7) % 12]) << (24 + b);
+            }
+            bits
+        })
+        .collect()
+}
+
+/// Normalized chroma vector of each frame.
+fn chroma_frames(samples: &[f32]) -> Vec<[f32; 12]> {
+    if samples.len() < FRAME_SIZE {
+        return Vec::new();
+    }
+    let mut planner = realfft::RealFftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(FRAME_SIZE);
+    let mut input = fft.make_input_vec();
+    let mut spectrum = fft.make_output_vec();
+
+    let window: Vec<f32> = (0..FRAME_SIZE)
+        .map(|i| 0.5 - 0.5 * (std::f32::consts::TAU * i as f32 / FRAME_SIZE as f32).cos())
+        .collect();
+    // Pitch class of each FFT bin, if it is in range
+    let classes: Vec<Option<usize>> = (0..spectrum.len())
+        .map(|k| {
+            let freq = k as f32 * SAMPLE_RATE as f32 / FRAME_SIZE as f32;
+            (MIN_FREQ..=MAX_FREQ).contains(&freq).then(|| {
+                let note = 12.0 * (freq / 440.0).log2() + 69.0;
+                (note.round() as i64).rem_euclid(12) as usize
+            })
+        })
+        .collect();
+
+    (0..=samples.len() - FRAME_SIZE)
+        .step_by(HOP_SIZE)
+        .map(|start| {
+            let frame = &samples[start..start + FRAME_SIZE];
+            for ((x, s), w) in input.iter_mut().zip(frame).zip(&window) {
+                *x = s * w;
+            }
+            fft.process(&mut input, &mut spectrum)
+                .expect("buffers come from the plan");
+
+            let mut chroma = [0f32; 12];
+            for (bin, class) in spectrum.iter().zip(&classes) {
+                if let Some(class) = class {
+                    chroma[*class] += bin.norm_sqr();
+                }
+            }
+            let norm = chroma.iter().map(|c| c * c).sum::<f32>().sqrt();
+            if norm > f32::EPSILON {
+                chroma.iter_mut().for_each(|c| *c /= norm);
+            }
+            chroma
+        })
+        .collect()
+}
+
+/// Share of equal bits between two fingerprints at their best alignment,
+/// from 0.0 to 1.0. Unrelated audio scores around 0.5.
+///
+/// Alignments overlapping less than half of the shorter fingerprint are not
+/// considered, so a short clip does not match any song containing it.
+pub fn similarity(a: &[u32], b: &[u32]) -> f32 {
+    let min_overlap = (a.len().min(b.len()) / 2).max(1);
+    let mut best = 0f32;
+    for offset in -MAX_OFFSET..=MAX_OFFSET {
+        // Pair a[i] with b[i + offset]
+        let a_start = (-offset).max(0) as usize;
+        let b_start = offset.max(0) as usize;
+        if a_start >= a.len() || b_start >= b.len() {
+            continue;
+        }
+        let pairs = (a.len() - a_start).min(b.len() - b_start);
+        if pairs < min_overlap {
+            continue;
+        }
+        let errors: u32 = a[a_start..a_start + pairs]
+            .iter()
+            .zip(&b[b_start..b_start + pairs])
+            .map(|(x, y)| (x ^ y).count_ones())
+            .sum();
+        best = best.max(1.0 - errors as f32 / (32 * pairs) as f32);
+    }
+    best
+}
+
+/// Hex encoding of a fingerprint, for keeping it in a `FingerprintIndex`.
+pub fn encode(fingerprint: &[u32]) -> String {
+    fingerprint.iter().map(|bits| format!("{:08x}", bits)).collect()
+}
+
+/// Fingerprint from its [`encode`]d form.
+pub fn decode(text: &str) -> Option<Vec<u32>> {
+    if !text.len().is_multiple_of(8) || !text.is_ascii() {
+        return None;
+    }
+    (0..text.len())
+        .step_by(8)
+        .map(|i| u32::from_str_radix(&text[i..i + 8], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+
+    /// A melody of sine tones, one every quarter second, from a simple
+    /// pseudo-random sequence seeded with `seed`.
+    pub(crate) fn melody(seed: u32, secs: usize, rate: u32) -> Vec<f32> {
+        let mut state = seed;
+        let notes: Vec<f32> = (0..secs * 4)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                let note = 48 + (state >> 16) % 36;
+                440.0 * 2f32.powf((note as f32 - 69.0) / 12.0)
+            })
+            .collect();
+        (0..secs * rate as usize)
+            .map(|i| {
+                let t = i as f32 / rate as f32;
+                let freq = notes[i * 4 / rate as usize];
+                0.3 * (std::f32::consts::TAU * freq * t).sin()
+                    + 0.1 * (std::f32::consts::TAU * 2.0 * freq * t).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_similarity_of_versions() {
+        let original = from_samples(&melody(1, 30, SAMPLE_RATE));
+        assert!(original.len() > 200);
+
+        // Quieter, with noise and three seconds of extra lead-in
+        let mut noise = 7u32;
+        let mut shifted = vec![0.0; 3 * SAMPLE_RATE as usize];
+        shifted.extend(melody(1, 30, SAMPLE_RATE).iter().map(|s| {
+            noise = noise.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            0.5 * s + ((noise >> 16) as f32 / 65536.0 - 0.5) * 0.02
+        }));
+        let shifted = from_samples(&shifted);
+
+        let other = from_samples(&melody(2, 30, SAMPLE_RATE));
+        let same = similarity(&original, &shifted);
+        let different = similarity(&original, &other);
+        assert!(same > 0.9, "same recording scored {}", same);
+        assert!(different < 0.75, "different recordings scored {}", different);
+        assert_eq!(similarity(&original, &original), 1.0);
+        assert_eq!(similarity(&original, &[]), 0.0);
+
+        assert!(from_samples(&[0.0; 1000]).is_empty());
+        assert_eq!(decode(&encode(&original)), Some(original));
+        assert_eq!(decode("0000000"), None);
+        assert_eq!(decode("0000000g"), None);
+    }
+
+    #[test]
+    fn test_fingerprint_file() {
+        use crate::audio::wav::WavWriter;
+
+        let dir = test_dir("acoustic");
+
+        // The same melody as 44.1 kHz stereo after a second of silence, and
+        // as 22.05 kHz mono
+        let stereo: Vec<f32> = std::iter::repeat_n(0.0, 44100)
+            .chain(melody(3, 20, 44100))
+            .flat_map(|s| [s, s])
+            .collect();
+        let mut writer = WavWriter::create(&dir.join("a.wav"), 44100, 2).unwrap();
+        writer.write_samples(&stereo).unwrap();
+        writer.finalize().unwrap();
+        let mut writer = WavWriter::create(&dir.join("b.wav"), 22050, 1).unwrap();
+        writer.write_samples(&melody(3, 20, 22050)).unwrap();
+        writer.finalize().unwrap();
+
+        let a = fingerprint(&dir.join("a.wav")).unwrap();
+        let b = fingerprint(&dir.join("b.wav")).unwrap();
+        assert!(a.len().abs_diff(b.len()) <= 2, "{} vs {} frames", a.len(), b.len());
+        assert!(similarity(&a, &b) > 0.9, "scored {}", similarity(&a, &b));
+        assert!(fingerprint(&dir.join("missing.wav")).is_err());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,254 @@
+//! Album-art extraction for the library scanner.
+//!
+//! Cover images come from the file's tags (front cover preferred) or, when
+//! there are none, from a `cover.jpg`/`folder.png`-style image next to the
+//! file. Every image is keyed by the BLAKE3 hash of its original bytes, so a
+//! cover shared by a whole album is decoded and stored only once. The cache
+//! holds JPEG thumbnails no larger than [`THUMBNAIL_SIZE`], each with a
+//! palette sidecar (see [`palette`](super::palette)).
+
+use super::palette;
+use crate::api::scanner::AlbumPalette;
+use image::codecs::jpeg::JpegEncoder;
+use lofty::file::TaggedFile;
+use lofty::prelude::*;
+use lofty::picture::PictureType;
+use lofty::probe::Probe;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Longest edge of a cached thumbnail in pixels
+pub const THUMBNAIL_SIZE: u32 = 512;
+
+/// JPEG quality for cached thumbnails
+const THUMBNAIL_QUALITY: u8 = 85;
+
+/// Base names recognised as folder art, in order of preference
+const FOLDER_ART_NAMES: &[&str] = &["cover", "folder", "front", "album"];
+
+/// Extensions recognised as folder art
+const FOLDER_ART_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png"];
+
+/// Distinguishes temporary files written concurrently for the same image
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A cached thumbnail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Artwork {
+    pub path: String,
+    pub palette: Option<AlbumPalette>,
+}
+
+/// Thumbnail cache shared by the threads of one scan.
+pub struct ArtworkCache {
+    dir: PathBuf,
+    /// Folder art already resolved during this scan, by directory
+    folder_art: Mutex<HashMap<PathBuf, Option<Artwork>>>,
+}
+
+impl ArtworkCache {
+    /// Open (creating if needed) the cache directory.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            folder_art: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Thumbnail for `path`, from embedded art or the folder image.
+    pub fn art_for(&self, path: &Path, tagged_file: &TaggedFile) -> Option<Artwork> {
+        embedded_cover(tagged_file)
+            .and_then(|bytes| self.store(bytes))
+            .or_else(|| self.folder_art(path.parent()?))
+    }
+
+    /// Thumbnail for the folder image in `dir`, if there is one.
+    fn folder_art(&self, dir: &Path) -> Option<Artwork> {
+        if let Some(cached) = self.folder_art.lock().get(dir) {
+            return cached.clone();
+        }
+
+        // Resolved without holding the lock; two threads racing on the same
+        // directory just store the same thumbnail twice
+        let art = find_folder_image(dir)
+            .and_then(|image| fs::read(image).ok())
+            .and_then(|bytes| self.store(&bytes));
+        self.folder_art.lock().insert(dir.to_path_buf(), art.clone());
+        art
+    }
+
+    /// Write a thumbnail and palette of `bytes` unless an identical image is
+    /// cached.
+    ///
+    /// Returns `None` if the image cannot be decoded or written.
+    pub fn store(&self, bytes: &[u8]) -> Option<Artwork> {
+        let hash = blake3::hash(bytes).to_hex();
+        let target = self.dir.join(format!("{}.jpg", &hash[..32]));
+        if target.exists() {
+            // Thumbnails from before palettes were cached have no sidecar
+            let palette = palette::read_sidecar(&target).or_else(|| {
+                let palette = palette::extract(&image::open(&target).ok()?)?;
+                let _ = palette::write_sidecar(&target, &palette);
+                Some(palette)
+            });
+            return Some(Artwork {
+                path: target.to_string_lossy().to_string(),
+                palette,
+            });
+        }
+
+        let image = image::load_from_memory(bytes).ok()?;
+        let image = if image.width() > THUMBNAIL_SIZE || image.height() > THUMBNAIL_SIZE {
+            image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+        } else {
+            image
+        };
+
+        // Write to a temporary name and rename, so readers never see a
+        // half-written thumbnail
+        let temp = self.dir.join(format!(
+            ".{}.{}.tmp",
+            &hash[..32],
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written = fs::File::create(&temp).ok().and_then(|file| {
+            let encoder = JpegEncoder::new_with_quality(BufWriter::new(file), THUMBNAIL_QUALITY);
+            image.to_rgb8().write_with_encoder(encoder).ok()
+        });
+        if written.is_none() || fs::rename(&temp, &target).is_err() {
+            let _ = fs::remove_file(&temp);
+            return None;
+        }
+
+        let palette = palette::extract(&image);
+        if let Some(palette) = &palette {
+            let _ = palette::write_sidecar(&target, palette);
+        }
+        Some(Artwork {
+            path: target.to_string_lossy().to_string(),
+            palette,
+        })
+    }
+}
+
+/// Original cover image bytes for the audio file at `path`, embedded or
+/// from its folder.
+pub fn cover_bytes(path: &Path) -> Option<Vec<u8>> {
+    let embedded = Probe::open(path)
+        .ok()
+        .and_then(|probe| probe.read().ok())
+        .and_then(|tagged_file| embedded_cover(&tagged_file).map(<[u8]>::to_vec));
+    embedded.or_else(|| fs::read(find_folder_image(path.parent()?)?).ok())
+}
+
+/// The front cover from any of the file's tags, else the first picture.
+fn embedded_cover(tagged_file: &TaggedFile) -> Option<&[u8]> {
+    let pictures = || tagged_file.tags().iter().flat_map(|tag| tag.pictures());
+    pictures()
+        .find(|picture| picture.pic_type() == PictureType::CoverFront)
+        .or_else(|| pictures().next())
+        .map(|picture| picture.data())
+}
+
+/// Find a folder image in `dir`, matching names case-insensitively.
+fn find_folder_image(dir: &Path) -> Option<PathBuf> {
+    let candidates: Vec<(usize, PathBuf)> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let stem = path.file_stem()?.to_str()?.to_lowercase();
+            let ext = path.extension()?.to_str()?.to_lowercase();
+            if !FOLDER_ART_EXTENSIONS.contains(&ext.as_str()) {
+                return None;
+            }
+            let rank = FOLDER_ART_NAMES.iter().position(|name| *name == stem)?;
+            path.is_file().then_some((rank, path))
+        })
+        .collect();
+
+    candidates
+        .into_iter()
+        .min_by(|(a_rank, a_path), (b_rank, b_path)| a_rank.cmp(b_rank).then(a_path.cmp(b_path)))
+        .map(|(_, path)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+    use image::{ImageFormat, RgbImage};
+    use std::io::Cursor;
+
+    fn png(width: u32, height: u32, shade: u8) -> Vec<u8> {
+        let image = RgbImage::from_pixel(width, height, image::Rgb([shade, 0, 255 - shade]));
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_store_dedupes_and_resizes() {
+        let dir = test_dir("artwork-store");
+        let cache = ArtworkCache::new(dir.join("cache")).unwrap();
+
+        let large = png(1200, 600, 10);
+        let first = cache.store(&large).unwrap();
+        let second = cache.store(&large).unwrap();
+        assert_eq!(first, second);
+        assert!(first.palette.is_some());
+        let first = first.path;
+
+        // A palette is added to thumbnails cached without one
+        fs::remove_file(palette::sidecar_path(Path::new(&first))).unwrap();
+        assert_eq!(cache.store(&large).unwrap().palette, second.palette);
+
+        let thumb = image::open(&first).unwrap();
+        assert_eq!((thumb.width(), thumb.height()), (THUMBNAIL_SIZE, THUMBNAIL_SIZE / 2));
+
+        // Different content gets its own entry; small images keep their size
+        let small = cache.store(&png(64, 64, 200)).unwrap().path;
+        assert_ne!(small, first);
+        let small = image::open(&small).unwrap();
+        assert_eq!((small.width(), small.height()), (64, 64));
+
+        // Two thumbnails and their palettes
+        assert_eq!(fs::read_dir(dir.join("cache")).unwrap().count(), 4);
+        assert!(cache.store(b"not an image").is_none());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_folder_art_lookup() {
+        let dir = test_dir("artwork-folder");
+        let album = dir.join("album");
+        fs::create_dir_all(&album).unwrap();
+        fs::write(album.join("Folder.PNG"), png(32, 32, 50)).unwrap();
+        fs::write(album.join("back.jpg"), png(32, 32, 90)).unwrap();
+        assert_eq!(find_folder_image(&album), Some(album.join("Folder.PNG")));
+
+        // `cover` beats `folder`
+        fs::write(album.join("cover.jpg"), png(32, 32, 120)).unwrap();
+        assert_eq!(find_folder_image(&album), Some(album.join("cover.jpg")));
+
+        let cache = ArtworkCache::new(dir.join("cache")).unwrap();
+        let art = cache.folder_art(&album).unwrap();
+        assert_eq!(cache.folder_art(&album), Some(art));
+        assert_eq!(cache.folder_art(&dir), None);
+
+        // A file without embedded art falls back to the folder image
+        let cover = cover_bytes(&album.join("missing.mp3")).unwrap();
+        assert_eq!(cover, fs::read(album.join("cover.jpg")).unwrap());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,541 @@
+//! Reading chapter markers from audio files.
+//!
+//! Lofty reads none of the chapter formats, so they are parsed here: ID3v2
+//! CHAP and CTOC frames (kept by lofty as raw frames), Vorbis `CHAPTERxxx`
+//! comments, and for MP4 the QuickTime chapter track that iTunes writes or
+//! else a Nero `chpl` atom.
+
+use super::lyrics::id3v2_tag;
+use crate::api::chapters::Chapter;
+use lofty::config::ParseOptions;
+use lofty::file::FileType;
+use lofty::id3::v2::{Frame, Id3v2Tag, Id3v2Version};
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::ItemKey;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Nero chapter times count in units of 100 ns
+const CHPL_UNITS_PER_SEC: f64 = 10_000_000.0;
+
+/// A chapter's start, and its end and title if the format gives them.
+type Marker = (f64, Option<f64>, Option<String>);
+
+/// The chapters of the file at `path`, sorted by start; empty if it has
+/// none.
+pub fn read(path: &Path) -> Result<Vec<Chapter>, String> {
+    let tagged_file = Probe::open(path)
+        .map_err(|e| e.to_string())?
+        .guess_file_type()
+        .map_err(|e| e.to_string())?
+        .options(ParseOptions::new().read_cover_art(false))
+        .read()
+        .map_err(|e| e.to_string())?;
+    let duration_secs = tagged_file.properties().duration().as_secs_f64();
+
+    let mut markers = match tagged_file.file_type() {
+        FileType::Mp4 => {
+            let mut file = File::open(path).map_err(|e| e.to_string())?;
+            mp4_markers(&mut file).map_err(|e| e.to_string())?
+        }
+        file_type => id3v2_tag(path, file_type).map(|tag| id3_markers(&tag)).unwrap_or_default(),
+    };
+    if markers.is_empty() {
+        let comments = tagged_file.tags().iter().flat_map(|tag| tag.items()).filter_map(|item| {
+            let ItemKey::Unknown(key) = item.key() else { return None };
+            Some((key.as_str(), item.value().text()?))
+        });
+        markers = vorbis_markers(comments);
+    }
+    Ok(chapters(markers, duration_secs))
+}
+
+/// The index of the last of `chapters` that has started at `position_secs`.
+pub fn active_chapter(chapters: &[Chapter], position_secs: f64) -> Option<usize> {
+    chapters
+        .partition_point(|chapter| chapter.start_secs <= position_secs)
+        .checked_sub(1)
+}
+
+/// Turn markers into chapters that end where the next one starts, unless
+/// the format says otherwise.
+fn chapters(mut markers: Vec<Marker>, duration_secs: f64) -> Vec<Chapter> {
+    markers.retain(|(start, _, _)| start.is_finite() && *start >= 0.0);
+    markers.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let starts: Vec<f64> = markers.iter().map(|(start, _, _)| *start).collect();
+    markers
+        .into_iter()
+        .enumerate()
+        .map(|(i, (start_secs, end_secs, title))| {
+            let next = starts.get(i + 1).copied().unwrap_or(duration_secs);
+            Chapter {
+                title: title.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()),
+                start_secs,
+                end_secs: end_secs.filter(|&end| end > start_secs).unwrap_or(next),
+            }
+        })
+        .collect()
+}
+
+/// Markers from the CHAP frames of `tag`, keeping only the ones its
+/// top-level CTOC frame lists when it has one.
+fn id3_markers(tag: &Id3v2Tag) -> Vec<Marker> {
+    let synchsafe = tag.original_version() == Id3v2Version::V4;
+    let mut chapters: BTreeMap<String, Marker> = BTreeMap::new();
+    let mut order: Option<Vec<String>> = None;
+    for frame in tag {
+        let Frame::Binary(binary) = frame else { continue };
+        let data = &binary.data[..];
+        match binary.id().as_str() {
+            "CHAP" => {
+                let Some((element_id, rest)) = split_terminated(data) else { continue };
+                let (Some(start_ms), Some(end_ms)) = (be_u32(rest, 0), be_u32(rest, 4)) else {
+                    continue;
+                };
+                let title = rest.get(16..).and_then(|subframes| title(subframes, synchsafe));
+                let marker = (
+                    f64::from(start_ms) / 1000.0,
+                    Some(f64::from(end_ms) / 1000.0),
+                    title,
+                );
+                chapters.insert(latin1(element_id), marker);
+            }
+            "CTOC" => {
+                let Some((_, rest)) = split_terminated(data) else { continue };
+                let (Some(&flags), Some(&count)) = (rest.first(), rest.get(1)) else {
+                    continue;
+                };
+                // Only the top-level table decides which chapters are played
+                if flags & 0x02 == 0 {
+                    continue;
+                }
+                let mut entries = Vec::new();
+                let mut rest = &rest[2..];
+                for _ in 0..count {
+                    let Some((entry, remainder)) = split_terminated(rest) else { break };
+                    entries.push(latin1(entry));
+                    rest = remainder;
+                }
+                order = Some(entries);
+            }
+            _ => {}
+        }
+    }
+    match order {
+        Some(order) => order.iter().filter_map(|id| chapters.remove(id)).collect(),
+        None => chapters.into_values().collect(),
+    }
+}
+
+/// The title in the TIT2 sub-frame of a CHAP frame.
+fn title(mut subframes: &[u8], synchsafe: bool) -> Option<String> {
+    while subframes.len() >= 10 {
+        let size = be_u32(subframes, 4)?;
+        let size = if synchsafe {
+            (size & 0x7f)
+                | (size & 0x7f00) >> 1
+                | (size & 0x7f_0000) >> 2
+                | (size & 0x7f00_0000) >> 3
+        } else {
+            size
+        } as usize;
+        let body = subframes.get(10..10 + size)?;
+        if &subframes[..4] == b"TIT2" {
+            let (&encoding, text) = body.split_first()?;
+            return Some(id3_text(encoding, text));
+        }
+        subframes = &subframes[10 + size..];
+    }
+    None
+}
+
+/// Decode ID3v2 text in the given encoding, up to any terminator.
+fn id3_text(encoding: u8, text: &[u8]) -> String {
+    let utf16 = |text: &[u8], big_endian: bool| {
+        let units: Vec<u16> = text
+            .chunks_exact(2)
+            .map(|pair| match big_endian {
+                true => u16::from_be_bytes([pair[0], pair[1]]),
+                false => u16::from_le_bytes([pair[0], pair[1]]),
+            })
+            .take_while(|&unit| unit != 0)
+            .collect();
+        String::from_utf16_lossy(&units)
+    };
+    match encoding {
+        1 => match text {
+            [0xfe, 0xff, rest @ ..] => utf16(rest, true),
+            [0xff, 0xfe, rest @ ..] => utf16(rest, false),
+            _ => utf16(text, false),
+        },
+        2 => utf16(text, true),
+        3 => {
+            let end = text.iter().position(|&b| b == 0).unwrap_or(text.len());
+            String::from_utf8_lossy(&text[..end]).to_string()
+        }
+        _ => latin1(split_terminated(text).map_or(text, |(text, _)| text)),
+    }
+}
+
+/// Markers from Vorbis comments: `CHAPTER001=00:01:02.500` with the title in
+/// `CHAPTER001NAME`.
+fn vorbis_markers<'a>(comments: impl Iterator<Item = (&'a str, &'a str)>) -> Vec<Marker> {
+    let mut chapters: BTreeMap<u32, (Option<f64>, Option<String>)> = BTreeMap::new();
+    for (key, value) in comments {
+        let key = key.to_ascii_uppercase();
+        let Some(rest) = key.strip_prefix("CHAPTER") else { continue };
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+        let Ok(number) = rest[..digits].parse() else { continue };
+        let chapter = chapters.entry(number).or_default();
+        match &rest[digits..] {
+            "" => chapter.0 = parse_time(value),
+            "NAME" => chapter.1 = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    chapters
+        .into_values()
+        .filter_map(|(start, title)| Some((start?, None, title)))
+        .collect()
+}
+
+/// Seconds from an `HH:MM:SS.mmm` time.
+fn parse_time(time: &str) -> Option<f64> {
+    let mut secs = 0.0;
+    for part in time.trim().split(':') {
+        secs = secs * 60.0 + part.parse::<f64>().ok().filter(|p| *p >= 0.0)?;
+    }
+    Some(secs)
+}
+
+/// Markers from an MP4 file: its QuickTime chapter track, or else its Nero
+/// `chpl` atom.
+fn mp4_markers<R: Read + Seek>(file: &mut R) -> io::Result<Vec<Marker>> {
+    let Some(moov) = find_moov(file)? else {
+        return Ok(Vec::new());
+    };
+    let traks: Vec<&[u8]> =
+        atoms(&moov).filter(|(kind, _)| kind == b"trak").map(|(_, body)| body).collect();
+    let chapter_ids: Vec<u32> = traks
+        .iter()
+        .filter_map(|trak| child(trak, &[b"tref", b"chap"]))
+        .flat_map(|ids| ids.chunks_exact(4).filter_map(|id| be_u32(id, 0)))
+        .collect();
+    let chapter_trak = traks.iter().find(|trak| {
+        let id = child(trak, &[b"tkhd"]).and_then(|tkhd| {
+            let offset = if tkhd.first() == Some(&1) { 20 } else { 12 };
+            be_u32(tkhd, offset)
+        });
+        id.is_some_and(|id| chapter_ids.contains(&id))
+    });
+    if let Some(markers) = chapter_trak.and_then(|trak| text_track(trak, file).transpose()) {
+        return markers;
+    }
+    Ok(child(&moov, &[b"udta", b"chpl"]).map(chpl_markers).unwrap_or_default())
+}
+
+/// The body of the top-level `moov` atom.
+fn find_moov<R: Read + Seek>(file: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let end = file.seek(SeekFrom::End(0))?;
+    let mut offset = file.seek(SeekFrom::Start(0))?;
+    while offset + 8 <= end {
+        let mut header = [0u8; 8];
+        file.read_exact(&mut header)?;
+        let mut header_len = 8;
+        let mut size = u64::from(u32::from_be_bytes([header[0], header[1], header[2], header[3]]));
+        if size == 1 {
+            let mut large = [0u8; 8];
+            file.read_exact(&mut large)?;
+            size = u64::from_be_bytes(large);
+            header_len = 16;
+        } else if size == 0 {
+            size = end - offset;
+        }
+        if size < header_len {
+            break;
+        }
+        if &header[4..] == b"moov" {
+            let mut body = vec![0u8; (size - header_len) as usize];
+            file.read_exact(&mut body)?;
+            return Ok(Some(body));
+        }
+        offset = file.seek(SeekFrom::Start(offset + size))?;
+    }
+    Ok(None)
+}
+
+/// The child atoms in `data`, as type and body.
+fn atoms(mut data: &[u8]) -> impl Iterator<Item = (&[u8], &[u8])> {
+    std::iter::from_fn(move || {
+        let size = be_u32(data, 0)? as usize;
+        if size < 8 || size > data.len() {
+            return None;
+        }
+        let atom = (&data[4..8], &data[8..size]);
+        data = &data[size..];
+        Some(atom)
+    })
+}
+
+/// The body of the atom at `path` below `data`.
+fn child<'a>(data: &'a [u8], path: &[&[u8; 4]]) -> Option<&'a [u8]> {
+    path.iter().try_fold(data, |data, kind| {
+        atoms(data).find(|(atom, _)| atom == kind).map(|(_, body)| body)
+    })
+}
+
+/// Markers from the samples of a QuickTime text track, each a 16-bit
+/// length and the chapter title.
+fn text_track<R: Read + Seek>(trak: &[u8], file: &mut R) -> io::Result<Option<Vec<Marker>>> {
+    let stbl = child(trak, &[b"mdia", b"minf", b"stbl"]);
+    let mdhd = child(trak, &[b"mdia", b"mdhd"]);
+    let (Some(stbl), Some(mdhd)) = (stbl, mdhd) else {
+        return Ok(None);
+    };
+    let timescale = be_u32(mdhd, if mdhd.first() == Some(&1) { 20 } else { 12 });
+    let Some(timescale) = timescale.filter(|&t| t > 0).map(f64::from) else {
+        return Ok(None);
+    };
+
+    // Start of each sample, from the run-length coded durations
+    let mut starts = Vec::new();
+    let mut time = 0u64;
+    for entry in table(child(stbl, &[b"stts"]), 8) {
+        let (count, delta) = (be_u32(entry, 0).unwrap_or(0), be_u32(entry, 4).unwrap_or(0));
+        for _ in 0..count.min(10_000) {
+            starts.push(time as f64 / timescale);
+            time += u64::from(delta);
+        }
+    }
+
+    // File offset of each sample, from the chunk offsets and samples per
+    // chunk
+    let chunk_offsets: Vec<u64> = match child(stbl, &[b"co64"]) {
+        Some(co64) => table(Some(co64), 8).filter_map(|e| be_u64(e, 0)).collect(),
+        None => table(child(stbl, &[b"stco"]), 4)
+            .filter_map(|e| be_u32(e, 0))
+            .map(u64::from)
+            .collect(),
+    };
+    let stsz = child(stbl, &[b"stsz"]).unwrap_or_default();
+    let fixed_size = be_u32(stsz, 4).unwrap_or(0);
+    let size_of = |i: usize| match fixed_size {
+        0 => be_u32(stsz, 12 + i * 4),
+        size => Some(size),
+    };
+    let runs: Vec<(usize, u32)> = table(child(stbl, &[b"stsc"]), 12)
+        .filter_map(|e| Some((be_u32(e, 0)? as usize, be_u32(e, 4)?)))
+        .collect();
+    let mut offsets = Vec::with_capacity(starts.len());
+    for (chunk, &chunk_offset) in chunk_offsets.iter().enumerate() {
+        let per_chunk = runs.iter().rev().find(|(first, _)| *first <= chunk + 1).map_or(0, |r| r.1);
+        let mut offset = chunk_offset;
+        for _ in 0..per_chunk {
+            let Some(size) = size_of(offsets.len()) else { break };
+            offsets.push(offset);
+            offset += u64::from(size);
+        }
+    }
+
+    let mut markers = Vec::new();
+    for (start, offset) in starts.into_iter().zip(offsets) {
+        file.seek(SeekFrom::Start(offset))?;
+        let mut len = [0u8; 2];
+        file.read_exact(&mut len)?;
+        let mut text = vec![0u8; usize::from(u16::from_be_bytes(len))];
+        file.read_exact(&mut text)?;
+        let title = match &text[..] {
+            [0xfe, 0xff, ..] | [0xff, 0xfe, ..] => id3_text(1, &text),
+            _ => String::from_utf8_lossy(&text).to_string(),
+        };
+        markers.push((start, None, Some(title)));
+    }
+    Ok(Some(markers).filter(|markers| !markers.is_empty()))
+}
+
+/// The entries of a sample table atom with a version, flags and count
+/// before fixed-size entries.
+fn table(atom: Option<&[u8]>, entry_len: usize) -> impl Iterator<Item = &[u8]> {
+    let atom = atom.unwrap_or_default();
+    let count = be_u32(atom, 4).unwrap_or(0) as usize;
+    atom.get(8..).unwrap_or_default().chunks_exact(entry_len).take(count)
+}
+
+/// Markers from a Nero `chpl` atom.
+fn chpl_markers(chpl: &[u8]) -> Vec<Marker> {
+    // Version 1 has four more reserved bytes before the count
+    let mut pos = if chpl.first() == Some(&1) { 8 } else { 4 };
+    let Some(&count) = chpl.get(pos) else {
+        return Vec::new();
+    };
+    pos += 1;
+    let mut markers = Vec::new();
+    for _ in 0..count {
+        let (Some(start), Some(&len)) = (be_u64(chpl, pos), chpl.get(pos + 8)) else {
+            break;
+        };
+        let Some(title) = chpl.get(pos + 9..pos + 9 + usize::from(len)) else {
+            break;
+        };
+        let title = String::from_utf8_lossy(title).to_string();
+        markers.push((start as f64 / CHPL_UNITS_PER_SEC, None, Some(title)));
+        pos += 9 + usize::from(len);
+    }
+    markers
+}
+
+fn be_u32(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn be_u64(data: &[u8], at: usize) -> Option<u64> {
+    Some(u64::from_be_bytes(data.get(at..at + 8)?.try_into().ok()?))
+}
+
+/// Split `data` at its first null byte, dropping the null.
+fn split_terminated(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let end = data.iter().position(|&b| b == 0)?;
+    Some((&data[..end], &data[end + 1..]))
+}
+
+fn latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+/// An ID3v2 tag with a CHAP frame per `(start_ms, end_ms, title)` and a
+/// top-level CTOC frame listing them in reverse.
+#[cfg(test)]
+pub(crate) fn test_chapter_tag(chapters: &[(u32, u32, &str)]) -> Id3v2Tag {
+    use lofty::id3::v2::{BinaryFrame, FrameId};
+
+    let mut tag = Id3v2Tag::new();
+    let mut toc = b"toc\0\x03".to_vec();
+    toc.push(chapters.len() as u8);
+    for (i, (start_ms, end_ms, title)) in chapters.iter().enumerate() {
+        let mut data = format!("ch{}\0", i).into_bytes();
+        for field in [*start_ms, *end_ms, u32::MAX, u32::MAX] {
+            data.extend_from_slice(&field.to_be_bytes());
+        }
+        data.extend_from_slice(b"TIT2");
+        data.extend_from_slice(&(title.len() as u32 + 2).to_be_bytes());
+        data.extend_from_slice(&[0, 0, 3]);
+        data.extend_from_slice(title.as_bytes());
+        data.push(0);
+        tag.insert(Frame::Binary(BinaryFrame::new(FrameId::new("CHAP").unwrap(), data)));
+    }
+    for i in (0..chapters.len()).rev() {
+        toc.extend_from_slice(format!("ch{}\0", i).as_bytes());
+    }
+    tag.insert(Frame::Binary(BinaryFrame::new(FrameId::new("CTOC").unwrap(), toc)));
+    tag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+    use lofty::config::WriteOptions;
+    use std::io::Cursor;
+
+    fn atom(kind: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut atom = (body.len() as u32 + 8).to_be_bytes().to_vec();
+        atom.extend_from_slice(kind);
+        atom.extend_from_slice(body);
+        atom
+    }
+
+    fn full_atom(kind: &[u8; 4], fields: &[u32]) -> Vec<u8> {
+        let body: Vec<u8> = fields.iter().flat_map(|f| f.to_be_bytes()).collect();
+        atom(kind, &body)
+    }
+
+    #[test]
+    fn test_read_id3_chapters() {
+        let dir = test_dir("chapters");
+        let path = dir.join("book.wav");
+        let mut bytes = b"RIFF\0\0\0\0WAVEfmt ".to_vec();
+        for field in [16u32, 0x0002_0001, 8000, 8000 * 4, 0x0010_0004] {
+            bytes.extend_from_slice(&field.to_le_bytes());
+        }
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&(8000u32 * 4 * 10).to_le_bytes());
+        bytes.resize(44 + 8000 * 4 * 10, 0);
+        let riff_len = bytes.len() as u32 - 8;
+        bytes[4..8].copy_from_slice(&riff_len.to_le_bytes());
+        std::fs::write(&path, bytes).unwrap();
+
+        // The table of contents lists the chapters in reverse, but they play
+        // in time order
+        let tag = test_chapter_tag(&[(4000, 0, "Part One"), (0, 4000, "Intro")]);
+        tag.save_to_path(&path, WriteOptions::default()).unwrap();
+        let chapters = read(&path).unwrap();
+        let titles: Vec<_> = chapters.iter().map(|c| c.title.as_deref().unwrap()).collect();
+        assert_eq!(titles, ["Intro", "Part One"]);
+        assert_eq!((chapters[0].start_secs, chapters[0].end_secs), (0.0, 4.0));
+        // An end before the start falls back to the end of the file
+        assert_eq!((chapters[1].start_secs, chapters[1].end_secs), (4.0, 10.0));
+
+        assert_eq!(active_chapter(&chapters, 3.9), Some(0));
+        assert_eq!(active_chapter(&chapters, 4.0), Some(1));
+        assert!(read(&dir.join("missing.wav")).is_err());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_mp4_and_vorbis_markers() {
+        // A QuickTime chapter track with two samples in one chunk, preferred
+        // over the Nero chapters
+        let mdat_offset = 8u32;
+        let samples = [b"\0\x05Intro".to_vec(), b"\0\x03End".to_vec()];
+        let mut mdat: Vec<u8> = samples.concat();
+        let stbl = [
+            full_atom(b"stts", &[0, 2, 1, 3000, 1, 1000]),
+            full_atom(b"stsc", &[0, 1, 1, 2, 1]),
+            full_atom(b"stsz", &[0, 0, 2, 7, 5]),
+            full_atom(b"stco", &[0, 1, mdat_offset]),
+        ]
+        .concat();
+        let minf = atom(b"minf", &atom(b"stbl", &stbl));
+        let mdhd = full_atom(b"mdhd", &[0, 0, 0, 1000, 4000]);
+        let text_trak = atom(
+            b"trak",
+            &[full_atom(b"tkhd", &[0, 0, 0, 2]), atom(b"mdia", &[mdhd, minf].concat())].concat(),
+        );
+        let audio_trak = atom(
+            b"trak",
+            &[full_atom(b"tkhd", &[0, 0, 0, 1]), atom(b"tref", &full_atom(b"chap", &[2]))]
+                .concat(),
+        );
+        let mut chpl = vec![1, 0, 0, 0, 0, 0, 0, 0, 1];
+        chpl.extend_from_slice(&25_000_000u64.to_be_bytes());
+        chpl.push(4);
+        chpl.extend_from_slice(b"Nero");
+        let udta = atom(b"udta", &atom(b"chpl", &chpl));
+
+        let mut file = atom(b"mdat", &mdat);
+        file.extend(atom(b"moov", &[audio_trak.clone(), text_trak, udta.clone()].concat()));
+        let markers = mp4_markers(&mut Cursor::new(file)).unwrap();
+        let expected = [(0.0, None, Some("Intro".to_string())), (3.0, None, Some("End".into()))];
+        assert_eq!(markers, expected);
+
+        mdat.clear();
+        let mut file = atom(b"moov", &[audio_trak, udta].concat());
+        file.extend(atom(b"mdat", &mdat));
+        let markers = mp4_markers(&mut Cursor::new(file)).unwrap();
+        assert_eq!(markers, [(2.5, None, Some("Nero".to_string()))]);
+
+        let comments = [
+            ("CHAPTER002", "00:01:30.250"),
+            ("CHAPTER002NAME", "Second"),
+            ("chapter001", "00:00:00.000"),
+            ("CHAPTER003NAME", "No start"),
+        ];
+        let chapters = chapters(vorbis_markers(comments.into_iter()), 120.0);
+        assert_eq!(chapters.len(), 2);
+        assert_eq!((chapters[0].title.as_deref(), chapters[0].end_secs), (None, 90.25));
+        assert_eq!((chapters[1].title.as_deref(), chapters[1].end_secs), (Some("Second"), 120.0));
+    }
+}
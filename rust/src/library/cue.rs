@@ -0,0 +1,336 @@
+//! CUE sheets, which split one audio file into tracks.
+//!
+//! A sheet is read from `<name>.cue` or `<name>.<ext>.cue` beside the audio
+//! file, or from a `CUESHEET` tag in it. Each track becomes a song of its
+//! own, listed under the file's path with `#` and the track number appended
+//! and played as a time range of the file.
+
+use crate::api::scanner::{AudioFileMetadata, TrackRange};
+use super::formats;
+use lofty::tag::{ItemKey, Tag};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// CUE times count frames of a CD, 75 to the second
+const FRAMES_PER_SEC: f64 = 75.0;
+
+/// A parsed CUE sheet.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CueSheet {
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    pub songwriter: Option<String>,
+    pub genre: Option<String>,
+    pub date: Option<String>,
+    pub files: Vec<CueFile>,
+}
+
+/// A `FILE` of a sheet and its audio tracks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueFile {
+    pub name: String,
+    pub tracks: Vec<CueTrack>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueTrack {
+    pub number: u32,
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    pub songwriter: Option<String>,
+    /// Where `INDEX 01` puts the track in its file
+    pub start_secs: f64,
+}
+
+/// A track and where it ends in its file; `None` for the end of the file.
+pub type TrackSpan<'a> = (&'a CueTrack, Option<f64>);
+
+impl CueSheet {
+    /// The tracks of the audio file named `file_name`, each ending where
+    /// the next one starts.
+    ///
+    /// A sheet with a single `FILE` describes the file whatever its name,
+    /// since rips are often re-encoded without updating the sheet. Otherwise
+    /// the names are matched ignoring case, then ignoring the extension.
+    pub fn tracks_of(&self, file_name: &str) -> Vec<TrackSpan<'_>> {
+        let stem = |name: &str| {
+            Path::new(name)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_lowercase())
+        };
+        let file = match &self.files[..] {
+            [file] => Some(file),
+            files => files
+                .iter()
+                .find(|file| base_name(&file.name).eq_ignore_ascii_case(file_name))
+                .or_else(|| {
+                    files
+                        .iter()
+                        .find(|file| stem(base_name(&file.name)) == stem(file_name))
+                }),
+        };
+        let Some(file) = file else {
+            return Vec::new();
+        };
+        file.tracks
+            .iter()
+            .enumerate()
+            .map(|(i, track)| (track, file.tracks.get(i + 1).map(|next| next.start_secs)))
+            .collect()
+    }
+}
+
+/// Parse the text of a CUE sheet, or `None` if it has no audio tracks.
+///
+/// Tracks without an `INDEX 01` are skipped.
+pub fn parse(text: &str) -> Option<CueSheet> {
+    let mut sheet = CueSheet::default();
+    let mut track: Option<CueTrack> = None;
+    for line in text.lines().map(str::trim) {
+        let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let args = arguments(rest);
+        let first = args.first().cloned().filter(|arg| !arg.is_empty());
+        match command.to_ascii_uppercase().as_str() {
+            "FILE" => {
+                finish_track(&mut sheet, track.take());
+                if let Some(name) = first {
+                    sheet.files.push(CueFile { name, tracks: Vec::new() });
+                }
+            }
+            "TRACK" => {
+                finish_track(&mut sheet, track.take());
+                let audio = args.get(1).is_some_and(|kind| kind.eq_ignore_ascii_case("AUDIO"));
+                let number = first.and_then(|n| n.parse().ok());
+                track = number.filter(|_| audio).map(|number| CueTrack {
+                    number,
+                    title: None,
+                    performer: None,
+                    songwriter: None,
+                    start_secs: f64::NAN,
+                });
+            }
+            "INDEX" if args.first().is_some_and(|index| index == "01") => {
+                if let (Some(track), Some(time)) = (&mut track, args.get(1)) {
+                    track.start_secs = parse_time(time).unwrap_or(f64::NAN);
+                }
+            }
+            "TITLE" | "PERFORMER" | "SONGWRITER" => {
+                let field = match (&mut track, command.to_ascii_uppercase().as_str()) {
+                    (Some(track), "TITLE") => &mut track.title,
+                    (Some(track), "PERFORMER") => &mut track.performer,
+                    (Some(track), _) => &mut track.songwriter,
+                    (None, "TITLE") => &mut sheet.title,
+                    (None, "PERFORMER") => &mut sheet.performer,
+                    (None, _) => &mut sheet.songwriter,
+                };
+                *field = first;
+            }
+            "REM" if track.is_none() => {
+                let value = args.get(1).cloned().filter(|value| !value.is_empty());
+                match args.first().map(|key| key.to_ascii_uppercase()).as_deref() {
+                    Some("GENRE") => sheet.genre = value,
+                    Some("DATE") => sheet.date = value,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    finish_track(&mut sheet, track);
+    sheet.files.retain(|file| !file.tracks.is_empty());
+    (!sheet.files.is_empty()).then_some(sheet)
+}
+
+/// The sheet describing the audio file at `path`: a sidecar `.cue` file,
+/// or else the `CUESHEET` item of its tag.
+pub fn find(path: &Path, tag: Option<&Tag>) -> Option<CueSheet> {
+    if let Some(sheet) = sidecar(path).and_then(|sidecar| fs::read(sidecar).ok()) {
+        return parse(&formats::decode_text(sheet));
+    }
+    let embedded = tag?.get_string(&ItemKey::Unknown("CUESHEET".to_string()))?;
+    parse(embedded)
+}
+
+/// The `.cue` file beside the audio file at `path`, if there is one.
+pub fn sidecar(path: &Path) -> Option<PathBuf> {
+    let mut with_ext = path.as_os_str().to_owned();
+    with_ext.push(".cue");
+    [path.with_extension("cue"), path.with_extension("CUE"), PathBuf::from(with_ext)]
+        .into_iter()
+        .find(|candidate| candidate.is_file())
+}
+
+/// Split the metadata of a whole file into one song per track of `sheet`.
+///
+/// Fields the sheet leaves out keep the file's tags, except those that
+/// only make sense for the whole file.
+pub fn split_tracks(
+    metadata: &AudioFileMetadata,
+    sheet: &CueSheet,
+    file_duration_secs: f64,
+) -> Vec<AudioFileMetadata> {
+    let file_name = Path::new(&metadata.path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let tracks = sheet.tracks_of(&file_name);
+    let track_total = tracks.len() as u32;
+    let year = sheet
+        .date
+        .as_ref()
+        .and_then(|date| date.get(..4)?.parse().ok())
+        .or(metadata.year);
+    tracks
+        .into_iter()
+        .filter(|(track, _)| track.start_secs < file_duration_secs)
+        .map(|(track, end_secs)| {
+            let end = end_secs.unwrap_or(file_duration_secs).min(file_duration_secs);
+            AudioFileMetadata {
+                path: track_path(&metadata.path, track.number),
+                title: track.title.clone().or_else(|| Some(format!("Track {}", track.number))),
+                artist: (track.performer.clone())
+                    .or_else(|| sheet.performer.clone())
+                    .or_else(|| metadata.artist.clone()),
+                album: sheet.title.clone().or_else(|| metadata.album.clone()),
+                album_artist: sheet.performer.clone().or_else(|| metadata.album_artist.clone()),
+                track_number: Some(track.number),
+                track_total: Some(track_total),
+                year,
+                genre: sheet.genre.clone().or_else(|| metadata.genre.clone()),
+                composer: (track.songwriter.clone())
+                    .or_else(|| sheet.songwriter.clone())
+                    .or_else(|| metadata.composer.clone()),
+                duration_secs: Some((end - track.start_secs) as u64),
+                has_lyrics: false,
+                musicbrainz_recording_id: None,
+                range: Some(TrackRange {
+                    source_path: metadata.path.clone(),
+                    start_secs: track.start_secs,
+                    end_secs: end_secs.filter(|&end| end < file_duration_secs),
+                }),
+                ..metadata.clone()
+            }
+        })
+        .collect()
+}
+
+/// The library path of track `number` of the file at `path`.
+pub fn track_path(path: &str, number: u32) -> String {
+    format!("{}#{}", path, number)
+}
+
+/// The file path and track number of a path made by [`track_path`].
+pub fn split_track_path(path: &str) -> Option<(&str, u32)> {
+    let (file, number) = path.rsplit_once('#')?;
+    Some((file, number.parse().ok()?))
+}
+
+fn finish_track(sheet: &mut CueSheet, track: Option<CueTrack>) {
+    let (Some(track), Some(file)) = (track, sheet.files.last_mut()) else {
+        return;
+    };
+    let ordered = file.tracks.last().is_none_or(|last| last.start_secs <= track.start_secs);
+    if track.start_secs.is_finite() && ordered {
+        file.tracks.push(track);
+    }
+}
+
+/// The arguments of a command, with quotes removed from quoted ones.
+fn arguments(rest: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut rest = rest.trim();
+    while !rest.is_empty() {
+        let (arg, remainder) = match rest.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => rest.split_once(char::is_whitespace).unwrap_or((rest, "")),
+        };
+        args.push(arg.to_string());
+        rest = remainder.trim_start();
+    }
+    args
+}
+
+/// Seconds from a `mm:ss:ff` time.
+fn parse_time(time: &str) -> Option<f64> {
+    let mut parts = time.split(':').map(|part| part.parse::<u32>().ok());
+    let (minutes, seconds, frames) = (parts.next()??, parts.next()??, parts.next()??);
+    if parts.next().is_some() || seconds >= 60 || f64::from(frames) >= FRAMES_PER_SEC {
+        return None;
+    }
+    Some(f64::from(minutes * 60 + seconds) + f64::from(frames) / FRAMES_PER_SEC)
+}
+
+/// The file name of a `FILE` entry, which may include a directory.
+fn base_name(name: &str) -> &str {
+    name.rsplit(['/', '\\']).next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = "REM GENRE \"Progressive Rock\"\r
+REM DATE 1973\r
+PERFORMER \"The Band\"\r
+TITLE \"Live Album\"\r
+FILE \"Live Album.wav\" WAVE\r
+  TRACK 01 AUDIO\r
+    TITLE \"Opening\"\r
+    INDEX 01 00:00:00\r
+  TRACK 02 AUDIO\r
+    TITLE \"Second Song\"\r
+    PERFORMER \"Guest Singer\"\r
+    INDEX 00 03:10:00\r
+    INDEX 01 03:12:37\r
+  TRACK 03 DATA\r
+    INDEX 01 05:00:00\r
+  TRACK 04 AUDIO\r
+    TITLE Encore\r
+    INDEX 01 07:00:74\r
+";
+
+    #[test]
+    fn test_parse_cue_sheet() {
+        let sheet = parse(SHEET).unwrap();
+        assert_eq!(sheet.title.as_deref(), Some("Live Album"));
+        assert_eq!(sheet.performer.as_deref(), Some("The Band"));
+        assert_eq!(sheet.genre.as_deref(), Some("Progressive Rock"));
+        assert_eq!(sheet.date.as_deref(), Some("1973"));
+        assert_eq!(sheet.files.len(), 1);
+
+        let tracks = &sheet.files[0].tracks;
+        let numbers: Vec<u32> = tracks.iter().map(|t| t.number).collect();
+        assert_eq!(numbers, [1, 2, 4]);
+        assert_eq!(tracks[1].performer.as_deref(), Some("Guest Singer"));
+        assert_eq!(tracks[1].start_secs, 192.0 + 37.0 / 75.0);
+        assert_eq!(tracks[2].title.as_deref(), Some("Encore"));
+        assert_eq!(tracks[2].start_secs, 420.0 + 74.0 / 75.0);
+
+        // The only file matches whatever the audio is called
+        let spans = sheet.tracks_of("live album.flac");
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].1, Some(tracks[1].start_secs));
+        assert_eq!(spans[2].1, None);
+
+        assert_eq!(parse("TITLE \"No tracks\"\nFILE \"a.wav\" WAVE\n"), None);
+        assert_eq!(parse_time("00:60:00"), None);
+        let path = track_path("/music/a#b.flac", 12);
+        assert_eq!(split_track_path(&path), Some(("/music/a#b.flac", 12)));
+        assert_eq!(split_track_path("/music/a#b.flac"), None);
+    }
+
+    #[test]
+    fn test_tracks_of_multi_file_sheet() {
+        let sheet = parse(
+            "FILE \"disc\\01.wav\" WAVE\nTRACK 1 AUDIO\nINDEX 01 00:00:00\nTRACK 2 AUDIO\n\
+             INDEX 01 01:00:00\nFILE \"02.wav\" WAVE\nTRACK 3 AUDIO\nINDEX 01 00:00:00\n",
+        )
+        .unwrap();
+        let numbers = |name: &str| -> Vec<u32> {
+            sheet.tracks_of(name).iter().map(|(track, _)| track.number).collect()
+        };
+        assert_eq!(numbers("01.WAV"), [1, 2]);
+        assert_eq!(numbers("02.flac"), [3]);
+        assert!(numbers("03.wav").is_empty());
+    }
+}
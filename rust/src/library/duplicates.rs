@@ -0,0 +1,286 @@
+//! Finding files that hold the same recording, and which copy to keep.
+//!
+//! Files are compared by acoustic fingerprint, so a FLAC rip and an MP3
+//! made from it are found even though their bytes and tags differ. Only
+//! files of about the same length are compared, which keeps large libraries
+//! from needing every pair. Copies are ranked lossless first, then by
+//! resolution or bitrate, and the best one is suggested to keep.
+
+use super::acoustic;
+use super::fingerprint::{FingerprintIndex, IndexEntry};
+use super::scan;
+use crate::api::scanner::{
+    scan_default_options, AudioFileMetadata, DuplicateFile, DuplicateGroup, DuplicateReport,
+    ScanFailure, ScanFailureType, ScanOptions,
+};
+use crate::audio::decoder::DecoderError;
+use rayon::prelude::*;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Fingerprints at least this similar are taken to be the same recording
+pub const MIN_SIMILARITY: f32 = 0.8;
+
+/// Copies of a recording differ in length by at most this many seconds
+const MAX_DURATION_DIFF_SECS: u64 = 10;
+
+/// Formats that only store lossless audio. ALAC in MP4 is recognized by its
+/// bit depth instead, which lofty only reports for lossless codecs.
+const LOSSLESS_FORMATS: &[&str] = &["aif", "aifc", "aiff", "ape", "flac", "wav", "wave", "wv"];
+
+/// A file with its acoustic fingerprint.
+pub struct Candidate {
+    pub metadata: AudioFileMetadata,
+    pub fingerprint: Vec<u32>,
+}
+
+/// Fingerprint `paths` and group them by recording.
+///
+/// Fingerprints are cached in `index_file` when given, keyed by file size
+/// and modification time. Files that cannot be read or decoded are reported
+/// in `failed`.
+pub fn find(paths: &[String], index_file: Option<&str>) -> DuplicateReport {
+    let options = scan_default_options();
+    let mut index = index_file.map(FingerprintIndex::load);
+    let results: Vec<_> = paths
+        .par_iter()
+        .map(|path| {
+            let path = Path::new(path);
+            let file = fs::metadata(path).ok();
+            let size = file.as_ref().map_or(0, |m| m.len());
+            let modified = file
+                .and_then(|m| m.modified().ok())
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_secs() as i64);
+            let cached = index
+                .as_ref()
+                .and_then(|index| index.get(&path.to_string_lossy()))
+                .filter(|entry| entry.matches(size, modified))
+                .and_then(|entry| acoustic::decode(&entry.hash));
+            (size, modified, read(path, modified, &options, cached))
+        })
+        .collect();
+
+    let mut candidates = Vec::new();
+    let mut failed = Vec::new();
+    for (size, modified, result) in results {
+        match result {
+            Ok(Some(candidate)) => {
+                if let Some(index) = index.as_mut() {
+                    let hash = acoustic::encode(&candidate.fingerprint);
+                    let entry = IndexEntry { size, modified, hash };
+                    index.insert(candidate.metadata.path.clone(), entry);
+                }
+                candidates.push(candidate);
+            }
+            Ok(None) => {}
+            Err(failure) => failed.push(failure),
+        }
+    }
+    if let Some(index) = index.as_mut() {
+        let paths: HashSet<&str> = paths.iter().map(String::as_str).collect();
+        index.retain(|path| paths.contains(path) || Path::new(path).exists());
+        let _ = index.save();
+    }
+
+    DuplicateReport {
+        groups: group(candidates),
+        failed,
+    }
+}
+
+/// Read one file's properties and fingerprint it, unless a fingerprint is
+/// already known. `None` for files too short to fingerprint, and for files
+/// split by a CUE sheet, which hold whole albums rather than songs.
+fn read(
+    path: &Path,
+    modified: i64,
+    options: &ScanOptions,
+    fingerprint: Option<Vec<u32>>,
+) -> Result<Option<Candidate>, ScanFailure> {
+    let ext = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    let songs = scan::read_file(path, ext, modified, options, None)?;
+    let Some(metadata) = songs.into_iter().next().filter(|song| song.range.is_none()) else {
+        return Ok(None);
+    };
+    let fingerprint = match fingerprint {
+        Some(fingerprint) => fingerprint,
+        None => acoustic::fingerprint(path).map_err(|e| ScanFailure {
+            path: metadata.path.clone(),
+            reason: failure_type(&e),
+            detail: e.to_string(),
+        })?,
+    };
+    Ok((!fingerprint.is_empty()).then_some(Candidate { metadata, fingerprint }))
+}
+
+fn failure_type(error: &DecoderError) -> ScanFailureType {
+    match error {
+        DecoderError::IoError(_) => ScanFailureType::Io,
+        DecoderError::UnsupportedFormat(_) => ScanFailureType::UnsupportedContainer,
+        DecoderError::NoAudioTrack => ScanFailureType::ZeroDuration,
+        DecoderError::DecodingFailed(_) | DecoderError::ResamplingFailed(_) => {
+            ScanFailureType::Corrupt
+        }
+    }
+}
+
+/// Whether the file stores lossless audio.
+pub fn is_lossless(metadata: &AudioFileMetadata) -> bool {
+    LOSSLESS_FORMATS.contains(&metadata.format.as_str()) || metadata.bit_depth.is_some()
+}
+
+/// Sort key for the quality of a copy; greater is better. Lossless copies
+/// rank by bit depth, then sample rate; lossy ones by bitrate, then sample
+/// rate.
+fn quality(metadata: &AudioFileMetadata) -> (bool, u32, u32) {
+    let lossless = is_lossless(metadata);
+    let detail = if lossless {
+        metadata.bit_depth
+    } else {
+        metadata.bitrate_kbps
+    };
+    (lossless, detail.unwrap_or(0), metadata.sample_rate.unwrap_or(0))
+}
+
+/// Group candidates holding the same recording, best copy first. Files
+/// without a duplicate are left out.
+pub fn group(mut candidates: Vec<Candidate>) -> Vec<DuplicateGroup> {
+    candidates.sort_by_key(|c| c.metadata.duration_secs.unwrap_or(0));
+    let duration = |i: usize| candidates[i].metadata.duration_secs.unwrap_or(0);
+
+    let matches: Vec<(usize, usize)> = (0..candidates.len())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let candidates = &candidates;
+            (i + 1..candidates.len())
+                .take_while(move |&j| duration(j) - duration(i) <= MAX_DURATION_DIFF_SECS)
+                .filter(move |&j| {
+                    let a = &candidates[i].fingerprint;
+                    acoustic::similarity(a, &candidates[j].fingerprint) >= MIN_SIMILARITY
+                })
+                .map(move |j| (i, j))
+        })
+        .collect();
+
+    // Union the matching pairs; each set's root is its smallest index
+    let mut parent: Vec<usize> = (0..candidates.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    for (i, j) in matches {
+        let (a, b) = (root(&mut parent, i), root(&mut parent, j));
+        parent[a.max(b)] = a.min(b);
+    }
+    let mut sets: Vec<Vec<usize>> = vec![Vec::new(); candidates.len()];
+    for i in 0..candidates.len() {
+        let r = root(&mut parent, i);
+        sets[r].push(i);
+    }
+
+    let mut groups: Vec<DuplicateGroup> = sets
+        .into_iter()
+        .filter(|set| set.len() > 1)
+        .map(|mut set| {
+            set.sort_by(|&a, &b| {
+                let (a, b) = (&candidates[a].metadata, &candidates[b].metadata);
+                (Reverse(quality(a)), &a.path).cmp(&(Reverse(quality(b)), &b.path))
+            });
+            let keep = &candidates[set[0]];
+            let files = set
+                .iter()
+                .map(|&i| {
+                    let Candidate { metadata, fingerprint } = &candidates[i];
+                    DuplicateFile {
+                        path: metadata.path.clone(),
+                        format: metadata.format.clone(),
+                        lossless: is_lossless(metadata),
+                        bitrate_kbps: metadata.bitrate_kbps,
+                        sample_rate: metadata.sample_rate,
+                        bit_depth: metadata.bit_depth,
+                        duration_secs: metadata.duration_secs,
+                        similarity: acoustic::similarity(&keep.fingerprint, fingerprint),
+                    }
+                })
+                .collect();
+            DuplicateGroup {
+                keep_path: keep.metadata.path.clone(),
+                files,
+            }
+        })
+        .collect();
+    groups.sort_by(|a, b| a.keep_path.cmp(&b.keep_path));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::acoustic::tests::melody;
+    use crate::test_util::{test_dir, write_wav, WavFormat};
+
+    #[test]
+    fn test_find_duplicates() {
+        let dir = test_dir("duplicates");
+        let path = |name: &str| dir.join(name).to_string_lossy().to_string();
+
+        write_wav(&dir.join("a.wav"), 22050, 1, WavFormat::Int16, &melody(1, 20, 22050));
+        write_wav(&dir.join("b.wav"), 44100, 1, WavFormat::Int24, &melody(1, 20, 44100));
+        write_wav(&dir.join("c.wav"), 44100, 1, WavFormat::Int24, &melody(2, 20, 44100));
+        write_wav(&dir.join("silent.wav"), 44100, 1, WavFormat::Int16, &[0.0; 44100]);
+        fs::write(dir.join("broken.wav"), b"RIFF....WAVEjunk").unwrap();
+        let paths: Vec<String> = ["a.wav", "b.wav", "c.wav", "silent.wav", "broken.wav"]
+            .iter()
+            .map(|name| path(name))
+            .collect();
+
+        let index = path("index");
+        for _ in 0..2 {
+            // The second round reads fingerprints from the index
+            let report = find(&paths, Some(&index));
+            assert_eq!(report.groups.len(), 1);
+            let group = &report.groups[0];
+            assert_eq!(group.keep_path, path("b.wav"));
+            let files: Vec<_> = group.files.iter().map(|f| f.path.clone()).collect();
+            assert_eq!(files, [path("b.wav"), path("a.wav")]);
+            assert!(group.files.iter().all(|f| f.lossless && f.similarity > 0.9));
+            assert_eq!(group.files[0].similarity, 1.0);
+            assert_eq!(group.files[1].bit_depth, Some(16));
+
+            assert_eq!(report.failed.len(), 1);
+            assert_eq!(report.failed[0].path, path("broken.wav"));
+        }
+        let cached = FingerprintIndex::load(&index);
+        assert!(cached.get(&path("c.wav")).is_some());
+        assert!(cached.get(&path("broken.wav")).is_none());
+
+        // A high-bitrate lossy copy ranks below any lossless one
+        let options = scan_default_options();
+        let read = |name: &str| {
+            let songs = scan::read_file(&dir.join(name), "wav".into(), 0, &options, None);
+            songs.unwrap().pop().unwrap()
+        };
+        let mut lossy = read("b.wav");
+        lossy.format = "mp3".to_string();
+        lossy.bit_depth = None;
+        lossy.bitrate_kbps = Some(320);
+        let lossless = read("a.wav");
+        assert!(!is_lossless(&lossy));
+        assert!(quality(&lossless) > quality(&lossy));
+        let mut low = lossy.clone();
+        low.bitrate_kbps = Some(128);
+        assert!(quality(&lossy) > quality(&low));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
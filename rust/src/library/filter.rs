@@ -0,0 +1,159 @@
+//! Exclusion rules applied while walking a scanned folder.
+
+use crate::api::scanner::ScanOptions;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::fs;
+use std::path::Path;
+use walkdir::DirEntry;
+
+/// Files whose presence hides a folder and everything below it
+const MARKER_FILES: &[&str] = &[".nomedia", ".ignore"];
+
+/// Decides which entries of a walk are skipped.
+pub struct ScanFilter {
+    excludes: GlobSet,
+    honor_marker_files: bool,
+    min_file_size: u64,
+}
+
+impl ScanFilter {
+    /// Compile the exclusion rules in `options`.
+    ///
+    /// Returns an error naming the first invalid glob pattern.
+    pub fn new(options: &ScanOptions) -> Result<Self, String> {
+        let mut excludes = GlobSetBuilder::new();
+        for pattern in &options.exclude_globs {
+            let glob = GlobBuilder::new(pattern)
+                .case_insensitive(true)
+                .literal_separator(true)
+                .build()
+                .map_err(|e| format!("Invalid exclude pattern {:?}: {}", pattern, e.kind()))?;
+            excludes.add(glob);
+        }
+        Ok(Self {
+            excludes: excludes.build().map_err(|e| e.to_string())?,
+            honor_marker_files: options.honor_marker_files,
+            min_file_size: u64::from(options.min_file_size),
+        })
+    }
+
+    /// Whether the walk should skip `entry`, and everything below it for a
+    /// folder. The root itself is never skipped.
+    pub fn skips(&self, root: &Path, entry: &DirEntry) -> bool {
+        if entry.depth() == 0 {
+            return false;
+        }
+        let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        if entry.file_type().is_dir() {
+            return self.skips_dir(relative, entry.path());
+        }
+        self.skips_file(relative, entry.metadata().ok().map(|m| m.len()))
+    }
+
+    /// Whether `path`, found outside a walk of `root`, is skipped by the
+    /// rules for itself or any folder between it and `root`.
+    pub fn excludes(&self, root: &Path, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(root) else {
+            return true;
+        };
+        let mut dir = root.to_path_buf();
+        for ancestor in relative.parent().into_iter().flat_map(|p| p.components()) {
+            dir.push(ancestor);
+            if self.skips_dir(dir.strip_prefix(root).unwrap_or(&dir), &dir) {
+                return true;
+            }
+        }
+        match fs::metadata(path) {
+            Ok(m) if m.is_dir() => self.skips_dir(relative, path),
+            metadata => self.skips_file(relative, metadata.ok().map(|m| m.len())),
+        }
+    }
+
+    fn skips_dir(&self, relative: &Path, dir: &Path) -> bool {
+        // `Music/**` should also prune the `Music` folder itself
+        self.excludes.is_match(relative)
+            || self.excludes.is_match(relative.join(""))
+            || (self.honor_marker_files && has_marker(dir))
+    }
+
+    fn skips_file(&self, relative: &Path, len: Option<u64>) -> bool {
+        self.excludes.is_match(relative)
+            || (self.min_file_size > 0 && len.is_none_or(|len| len < self.min_file_size))
+    }
+
+    /// Whether `path` is a marker file that hides its folder, when markers
+    /// are honored.
+    pub fn is_marker(&self, path: &Path) -> bool {
+        self.honor_marker_files
+            && path
+                .file_name()
+                .is_some_and(|name| MARKER_FILES.iter().any(|marker| name == *marker))
+    }
+}
+
+/// Whether `dir` contains a marker file.
+fn has_marker(dir: &Path) -> bool {
+    MARKER_FILES.iter().any(|name| dir.join(name).is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::scanner::scan_default_options;
+    use crate::test_util::test_dir;
+    use walkdir::WalkDir;
+
+    #[test]
+    fn test_filter_rules() {
+        let root = test_dir("filter");
+        for dir in ["Music/Album", "Ringtones/Pack", "Podcasts/Hidden", "notes"] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        std::fs::write(root.join("Music/Album/song.mp3"), vec![0u8; 4096]).unwrap();
+        std::fs::write(root.join("Music/Album/tiny.mp3"), vec![0u8; 16]).unwrap();
+        std::fs::write(root.join("Ringtones/Pack/ring.mp3"), vec![0u8; 4096]).unwrap();
+        std::fs::write(root.join("Podcasts/Hidden/.nomedia"), b"").unwrap();
+        std::fs::write(root.join("Podcasts/Hidden/episode.mp3"), vec![0u8; 4096]).unwrap();
+        std::fs::write(root.join("notes/memo.mp3"), vec![0u8; 4096]).unwrap();
+
+        let walk = |options: &ScanOptions| {
+            let filter = ScanFilter::new(options).unwrap();
+            let mut files: Vec<String> = WalkDir::new(&root)
+                .into_iter()
+                .filter_entry(|e| !filter.skips(&root, e))
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .collect();
+            files.sort();
+            files
+        };
+
+        let mut options = scan_default_options();
+        assert_eq!(walk(&options), ["memo.mp3", "song.mp3", "tiny.mp3"]);
+
+        options.exclude_globs.push("NOTES/**".to_string());
+        options.min_file_size = 1024;
+        assert_eq!(walk(&options), ["song.mp3"]);
+
+        options.exclude_globs.clear();
+        options.honor_marker_files = false;
+        options.min_file_size = 0;
+        assert_eq!(walk(&options).len(), 6);
+
+        // Single paths are checked against the rules of their folders too
+        let filter = ScanFilter::new(&scan_default_options()).unwrap();
+        assert!(!filter.excludes(&root, &root.join("Music/Album/song.mp3")));
+        assert!(filter.excludes(&root, &root.join("Ringtones/Pack/ring.mp3")));
+        assert!(filter.excludes(&root, &root.join("Podcasts/Hidden/episode.mp3")));
+        assert!(filter.excludes(&root, &root.join("Podcasts/Hidden")));
+        assert!(filter.excludes(&root, Path::new("/elsewhere/song.mp3")));
+        assert!(filter.is_marker(&root.join("Podcasts/Hidden/.nomedia")));
+        assert!(!filter.is_marker(&root.join("Music/Album/song.mp3")));
+
+        options.exclude_globs = vec!["[".to_string()];
+        assert!(ScanFilter::new(&options).is_err());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
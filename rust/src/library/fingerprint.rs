@@ -0,0 +1,166 @@
+//! Content fingerprints for following files across renames and moves.
+//!
+//! A fingerprint hashes the file size with its first and last 64 KiB, which
+//! is cheap to compute and in practice unique within a library. The scanner
+//! keeps fingerprints in an index file between scans, so a path that
+//! disappeared can be matched against one that appeared.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Bytes hashed from each end of a file
+const SPAN: u64 = 64 * 1024;
+
+/// Version tag of the index format
+const INDEX_VERSION: &str = "v1";
+
+/// Fingerprint of the file at `path`, as a hex string.
+pub fn fingerprint(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let size = file.metadata()?.len();
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&size.to_le_bytes());
+
+    let mut buf = vec![0u8; SPAN.min(size) as usize];
+    file.read_exact(&mut buf)?;
+    hasher.update(&buf);
+    if size > SPAN {
+        let tail = SPAN.min(size - SPAN);
+        file.seek(SeekFrom::End(-(tail as i64)))?;
+        buf.truncate(tail as usize);
+        file.read_exact(&mut buf)?;
+        hasher.update(&buf);
+    }
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// A fingerprint together with the file state it was computed for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexEntry {
+    pub size: u64,
+    pub modified: i64,
+    pub hash: String,
+}
+
+impl IndexEntry {
+    /// Whether the fingerprint still describes a file of this size and
+    /// modification time.
+    pub fn matches(&self, size: u64, modified: i64) -> bool {
+        self.size == size && self.modified == modified
+    }
+}
+
+/// Fingerprints of scanned files by path, persisted in a text file.
+pub struct FingerprintIndex {
+    file: PathBuf,
+    entries: HashMap<String, IndexEntry>,
+}
+
+impl FingerprintIndex {
+    /// Load the index kept in `file`. A missing or unreadable index starts
+    /// out empty, which only costs re-hashing.
+    pub fn load(file: impl Into<PathBuf>) -> Self {
+        let file = file.into();
+        let entries = fs::read_to_string(&file)
+            .ok()
+            .filter(|text| text.lines().next() == Some(INDEX_VERSION))
+            .map(|text| text.lines().skip(1).filter_map(parse_line).collect())
+            .unwrap_or_default();
+        Self { file, entries }
+    }
+
+    pub fn get(&self, path: &str) -> Option<&IndexEntry> {
+        self.entries.get(path)
+    }
+
+    pub fn insert(&mut self, path: String, entry: IndexEntry) {
+        self.entries.insert(path, entry);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &IndexEntry)> {
+        self.entries.iter()
+    }
+
+    /// Keep only the entries for which `keep` returns true.
+    pub fn retain(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.entries.retain(|path, _| keep(path));
+    }
+
+    /// Write the index back, replacing the file atomically.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp = self.file.with_extension("tmp");
+        let mut out = io::BufWriter::new(File::create(&temp)?);
+        writeln!(out, "{}", INDEX_VERSION)?;
+        for (path, entry) in &self.entries {
+            // Paths go last, so only a newline in one could break the format
+            if !path.contains('\n') {
+                writeln!(out, "{}\t{}\t{}\t{}", entry.hash, entry.size, entry.modified, path)?;
+            }
+        }
+        out.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        fs::rename(temp, &self.file)
+    }
+}
+
+/// Parse a `hash size modified path` line of the index.
+fn parse_line(line: &str) -> Option<(String, IndexEntry)> {
+    let mut fields = line.splitn(4, '\t');
+    let hash = fields.next()?.to_string();
+    let size = fields.next()?.parse().ok()?;
+    let modified = fields.next()?.parse().ok()?;
+    let path = fields.next()?.to_string();
+    Some((path, IndexEntry { size, modified, hash }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+
+    #[test]
+    fn test_fingerprint_and_index() {
+        let dir = test_dir("fingerprint");
+
+        let bytes: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(dir.join("a.flac"), &bytes).unwrap();
+        fs::write(dir.join("b.flac"), &bytes).unwrap();
+        let hash = fingerprint(&dir.join("a.flac")).unwrap();
+        assert_eq!(fingerprint(&dir.join("b.flac")).unwrap(), hash);
+
+        // The middle of a large file is not hashed, but its ends and size are
+        let mut middle = bytes.clone();
+        middle[100_000] ^= 0xff;
+        fs::write(dir.join("b.flac"), &middle).unwrap();
+        assert_eq!(fingerprint(&dir.join("b.flac")).unwrap(), hash);
+        let mut tail = bytes.clone();
+        *tail.last_mut().unwrap() ^= 0xff;
+        fs::write(dir.join("b.flac"), &tail).unwrap();
+        assert_ne!(fingerprint(&dir.join("b.flac")).unwrap(), hash);
+        fs::write(dir.join("b.flac"), &bytes[..1000]).unwrap();
+        assert_ne!(fingerprint(&dir.join("b.flac")).unwrap(), hash);
+
+        let file = dir.join("index/fingerprints");
+        let mut index = FingerprintIndex::load(&file);
+        assert!(index.get("/music/a.flac").is_none());
+        let entry = IndexEntry { size: 200_000, modified: 42, hash };
+        index.insert("/music/a\tb.flac".to_string(), entry.clone());
+        index.insert("/music/gone.flac".to_string(), entry.clone());
+        index.retain(|path| !path.ends_with("gone.flac"));
+        index.save().unwrap();
+
+        let index = FingerprintIndex::load(&file);
+        assert_eq!(index.get("/music/a\tb.flac"), Some(&entry));
+        assert!(index.get("/music/gone.flac").is_none());
+        assert!(entry.matches(200_000, 42) && !entry.matches(200_000, 43));
+
+        fs::write(&file, "v0\nabc\t1\t2\t/music/a.flac\n").unwrap();
+        assert_eq!(FingerprintIndex::load(&file).iter().count(), 0);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
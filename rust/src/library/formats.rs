@@ -0,0 +1,148 @@
+//! Which files the library scanner treats as audio.
+//!
+//! A file is only worth listing if the engine can play it and lofty can read
+//! its tags, so the default extensions are the ones claimed by symphonia's
+//! format readers that lofty also understands. Files with a wrong or missing
+//! extension can optionally be identified from their first bytes.
+
+use lofty::file::FileType;
+use lofty::probe::Probe;
+use std::path::Path;
+
+/// Extensions lofty reads tags from. `.oga` is not in lofty's extension map
+/// but is identified from its content.
+const TAGGED_EXTENSIONS: &[&str] = &[
+    "aac", "aif", "aifc", "aiff", "ape", "flac", "m4a", "m4b", "mp2", "mp3", "mp4", "mpc", "oga",
+    "ogg", "opus", "spx", "wav", "wave", "wv",
+];
+
+/// Extensions the Ogg reader claims for codecs symphonia cannot decode
+#[cfg(native_audio_engine)]
+const UNDECODABLE_EXTENSIONS: &[&str] = &["opus", "spx"];
+
+/// Files that often sit next to audio and are never worth sniffing
+const COMPANION_EXTENSIONS: &[&str] = &[
+    "bmp", "cue", "db", "gif", "htm", "html", "ini", "jpeg", "jpg", "json", "log", "lrc", "m3u",
+    "m3u8", "md", "nfo", "pdf", "pls", "png", "sfv", "txt", "webp", "xml", "xspf",
+];
+
+/// Lowercase extensions, without the dot, that the engine can play and the
+/// scanner can read tags from.
+#[cfg(native_audio_engine)]
+pub fn supported_extensions() -> Vec<String> {
+    use symphonia::core::probe::QueryDescriptor;
+    use symphonia::default::formats::*;
+
+    let descriptors = [
+        AdtsReader::query(),
+        AiffReader::query(),
+        CafReader::query(),
+        FlacReader::query(),
+        IsoMp4Reader::query(),
+        MkvReader::query(),
+        MpaReader::query(),
+        OggReader::query(),
+        WavReader::query(),
+    ];
+    let mut extensions: Vec<String> = descriptors
+        .iter()
+        .flat_map(|d| d.iter())
+        .flat_map(|d| d.extensions.iter())
+        .map(|ext| ext.to_lowercase())
+        .filter(|ext| TAGGED_EXTENSIONS.contains(&ext.as_str()))
+        .filter(|ext| !UNDECODABLE_EXTENSIONS.contains(&ext.as_str()))
+        .collect();
+    extensions.sort();
+    extensions.dedup();
+    extensions
+}
+
+/// Lowercase extensions, without the dot, that the scanner can read tags
+/// from. Without the native engine the platform player decodes, so every
+/// format lofty reads is listed.
+#[cfg(not(native_audio_engine))]
+pub fn supported_extensions() -> Vec<String> {
+    TAGGED_EXTENSIONS.iter().map(|ext| ext.to_string()).collect()
+}
+
+/// Whether a file with `ext` could be audio in disguise.
+///
+/// Images, lyrics, playlists and the like are skipped so sniffing does not
+/// open every file in the library.
+pub fn worth_sniffing(ext: Option<&str>) -> bool {
+    ext.is_none_or(|ext| !COMPANION_EXTENSIONS.contains(&ext))
+}
+
+/// Identify an audio file from its first bytes, returning the usual
+/// extension for its format.
+pub fn sniff(path: &Path) -> Option<&'static str> {
+    let file_type = Probe::open(path).ok()?.guess_file_type().ok()?.file_type()?;
+    canonical_extension(file_type)
+}
+
+/// Decode a text file that sits beside audio (lyrics, CUE sheets), falling
+/// back to Latin-1 for files that are not UTF-8.
+pub fn decode_text(bytes: Vec<u8>) -> String {
+    let text = String::from_utf8(bytes)
+        .unwrap_or_else(|e| e.into_bytes().iter().map(|&b| b as char).collect());
+    text.strip_prefix('\u{feff}').map(str::to_string).unwrap_or(text)
+}
+
+/// The usual extension for files of `file_type`.
+fn canonical_extension(file_type: FileType) -> Option<&'static str> {
+    Some(match file_type {
+        FileType::Aac => "aac",
+        FileType::Aiff => "aiff",
+        FileType::Ape => "ape",
+        FileType::Flac => "flac",
+        FileType::Mpeg => "mp3",
+        FileType::Mp4 => "m4a",
+        FileType::Mpc => "mpc",
+        FileType::Opus => "opus",
+        FileType::Vorbis => "ogg",
+        FileType::Speex => "spx",
+        FileType::Wav => "wav",
+        FileType::WavPack => "wv",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+
+    #[test]
+    fn test_supported_extensions() {
+        let extensions = supported_extensions();
+        for ext in ["mp3", "flac", "ogg", "oga", "m4a", "wav", "aiff", "aac"] {
+            assert!(extensions.contains(&ext.to_string()), "missing {}", ext);
+        }
+        // Containers lofty cannot read tags from are left out
+        assert!(!extensions.contains(&"mkv".to_string()));
+        assert!(!extensions.contains(&"caf".to_string()));
+        #[cfg(native_audio_engine)]
+        assert!(!extensions.contains(&"opus".to_string()));
+    }
+
+    #[test]
+    fn test_sniff() {
+        let dir = test_dir("formats");
+
+        let flac = dir.join("track");
+        let mut bytes = b"fLaC".to_vec();
+        bytes.resize(64, 0);
+        std::fs::write(&flac, bytes).unwrap();
+        assert_eq!(sniff(&flac), Some("flac"));
+
+        let text = dir.join("readme");
+        std::fs::write(&text, b"just some notes").unwrap();
+        assert_eq!(sniff(&text), None);
+
+        assert!(worth_sniffing(None));
+        assert!(worth_sniffing(Some("dat")));
+        assert!(!worth_sniffing(Some("jpg")));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
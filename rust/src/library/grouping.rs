@@ -0,0 +1,322 @@
+//! Album and artist grouping keys.
+//!
+//! Tags spell the same artist in different ways ("The Beatles", "Beatles,
+//! The", "the beatles"), credit guests inside the artist tag ("A feat. B")
+//! and tag album artists inconsistently across an album. Each song gets
+//! keys under which these variants group together, computed here from its
+//! tags and stored beside it:
+//!
+//! - Artist names are split on the configured separators, a trailing
+//!   article is moved to the front, and the result is folded to lowercase
+//!   without diacritics or punctuation.
+//! - An album is keyed by its title and album artist, falling back to the
+//!   first track artist. Discs of one album share a key; untagged discs in
+//!   "CD1" and "CD2" folders are keyed by the folder above.
+//! - Compilations, and albums with no album artist, are keyed by title and
+//!   folder instead, so the songs of a compilation group together whatever
+//!   their track artists. An album keyed that way whose songs have more
+//!   than one artist is treated as a compilation by the store, and songs
+//!   without an album artist join a tagged album of the same title in
+//!   their folder.
+//! - Sort names drop a leading article: "The Beatles" sorts as "beatles".
+
+use super::search::fold;
+use crate::api::library::GroupingOptions;
+use crate::api::scanner::AudioFileMetadata;
+use std::path::Path;
+
+/// Separates the parts of an album key.
+const KEY_SEPARATOR: char = '\u{1f}';
+
+/// The tags a song is grouped by.
+#[derive(Debug, Clone, Copy)]
+pub struct GroupTags<'a> {
+    pub path: &'a str,
+    pub artist: Option<&'a str>,
+    pub album: Option<&'a str>,
+    pub album_artist: Option<&'a str>,
+    pub compilation: bool,
+}
+
+impl<'a> From<&'a AudioFileMetadata> for GroupTags<'a> {
+    fn from(metadata: &'a AudioFileMetadata) -> Self {
+        Self {
+            path: &metadata.path,
+            artist: metadata.artist.as_deref(),
+            album: metadata.album.as_deref(),
+            album_artist: metadata.album_artist.as_deref(),
+            compilation: metadata.compilation,
+        }
+    }
+}
+
+/// An artist credited on a song.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtistName {
+    pub key: String,
+    /// The name as tagged, with a trailing article moved to the front
+    pub name: String,
+    pub sort_name: String,
+}
+
+/// Where a song is grouped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SongGroup {
+    /// Key of the song's album; `None` without an album tag
+    pub album_key: Option<String>,
+    /// Key of the album's title and folder, which is the album key of
+    /// compilations and songs without an album artist
+    pub folder_key: Option<String>,
+    pub album_sort: Option<String>,
+    /// The album artist, or the first track artist where none is tagged
+    pub album_artist: Option<ArtistName>,
+    /// The album is tagged as a compilation or by the compilation artist
+    pub compilation: bool,
+    /// Track artists, then album artists not already credited, without
+    /// the compilation artist
+    pub artists: Vec<ArtistName>,
+}
+
+impl SongGroup {
+    pub fn new(tags: GroupTags<'_>, options: &GroupingOptions) -> Self {
+        let track_artists = tags.artist.map_or_else(Vec::new, |a| split_artists(a, options));
+        let album_artists = tags.album_artist.map_or_else(Vec::new, |a| split_artists(a, options));
+        let various = name_key(&options.various_artists, options);
+        let compilation =
+            tags.compilation || album_artists.first().is_some_and(|a| a.key == various);
+
+        let mut artists: Vec<ArtistName> = Vec::new();
+        for artist in track_artists.iter().chain(&album_artists) {
+            if artist.key != various && !artists.iter().any(|a| a.key == artist.key) {
+                artists.push(artist.clone());
+            }
+        }
+        let album_artist = album_artists.into_iter().chain(track_artists).next();
+
+        let album = tags.album.map(str::trim).filter(|a| !a.is_empty());
+        let key = |owner: &str| {
+            album.map(|album| format!("{}{}{}", name_key(album, options), KEY_SEPARATOR, owner))
+        };
+        let folder_key = key(&album_folder(tags.path));
+        let album_key = match &album_artist {
+            Some(artist) if !compilation && tags.album_artist.is_some() => key(&artist.key),
+            _ => folder_key.clone(),
+        };
+        Self {
+            album_key,
+            folder_key,
+            album_sort: album.map(|album| sort_name(album, options)),
+            album_artist,
+            compilation,
+            artists,
+        }
+    }
+}
+
+/// The artists credited in an artist tag, in order and without repeats.
+pub fn split_artists(text: &str, options: &GroupingOptions) -> Vec<ArtistName> {
+    let separators: Vec<&[u8]> = options
+        .artist_separators
+        .iter()
+        .map(|s| s.as_bytes())
+        .filter(|s| !s.is_empty())
+        .collect();
+    let bytes = text.as_bytes();
+    let mut parts = Vec::new();
+    let (mut start, mut i) = (0, 0);
+    // Separators start with a character's first byte, so every match is at
+    // a character boundary
+    while i < bytes.len() {
+        let found = separators.iter().find(|sep| {
+            bytes.len() - i >= sep.len() && bytes[i..i + sep.len()].eq_ignore_ascii_case(sep)
+        });
+        match found {
+            Some(sep) => {
+                parts.push(&text[start..i]);
+                i += sep.len();
+                start = i;
+            }
+            None => i += 1,
+        }
+    }
+    parts.push(&text[start..]);
+
+    let split = parts.len() > 1;
+    let mut artists: Vec<ArtistName> = Vec::new();
+    for part in parts {
+        let part = if split { trim_brackets(part) } else { part.trim() };
+        let name = unflip(part, options);
+        let key = name_key(&name, options);
+        if !key.is_empty() && !artists.iter().any(|a| a.key == key) {
+            artists.push(ArtistName { sort_name: sort_name(&name, options), key, name });
+        }
+    }
+    artists
+}
+
+/// Key under which spellings of a name group together: folded to
+/// lowercase without diacritics, with "&" read as "and", punctuation
+/// dropped and a trailing article moved to the front.
+pub fn name_key(name: &str, options: &GroupingOptions) -> String {
+    key_words(&unflip(name.trim(), options))
+}
+
+/// Key a name sorts by: its [`name_key`] without a leading article.
+pub fn sort_name(name: &str, options: &GroupingOptions) -> String {
+    let name = unflip(name.trim(), options);
+    let stripped = options
+        .articles
+        .iter()
+        .find_map(|article| strip_prefix_ignore_case(&name, article)?.strip_prefix(' '))
+        .map(str::trim_start)
+        .filter(|rest| !rest.is_empty());
+    key_words(stripped.unwrap_or(&name))
+}
+
+/// "Beatles, The" as "The Beatles".
+fn unflip(name: &str, options: &GroupingOptions) -> String {
+    for article in &options.articles {
+        let Some(split) = name.len().checked_sub(article.len() + 2) else {
+            continue;
+        };
+        let (Some(head), Some(tail)) = (name.get(..split), name.get(split..)) else {
+            continue;
+        };
+        if let Some(tail) = tail.strip_prefix(", ") {
+            if !head.trim().is_empty() && tail.eq_ignore_ascii_case(article) {
+                return format!("{} {}", tail, head.trim_end());
+            }
+        }
+    }
+    name.to_string()
+}
+
+fn key_words(name: &str) -> String {
+    fold(&name.replace('&', " and "))
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix).then(|| &text[prefix.len()..])
+}
+
+/// A part of a split artist tag without the bracket a separator left
+/// open or closed, as in "A (feat. B)".
+fn trim_brackets(part: &str) -> &str {
+    let mut part = part.trim();
+    if part.ends_with(['(', '[']) {
+        part = part[..part.len() - 1].trim_end();
+    }
+    let unbalanced = |open: char, close: char| {
+        part.ends_with(close) && part.matches(close).count() > part.matches(open).count()
+    };
+    if unbalanced('(', ')') || unbalanced('[', ']') {
+        part = part[..part.len() - 1].trim_end();
+    }
+    part
+}
+
+/// The folder holding an album's songs, above any per-disc folder like
+/// "CD1" or "Disc 2".
+fn album_folder(path: &str) -> String {
+    let parent = Path::new(path).parent().unwrap_or(Path::new(""));
+    let is_disc = parent
+        .file_name()
+        .map(|name| name.to_string_lossy().to_ascii_lowercase())
+        .is_some_and(|name| {
+            let rest = ["cd", "disc", "disk"].iter().find_map(|word| name.strip_prefix(word));
+            rest.map(|rest| rest.trim_start_matches([' ', '_', '-', '.']))
+                .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+        });
+    let folder = match parent.parent() {
+        Some(above) if is_disc => above,
+        _ => parent,
+    };
+    folder.to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::library::library_default_grouping_options;
+
+    fn tags<'a>(path: &'a str, artist: &'a str, album_artist: Option<&'a str>) -> GroupTags<'a> {
+        GroupTags {
+            path,
+            artist: Some(artist),
+            album: Some("Greatest Hits"),
+            album_artist,
+            compilation: false,
+        }
+    }
+
+    fn names(options: &GroupingOptions, text: &str) -> Vec<String> {
+        split_artists(text, options).into_iter().map(|a| a.name).collect()
+    }
+
+    #[test]
+    fn test_names() {
+        let options = library_default_grouping_options();
+        assert_eq!(name_key("Beatles, The", &options), "the beatles");
+        assert_eq!(name_key("  The BEATLES ", &options), "the beatles");
+        assert_eq!(name_key("Simon & Garfunkel", &options), "simon and garfunkel");
+        assert_eq!(name_key("Björk", &options), "bjork");
+        assert_eq!(name_key("AC/DC", &options), name_key("AC-DC", &options));
+        assert_eq!(sort_name("The Beatles", &options), "beatles");
+        assert_eq!(sort_name("Beatles, The", &options), "beatles");
+        assert_eq!(sort_name("A Tribe Called Quest", &options), "tribe called quest");
+        assert_eq!(sort_name("The", &options), "the");
+        assert_eq!(sort_name("Theatre", &options), "theatre");
+
+        let split = |text: &str| names(&options, text);
+        assert_eq!(split("Queen feat. David Bowie"), ["Queen", "David Bowie"]);
+        assert_eq!(split("Queen (Feat. David Bowie)"), ["Queen", "David Bowie"]);
+        assert_eq!(split("A; B;A ;; Beatles, The"), ["A", "B", "The Beatles"]);
+        assert_eq!(split("AC/DC"), ["AC/DC"]);
+        assert_eq!(split("Sunn O)))"), ["Sunn O)))"]);
+        assert_eq!(split("Café ft. Niño"), ["Café", "Niño"]);
+
+        let mut options = options;
+        options.artist_separators.push(" & ".to_string());
+        options.articles.push("Les".to_string());
+        assert_eq!(names(&options, "Simon & Garfunkel"), ["Simon", "Garfunkel"]);
+        assert_eq!(sort_name("Les Négresses Vertes", &options), "negresses vertes");
+    }
+
+    #[test]
+    fn test_song_groups() {
+        let options = library_default_grouping_options();
+        let key = |tags: GroupTags<'_>| SongGroup::new(tags, &options).album_key.unwrap();
+
+        // Spellings of the album artist, and the track artist where none is
+        // tagged, group together wherever the files are
+        let a = key(tags("/m/q/1.mp3", "Queen", Some("Queen")));
+        assert_eq!(a, key(tags("/n/2.mp3", "Queen feat. Bowie", Some("queen"))));
+        assert_ne!(a, key(tags("/m/q/3.mp3", "ABBA", Some("ABBA"))));
+
+        // Without an album artist, songs group by folder
+        let untagged = key(tags("/m/v/CD1/1.mp3", "A", None));
+        assert_eq!(untagged, key(tags("/m/v/Disc 2/1.mp3", "B", None)));
+        assert_ne!(untagged, key(tags("/m/w/1.mp3", "A", None)));
+        assert_ne!(untagged, key(tags("/m/v/Bonus/1.mp3", "A", None)));
+
+        // Compilations group by folder too, under no artist
+        let tagged = tags("/m/v/CD1/2.mp3", "C", Some("Various Artists"));
+        let various = SongGroup::new(tagged, &options);
+        assert_eq!(various.album_key.as_deref(), Some(untagged.as_str()));
+        assert!(various.compilation);
+        let names: Vec<_> = various.artists.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["C"]);
+
+        let group = SongGroup::new(tags("/m/1.mp3", "Queen & Bowie; Beatles, The", None), &options);
+        assert_eq!(group.album_artist.unwrap().name, "Queen & Bowie");
+        assert_eq!(group.artists[1].sort_name, "beatles");
+        assert_eq!(group.album_sort.as_deref(), Some("greatest hits"));
+        let no_album = GroupTags { album: None, ..tags("/m/1.mp3", "Queen", None) };
+        assert_eq!(SongGroup::new(no_album, &options).album_key, None);
+    }
+}
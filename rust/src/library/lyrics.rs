@@ -0,0 +1,401 @@
+//! Reading lyrics from tags and `.lrc` files.
+
+use super::formats;
+use crate::api::lyrics::{Lyrics, LyricsLine, LyricsSourceType, LyricsWord};
+use lofty::config::ParseOptions;
+use lofty::file::{AudioFile, FileType};
+use lofty::id3::v2::{
+    Frame, Id3v2Tag, SyncTextContentType, SynchronizedTextFrame, TimestampFormat,
+};
+use lofty::iff::aiff::AiffFile;
+use lofty::iff::wav::WavFile;
+use lofty::mpeg::MpegFile;
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::ItemKey;
+use std::fs::{self, File};
+use std::path::Path;
+
+/// The lyrics of the song at `path`.
+///
+/// Synced lyrics are preferred, from the `.lrc` file beside the song, then
+/// a SYLT frame, then a lyrics tag holding LRC text. Without any, the plain
+/// text of the `.lrc` file or the lyrics tag is used.
+pub fn read(path: &Path) -> Result<Option<Lyrics>, String> {
+    let sidecar = ["lrc", "LRC"]
+        .iter()
+        .find_map(|extension| fs::read(path.with_extension(extension)).ok())
+        .and_then(|bytes| parse(&formats::decode_text(bytes), LyricsSourceType::Sidecar));
+    if let Some(lyrics) = sidecar.as_ref().filter(|l| l.synced) {
+        return Ok(Some(lyrics.clone()));
+    }
+
+    let embedded = match read_embedded(path) {
+        Ok(embedded) => embedded,
+        Err(_) if sidecar.is_some() => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    let mut found: Vec<Lyrics> = embedded.into_iter().chain(sidecar).collect();
+    // Stable, so sources keep their order of preference within each kind
+    found.sort_by_key(|lyrics| !lyrics.synced);
+    Ok(found.into_iter().next())
+}
+
+/// The lyrics in the tags of the file at `path`: SYLT frames first, then
+/// the lyrics text of the first tag that has any.
+fn read_embedded(path: &Path) -> Result<Vec<Lyrics>, String> {
+    let tagged_file = Probe::open(path)
+        .map_err(|e| e.to_string())?
+        .guess_file_type()
+        .map_err(|e| e.to_string())?
+        .read()
+        .map_err(|e| e.to_string())?;
+
+    // Lofty keeps SYLT frames out of its generic tags, so the ID3v2 tag is
+    // read again on its own
+    let mut found = id3v2_tag(path, tagged_file.file_type())
+        .map(|tag| synced_frames(&tag))
+        .unwrap_or_default();
+    let text = tagged_file
+        .tags()
+        .iter()
+        .find_map(|tag| tag.get_string(&ItemKey::Lyrics));
+    found.extend(text.and_then(|text| parse(text, LyricsSourceType::Tag)));
+    Ok(found)
+}
+
+/// The ID3v2 tag of the file at `path`, with the frames lofty leaves out of
+/// its generic tags.
+pub fn id3v2_tag(path: &Path, file_type: FileType) -> Option<Id3v2Tag> {
+    let mut file = File::open(path).ok()?;
+    let options = ParseOptions::new().read_properties(false);
+    match file_type {
+        FileType::Mpeg => MpegFile::read_from(&mut file, options).ok()?.id3v2().cloned(),
+        FileType::Wav => WavFile::read_from(&mut file, options).ok()?.id3v2().cloned(),
+        FileType::Aiff => AiffFile::read_from(&mut file, options).ok()?.id3v2().cloned(),
+        _ => None,
+    }
+}
+
+/// Lyrics from the SYLT frames of `tag` that are timed in milliseconds.
+///
+/// Each entry of a frame is a line, unless entries carry their own line
+/// breaks, in which case they are words and a line starts after each break.
+fn synced_frames(tag: &Id3v2Tag) -> Vec<Lyrics> {
+    tag.into_iter()
+        .filter_map(|frame| match frame {
+            Frame::Binary(binary) if binary.id().as_str() == "SYLT" => {
+                SynchronizedTextFrame::parse(&binary.data, binary.flags()).ok()
+            }
+            _ => None,
+        })
+        .filter(|frame| {
+            frame.timestamp_format == TimestampFormat::MS
+                && matches!(
+                    frame.content_type,
+                    SyncTextContentType::Lyrics | SyncTextContentType::TextTranscription
+                )
+        })
+        .filter_map(|frame| {
+            let words = frame.content.iter().any(|(_, text)| text.contains(['\n', '\r']));
+            let mut lines: Vec<LyricsLine> = Vec::new();
+            let mut line_ended = true;
+            for (start_ms, text) in frame.content {
+                let starts_line = text.starts_with(['\n', '\r']);
+                if !words || line_ended || starts_line {
+                    lines.push(LyricsLine {
+                        start_ms: Some(start_ms),
+                        text: String::new(),
+                        words: Vec::new(),
+                    });
+                }
+                line_ended = text.ends_with(['\n', '\r']);
+                let text = text.trim_matches(['\n', '\r']).to_string();
+                let line = lines.last_mut()?;
+                line.text.push_str(&text);
+                if words {
+                    line.words.push(LyricsWord { start_ms, text });
+                }
+            }
+            for line in &mut lines {
+                line.text = line.text.trim().to_string();
+            }
+            lines.sort_by_key(|line| line.start_ms);
+            Some(Lyrics { source: LyricsSourceType::SyncedTag, synced: true, lines })
+        })
+        .filter(|lyrics| lyrics.lines.iter().any(|line| !line.text.is_empty()))
+        .collect()
+}
+
+/// A time in milliseconds, before any offset, and the text from it on.
+type TimedText = (i64, String);
+
+/// Parse LRC text, including enhanced LRC word times (`<mm:ss.xx>`) and an
+/// `[offset:±ms]` tag. Text without timestamps becomes plain lyrics.
+pub fn parse(text: &str, source: LyricsSourceType) -> Option<Lyrics> {
+    let mut offset_ms = 0i64;
+    let mut timed: Vec<(i64, String, Vec<TimedText>)> = Vec::new();
+    let mut plain: Vec<String> = Vec::new();
+
+    for raw in text.lines() {
+        let mut rest = raw.trim();
+        let mut starts = Vec::new();
+        let mut is_tag = false;
+        while let Some(end) = rest.strip_prefix('[').and_then(|r| r.find(']')) {
+            let inner = &rest[1..end + 1];
+            if let Some(ms) = parse_time(inner) {
+                starts.push(ms);
+                rest = rest[end + 2..].trim_start();
+                continue;
+            }
+            // ID tags like [ar:Artist] take the whole line
+            if let Some((key, value)) = inner.split_once(':') {
+                if starts.is_empty() && key.trim().chars().all(|c| c.is_ascii_alphabetic()) {
+                    if key.trim().eq_ignore_ascii_case("offset") {
+                        offset_ms = value.trim().trim_start_matches('+').parse().unwrap_or(0);
+                    }
+                    is_tag = true;
+                }
+            }
+            break;
+        }
+        if is_tag {
+            continue;
+        }
+
+        let (line, words) = parse_words(rest);
+        if starts.is_empty() {
+            plain.push(line);
+            continue;
+        }
+        // Word times belong to the first timestamp; repeats are shifted
+        for &start in &starts {
+            let shift = start - starts[0];
+            let words = words.iter().map(|(ms, word)| (ms + shift, word.clone())).collect();
+            timed.push((start, line.clone(), words));
+        }
+    }
+
+    // A positive offset shows the lyrics earlier
+    let time = |ms: i64| (ms - offset_ms).clamp(0, u32::MAX as i64) as u32;
+    let synced = !timed.is_empty();
+    let mut lines: Vec<LyricsLine> = if synced {
+        timed.sort_by_key(|(start, _, _)| *start);
+        timed
+            .into_iter()
+            .map(|(start, text, words)| LyricsLine {
+                start_ms: Some(time(start)),
+                text,
+                words: words
+                    .into_iter()
+                    .map(|(ms, text)| LyricsWord { start_ms: time(ms), text })
+                    .collect(),
+            })
+            .collect()
+    } else {
+        plain
+            .into_iter()
+            .map(|text| LyricsLine { start_ms: None, text, words: Vec::new() })
+            .collect()
+    };
+    if !synced {
+        // Blank lines between verses stay, but not around the lyrics
+        while lines.last().is_some_and(|line| line.text.is_empty()) {
+            lines.pop();
+        }
+        let leading = lines.iter().take_while(|line| line.text.is_empty()).count();
+        lines.drain(..leading);
+    }
+
+    lines
+        .iter()
+        .any(|line| !line.text.is_empty())
+        .then_some(Lyrics { source, synced, lines })
+}
+
+/// Split the text of an LRC line at its word times.
+fn parse_words(text: &str) -> (String, Vec<TimedText>) {
+    let mut line = String::new();
+    let mut words: Vec<TimedText> = Vec::new();
+    let mut append = |text: &str, words: &mut Vec<TimedText>| {
+        line.push_str(text);
+        if let Some((_, word)) = words.last_mut() {
+            word.push_str(text);
+        }
+    };
+    let mut rest = text;
+    while let Some(open) = rest.find('<') {
+        let Some(close) = rest[open..].find('>').map(|close| open + close) else {
+            break;
+        };
+        match parse_time(&rest[open + 1..close]) {
+            Some(ms) => {
+                append(&rest[..open], &mut words);
+                words.push((ms, String::new()));
+            }
+            None => append(&rest[..=close], &mut words),
+        }
+        rest = &rest[close + 1..];
+    }
+    append(rest, &mut words);
+    words.retain(|(_, word)| !word.trim().is_empty());
+    (line.trim().to_string(), words)
+}
+
+/// An LRC time (`mm:ss`, `mm:ss.xx`, `mm:ss.xxx` or `mm:ss:xx`) in
+/// milliseconds.
+fn parse_time(text: &str) -> Option<i64> {
+    let (minutes, rest) = text.trim().split_once(':')?;
+    let (seconds, fraction) = rest.split_once(['.', ':']).unwrap_or((rest, ""));
+    let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if minutes.is_empty() || seconds.is_empty() {
+        return None;
+    }
+    if ![minutes, seconds, fraction].into_iter().all(digits) {
+        return None;
+    }
+    let mut fraction = fraction.chars().take(3).collect::<String>();
+    while fraction.len() < 3 {
+        fraction.push('0');
+    }
+    let minutes: i64 = minutes.parse().ok()?;
+    let seconds: i64 = seconds.parse().ok()?;
+    Some((minutes * 60 + seconds) * 1000 + fraction.parse::<i64>().ok()?)
+}
+
+/// The index of the last line of synced `lyrics` that has started at
+/// `position_secs`.
+pub fn active_line(lyrics: &Lyrics, position_secs: f64) -> Option<usize> {
+    if !lyrics.synced {
+        return None;
+    }
+    let position_ms = (position_secs * 1000.0).max(0.0) as u32;
+    let started = lyrics
+        .lines
+        .partition_point(|line| line.start_ms.is_some_and(|start| start <= position_ms));
+    started.checked_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{test_dir, write_silence};
+    use lofty::config::WriteOptions;
+    use lofty::id3::v2::{BinaryFrame, FrameId, UnsynchronizedTextFrame};
+    use lofty::TextEncoding;
+
+    fn starts(lyrics: &Lyrics) -> Vec<Option<u32>> {
+        lyrics.lines.iter().map(|line| line.start_ms).collect()
+    }
+
+    fn texts(lyrics: &Lyrics) -> Vec<&str> {
+        lyrics.lines.iter().map(|line| line.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_lrc() {
+        let lrc = "[ar:Artist]\n[ti:Title]\n[offset:+250]\n\n\
+                   [00:01.50]First line\n\
+                   [00:10.00][01:02.5]Chorus\n\
+                   [00:05:20]Second line\n\
+                   [Verse 2] is not a time\n\
+                   [00:20.000]\n";
+        let lyrics = parse(lrc, LyricsSourceType::Sidecar).unwrap();
+        assert!(lyrics.synced);
+        assert_eq!(texts(&lyrics), ["First line", "Second line", "Chorus", "", "Chorus"]);
+        assert_eq!(
+            starts(&lyrics),
+            [Some(1250), Some(4950), Some(9750), Some(19_750), Some(62_250)]
+        );
+
+        assert_eq!(active_line(&lyrics, 0.5), None);
+        assert_eq!(active_line(&lyrics, 1.25), Some(0));
+        assert_eq!(active_line(&lyrics, 9.0), Some(1));
+        assert_eq!(active_line(&lyrics, 600.0), Some(4));
+
+        // Enhanced LRC, with a negative offset
+        let lrc = "[offset:-500]\n\
+                   [00:01.00]<00:01.00>Hello <00:01.40>big <00:01.80>world<00:02.50>";
+        let lyrics = parse(lrc, LyricsSourceType::Tag).unwrap();
+        assert_eq!(texts(&lyrics), ["Hello big world"]);
+        let words: Vec<_> = lyrics.lines[0]
+            .words
+            .iter()
+            .map(|word| (word.start_ms, word.text.as_str()))
+            .collect();
+        assert_eq!(words, [(1500, "Hello "), (1900, "big "), (2300, "world")]);
+
+        // Plain text keeps its verse breaks
+        let lyrics = parse("\nOne\nTwo\n\nThree <b>\n\n", LyricsSourceType::Tag).unwrap();
+        assert!(!lyrics.synced);
+        assert_eq!(texts(&lyrics), ["One", "Two", "", "Three <b>"]);
+        assert_eq!(active_line(&lyrics, 10.0), None);
+
+        assert_eq!(parse("[ar:Artist]\n\n", LyricsSourceType::Tag), None);
+    }
+
+    #[test]
+    fn test_read_lyrics() {
+        let dir = test_dir("lyrics");
+
+        // SYLT words with line breaks, beside plain USLT text
+        let song = dir.join("song.wav");
+        write_silence(&song, 100);
+        let sylt = SynchronizedTextFrame::new(
+            TextEncoding::UTF8,
+            *b"eng",
+            TimestampFormat::MS,
+            SyncTextContentType::Lyrics,
+            None,
+            vec![
+                (1000, "Hel".to_string()),
+                (1200, "lo\n".to_string()),
+                (3000, "World".to_string()),
+            ],
+        );
+        let mut tag = Id3v2Tag::new();
+        let id = FrameId::new("SYLT").unwrap();
+        tag.insert(Frame::Binary(BinaryFrame::new(id, sylt.as_bytes().unwrap())));
+        tag.insert(Frame::UnsynchronizedText(UnsynchronizedTextFrame::new(
+            TextEncoding::UTF8,
+            *b"eng",
+            String::new(),
+            "Hello\nWorld".to_string(),
+        )));
+        tag.save_to_path(&song, WriteOptions::default()).unwrap();
+
+        let lyrics = read(&song).unwrap().unwrap();
+        assert_eq!(lyrics.source, LyricsSourceType::SyncedTag);
+        assert_eq!(texts(&lyrics), ["Hello", "World"]);
+        assert_eq!(starts(&lyrics), [Some(1000), Some(3000)]);
+        assert_eq!(lyrics.lines[0].words.len(), 2);
+
+        // A synced sidecar wins; a plain one loses to synced tags
+        fs::write(dir.join("song.lrc"), "Just text").unwrap();
+        assert_eq!(read(&song).unwrap().unwrap().source, LyricsSourceType::SyncedTag);
+        fs::write(dir.join("song.lrc"), b"\xef\xbb\xbf[00:00.50]Caf\xc3\xa9").unwrap();
+        let lyrics = read(&song).unwrap().unwrap();
+        assert_eq!((lyrics.source, texts(&lyrics)), (LyricsSourceType::Sidecar, vec!["Café"]));
+
+        // Latin-1 sidecars, and plain tags
+        let other = dir.join("other.wav");
+        write_silence(&other, 100);
+        assert_eq!(read(&other).unwrap(), None);
+        fs::write(dir.join("other.lrc"), b"[00:01.00]Caf\xe9").unwrap();
+        assert_eq!(texts(&read(&other).unwrap().unwrap()), ["Café"]);
+        fs::remove_file(dir.join("other.lrc")).unwrap();
+        let mut tag = Id3v2Tag::new();
+        tag.insert(Frame::UnsynchronizedText(UnsynchronizedTextFrame::new(
+            TextEncoding::UTF8,
+            *b"eng",
+            String::new(),
+            "Plain".to_string(),
+        )));
+        tag.save_to_path(&other, WriteOptions::default()).unwrap();
+        let lyrics = read(&other).unwrap().unwrap();
+        assert_eq!((lyrics.source, lyrics.synced), (LyricsSourceType::Tag, false));
+
+        assert!(read(&dir.join("missing.wav")).is_err());
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,74 @@
+//! Tag and audio-property extraction for scanned files.
+
+use crate::api::scanner::AudioFileMetadata;
+use lofty::file::TaggedFile;
+use lofty::prelude::*;
+use lofty::tag::{ItemKey, Tag};
+use std::path::Path;
+
+/// Collect everything the library needs from a probed file.
+///
+/// Tag fields come from the primary tag, falling back to whatever tag the
+/// file has (e.g. an MP3 with only ID3v1).
+pub fn extract_metadata(
+    path: &Path,
+    tagged_file: &TaggedFile,
+    format: String,
+    last_modified: i64,
+) -> AudioFileMetadata {
+    let tag = tagged_file
+        .primary_tag()
+        .or_else(|| tagged_file.first_tag());
+    let properties = tagged_file.properties();
+
+    let text = |key: ItemKey| tag.and_then(|t| tag_string(t, &key));
+    let accessor = |get: fn(&Tag) -> Option<std::borrow::Cow<'_, str>>| {
+        tag.and_then(get)
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+
+    AudioFileMetadata {
+        path: path.to_string_lossy().to_string(),
+        title: accessor(|t| t.title()),
+        artist: accessor(|t| t.artist()),
+        album: accessor(|t| t.album()),
+        album_artist: text(ItemKey::AlbumArtist),
+        track_number: tag.and_then(|t| t.track()),
+        track_total: tag.and_then(|t| t.track_total()),
+        disc_number: tag.and_then(|t| t.disk()),
+        disc_total: tag.and_then(|t| t.disk_total()),
+        year: tag.and_then(|t| t.year()),
+        genre: accessor(|t| t.genre()),
+        composer: text(ItemKey::Composer),
+        compilation: text(ItemKey::FlagCompilation)
+            .is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true")),
+        duration_secs: Some(properties.duration().as_secs()),
+        sample_rate: properties.sample_rate(),
+        bit_depth: properties.bit_depth().map(u32::from),
+        bitrate_kbps: properties
+            .audio_bitrate()
+            .or_else(|| properties.overall_bitrate()),
+        channels: properties.channels().map(u32::from),
+        has_lyrics: text(ItemKey::Lyrics).is_some(),
+        musicbrainz_recording_id: text(ItemKey::MusicBrainzRecordingId),
+        musicbrainz_release_id: text(ItemKey::MusicBrainzReleaseId),
+        musicbrainz_release_group_id: text(ItemKey::MusicBrainzReleaseGroupId),
+        musicbrainz_artist_id: text(ItemKey::MusicBrainzArtistId),
+        musicbrainz_release_artist_id: text(ItemKey::MusicBrainzReleaseArtistId),
+        album_art_path: None,
+        album_palette: None,
+        format,
+        last_modified,
+        range: None,
+    }
+}
+
+/// A text item with surrounding whitespace removed; empty values count as
+/// missing.
+fn tag_string(tag: &Tag, key: &ItemKey) -> Option<String> {
+    tag.get_string(key)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
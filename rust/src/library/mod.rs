@@ -0,0 +1,23 @@
+//! Library scanning helpers that are not exposed to Dart directly.
+
+#[cfg(native_audio_engine)]
+pub mod acoustic;
+pub mod artwork;
+pub mod chapters;
+pub mod cue;
+#[cfg(native_audio_engine)]
+pub mod duplicates;
+pub mod filter;
+pub mod fingerprint;
+pub mod formats;
+pub mod grouping;
+pub mod lyrics;
+pub mod metadata;
+pub mod palette;
+pub mod playlists;
+pub mod scan;
+pub mod search;
+pub mod smart_playlists;
+pub mod store;
+pub mod tags;
+pub mod watch;
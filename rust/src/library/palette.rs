@@ -0,0 +1,392 @@
+//! Dominant-color palettes for album art.
+//!
+//! The image is reduced to a 5-bit-per-channel histogram and clustered with
+//! weighted k-means. The dominant color is the largest cluster; vibrant and
+//! muted colors are picked from the clusters by saturation and lightness,
+//! with a derived fallback when the art has no suitable cluster. Text colors
+//! are chosen to meet the WCAG contrast ratios against the dominant color.
+//!
+//! Colors are packed as `0xAARRGGBB`, the layout of Flutter's `Color`.
+
+use crate::api::scanner::AlbumPalette;
+use image::DynamicImage;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Longest edge the image is reduced to before sampling
+const SAMPLE_SIZE: u32 = 64;
+
+/// Number of k-means clusters
+const CLUSTERS: usize = 6;
+
+/// Lloyd iterations; the histogram converges well before this
+const ITERATIONS: usize = 12;
+
+/// Minimum contrast for primary text (WCAG AAA)
+const TEXT_CONTRAST: f32 = 7.0;
+
+/// Minimum contrast for secondary text (WCAG AA)
+const SECONDARY_TEXT_CONTRAST: f32 = 4.5;
+
+/// Version tag of the sidecar format
+const SIDECAR_VERSION: &str = "v1";
+
+type Rgb = [f32; 3];
+
+/// Compute the palette of a decoded image.
+///
+/// Returns `None` for images without opaque pixels.
+pub fn extract(image: &DynamicImage) -> Option<AlbumPalette> {
+    let clusters = cluster(&histogram(image))?;
+    let dominant = clusters[0].0;
+
+    let hsl = |color: &Rgb| to_hsl(*color);
+    let vibrant = clusters
+        .iter()
+        .filter(|(color, _)| {
+            let (_, s, l) = hsl(color);
+            s >= 0.35 && (0.25..=0.8).contains(&l)
+        })
+        .max_by(|a, b| vibrancy(a).total_cmp(&vibrancy(b)))
+        .map(|(color, _)| *color)
+        .unwrap_or_else(|| {
+            let (h, s, l) = hsl(&dominant);
+            from_hsl(h, s.max(0.55), l.clamp(0.45, 0.6))
+        });
+    let muted = clusters
+        .iter()
+        .filter(|(color, _)| {
+            let (_, s, l) = hsl(color);
+            s < 0.35 && (0.2..=0.7).contains(&l)
+        })
+        .map(|(color, _)| *color)
+        .next()
+        .unwrap_or_else(|| {
+            let (h, s, l) = hsl(&dominant);
+            from_hsl(h, s * 0.4, l.clamp(0.3, 0.5))
+        });
+
+    let text = text_color(dominant);
+    let secondary_text = secondary_text_color(text, dominant);
+
+    Some(AlbumPalette {
+        dominant: pack(dominant),
+        vibrant: pack(vibrant),
+        muted: pack(muted),
+        text: pack(text),
+        secondary_text: pack(secondary_text),
+    })
+}
+
+/// Palette of the image at `path`, from its sidecar if one exists.
+pub fn for_image(path: &Path) -> Option<AlbumPalette> {
+    read_sidecar(path).or_else(|| extract(&image::open(path).ok()?))
+}
+
+/// Where the palette of the image at `image_path` is stored.
+pub fn sidecar_path(image_path: &Path) -> PathBuf {
+    image_path.with_extension("palette")
+}
+
+/// Read a palette written by [`write_sidecar`].
+pub fn read_sidecar(image_path: &Path) -> Option<AlbumPalette> {
+    let line = fs::read_to_string(sidecar_path(image_path)).ok()?;
+    let mut words = line.split_whitespace();
+    if words.next()? != SIDECAR_VERSION {
+        return None;
+    }
+    let mut next = || u32::from_str_radix(words.next()?, 16).ok();
+    Some(AlbumPalette {
+        dominant: next()?,
+        vibrant: next()?,
+        muted: next()?,
+        text: next()?,
+        secondary_text: next()?,
+    })
+}
+
+/// Store `palette` next to the image it was computed from.
+pub fn write_sidecar(image_path: &Path, palette: &AlbumPalette) -> std::io::Result<()> {
+    fs::write(
+        sidecar_path(image_path),
+        format!(
+            "{} {:08x} {:08x} {:08x} {:08x} {:08x}\n",
+            SIDECAR_VERSION,
+            palette.dominant,
+            palette.vibrant,
+            palette.muted,
+            palette.text,
+            palette.secondary_text
+        ),
+    )
+}
+
+/// Opaque pixels of a downsampled copy, bucketed to 5 bits per channel.
+fn histogram(image: &DynamicImage) -> Vec<(Rgb, f32)> {
+    let image = if image.width() > SAMPLE_SIZE || image.height() > SAMPLE_SIZE {
+        image.thumbnail(SAMPLE_SIZE, SAMPLE_SIZE)
+    } else {
+        image.clone()
+    };
+
+    let mut buckets: HashMap<u16, (Rgb, f32)> = HashMap::new();
+    for pixel in image.to_rgba8().pixels() {
+        let [r, g, b, a] = pixel.0;
+        if a < 128 {
+            continue;
+        }
+        let key = ((r as u16 >> 3) << 10) | ((g as u16 >> 3) << 5) | (b as u16 >> 3);
+        let entry = buckets.entry(key).or_insert(([0.0; 3], 0.0));
+        entry.0[0] += r as f32;
+        entry.0[1] += g as f32;
+        entry.0[2] += b as f32;
+        entry.1 += 1.0;
+    }
+
+    // Sorted so that clustering does not depend on hash map order
+    let mut points: Vec<(u16, Rgb, f32)> = buckets
+        .into_iter()
+        .map(|(key, (sum, count))| (key, sum.map(|c| c / count), count))
+        .collect();
+    points.sort_by_key(|(key, _, _)| *key);
+    points.into_iter().map(|(_, color, count)| (color, count)).collect()
+}
+
+/// Weighted k-means over the histogram.
+///
+/// Returns (centroid, share of pixels), largest cluster first.
+fn cluster(points: &[(Rgb, f32)]) -> Option<Vec<(Rgb, f32)>> {
+    let total: f32 = points.iter().map(|(_, w)| w).sum();
+    if total == 0.0 {
+        return None;
+    }
+
+    // Greedy k-means++ seeding: the heaviest bucket, then whichever bucket
+    // has the most weight far away from the chosen centroids
+    let mut centroids: Vec<Rgb> = Vec::with_capacity(CLUSTERS);
+    let heaviest = points.iter().max_by(|a, b| a.1.total_cmp(&b.1))?;
+    centroids.push(heaviest.0);
+    while centroids.len() < CLUSTERS.min(points.len()) {
+        let next = points
+            .iter()
+            .map(|(color, w)| (color, w * nearest(&centroids, color).1))
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+        if next.1 == 0.0 {
+            break;
+        }
+        centroids.push(*next.0);
+    }
+
+    let mut weights = vec![0.0; centroids.len()];
+    for _ in 0..ITERATIONS {
+        let mut sums = vec![[0.0f32; 3]; centroids.len()];
+        weights.iter_mut().for_each(|w| *w = 0.0);
+        for (color, w) in points {
+            let (index, _) = nearest(&centroids, color);
+            for c in 0..3 {
+                sums[index][c] += color[c] * w;
+            }
+            weights[index] += w;
+        }
+
+        let mut moved = false;
+        for (i, centroid) in centroids.iter_mut().enumerate() {
+            if weights[i] > 0.0 {
+                let updated = sums[i].map(|s| s / weights[i]);
+                moved |= distance(&updated, centroid) > 0.25;
+                *centroid = updated;
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+
+    let mut clusters: Vec<(Rgb, f32)> = centroids
+        .into_iter()
+        .zip(weights)
+        .filter(|(_, w)| *w > 0.0)
+        .map(|(color, w)| (color, w / total))
+        .collect();
+    clusters.sort_by(|a, b| b.1.total_cmp(&a.1));
+    Some(clusters)
+}
+
+fn nearest(centroids: &[Rgb], color: &Rgb) -> (usize, f32) {
+    centroids
+        .iter()
+        .map(|c| distance(c, color))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((0, 0.0))
+}
+
+/// Squared distance, with channels weighted roughly by perceived brightness
+fn distance(a: &Rgb, b: &Rgb) -> f32 {
+    let dr = a[0] - b[0];
+    let dg = a[1] - b[1];
+    let db = a[2] - b[2];
+    2.0 * dr * dr + 4.0 * dg * dg + 3.0 * db * db
+}
+
+/// Favour saturated clusters while still rewarding coverage
+fn vibrancy((color, share): &(Rgb, f32)) -> f32 {
+    let (_, s, l) = to_hsl(*color);
+    s * (1.0 - (l - 0.5).abs()) * share.sqrt()
+}
+
+/// White or near-black, whichever contrasts more with `background`.
+fn text_color(background: Rgb) -> Rgb {
+    let white = [255.0; 3];
+    let black = [18.0; 3];
+    if contrast(white, background) >= TEXT_CONTRAST
+        || contrast(white, background) >= contrast(black, background)
+    {
+        white
+    } else {
+        black
+    }
+}
+
+/// `text` blended towards `background` as far as contrast allows.
+fn secondary_text_color(text: Rgb, background: Rgb) -> Rgb {
+    [0.45, 0.35, 0.25, 0.15]
+        .iter()
+        .map(|&t| mix(text, background, t))
+        .find(|color| contrast(*color, background) >= SECONDARY_TEXT_CONTRAST)
+        .unwrap_or(text)
+}
+
+fn mix(a: Rgb, b: Rgb, t: f32) -> Rgb {
+    [0, 1, 2].map(|c| a[c] + (b[c] - a[c]) * t)
+}
+
+/// WCAG contrast ratio between two colors
+fn contrast(a: Rgb, b: Rgb) -> f32 {
+    let (la, lb) = (luminance(a), luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// WCAG relative luminance
+fn luminance(color: Rgb) -> f32 {
+    let linear = |c: f32| {
+        let c = c / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(color[0]) + 0.7152 * linear(color[1]) + 0.0722 * linear(color[2])
+}
+
+fn to_hsl(color: Rgb) -> (f32, f32, f32) {
+    let [r, g, b] = color.map(|c| c / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+    if d == 0.0 {
+        return (0.0, 0.0, l);
+    }
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h * 60.0, s.min(1.0), l)
+}
+
+fn from_hsl(h: f32, s: f32, l: f32) -> Rgb {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match (h / 60.0) as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    [r, g, b].map(|v| (v + m) * 255.0)
+}
+
+/// Pack as opaque `0xAARRGGBB`
+fn pack(color: Rgb) -> u32 {
+    let [r, g, b] = color.map(|c| c.round().clamp(0.0, 255.0) as u32);
+    0xff00_0000 | (r << 16) | (g << 8) | b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+    use image::{Rgb as Pixel, RgbImage};
+
+    fn unpack(color: u32) -> Rgb {
+        [(color >> 16) & 0xff, (color >> 8) & 0xff, color & 0xff].map(|c| c as f32)
+    }
+
+    /// 60% slate, 25% bright blue and 15% brown.
+    fn test_image() -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_fn(100, 100, |x, _| match x {
+            0..=59 => Pixel([60, 62, 66]),
+            60..=84 => Pixel([30, 80, 250]),
+            _ => Pixel([100, 70, 60]),
+        }))
+    }
+
+    #[test]
+    fn test_palette_picks_clusters() {
+        let palette = extract(&test_image()).unwrap();
+        let dominant = unpack(palette.dominant);
+        assert!(distance(&dominant, &[60.0, 62.0, 66.0]) < 100.0, "{:?}", dominant);
+        assert_eq!(palette.dominant >> 24, 0xff);
+
+        let vibrant = unpack(palette.vibrant);
+        assert!(distance(&vibrant, &[30.0, 80.0, 250.0]) < 100.0, "{:?}", vibrant);
+        // Slate beats brown on coverage
+        let muted = unpack(palette.muted);
+        assert!(distance(&muted, &[60.0, 62.0, 66.0]) < 100.0, "{:?}", muted);
+
+        assert!(contrast(unpack(palette.text), dominant) >= TEXT_CONTRAST);
+        assert!(contrast(unpack(palette.secondary_text), dominant) >= SECONDARY_TEXT_CONTRAST);
+        assert_ne!(palette.secondary_text, palette.text);
+    }
+
+    #[test]
+    fn test_palette_fallbacks() {
+        // A single light grey has no vibrant cluster and needs dark text
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(8, 8, Pixel([200, 200, 205])));
+        let palette = extract(&image).unwrap();
+        assert_eq!(palette.text, pack([18.0; 3]));
+        let (_, s, _) = to_hsl(unpack(palette.vibrant));
+        assert!(s >= 0.5);
+
+        let transparent = DynamicImage::ImageRgba8(image::RgbaImage::new(4, 4));
+        assert!(extract(&transparent).is_none());
+    }
+
+    #[test]
+    fn test_sidecar_round_trip() {
+        let dir = test_dir("palette");
+        let image_path = dir.join("art.jpg");
+
+        let palette = extract(&test_image()).unwrap();
+        write_sidecar(&image_path, &palette).unwrap();
+        assert_eq!(sidecar_path(&image_path), dir.join("art.palette"));
+        assert_eq!(read_sidecar(&image_path), Some(palette));
+        // The image itself is never opened when the sidecar exists
+        assert_eq!(for_image(&image_path), Some(palette));
+
+        fs::write(sidecar_path(&image_path), "v0 ff000000").unwrap();
+        assert_eq!(read_sidecar(&image_path), None);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
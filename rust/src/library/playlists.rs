@@ -0,0 +1,781 @@
+//! Reading and writing playlist files, and matching their entries to the
+//! library.
+//!
+//! M3U entries may follow an `#EXTINF` line with a duration and an "Artist -
+//! Title" name, PLS files number their entries, and XSPF is XML whose
+//! locations are URIs. Windows separators in entries are accepted
+//! everywhere. Files that are not UTF-8 are read as Latin-1; playlists are
+//! always written as UTF-8.
+
+use crate::api::playlists::{Playlist, PlaylistEntry, UnresolvedEntry};
+use super::formats::decode_text;
+use super::search::fold;
+use super::tags::temp_path;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// How far the duration of an entry matched by title and artist may be
+/// from the song's
+const DURATION_TOLERANCE_SECS: f64 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    M3u,
+    Pls,
+    Xspf,
+}
+
+impl Format {
+    fn of(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "m3u" | "m3u8" => Some(Format::M3u),
+            "pls" => Some(Format::Pls),
+            "xspf" => Some(Format::Xspf),
+            _ => None,
+        }
+    }
+
+    /// The format of a playlist with an unknown extension, from its text.
+    fn sniff(text: &str) -> Self {
+        let start = text.trim_start();
+        if start.starts_with('<') {
+            Format::Xspf
+        } else if start.get(..10).is_some_and(|s| s.eq_ignore_ascii_case("[playlist]")) {
+            Format::Pls
+        } else {
+            Format::M3u
+        }
+    }
+}
+
+/// A library song that playlist entries are matched against.
+pub struct LibraryTrack {
+    pub path: String,
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub duration_secs: Option<f64>,
+}
+
+/// Read the playlist file at `path`, resolving its entries against its
+/// folder.
+pub fn read(path: &Path) -> Result<Playlist, String> {
+    let text = decode_text(fs::read(path).map_err(|e| e.to_string())?);
+    let base = path.parent().unwrap_or(Path::new(""));
+    let mut playlist = match Format::of(path).unwrap_or_else(|| Format::sniff(&text)) {
+        Format::M3u => parse_m3u(&text, base),
+        Format::Pls => parse_pls(&text, base),
+        Format::Xspf => parse_xspf(&text, base),
+    };
+    if playlist.name.is_none() {
+        playlist.name = path.file_stem().map(|stem| stem.to_string_lossy().to_string());
+    }
+    Ok(playlist)
+}
+
+/// Write `playlist` to `path` in the format of its extension, through a
+/// temporary file beside it.
+pub fn write(path: &Path, playlist: &Playlist, relative_paths: bool) -> Result<(), String> {
+    let format = Format::of(path)
+        .ok_or_else(|| format!("Unsupported playlist format: {}", path.display()))?;
+    let base = path.parent().filter(|_| relative_paths);
+    let text = match format {
+        Format::M3u => render_m3u(playlist, base),
+        Format::Pls => render_pls(playlist, base),
+        Format::Xspf => render_xspf(playlist, base),
+    };
+    let temp = temp_path(path);
+    fs::write(&temp, text).and_then(|()| fs::rename(&temp, path)).map_err(|e| {
+        let _ = fs::remove_file(&temp);
+        e.to_string()
+    })
+}
+
+/// Match the entries of `playlist` to `tracks`, returning the paths of the
+/// matched songs in order and the entries that matched none.
+///
+/// An entry matches the song at its path, else the song whose path differs
+/// only in case, else the only song with its file name, else a song with
+/// its title and artist and about its duration. Entries without an artist
+/// only match a title no other song has.
+pub fn resolve(
+    playlist: &Playlist,
+    tracks: &[LibraryTrack],
+) -> (Vec<String>, Vec<UnresolvedEntry>) {
+    let index = TrackIndex::new(tracks);
+    let mut paths = Vec::new();
+    let mut unresolved = Vec::new();
+    for (i, entry) in playlist.entries.iter().enumerate() {
+        match index.find(entry) {
+            Some(path) => paths.push(path.to_string()),
+            None => unresolved.push(UnresolvedEntry {
+                index: i as u32,
+                entry: entry.clone(),
+            }),
+        }
+    }
+    (paths, unresolved)
+}
+
+/// Library songs by the keys entries are matched on.
+struct TrackIndex<'a> {
+    paths: HashSet<&'a str>,
+    folded_paths: HashMap<String, &'a str>,
+    file_names: HashMap<String, Vec<&'a str>>,
+    /// Songs by title key, with their artist keys
+    titles: HashMap<String, Vec<(Option<String>, &'a LibraryTrack)>>,
+}
+
+impl<'a> TrackIndex<'a> {
+    fn new(tracks: &'a [LibraryTrack]) -> Self {
+        let mut index = TrackIndex {
+            paths: HashSet::new(),
+            folded_paths: HashMap::new(),
+            file_names: HashMap::new(),
+            titles: HashMap::new(),
+        };
+        for track in tracks {
+            let path = track.path.as_str();
+            index.paths.insert(path);
+            index.folded_paths.entry(path_key(path)).or_insert(path);
+            index.file_names.entry(file_name_key(path)).or_default().push(path);
+            if let Some(title) = track.title.as_deref() {
+                let artist = track.artist.as_deref().map(tag_key);
+                index.titles.entry(tag_key(title)).or_default().push((artist, track));
+            }
+        }
+        index
+    }
+
+    fn find(&self, entry: &PlaylistEntry) -> Option<&'a str> {
+        let location = entry.location.as_str();
+        if let Some(path) = self.paths.get(location) {
+            return Some(path);
+        }
+        if let Some(path) = self.folded_paths.get(&path_key(location)) {
+            return Some(path);
+        }
+        if !is_url(location) {
+            if let Some([path]) = self.file_names.get(&file_name_key(location)).map(Vec::as_slice) {
+                return Some(path);
+            }
+        }
+        self.find_by_tags(entry)
+    }
+
+    fn find_by_tags(&self, entry: &PlaylistEntry) -> Option<&'a str> {
+        let songs = self.titles.get(&tag_key(entry.title.as_deref()?))?;
+        let artist = entry.artist.as_deref().map(tag_key);
+        let candidates: Vec<&LibraryTrack> = songs
+            .iter()
+            .filter(|(song_artist, _)| artist.is_none() || *song_artist == artist)
+            .map(|(_, track)| *track)
+            .filter(|track| match (entry.duration_secs, track.duration_secs) {
+                (Some(entry), Some(track)) => (entry - track).abs() <= DURATION_TOLERANCE_SECS,
+                _ => true,
+            })
+            .collect();
+        match candidates.as_slice() {
+            [track] => Some(&track.path),
+            [track, ..] if artist.is_some() => Some(&track.path),
+            _ => None,
+        }
+    }
+}
+
+/// A path folded to lowercase, with `/` separators.
+fn path_key(path: &str) -> String {
+    path.replace('\\', "/").to_lowercase()
+}
+
+/// The file name of a path, folded to lowercase.
+fn file_name_key(path: &str) -> String {
+    path.rsplit(['/', '\\']).next().unwrap_or(path).to_lowercase()
+}
+
+/// Text folded like search words, so tags match whatever their case,
+/// accents and punctuation.
+fn tag_key(text: &str) -> String {
+    fold(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_m3u(text: &str, base: &Path) -> Playlist {
+    let mut playlist = Playlist::default();
+    // Directives describe the entry that follows them
+    let mut pending = PlaylistEntry::default();
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let Some(directive) = line.strip_prefix('#') else {
+            pending.location = location(line, base);
+            playlist.entries.push(std::mem::take(&mut pending));
+            continue;
+        };
+        if let Some(info) = directive.strip_prefix("EXTINF:") {
+            extinf(info, &mut pending);
+        } else if let Some(name) = directive.strip_prefix("PLAYLIST:") {
+            playlist.name = non_empty(name);
+        } else if let Some(album) = directive.strip_prefix("EXTALB:") {
+            pending.album = non_empty(album);
+        } else if let Some(artist) = directive.strip_prefix("EXTART:") {
+            pending.artist = pending.artist.take().or_else(|| non_empty(artist));
+        }
+    }
+    playlist
+}
+
+/// Read `#EXTINF:<duration> <attributes>,<name>` into `entry`.
+fn extinf(info: &str, entry: &mut PlaylistEntry) {
+    let mut quoted = false;
+    let comma = info.find(|c| {
+        quoted ^= c == '"';
+        c == ',' && !quoted
+    });
+    let (head, name) = match comma {
+        Some(i) => (&info[..i], &info[i + 1..]),
+        None => (info, ""),
+    };
+    entry.duration_secs = head
+        .split_whitespace()
+        .next()
+        .and_then(|secs| secs.parse::<f64>().ok())
+        .filter(|secs| *secs >= 0.0);
+    let (artist, title) = split_name(name);
+    entry.artist = artist.or(entry.artist.take());
+    entry.title = title;
+}
+
+fn parse_pls(text: &str, base: &Path) -> Playlist {
+    let mut entries: BTreeMap<u32, PlaylistEntry> = BTreeMap::new();
+    for line in text.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let value = value.trim();
+        for field in ["file", "title", "length"] {
+            let Some(number) = key.strip_prefix(field).and_then(|n| n.parse::<u32>().ok()) else {
+                continue;
+            };
+            let entry = entries.entry(number).or_default();
+            match field {
+                "file" => entry.location = location(value, base),
+                "title" => (entry.artist, entry.title) = split_name(value),
+                _ => entry.duration_secs = value.parse().ok().filter(|secs: &f64| *secs >= 0.0),
+            }
+        }
+    }
+    Playlist {
+        name: None,
+        entries: entries.into_values().filter(|entry| !entry.location.is_empty()).collect(),
+    }
+}
+
+fn parse_xspf(text: &str, base: &Path) -> Playlist {
+    let (head, tracks) = text.split_at(text.find("<trackList").unwrap_or(text.len()));
+    let entries = elements(tracks, "track")
+        .into_iter()
+        .filter_map(|track| {
+            let field = |name| element(track, name).map(xml_text).filter(|t| !t.is_empty());
+            Some(PlaylistEntry {
+                location: uri_location(&field("location")?, base),
+                title: field("title"),
+                artist: field("creator"),
+                album: field("album"),
+                duration_secs: field("duration")
+                    .and_then(|ms| ms.parse::<f64>().ok())
+                    .map(|ms| ms / 1000.0),
+            })
+        })
+        .collect();
+    Playlist {
+        name: element(head, "title").map(xml_text).filter(|t| !t.is_empty()),
+        entries,
+    }
+}
+
+/// Split an "Artist - Title" name.
+fn split_name(name: &str) -> (Option<String>, Option<String>) {
+    match name.split_once(" - ") {
+        Some((artist, title)) if !artist.trim().is_empty() && !title.trim().is_empty() => {
+            (non_empty(artist), non_empty(title))
+        }
+        _ => (None, non_empty(name)),
+    }
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    Some(text.trim().to_string()).filter(|text| !text.is_empty())
+}
+
+fn render_m3u(playlist: &Playlist, base: Option<&Path>) -> String {
+    let mut text = String::from("#EXTM3U\n");
+    if let Some(name) = &playlist.name {
+        let _ = writeln!(text, "#PLAYLIST:{}", one_line(name));
+    }
+    for entry in &playlist.entries {
+        let name = display_name(entry);
+        if name.is_some() || entry.duration_secs.is_some() {
+            let secs = entry.duration_secs.map_or(-1, |secs| secs.round() as i64);
+            let _ = writeln!(text, "#EXTINF:{},{}", secs, name.unwrap_or_default());
+        }
+        if let Some(album) = &entry.album {
+            let _ = writeln!(text, "#EXTALB:{}", one_line(album));
+        }
+        let _ = writeln!(text, "{}", written_path(&entry.location, base));
+    }
+    text
+}
+
+fn render_pls(playlist: &Playlist, base: Option<&Path>) -> String {
+    let mut text = String::from("[playlist]\n");
+    for (i, entry) in playlist.entries.iter().enumerate() {
+        let number = i + 1;
+        let _ = writeln!(text, "File{}={}", number, written_path(&entry.location, base));
+        if let Some(name) = display_name(entry) {
+            let _ = writeln!(text, "Title{}={}", number, name);
+        }
+        let secs = entry.duration_secs.map_or(-1, |secs| secs.round() as i64);
+        let _ = writeln!(text, "Length{}={}", number, secs);
+    }
+    let _ = writeln!(text, "NumberOfEntries={}\nVersion=2", playlist.entries.len());
+    text
+}
+
+fn render_xspf(playlist: &Playlist, base: Option<&Path>) -> String {
+    let mut text = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <playlist version=\"1\" xmlns=\"http://xspf.org/ns/0/\">\n",
+    );
+    if let Some(name) = &playlist.name {
+        let _ = writeln!(text, "  <title>{}</title>", escape(name));
+    }
+    text.push_str("  <trackList>\n");
+    for entry in &playlist.entries {
+        text.push_str("    <track>\n");
+        let location = escape(&uri(&entry.location, base));
+        let _ = writeln!(text, "      <location>{}</location>", location);
+        let fields = [("title", &entry.title), ("creator", &entry.artist), ("album", &entry.album)];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                let _ = writeln!(text, "      <{0}>{1}</{0}>", name, escape(value));
+            }
+        }
+        if let Some(secs) = entry.duration_secs {
+            let _ = writeln!(text, "      <duration>{}</duration>", (secs * 1000.0).round() as u64);
+        }
+        text.push_str("    </track>\n");
+    }
+    text.push_str("  </trackList>\n</playlist>\n");
+    text
+}
+
+/// The "Artist - Title" name of an entry, on one line.
+fn display_name(entry: &PlaylistEntry) -> Option<String> {
+    let title = one_line(entry.title.as_deref()?);
+    Some(match &entry.artist {
+        Some(artist) => format!("{} - {}", one_line(artist), title),
+        None => title,
+    })
+}
+
+fn one_line(text: &str) -> String {
+    text.replace(['\r', '\n'], " ")
+}
+
+/// The absolute path of an entry, or the URL of a stream as it is.
+fn location(entry: &str, base: &Path) -> String {
+    if let Some(path) = file_url_path(entry) {
+        return path;
+    }
+    if is_url(entry) {
+        return entry.to_string();
+    }
+    let entry = if cfg!(windows) {
+        entry.to_string()
+    } else {
+        entry.replace('\\', "/")
+    };
+    normalize(&base.join(entry)).to_string_lossy().to_string()
+}
+
+/// [`location`] of an XSPF location, which is a URI.
+fn uri_location(uri: &str, base: &Path) -> String {
+    if is_url(uri) {
+        location(uri, base)
+    } else {
+        location(&percent_decode(uri), base)
+    }
+}
+
+/// Whether `text` starts with a URL scheme; drive letters are too short to
+/// count.
+fn is_url(text: &str) -> bool {
+    text.split_once("://").is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+    })
+}
+
+/// The path of a `file://` URL.
+fn file_url_path(url: &str) -> Option<String> {
+    let rest = url.get(..7).filter(|s| s.eq_ignore_ascii_case("file://")).map(|_| &url[7..])?;
+    // Skip the host, usually empty or "localhost"
+    let path = percent_decode(&rest[rest.find('/')?..]);
+    // "/C:/Music" on Windows
+    let drive = path.as_bytes().get(2) == Some(&b':');
+    Some(if cfg!(windows) && drive { path[1..].to_string() } else { path })
+}
+
+/// Resolve `.` and `..` in `path` without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normal = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(normal.components().next_back(), Some(Component::Normal(_))) {
+                    normal.pop();
+                } else if !normal.has_root() {
+                    normal.push("..");
+                }
+            }
+            other => normal.push(other),
+        }
+    }
+    normal
+}
+
+/// How an entry is written: relative to `base` when given and it can be,
+/// else as it is.
+fn written_path(location: &str, base: Option<&Path>) -> String {
+    if is_url(location) {
+        return location.to_string();
+    }
+    base.and_then(|base| relative_to(Path::new(location), base))
+        .map_or_else(|| location.to_string(), |path| path.to_string_lossy().to_string())
+}
+
+/// `path` relative to the folder `base`, if both start at the same root.
+fn relative_to(path: &Path, base: &Path) -> Option<PathBuf> {
+    let (path, base) = (normalize(path), normalize(base));
+    let path: Vec<_> = path.components().collect();
+    let base: Vec<_> = base.components().collect();
+    if path.first()? != base.first()? {
+        return None;
+    }
+    let common = path.iter().zip(&base).take_while(|(a, b)| a == b).count();
+    let mut relative = PathBuf::new();
+    for _ in common..base.len() {
+        relative.push("..");
+    }
+    relative.extend(&path[common..]);
+    Some(relative)
+}
+
+/// An entry as an XSPF location: a relative or `file://` URI for files.
+fn uri(location: &str, base: Option<&Path>) -> String {
+    if is_url(location) {
+        return location.to_string();
+    }
+    let relative = base.and_then(|base| relative_to(Path::new(location), base));
+    match relative {
+        Some(path) => percent_encode(&path.to_string_lossy().replace('\\', "/"), "/"),
+        None if Path::new(location).is_absolute() => {
+            let path = location.replace('\\', "/");
+            let slash = if path.starts_with('/') { "" } else { "/" };
+            format!("file://{}{}", slash, percent_encode(&path, "/:"))
+        }
+        None => percent_encode(&location.replace('\\', "/"), "/"),
+    }
+}
+
+fn percent_encode(text: &str, keep: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        let unreserved = byte.is_ascii_alphanumeric() || b"-._~".contains(&byte);
+        if unreserved || keep.as_bytes().contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{:02X}", byte);
+        }
+    }
+    encoded
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok());
+        match hex.filter(|_| bytes[i] == b'%').and_then(|h| u8::from_str_radix(h, 16).ok()) {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// The contents of the `name` elements of `xml`, which must not nest.
+fn elements<'a>(mut xml: &'a str, name: &str) -> Vec<&'a str> {
+    let open = format!("<{}", name);
+    let close = format!("</{}>", name);
+    let mut found = Vec::new();
+    while let Some(start) = xml.find(&open) {
+        let rest = &xml[start + open.len()..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        // `<trackList>` when looking for `<track>`
+        if !rest.starts_with(|c: char| c == '>' || c == '/' || c.is_whitespace()) {
+            xml = rest;
+            continue;
+        }
+        if rest[..end].ends_with('/') {
+            found.push("");
+            xml = &rest[end + 1..];
+            continue;
+        }
+        let body = &rest[end + 1..];
+        let Some(stop) = body.find(&close) else {
+            break;
+        };
+        found.push(&body[..stop]);
+        xml = &body[stop + close.len()..];
+    }
+    found
+}
+
+fn element<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    elements(xml, name).into_iter().next()
+}
+
+/// The text of an element's contents, with entities and CDATA sections
+/// decoded.
+fn xml_text(contents: &str) -> String {
+    let mut text = String::new();
+    let mut rest = contents;
+    while let Some(start) = rest.find("<![CDATA[") {
+        text.push_str(&unescape(&rest[..start]));
+        let data = &rest[start + 9..];
+        let end = data.find("]]>").unwrap_or(data.len());
+        text.push_str(&data[..end]);
+        rest = data.get(end + 3..).unwrap_or("");
+    }
+    text.push_str(&unescape(rest));
+    text.trim().to_string()
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        unescaped.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let entity = rest.find(';').and_then(|semi| Some((entity(&rest[1..semi])?, semi)));
+        match entity {
+            Some((c, semi)) => {
+                unescaped.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+fn entity(name: &str) -> Option<char> {
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    })
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+
+    fn entry(location: &str, title: Option<&str>, artist: Option<&str>) -> PlaylistEntry {
+        PlaylistEntry {
+            location: location.to_string(),
+            title: title.map(str::to_string),
+            artist: artist.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_formats() {
+        let base = Path::new("/music/lists");
+        let m3u = "#EXTM3U\n#PLAYLIST:Road Trip\n\
+            #EXTINF:215 tvg-name=\"a,b\",Air - Sexy Boy\n../Air/01 Sexy Boy.flac\n\
+            # a comment\n#EXTALB:Moon Safari\n#EXTINF:-1,Kelly Watch the Stars\n\
+            sub\\02.flac\nfile:///music/Bj%C3%B6rk/Joga.mp3\nhttp://radio.example/stream\n";
+        let playlist = parse_m3u(m3u, base);
+        assert_eq!(playlist.name.as_deref(), Some("Road Trip"));
+        let mut first = entry("/music/Air/01 Sexy Boy.flac", Some("Sexy Boy"), Some("Air"));
+        first.duration_secs = Some(215.0);
+        let mut second = entry("/music/lists/sub/02.flac", Some("Kelly Watch the Stars"), None);
+        second.album = Some("Moon Safari".to_string());
+        let expected = [
+            first,
+            second,
+            entry("/music/Björk/Joga.mp3", None, None),
+            entry("http://radio.example/stream", None, None),
+        ];
+        assert_eq!(playlist.entries, expected);
+
+        let pls = "[playlist]\nTitle2=Second\nFile2=b.mp3\nFile1=/x/a.mp3\nLength1=61\n\
+            Length2=-1\nNumberOfEntries=2\nVersion=2\n";
+        assert_eq!(Format::sniff(pls), Format::Pls);
+        let playlist = parse_pls(pls, base);
+        let mut first = entry("/x/a.mp3", None, None);
+        first.duration_secs = Some(61.0);
+        let second = entry("/music/lists/b.mp3", Some("Second"), None);
+        assert_eq!(playlist.entries, [first, second]);
+
+        let xspf = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <playlist version="1" xmlns="http://xspf.org/ns/0/">
+              <title>Rock &amp; Roll</title>
+              <trackList>
+                <track><title>Ghost</title></track>
+                <track>
+                  <location>Sigur%20R%C3%B3s/Untitled.ogg</location>
+                  <title><![CDATA[Untitled <1>]]></title>
+                  <creator>Sigur R&#xF3;s</creator>
+                  <duration>402500</duration>
+                </track>
+                <track><location>file:///m/a.mp3</location><album/></track>
+              </trackList>
+            </playlist>"#;
+        assert_eq!(Format::sniff(xspf), Format::Xspf);
+        let playlist = parse_xspf(xspf, base);
+        assert_eq!(playlist.name.as_deref(), Some("Rock & Roll"));
+        let mut first = entry(
+            "/music/lists/Sigur Rós/Untitled.ogg",
+            Some("Untitled <1>"),
+            Some("Sigur Rós"),
+        );
+        first.duration_secs = Some(402.5);
+        assert_eq!(playlist.entries, [first, entry("/m/a.mp3", None, None)]);
+    }
+
+    #[test]
+    fn test_write_and_read_back() {
+        let dir = test_dir("playlists");
+        let song = dir.join("Artist").join("Song & Dance #1.flac");
+        let mut first = entry(&song.to_string_lossy(), Some("Song & Dance"), Some("Artist"));
+        first.album = Some("Album".to_string());
+        first.duration_secs = Some(183.0);
+        let playlist = Playlist {
+            name: Some("Mix".to_string()),
+            entries: vec![
+                first,
+                entry("/elsewhere/untitled.mp3", None, None),
+                entry("https://radio.example/live?x=1&y=2", Some("Live"), None),
+            ],
+        };
+
+        for name in ["mix.m3u8", "mix.pls", "mix.xspf"] {
+            let path = dir.join("lists").join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            write(&path, &playlist, true).unwrap();
+            let text = fs::read_to_string(&path).unwrap();
+            assert!(text.contains("../Artist/Song"), "{}: {}", name, text);
+            let mut read_back = read(&path).unwrap();
+            if name.ends_with(".pls") {
+                // PLS has neither playlist names nor albums
+                assert_eq!(read_back.name.as_deref(), Some("mix"));
+                read_back.name = playlist.name.clone();
+                read_back.entries[0].album = Some("Album".to_string());
+            }
+            assert_eq!(read_back, playlist, "{}", name);
+        }
+        assert!(write(&dir.join("mix.txt"), &playlist, false).is_err());
+        // No temporary files are left behind
+        assert_eq!(fs::read_dir(dir.join("lists")).unwrap().count(), 3);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let track = |path: &str, title: &str, artist: &str, duration_secs: f64| LibraryTrack {
+            path: path.to_string(),
+            title: Some(title.to_string()),
+            artist: Some(artist.to_string()),
+            duration_secs: Some(duration_secs),
+        };
+        let tracks = [
+            track("/m/Air/01 Sexy Boy.flac", "Sexy Boy", "Air", 298.0),
+            track("/m/Björk/Jóga.mp3", "Jóga", "Björk", 305.0),
+            track("/m/Live/Intro.mp3", "Intro", "Band A", 60.0),
+            track("/m/Studio/Intro.mp3", "Intro", "Band B", 90.0),
+            track("/m/x/Unique Song.mp3", "Unique Song", "C", 100.0),
+        ];
+        let mut by_tags = entry("/old/lib/björk - joga.mp3", Some("JOGA"), Some("bjork"));
+        by_tags.duration_secs = Some(304.0);
+        let mut wrong_duration = entry("/old/intro.mp3", Some("Intro"), Some("Band A"));
+        wrong_duration.duration_secs = Some(200.0);
+        let playlist = Playlist {
+            name: None,
+            entries: vec![
+                entry("/m/Air/01 Sexy Boy.flac", None, None),
+                entry("/M/AIR/01 SEXY BOY.FLAC", None, None),
+                entry("D:/Music/Unique Song.mp3", None, None),
+                by_tags,
+                entry("/old/Intro.mp3", Some("Intro"), None),
+                entry("/old/x.mp3", Some("unique song"), None),
+                wrong_duration,
+                entry("http://radio.example/Intro.mp3", None, None),
+            ],
+        };
+        let (paths, unresolved) = resolve(&playlist, &tracks);
+        let expected = [
+            "/m/Air/01 Sexy Boy.flac",
+            "/m/Air/01 Sexy Boy.flac",
+            "/m/x/Unique Song.mp3",
+            "/m/Björk/Jóga.mp3",
+            "/m/x/Unique Song.mp3",
+        ];
+        assert_eq!(paths, expected);
+        // An ambiguous title, a duration too far off and a stream
+        let indexes: Vec<u32> = unresolved.iter().map(|u| u.index).collect();
+        assert_eq!(indexes, [4, 6, 7]);
+        assert_eq!(unresolved[0].entry, playlist.entries[4]);
+    }
+}
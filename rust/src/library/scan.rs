@@ -0,0 +1,805 @@
+//! Directory walk for the library scanner.
+//!
+//! Files are read in parallel batches as the walk goes, so callers can show
+//! progress and store results long before a large library is fully walked.
+//!
+//! With a fingerprint index, files are also identified by content. A new
+//! path whose fingerprint matches a known path that is gone is reported as a
+//! move, and a known file whose mtime changed but whose content did not is
+//! not read again.
+//!
+//! A file described by a CUE sheet is listed as one song per track, under
+//! paths made by [`cue::track_path`]. The sheet's mtime counts as the
+//! file's, so editing the sheet re-reads the tracks.
+
+use super::artwork::ArtworkCache;
+use super::cue;
+use super::filter::ScanFilter;
+use super::fingerprint::{self, FingerprintIndex, IndexEntry};
+use super::formats;
+use super::metadata::extract_metadata;
+use crate::api::scanner::{
+    AudioFileMetadata, ScanEventType, ScanFailure, ScanFailureType, ScanMove, ScanOptions,
+    ScanStreamEvent,
+};
+use lofty::error::{ErrorKind, LoftyError};
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use walkdir::WalkDir;
+
+/// Files handled per batch; also how often progress is reported
+const SCAN_BATCH_SIZE: usize = 128;
+
+/// Walk `root_path`, reading changed audio files in parallel batches.
+///
+/// Fails before walking if `options` has an invalid exclude pattern.
+pub fn scan(
+    root_path: &str,
+    known_files: &HashMap<String, i64>,
+    art_cache_dir: Option<String>,
+    options: &ScanOptions,
+    cancel: &AtomicBool,
+    mut on_event: impl FnMut(ScanStreamEvent),
+) -> Result<(), String> {
+    let filter = ScanFilter::new(options)?;
+    let root = Path::new(root_path);
+    let mut walker = WalkDir::new(root).follow_links(options.follow_symlinks);
+    if let Some(max_depth) = options.max_depth {
+        walker = walker.max_depth(max_depth as usize + 1);
+    }
+
+    let artwork = art_cache_dir.and_then(|dir| ArtworkCache::new(dir).ok());
+    let mut index = options.fingerprint_index.as_ref().map(FingerprintIndex::load);
+    // Known paths by fingerprint, the candidates for the old side of a move
+    let mut known_by_hash: HashMap<String, Vec<&str>> = HashMap::new();
+    for (path, entry) in index.iter().flat_map(|index| index.iter()) {
+        if let Some((known, _)) = known_files.get_key_value(path) {
+            known_by_hash.entry(entry.hash.clone()).or_default().push(known);
+        }
+    }
+
+    // Known CUE tracks by the path of their file
+    let mut known_tracks: HashMap<&str, Vec<&str>> = HashMap::new();
+    for path in known_files.keys() {
+        if let Some((file, _)) = cue::split_track_path(path) {
+            known_tracks.entry(file).or_default().push(path);
+        }
+    }
+
+    let mut found_paths: HashSet<&str> = HashSet::new();
+    let mut walked_paths: HashSet<String> = HashSet::new();
+    let mut pending: Vec<PendingFile> = Vec::with_capacity(SCAN_BATCH_SIZE);
+    // New files that may turn out to be moves once the walk is complete
+    let mut held: Vec<(AudioFileMetadata, String)> = Vec::new();
+    let mut counts = ScanCounts::default();
+    let mut unreported = 0;
+
+    // Symlink loops surface as errors when following links, and are skipped
+    // along with unreadable entries
+    let entries = walker
+        .into_iter()
+        .filter_entry(|entry| !filter.skips(root, entry))
+        .filter_map(|e| e.ok());
+    for entry in entries {
+        if cancel.load(Ordering::Acquire) {
+            save_index(index.as_ref());
+            on_event(counts.event(ScanEventType::Cancelled, Batch::default()));
+            return Ok(());
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let Some(ext) = audio_format(entry.path(), options) else {
+            continue;
+        };
+        let path_str = entry.path().to_string_lossy().to_string();
+        let known = known_songs(&path_str, known_files, &known_tracks);
+        found_paths.extend(known.iter().map(|(path, _)| path));
+        counts.discovered += 1;
+        unreported += 1;
+
+        let sheet_modified = cue::sidecar(entry.path()).map_or(0, |sheet| mtime_secs(&sheet));
+        let modified = modified_secs(&entry).max(sheet_modified);
+        // Read if any song of the file is out of date
+        let read = match known.iter().map(|&(_, timestamp)| timestamp).min() {
+            Some(known_timestamp) => modified > known_timestamp,
+            None => true,
+        };
+        let mut file = PendingFile {
+            size: entry.metadata().map_or(0, |m| m.len()),
+            entry,
+            ext,
+            modified,
+            read,
+            fingerprint: false,
+            hash: None,
+            previous_hash: None,
+        };
+        if let Some(index) = &index {
+            match index.get(&path_str) {
+                Some(indexed) if indexed.matches(file.size, modified) => {
+                    // A known file is indexed past its stored mtime only when
+                    // a touch left its content as it was read
+                    file.read = known.is_empty();
+                    file.hash = Some(indexed.hash.clone())
+                }
+                indexed => {
+                    file.fingerprint = true;
+                    file.previous_hash = indexed
+                        .filter(|_| !known.is_empty())
+                        .map(|indexed| indexed.hash.clone());
+                }
+            }
+            walked_paths.insert(path_str);
+        }
+        if file.read || file.fingerprint {
+            pending.push(file);
+        } else {
+            counts.processed += 1;
+        }
+
+        if pending.len() == SCAN_BATCH_SIZE || unreported >= SCAN_BATCH_SIZE {
+            let batch = read_batch(
+                &mut pending,
+                artwork.as_ref(),
+                options,
+                index.as_mut(),
+                &mut counts,
+            );
+            let batch = hold_moves(
+                batch,
+                known_files,
+                &known_tracks,
+                &known_by_hash,
+                &mut held,
+                &mut found_paths,
+            );
+            on_event(counts.event(ScanEventType::Batch, batch));
+            unreported = 0;
+        }
+    }
+
+    if !pending.is_empty() || unreported > 0 {
+        let batch = read_batch(
+            &mut pending,
+            artwork.as_ref(),
+            options,
+            index.as_mut(),
+            &mut counts,
+        );
+        let batch = hold_moves(
+            batch,
+            known_files,
+            &known_tracks,
+            &known_by_hash,
+            &mut held,
+            &mut found_paths,
+        );
+        on_event(counts.event(ScanEventType::Batch, batch));
+    }
+
+    // A held file is a move if its old path was not found, and a copy if it
+    // was
+    let mut finished = Batch::default();
+    for (metadata, hash) in held {
+        let old_path = known_by_hash.get_mut(&hash).and_then(|paths| {
+            let i = paths.iter().position(|p| !found_paths.contains(p))?;
+            Some(paths.swap_remove(i))
+        });
+        match old_path {
+            Some(old_path) => {
+                found_paths.insert(old_path);
+                finished.moved.push(ScanMove {
+                    old_path: old_path.to_string(),
+                    new_path: metadata.path,
+                });
+            }
+            None => finished.files.push(metadata),
+        }
+    }
+    finished.deleted_paths = known_files
+        .keys()
+        .filter(|k| !found_paths.contains(k.as_str()))
+        .cloned()
+        .collect();
+
+    if let Some(index) = &mut index {
+        // Other roots share the index, so outside this root only files known
+        // to be gone are pruned
+        let gone: HashSet<&str> = (finished.deleted_paths.iter())
+            .chain(finished.moved.iter().map(|m| &m.old_path))
+            .map(String::as_str)
+            .collect();
+        index.retain(|path| {
+            walked_paths.contains(path)
+                || !(Path::new(path).starts_with(root) || gone.contains(path))
+        });
+    }
+    save_index(index.as_ref());
+    on_event(counts.event(ScanEventType::Finished, finished));
+    Ok(())
+}
+
+/// A file waiting to be read or fingerprinted.
+struct PendingFile {
+    entry: walkdir::DirEntry,
+    ext: String,
+    modified: i64,
+    size: u64,
+    /// New or modified according to the caller
+    read: bool,
+    /// The index has no current fingerprint for the file
+    fingerprint: bool,
+    /// Current fingerprint from the index
+    hash: Option<String>,
+    /// Outdated fingerprint of a known file, to tell whether its content
+    /// actually changed
+    previous_hash: Option<String>,
+}
+
+/// Files read from one batch.
+#[derive(Default)]
+struct Batch {
+    files: Vec<AudioFileMetadata>,
+    failures: Vec<ScanFailure>,
+    /// Paths of the files read; known songs of theirs that were not read
+    /// again, such as files now below the minimum duration, count as deleted
+    read: Vec<String>,
+    /// Fingerprints of the files read, when an index is kept
+    hashes: HashMap<String, String>,
+    moved: Vec<ScanMove>,
+    deleted_paths: Vec<String>,
+}
+
+#[derive(Default)]
+struct ScanCounts {
+    discovered: u32,
+    processed: u32,
+    failed: u32,
+}
+
+impl ScanCounts {
+    fn event(&self, kind: ScanEventType, batch: Batch) -> ScanStreamEvent {
+        ScanStreamEvent {
+            kind,
+            files: batch.files,
+            failures: batch.failures,
+            deleted_paths: batch.deleted_paths,
+            moved: batch.moved,
+            discovered: self.discovered,
+            processed: self.processed,
+            failed: self.failed,
+        }
+    }
+}
+
+/// Read and fingerprint the pending files in parallel, draining `pending`.
+///
+/// Known files whose fingerprint did not change are not read again.
+fn read_batch(
+    pending: &mut Vec<PendingFile>,
+    artwork: Option<&ArtworkCache>,
+    options: &ScanOptions,
+    index: Option<&mut FingerprintIndex>,
+    counts: &mut ScanCounts,
+) -> Batch {
+    let results: Vec<_> = pending
+        .par_drain(..)
+        .map(|file| {
+            let hash = if file.fingerprint {
+                fingerprint::fingerprint(file.entry.path()).ok()
+            } else {
+                file.hash
+            };
+            let unchanged = file.previous_hash.is_some() && hash == file.previous_hash;
+            let result = (file.read && !unchanged)
+                .then(|| read_file(file.entry.path(), file.ext, file.modified, options, artwork));
+            let path = file.entry.into_path().to_string_lossy().to_string();
+            (path, file.size, file.modified, hash, result)
+        })
+        .collect();
+
+    let mut batch = Batch::default();
+    counts.processed += results.len() as u32;
+    let mut index = index;
+    for (path, size, modified, hash, result) in results {
+        // Files that failed to read stay out of date, so they are read again
+        let failed = matches!(result, Some(Err(_)));
+        if let (Some(index), Some(hash), false) = (index.as_deref_mut(), &hash, failed) {
+            let entry = IndexEntry { size, modified, hash: hash.clone() };
+            index.insert(path.clone(), entry);
+        }
+        match result {
+            Some(Ok(songs)) => {
+                if let Some(hash) = hash {
+                    batch.hashes.insert(path.clone(), hash);
+                }
+                batch.files.extend(songs);
+                batch.read.push(path);
+            }
+            Some(Err(failure)) => batch.failures.push(failure),
+            None => {}
+        }
+    }
+    counts.failed += batch.failures.len() as u32;
+    batch
+}
+
+/// Drop known songs that files no longer produce from `found_paths` and
+/// move new files that share a fingerprint with a known file into `held`,
+/// until the walk shows whether they were moved or copied.
+fn hold_moves<'a>(
+    mut batch: Batch,
+    known_files: &'a HashMap<String, i64>,
+    known_tracks: &HashMap<&'a str, Vec<&'a str>>,
+    known_by_hash: &HashMap<String, Vec<&str>>,
+    held: &mut Vec<(AudioFileMetadata, String)>,
+    found_paths: &mut HashSet<&'a str>,
+) -> Batch {
+    let read_songs: HashSet<&str> = batch.files.iter().map(|m| m.path.as_str()).collect();
+    for path in &batch.read {
+        for (known, _) in known_songs(path, known_files, known_tracks) {
+            if !read_songs.contains(known) {
+                found_paths.remove(known);
+            }
+        }
+    }
+    let mut files = Vec::with_capacity(batch.files.len());
+    for metadata in batch.files {
+        let hash = batch.hashes.remove(&metadata.path);
+        match hash {
+            Some(hash)
+                if !known_files.contains_key(&metadata.path)
+                    && known_by_hash.contains_key(&hash) =>
+            {
+                held.push((metadata, hash))
+            }
+            _ => files.push(metadata),
+        }
+    }
+    batch.files = files;
+    batch
+}
+
+/// Write the fingerprint index back. A lost index only costs re-hashing on
+/// the next scan, so errors are ignored.
+fn save_index(index: Option<&FingerprintIndex>) {
+    if let Some(index) = index {
+        let _ = index.save();
+    }
+}
+
+/// Read one audio file's metadata and artwork: one song, one per track of
+/// its CUE sheet, or none if it is shorter than the minimum duration.
+///
+/// The container is taken from the extension unless lofty does not know it
+/// or content sniffing is on, in which case the file's content decides.
+pub fn read_file(
+    path: &Path,
+    ext: String,
+    modified: i64,
+    options: &ScanOptions,
+    artwork: Option<&ArtworkCache>,
+) -> Result<Vec<AudioFileMetadata>, ScanFailure> {
+    let failure = |reason, detail: String| ScanFailure {
+        path: path.to_string_lossy().to_string(),
+        reason,
+        detail,
+    };
+    let tagged_file = Probe::open(path)
+        .and_then(|probe| {
+            if options.sniff_content || probe.file_type().is_none() {
+                Ok(probe.guess_file_type()?)
+            } else {
+                Ok(probe)
+            }
+        })
+        .and_then(|probe| probe.read())
+        .map_err(|e| failure(failure_type(&e), e.to_string()))?;
+    let duration = tagged_file.properties().duration();
+    if duration.is_zero() {
+        return Err(failure(
+            ScanFailureType::ZeroDuration,
+            "No audio frames".to_string(),
+        ));
+    }
+    let min_duration = u64::from(options.min_duration_secs);
+    if duration.as_secs() < min_duration {
+        return Ok(Vec::new());
+    }
+
+    let mut metadata = extract_metadata(path, &tagged_file, ext, modified);
+    if let Some(art) = artwork.and_then(|cache| cache.art_for(path, &tagged_file)) {
+        metadata.album_art_path = Some(art.path);
+        metadata.album_palette = art.palette;
+    }
+    let tag = tagged_file.primary_tag().or_else(|| tagged_file.first_tag());
+    let tracks = cue::find(path, tag)
+        .map(|sheet| cue::split_tracks(&metadata, &sheet, duration.as_secs_f64()))
+        .unwrap_or_default();
+    if tracks.is_empty() {
+        return Ok(vec![metadata]);
+    }
+    Ok(tracks
+        .into_iter()
+        .filter(|track| track.duration_secs.is_some_and(|secs| secs >= min_duration))
+        .collect())
+}
+
+/// The known songs read from the file at `path` with their modification
+/// times: the file itself, or the tracks of its CUE sheet.
+fn known_songs<'a>(
+    path: &str,
+    known_files: &'a HashMap<String, i64>,
+    known_tracks: &HashMap<&'a str, Vec<&'a str>>,
+) -> Vec<(&'a str, i64)> {
+    let file = known_files.get_key_value(path).map(|(path, &t)| (path.as_str(), t));
+    let tracks = known_tracks.get(path).into_iter().flatten();
+    let tracks = tracks.map(|&track| (track, known_files[track]));
+    file.into_iter().chain(tracks).collect()
+}
+
+/// Categorize a read error for the problem-files report.
+fn failure_type(error: &LoftyError) -> ScanFailureType {
+    match error.kind() {
+        // Running out of data or seeking before the start means the file is
+        // shorter than its format requires, not that it is unreadable
+        ErrorKind::Io(e)
+            if matches!(
+                e.kind(),
+                std::io::ErrorKind::UnexpectedEof | std::io::ErrorKind::InvalidInput
+            ) =>
+        {
+            ScanFailureType::Corrupt
+        }
+        ErrorKind::Io(_) => ScanFailureType::Io,
+        ErrorKind::UnknownFormat => ScanFailureType::UnsupportedContainer,
+        ErrorKind::FakeTag
+        | ErrorKind::TextDecode(_)
+        | ErrorKind::BadTimestamp(_)
+        | ErrorKind::Id3v2(_)
+        | ErrorKind::BadAtom(_)
+        | ErrorKind::StringFromUtf8(_)
+        | ErrorKind::StrFromUtf8(_)
+        | ErrorKind::NotAPicture
+        | ErrorKind::UnsupportedPicture => ScanFailureType::BadTag,
+        _ => ScanFailureType::Corrupt,
+    }
+}
+
+/// Format of `path` if the scanner reads it: its lowercased extension, or
+/// when sniffing, the extension its content calls for.
+pub fn audio_format(path: &Path, options: &ScanOptions) -> Option<String> {
+    let ext = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase);
+    if let Some(ext) = ext.as_ref().filter(|ext| options.extensions.contains(ext)) {
+        return Some(ext.clone());
+    }
+    if !options.sniff_content || !formats::worth_sniffing(ext.as_deref()) {
+        return None;
+    }
+    let sniffed = formats::sniff(path)?;
+    options
+        .extensions
+        .iter()
+        .any(|ext| ext == sniffed)
+        .then(|| sniffed.to_string())
+}
+
+/// Modification time in seconds since the epoch, or 0 if unknown.
+fn modified_secs(entry: &walkdir::DirEntry) -> i64 {
+    entry.metadata().ok().map_or(0, |m| epoch_secs(&m))
+}
+
+/// Modification time of the file at `path`, as for [`modified_secs`].
+fn mtime_secs(path: &Path) -> i64 {
+    std::fs::metadata(path).map_or(0, |m| epoch_secs(&m))
+}
+
+fn epoch_secs(metadata: &std::fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::scanner::scan_default_options;
+    use crate::test_util::{test_dir, write_silence};
+
+    #[test]
+    fn test_streamed_scan_batches_and_counts() {
+        let dir = test_dir("scanner-stream");
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        let new_files = SCAN_BATCH_SIZE + 22;
+        for i in 0..new_files {
+            write_silence(&dir.join("nested").join(format!("{}.wav", i)), 64);
+        }
+        std::fs::write(dir.join("broken.mp3"), b"not audio").unwrap();
+        // A single frame is shorter than a millisecond
+        write_silence(&dir.join("empty.wav"), 1);
+        std::fs::write(dir.join("notes.txt"), b"ignored").unwrap();
+        let kept = dir.join("kept.wav");
+        write_silence(&kept, 64);
+
+        let mut known = HashMap::new();
+        known.insert(kept.to_string_lossy().to_string(), i64::MAX);
+        known.insert(dir.join("gone.wav").to_string_lossy().to_string(), 0);
+
+        let mut events = Vec::new();
+        let root = dir.to_string_lossy().to_string();
+        let options = scan_default_options();
+        scan(&root, &known, None, &options, &AtomicBool::new(false), |e| {
+            events.push(e)
+        })
+        .unwrap();
+
+        let last = events.last().unwrap();
+        assert_eq!(last.kind, ScanEventType::Finished);
+        assert_eq!(
+            last.deleted_paths,
+            vec![dir.join("gone.wav").to_string_lossy().to_string()]
+        );
+        assert_eq!(last.discovered, new_files as u32 + 3);
+        assert_eq!(last.processed, last.discovered);
+        assert_eq!(last.failed, 2);
+
+        let batches: Vec<_> = events.iter().filter(|e| e.kind == ScanEventType::Batch).collect();
+        assert!(batches.len() >= 2);
+        assert_eq!(batches.iter().map(|e| e.files.len()).sum::<usize>(), new_files);
+        assert!(events.windows(2).all(|w| w[0].processed <= w[1].processed));
+
+        let mut failures: Vec<_> = events.iter().flat_map(|e| e.failures.clone()).collect();
+        failures.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(failures.len(), 2);
+        assert!(failures[0].path.ends_with("broken.mp3"));
+        assert_eq!(failures[0].reason, ScanFailureType::Corrupt);
+        assert!(failures[1].path.ends_with("empty.wav"));
+        assert_eq!(failures[1].reason, ScanFailureType::ZeroDuration);
+
+        // A cancelled scan reports no deletions
+        let mut events = Vec::new();
+        scan(&root, &known, None, &options, &AtomicBool::new(true), |e| {
+            events.push(e)
+        })
+        .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, ScanEventType::Cancelled);
+        assert!(events[0].deleted_paths.is_empty());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_failure_types() {
+        let io = LoftyError::from(std::io::Error::other("disk on fire"));
+        assert_eq!(failure_type(&io), ScanFailureType::Io);
+        let utf8 = LoftyError::from(String::from_utf8(vec![0xff]).unwrap_err());
+        assert_eq!(failure_type(&utf8), ScanFailureType::BadTag);
+
+        let path = Path::new("/nonexistent/song.flac");
+        let missing = read_file(path, "flac".into(), 0, &scan_default_options(), None);
+        assert_eq!(missing.unwrap_err().reason, ScanFailureType::Io);
+    }
+
+    #[test]
+    fn test_extension_options_and_sniffing() {
+        let dir = test_dir("scanner-sniff");
+        write_silence(&dir.join("listed.wav"), 64);
+        write_silence(&dir.join("renamed.dat"), 64);
+        write_silence(&dir.join("untitled"), 64);
+        write_silence(&dir.join("cover.jpg"), 64);
+        std::fs::write(dir.join("notes"), b"not audio").unwrap();
+
+        let root = dir.to_string_lossy().to_string();
+        let scan_with = |options: &ScanOptions| {
+            let mut files = Vec::new();
+            scan(&root, &HashMap::new(), None, options, &AtomicBool::new(false), |e| {
+                files.extend(e.files.into_iter().map(|f| {
+                    let name = Path::new(&f.path).file_name().unwrap().to_string_lossy().to_string();
+                    (name, f.format)
+                }))
+            })
+            .unwrap();
+            files.sort();
+            files
+        };
+
+        let mut options = scan_default_options();
+        assert_eq!(scan_with(&options), vec![("listed.wav".to_string(), "wav".to_string())]);
+
+        // Sniffing finds audio behind wrong and missing extensions, but leaves
+        // companion files alone
+        options.sniff_content = true;
+        let found = scan_with(&options);
+        let names: Vec<&str> = found.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["listed.wav", "renamed.dat", "untitled"]);
+        assert!(found.iter().all(|(_, format)| format == "wav"));
+
+        // Formats left out of the list are skipped even when sniffed
+        options.extensions = vec!["flac".to_string()];
+        assert!(scan_with(&options).is_empty());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_depth_duration_and_symlinks() {
+        let dir = test_dir("scanner-walk");
+        std::fs::create_dir_all(dir.join("a/b")).unwrap();
+        write_silence(&dir.join("top.wav"), 44100 * 3);
+        write_silence(&dir.join("a/mid.wav"), 44100 * 3);
+        write_silence(&dir.join("a/b/deep.wav"), 44100 * 3);
+        let short = dir.join("a/short.wav");
+        write_silence(&short, 4410);
+
+        let root = dir.to_string_lossy().to_string();
+        let scan_with = |options: &ScanOptions, known: &HashMap<String, i64>| {
+            let mut events = Vec::new();
+            scan(&root, known, None, options, &AtomicBool::new(false), |e| events.push(e)).unwrap();
+            let mut names: Vec<String> = events
+                .iter()
+                .flat_map(|e| &e.files)
+                .map(|f| Path::new(&f.path).file_name().unwrap().to_string_lossy().to_string())
+                .collect();
+            names.sort();
+            (names, events.pop().unwrap().deleted_paths)
+        };
+
+        let mut options = scan_default_options();
+        options.max_depth = Some(1);
+        let (names, _) = scan_with(&options, &HashMap::new());
+        assert_eq!(names, ["mid.wav", "short.wav", "top.wav"]);
+
+        // A known file that is now too short is dropped from the library
+        options.max_depth = None;
+        options.min_duration_secs = 1;
+        let known = HashMap::from([(short.to_string_lossy().to_string(), 0)]);
+        let (names, deleted) = scan_with(&options, &known);
+        assert_eq!(names, ["deep.wav", "mid.wav", "top.wav"]);
+        assert_eq!(deleted, vec![short.to_string_lossy().to_string()]);
+
+        // A link back to the root is detected instead of walked forever
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&dir, dir.join("a/b/loop")).unwrap();
+            options.follow_symlinks = true;
+            let (names, _) = scan_with(&options, &HashMap::new());
+            assert_eq!(names, ["deep.wav", "mid.wav", "top.wav"]);
+        }
+
+        options.exclude_globs.push("[".to_string());
+        assert!(scan(&root, &HashMap::new(), None, &options, &AtomicBool::new(false), |_| {}).is_err());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_cue_sheet_tracks() {
+        let dir = test_dir("scanner-cue");
+        let file = dir.join("album.wav");
+        write_silence(&file, 44100 * 10);
+        std::fs::write(
+            dir.join("album.cue"),
+            "PERFORMER \"Band\"\nTITLE \"Album\"\nFILE \"album.flac\" WAVE\n\
+             TRACK 01 AUDIO\nTITLE \"One\"\nINDEX 01 00:00:00\n\
+             TRACK 02 AUDIO\nTITLE \"Two\"\nINDEX 01 00:04:00\n\
+             TRACK 03 AUDIO\nINDEX 01 00:07:00\n",
+        )
+        .unwrap();
+
+        let file_path = file.to_string_lossy().to_string();
+        let track = |number: u32| cue::track_path(&file_path, number);
+        let root = dir.to_string_lossy().to_string();
+        let scan_with = |options: &ScanOptions, known: &HashMap<String, i64>| {
+            let mut events = Vec::new();
+            scan(&root, known, None, options, &AtomicBool::new(false), |e| events.push(e)).unwrap();
+            let files: Vec<AudioFileMetadata> =
+                events.iter().flat_map(|e| e.files.clone()).collect();
+            (files, events.pop().unwrap().deleted_paths)
+        };
+
+        let mut options = scan_default_options();
+        let (files, _) = scan_with(&options, &HashMap::new());
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, [track(1), track(2), track(3)]);
+        assert_eq!(files[1].title.as_deref(), Some("Two"));
+        assert_eq!(files[1].album.as_deref(), Some("Album"));
+        assert_eq!(files[1].artist.as_deref(), Some("Band"));
+        assert_eq!((files[1].track_number, files[1].track_total), (Some(2), Some(3)));
+        assert_eq!(files[1].duration_secs, Some(3));
+        let range = files[1].range.as_ref().unwrap();
+        assert_eq!(range.source_path, file_path);
+        assert_eq!((range.start_secs, range.end_secs), (4.0, Some(7.0)));
+        assert_eq!(files[2].range.as_ref().unwrap().end_secs, None);
+
+        // Known tracks keep the file from being read again
+        let known: HashMap<String, i64> = (1..=3).map(|n| (track(n), i64::MAX)).collect();
+        let (files, deleted) = scan_with(&options, &known);
+        assert!(files.is_empty() && deleted.is_empty());
+
+        // The song the file was before it had a sheet is replaced, and a
+        // track now below the minimum duration is dropped
+        let mut known: HashMap<String, i64> = (1..=3).map(|n| (track(n), 0)).collect();
+        known.insert(file_path.clone(), 0);
+        options.min_duration_secs = 4;
+        let (files, mut deleted) = scan_with(&options, &known);
+        deleted.sort();
+        assert_eq!(files.len(), 1);
+        assert_eq!(deleted, [file_path.clone(), track(2), track(3)]);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_moves_and_unchanged_content() {
+        let dir = test_dir("scanner-moves");
+        let library = dir.join("library");
+        std::fs::create_dir_all(library.join("renamed")).unwrap();
+        let path = |name: &str| library.join(name).to_string_lossy().to_string();
+        write_silence(&library.join("a.wav"), 64);
+        write_silence(&library.join("c.wav"), 80);
+        write_silence(&library.join("gone.wav"), 96);
+
+        let mut options = scan_default_options();
+        options.fingerprint_index = Some(dir.join("fingerprints").to_string_lossy().to_string());
+        let root = library.to_string_lossy().to_string();
+        let scan_with = |known: &HashMap<String, i64>| {
+            let mut events = Vec::new();
+            let cancel = AtomicBool::new(false);
+            scan(&root, known, None, &options, &cancel, |e| events.push(e)).unwrap();
+            let mut names: Vec<String> =
+                events.iter().flat_map(|e| &e.files).map(|f| f.path.clone()).collect();
+            names.sort();
+            (names, events.pop().unwrap())
+        };
+
+        // Unchanged known files are only fingerprinted
+        let mut known: HashMap<String, i64> =
+            ["a.wav", "c.wav", "gone.wav"].iter().map(|name| (path(name), i64::MAX)).collect();
+        let (names, finished) = scan_with(&known);
+        assert!(names.is_empty());
+        assert_eq!(finished.processed, 3);
+
+        // A rename is a move, a copy of a file still in place is a new file,
+        // and a file that is really gone is deleted
+        std::fs::rename(library.join("a.wav"), library.join("renamed/b.wav")).unwrap();
+        std::fs::copy(library.join("c.wav"), library.join("copy.wav")).unwrap();
+        std::fs::remove_file(library.join("gone.wav")).unwrap();
+        let (names, finished) = scan_with(&known);
+        assert_eq!(names, [path("copy.wav")]);
+        assert_eq!(
+            finished.moved,
+            [ScanMove { old_path: path("a.wav"), new_path: path("renamed/b.wav") }]
+        );
+        assert_eq!(finished.deleted_paths, [path("gone.wav")]);
+
+        // A newer mtime alone does not cause a re-read, a content change does
+        known = HashMap::from([(path("c.wav"), 0)]);
+        let touched = std::time::SystemTime::now() + std::time::Duration::from_secs(60);
+        let file = std::fs::File::options().write(true).open(library.join("c.wav")).unwrap();
+        file.set_modified(touched).unwrap();
+        let (names, finished) = scan_with(&known);
+        assert!(!names.contains(&path("c.wav")));
+        assert!(finished.moved.is_empty() && finished.deleted_paths.is_empty());
+        // Nor on the next scan, though the caller still has the old mtime
+        let (names, _) = scan_with(&known);
+        assert!(!names.contains(&path("c.wav")));
+        write_silence(&library.join("c.wav"), 100);
+        let (names, _) = scan_with(&known);
+        assert!(names.contains(&path("c.wav")));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
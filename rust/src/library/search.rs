@@ -0,0 +1,573 @@
+//! Fuzzy search over the titles, artists and albums of the library.
+//!
+//! Text is folded to lowercase without diacritics, so "bjork" finds
+//! "Björk", and split into words. Runs of Chinese, Japanese or Korean
+//! characters, which are not separated by spaces, are split into
+//! overlapping pairs instead. Every word of a query must match a word of
+//! the song exactly, as a prefix, or with a typo after the first letter in
+//! longer words. Songs rank by how closely their words matched, weighted
+//! by field so title matches come first.
+//!
+//! The index lives in memory. It is built when the library opens and kept
+//! in step with the same scan and watch changes that update the store.
+
+use super::cue;
+use crate::api::scanner::{AudioFileMetadata, ScanEventType, ScanStreamEvent, WatchEvent};
+use std::collections::{BTreeMap, HashMap};
+use std::path::MAIN_SEPARATOR;
+use unicode_normalization::char::{decompose_compatible, is_combining_mark};
+
+/// Query words at least this long may contain one typo
+const ONE_TYPO_LEN: usize = 4;
+
+/// Query words at least this long may contain two typos
+const TWO_TYPOS_LEN: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Title,
+    /// The artist or album artist
+    Artist,
+    Album,
+}
+
+impl Field {
+    fn weight(self) -> f32 {
+        match self {
+            Field::Title => 3.0,
+            Field::Artist => 2.0,
+            Field::Album => 1.5,
+        }
+    }
+}
+
+/// A word occurring in a field of a song.
+#[derive(Debug, Clone, Copy)]
+struct Posting {
+    doc: u32,
+    field: Field,
+}
+
+/// An indexed word and the songs it occurs in.
+#[derive(Default)]
+struct Word {
+    postings: Vec<Posting>,
+    len: usize,
+    /// [`letters`] of the word, to rule out most typo matches cheaply
+    letters: u64,
+}
+
+struct Doc {
+    path: String,
+    title: String,
+    /// Distinct words of the song, for removing its postings
+    words: Vec<String>,
+}
+
+/// A song found by [`SearchIndex::search`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub path: String,
+    pub score: f32,
+}
+
+/// The text a song is found by.
+pub struct SearchFields<'a> {
+    pub title: &'a str,
+    pub artist: Option<&'a str>,
+    pub album_artist: Option<&'a str>,
+    pub album: Option<&'a str>,
+}
+
+/// An in-memory word index over the library.
+#[derive(Default)]
+pub struct SearchIndex {
+    /// Songs by id; removed songs leave a hole for reuse
+    docs: Vec<Option<Doc>>,
+    free: Vec<u32>,
+    ids: HashMap<String, u32>,
+    /// Indexed words, ordered so words with a prefix are adjacent
+    words: BTreeMap<String, Word>,
+}
+
+impl SearchIndex {
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Add the song at `path`, replacing what was indexed for it.
+    pub fn insert(&mut self, path: &str, fields: SearchFields<'_>) {
+        self.remove(path);
+        let id = match self.free.pop() {
+            Some(id) => id,
+            None => {
+                self.docs.push(None);
+                (self.docs.len() - 1) as u32
+            }
+        };
+
+        let mut postings: Vec<(String, Field)> = Vec::new();
+        let texts = [
+            (Some(fields.title), Field::Title),
+            (fields.artist, Field::Artist),
+            (fields.album_artist, Field::Artist),
+            (fields.album, Field::Album),
+        ];
+        for (text, field) in texts {
+            for word in words(text.unwrap_or_default()) {
+                if !postings.iter().any(|(w, f)| *w == word && *f == field) {
+                    postings.push((word, field));
+                }
+            }
+        }
+        let mut doc_words = Vec::new();
+        for (word, field) in postings {
+            let entry = self.words.entry(word.clone()).or_insert_with(|| Word {
+                postings: Vec::new(),
+                len: word.chars().count(),
+                letters: letters(&word),
+            });
+            entry.postings.push(Posting { doc: id, field });
+            if !doc_words.contains(&word) {
+                doc_words.push(word);
+            }
+        }
+
+        self.docs[id as usize] = Some(Doc {
+            path: path.to_string(),
+            title: fields.title.to_lowercase(),
+            words: doc_words,
+        });
+        self.ids.insert(path.to_string(), id);
+    }
+
+    /// Add a file read by the scanner, titled like the library titles it.
+    pub fn insert_metadata(&mut self, metadata: &AudioFileMetadata) {
+        let title = super::store::display_title(metadata);
+        let fields = SearchFields {
+            title: &title,
+            artist: metadata.artist.as_deref(),
+            album_artist: metadata.album_artist.as_deref(),
+            album: metadata.album.as_deref(),
+        };
+        self.insert(&metadata.path, fields);
+    }
+
+    pub fn remove(&mut self, path: &str) {
+        let Some(id) = self.ids.remove(path) else {
+            return;
+        };
+        let Some(doc) = self.docs[id as usize].take() else {
+            return;
+        };
+        for word in doc.words {
+            if let Some(entry) = self.words.get_mut(&word) {
+                entry.postings.retain(|p| p.doc != id);
+                if entry.postings.is_empty() {
+                    self.words.remove(&word);
+                }
+            }
+        }
+        self.free.push(id);
+    }
+
+    /// Remove the song at `path`, the tracks of its CUE sheet, and every
+    /// song in the folder at `path`.
+    pub fn remove_under(&mut self, path: &str) {
+        let folder = format!("{}{}", path.trim_end_matches(MAIN_SEPARATOR), MAIN_SEPARATOR);
+        let paths: Vec<String> = self
+            .ids
+            .keys()
+            .filter(|p| {
+                *p == path
+                    || p.starts_with(&folder)
+                    || cue::split_track_path(p).is_some_and(|(file, _)| file == path)
+            })
+            .cloned()
+            .collect();
+        for path in paths {
+            self.remove(&path);
+        }
+    }
+
+    /// Re-path a song, keeping what was indexed for it.
+    pub fn rename(&mut self, old_path: &str, new_path: &str) {
+        let Some(id) = self.ids.remove(old_path) else {
+            return;
+        };
+        self.remove(new_path);
+        if let Some(doc) = self.docs[id as usize].as_mut() {
+            doc.path = new_path.to_string();
+        }
+        self.ids.insert(new_path.to_string(), id);
+    }
+
+    /// Apply one event of a library scan; the `Finished` event carries the
+    /// moves and deletions.
+    pub fn apply_scan(&mut self, event: &ScanStreamEvent) {
+        if event.kind == ScanEventType::Finished {
+            for moved in &event.moved {
+                self.rename(&moved.old_path, &moved.new_path);
+            }
+            for path in &event.deleted_paths {
+                self.remove(path);
+            }
+        }
+        for metadata in &event.files {
+            self.insert_metadata(metadata);
+        }
+    }
+
+    pub fn apply_watch(&mut self, event: &WatchEvent) {
+        for moved in &event.moved {
+            self.rename(&moved.old_path, &moved.new_path);
+        }
+        for path in &event.removed_paths {
+            self.remove_under(path);
+        }
+        for metadata in event.added.iter().chain(&event.modified) {
+            self.insert_metadata(metadata);
+        }
+    }
+
+    /// The best `limit` songs matching every word of `query`, best first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        let query = words(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        // Scores by song id; songs missing a word drop out with a negative
+        // score
+        let mut scores = vec![0.0f32; self.docs.len()];
+        let mut best = vec![0.0f32; self.docs.len()];
+        for word in &query {
+            // Each song scores its best match for the word
+            best.fill(0.0);
+            for (postings, quality) in self.matches(word) {
+                for posting in postings {
+                    let score = &mut best[posting.doc as usize];
+                    *score = score.max(quality * posting.field.weight());
+                }
+            }
+            for (score, best) in scores.iter_mut().zip(&best) {
+                *score = if *score >= 0.0 && *best > 0.0 {
+                    *score + best
+                } else {
+                    -1.0
+                };
+            }
+        }
+
+        let mut hits: Vec<(&Doc, f32)> = scores
+            .into_iter()
+            .enumerate()
+            .filter(|(_, score)| *score > 0.0)
+            .filter_map(|(id, score)| Some((self.docs[id].as_ref()?, score)))
+            .collect();
+        let order = |(a, a_score): &(&Doc, f32), (b, b_score): &(&Doc, f32)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| a.title.cmp(&b.title))
+                .then_with(|| a.path.cmp(&b.path))
+        };
+        if hits.len() > limit && limit > 0 {
+            hits.select_nth_unstable_by(limit - 1, order);
+        }
+        hits.truncate(limit);
+        hits.sort_by(order);
+        hits.into_iter()
+            .map(|(doc, score)| SearchHit {
+                path: doc.path.clone(),
+                score,
+            })
+            .collect()
+    }
+
+    /// Postings of the indexed words matching a query word, with the
+    /// quality of the match: 1 for the same word, less for a prefix of a
+    /// longer word and less again for a typo.
+    fn matches(&self, word: &str) -> Vec<(&[Posting], f32)> {
+        let mut matches = Vec::new();
+        let len = word.chars().count();
+        for (indexed, entry) in self.words.range(word.to_string()..) {
+            if !indexed.starts_with(word) {
+                break;
+            }
+            let quality = if indexed.len() == word.len() {
+                1.0
+            } else {
+                0.5 + 0.4 * len as f32 / entry.len as f32
+            };
+            matches.push((entry.postings.as_slice(), quality));
+        }
+
+        let max_typos = match len {
+            _ if word.chars().any(is_cjk) => return matches,
+            l if l >= TWO_TYPOS_LEN => 2,
+            l if l >= ONE_TYPO_LEN => 1,
+            _ => return matches,
+        };
+        // Typos are only looked for after the first letter, which keeps the
+        // words to compare few
+        let query: Vec<char> = word.chars().collect();
+        let first = &word[..query[0].len_utf8()];
+        let query_letters = letters(word);
+        let mut chars = Vec::new();
+        for (indexed, entry) in self.words.range(first.to_string()..) {
+            if !indexed.starts_with(first) {
+                break;
+            }
+            // Each typo adds at most one letter the other word lacks
+            if entry.len + max_typos < len
+                || (query_letters & !entry.letters).count_ones() as usize > max_typos
+                || indexed.starts_with(word)
+            {
+                continue;
+            }
+            chars.clear();
+            chars.extend(indexed.chars());
+            // Compare whole words, and the start of longer words so a word
+            // being typed still matches
+            let whole = (chars.len() <= len + max_typos
+                && (entry.letters & !query_letters).count_ones() as usize <= max_typos)
+                .then(|| typos(&query, &chars, max_typos))
+                .flatten();
+            let quality = match whole {
+                Some(typos) => 0.7 - 0.2 * typos as f32,
+                None if chars.len() > len => match typos(&query, &chars[..len], max_typos) {
+                    Some(typos) => 0.5 - 0.2 * typos as f32,
+                    None => continue,
+                },
+                None => continue,
+            };
+            matches.push((entry.postings.as_slice(), quality));
+        }
+        matches
+    }
+}
+
+/// Edits turning `a` into `b`, counting a swap of neighbors as one, if
+/// there are at most `max`.
+fn typos(a: &[char], b: &[char], max: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    let width = b.len() + 1;
+    let mut rows = vec![0usize; width * 3];
+    let (mut before, mut prev, mut cur) = (0, width, 2 * width);
+    for (j, cell) in rows[prev..cur].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        rows[cur] = i;
+        let mut row_min = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut d = (rows[prev + j] + 1)
+                .min(rows[cur + j - 1] + 1)
+                .min(rows[prev + j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[before + j - 2] + 1);
+            }
+            rows[cur + j] = d;
+            row_min = row_min.min(d);
+        }
+        if row_min > max {
+            return None;
+        }
+        (before, prev, cur) = (prev, cur, before);
+    }
+    let distance = rows[prev + b.len()];
+    (distance <= max).then_some(distance)
+}
+
+/// The set of letters in `word`, as bits; digits and other letters share
+/// the bits past the alphabet.
+fn letters(word: &str) -> u64 {
+    word.chars().fold(0, |set, c| {
+        let bit = match c {
+            'a'..='z' => c as u32 - 'a' as u32,
+            _ => 26 + c as u32 % 38,
+        };
+        set | 1 << bit
+    })
+}
+
+/// Chinese, Japanese and Korean characters, which are written without
+/// spaces between words.
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF     // Hiragana and Katakana
+            | 0x3400..=0x4DBF   // CJK Extension A
+            | 0x4E00..=0x9FFF   // CJK Unified Ideographs
+            | 0xAC00..=0xD7AF   // Hangul syllables
+            | 0xF900..=0xFAFF   // CJK Compatibility Ideographs
+            | 0x20000..=0x2FFFF // CJK Extensions B and later
+    )
+}
+
+/// Lowercase `text` and strip its diacritics. Letters that do not
+/// decompose, like "ø" and "ß", are spelled out in plain Latin letters.
+pub fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        if is_cjk(c) {
+            folded.push(c);
+            continue;
+        }
+        decompose_compatible(c, |d| {
+            if is_combining_mark(d) {
+                return;
+            }
+            for lower in d.to_lowercase() {
+                match lower {
+                    'ø' => folded.push('o'),
+                    'æ' => folded.push_str("ae"),
+                    'œ' => folded.push_str("oe"),
+                    'ß' => folded.push_str("ss"),
+                    'đ' | 'ð' => folded.push('d'),
+                    'ł' => folded.push('l'),
+                    'þ' => folded.push_str("th"),
+                    'ı' => folded.push('i'),
+                    // Apostrophes join the parts of a word
+                    '\'' | '\u{2019}' => {}
+                    other => folded.push(other),
+                }
+            }
+        });
+    }
+    folded
+}
+
+/// The searchable words of `text`, folded.
+pub fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut cjk: Vec<char> = Vec::new();
+    let flush_cjk = |cjk: &mut Vec<char>, words: &mut Vec<String>| {
+        if cjk.len() == 1 {
+            words.push(cjk[0].to_string());
+        }
+        words.extend(cjk.windows(2).map(|pair| pair.iter().collect::<String>()));
+        cjk.clear();
+    };
+    for c in fold(text).chars() {
+        if is_cjk(c) {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            cjk.push(c);
+            continue;
+        }
+        flush_cjk(&mut cjk, &mut words);
+        if c.is_alphanumeric() {
+            word.push(c);
+        } else if !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+    }
+    flush_cjk(&mut cjk, &mut words);
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(songs: &[(&str, &str, &str, &str)]) -> SearchIndex {
+        let mut index = SearchIndex::default();
+        for (path, title, artist, album) in songs {
+            let fields = SearchFields {
+                title,
+                artist: Some(artist),
+                album_artist: None,
+                album: Some(album),
+            };
+            index.insert(path, fields);
+        }
+        index
+    }
+
+    fn paths(index: &SearchIndex, query: &str) -> Vec<String> {
+        index.search(query, 10).into_iter().map(|hit| hit.path).collect()
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(words("Björk – Jóga (Live)"), ["bjork", "joga", "live"]);
+        assert_eq!(words("Don't Stop Me Now"), ["dont", "stop", "me", "now"]);
+        assert_eq!(words("Sigur Rós: Ágætis byrjun"), ["sigur", "ros", "agaetis", "byrjun"]);
+        assert_eq!(words("Motörhead ØRESUND Straße"), ["motorhead", "oresund", "strasse"]);
+        assert_eq!(words("ＡＢＣ"), ["abc"]);
+        assert_eq!(words("東京タワー 2"), ["東京", "京タ", "タワ", "ワー", "2"]);
+        assert_eq!(words("花 BTS 방탄소년단"), ["花", "bts", "방탄", "탄소", "소년", "년단"]);
+    }
+
+    #[test]
+    fn test_typos() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(typos(&chars("beatles"), &chars("beatles"), 1), Some(0));
+        assert_eq!(typos(&chars("beatels"), &chars("beatles"), 1), Some(1));
+        assert_eq!(typos(&chars("betles"), &chars("beatles"), 1), Some(1));
+        assert_eq!(typos(&chars("queeen"), &chars("queen"), 1), Some(1));
+        assert_eq!(typos(&chars("radiohed"), &chars("radiohead"), 2), Some(1));
+        assert_eq!(typos(&chars("metalica"), &chars("megadeth"), 2), None);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut index = index(&[
+            ("/m/1.flac", "Jóga", "Björk", "Homogenic"),
+            ("/m/2.flac", "Hunter", "Björk", "Homogenic"),
+            ("/m/3.mp3", "Yesterday", "The Beatles", "Help!"),
+            ("/m/4.mp3", "Help!", "The Beatles", "Help!"),
+            ("/m/5.mp3", "Bohemian Rhapsody", "Queen", "A Night at the Opera"),
+            ("/m/6.mp3", "Queen of Hearts", "Fleetwood Mac", "Tusk"),
+            ("/m/7.mp3", "夜に駆ける", "YOASOBI", "THE BOOK"),
+        ]);
+        assert_eq!(index.len(), 7);
+
+        // Diacritics, prefixes and several words
+        assert_eq!(paths(&index, "bjork"), ["/m/2.flac", "/m/1.flac"]);
+        assert_eq!(paths(&index, "BJÖRK joga"), ["/m/1.flac"]);
+        assert_eq!(paths(&index, "bohem rhaps"), ["/m/5.mp3"]);
+        assert_eq!(paths(&index, "yester"), ["/m/3.mp3"]);
+
+        // Typos in whole words and in words being typed
+        assert_eq!(paths(&index, "beatels"), ["/m/4.mp3", "/m/3.mp3"]);
+        assert_eq!(paths(&index, "yestrday"), ["/m/3.mp3"]);
+        assert_eq!(paths(&index, "bohemain"), ["/m/5.mp3"]);
+        assert_eq!(paths(&index, "yestre"), ["/m/3.mp3"]);
+        assert!(paths(&index, "zzz").is_empty());
+        assert!(paths(&index, "bjork beatles").is_empty());
+
+        // Title matches rank above artist matches, exact above prefix
+        assert_eq!(paths(&index, "queen"), ["/m/6.mp3", "/m/5.mp3"]);
+        assert_eq!(paths(&index, "help"), ["/m/4.mp3", "/m/3.mp3"]);
+
+        // CJK titles match on any part
+        assert_eq!(paths(&index, "駆ける"), ["/m/7.mp3"]);
+        assert_eq!(paths(&index, "夜"), ["/m/7.mp3"]);
+        assert!(paths(&index, "駆夜").is_empty());
+
+        // Updates
+        index.rename("/m/1.flac", "/m/joga.flac");
+        assert_eq!(paths(&index, "joga"), ["/m/joga.flac"]);
+        index.remove("/m/2.flac");
+        assert_eq!(paths(&index, "hunter"), Vec::<String>::new());
+        assert_eq!(paths(&index, "bjork"), ["/m/joga.flac"]);
+        index.remove_under("/m");
+        assert!(index.is_empty());
+        assert!(index.words.is_empty());
+        assert_eq!(index.free.len(), index.docs.len());
+    }
+}
@@ -0,0 +1,636 @@
+//! Rule evaluation for smart playlists.
+//!
+//! The fields rules test are kept in memory for every song once a smart
+//! playlist is first evaluated, and updated with the songs the store
+//! reports changed. Watched playlists remember which songs match, so a
+//! change only checks the changed songs against them, unless a rule depends
+//! on the current time. Those rules are also checked again as time passes.
+
+use crate::api::library::LibrarySong;
+use crate::api::smart_playlists::{
+    SmartCondition, SmartField, SmartOperator, SmartPlaylist, SmartRule, SmartSortType,
+};
+use super::search::fold;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+/// Sends the songs of a watched playlist; false once nobody listens.
+pub type SmartSink = Box<dyn FnMut(Vec<LibrarySong>) -> bool + Send>;
+
+/// The fields of a song that rules test. Text is folded by
+/// [`fold`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SmartTrack {
+    pub path: String,
+    pub path_key: String,
+    pub title: String,
+    pub artist: Option<String>,
+    /// Names of the artists the song credits
+    pub artists: Vec<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub genre: Option<String>,
+    pub composer: Option<String>,
+    pub format: String,
+    pub year: Option<f64>,
+    pub track_number: Option<f64>,
+    pub disc_number: Option<f64>,
+    pub duration_secs: Option<f64>,
+    pub sample_rate: Option<f64>,
+    pub bit_depth: Option<f64>,
+    pub bitrate_kbps: Option<f64>,
+    pub channels: Option<f64>,
+    pub play_count: u32,
+    pub date_added: i64,
+    pub last_played: Option<i64>,
+    pub last_modified: i64,
+    pub favorite: bool,
+    pub compilation: bool,
+    pub has_lyrics: bool,
+}
+
+/// The value of a field of a song.
+enum Value<'a> {
+    /// Empty when the field is missing; artists can have several
+    Text(Vec<&'a str>),
+    Number(Option<f64>),
+    Date(Option<i64>),
+    Flag(bool),
+}
+
+impl SmartTrack {
+    fn value(&self, field: SmartField) -> Value<'_> {
+        match field {
+            SmartField::Title => Value::Text(vec![&self.title]),
+            SmartField::Artist => Value::Text(
+                self.artist.iter().chain(&self.artists).map(String::as_str).collect(),
+            ),
+            SmartField::Album => Value::Text(texts(&self.album)),
+            SmartField::AlbumArtist => Value::Text(texts(&self.album_artist)),
+            SmartField::Genre => Value::Text(texts(&self.genre)),
+            SmartField::Composer => Value::Text(texts(&self.composer)),
+            SmartField::Format => Value::Text(vec![&self.format]),
+            SmartField::Path => Value::Text(vec![&self.path_key]),
+            SmartField::Year => Value::Number(self.year),
+            SmartField::TrackNumber => Value::Number(self.track_number),
+            SmartField::DiscNumber => Value::Number(self.disc_number),
+            SmartField::DurationSecs => Value::Number(self.duration_secs),
+            SmartField::SampleRate => Value::Number(self.sample_rate),
+            SmartField::BitDepth => Value::Number(self.bit_depth),
+            SmartField::BitrateKbps => Value::Number(self.bitrate_kbps),
+            SmartField::Channels => Value::Number(self.channels),
+            SmartField::PlayCount => Value::Number(Some(f64::from(self.play_count))),
+            SmartField::DateAdded => Value::Date(Some(self.date_added)),
+            SmartField::LastPlayed => Value::Date(self.last_played),
+            SmartField::LastModified => Value::Date(Some(self.last_modified)),
+            SmartField::Favorite => Value::Flag(self.favorite),
+            SmartField::Compilation => Value::Flag(self.compilation),
+            SmartField::HasLyrics => Value::Flag(self.has_lyrics),
+        }
+    }
+}
+
+fn texts(value: &Option<String>) -> Vec<&str> {
+    value.iter().map(String::as_str).collect()
+}
+
+/// Check that every operator of `playlist` applies to its field.
+pub fn validate(playlist: &SmartPlaylist) -> Result<(), String> {
+    validate_rule(&playlist.rule)
+}
+
+fn validate_rule(rule: &SmartRule) -> Result<(), String> {
+    let track = SmartTrack::default();
+    for condition in &rule.conditions {
+        let field = condition.field;
+        let kind = track.value(field);
+        let applies = match condition.operator {
+            SmartOperator::Is | SmartOperator::IsNot => {
+                matches!(kind, Value::Text(_) | Value::Number(_))
+            }
+            SmartOperator::Contains
+            | SmartOperator::DoesNotContain
+            | SmartOperator::StartsWith
+            | SmartOperator::EndsWith => matches!(kind, Value::Text(_)),
+            SmartOperator::GreaterThan | SmartOperator::LessThan => {
+                matches!(kind, Value::Number(_))
+            }
+            SmartOperator::Before
+            | SmartOperator::After
+            | SmartOperator::InTheLast
+            | SmartOperator::NotInTheLast => matches!(kind, Value::Date(_)),
+            SmartOperator::IsTrue | SmartOperator::IsFalse => matches!(kind, Value::Flag(_)),
+            SmartOperator::IsEmpty | SmartOperator::IsNotEmpty => !matches!(kind, Value::Flag(_)),
+        };
+        if !applies {
+            return Err(format!("{:?} does not apply to {:?}", condition.operator, field));
+        }
+        if !condition.number.is_finite() {
+            return Err(format!("Invalid number for {:?}", field));
+        }
+    }
+    rule.groups.iter().try_for_each(validate_rule)
+}
+
+/// `rule` with the text of its conditions folded like the songs' text.
+fn folded(rule: &SmartRule) -> SmartRule {
+    SmartRule {
+        match_all: rule.match_all,
+        conditions: rule
+            .conditions
+            .iter()
+            .map(|condition| SmartCondition {
+                text: fold(&condition.text),
+                ..condition.clone()
+            })
+            .collect(),
+        groups: rule.groups.iter().map(folded).collect(),
+    }
+}
+
+/// Whether `track` matches `rule`, with its text [`folded`], at `now` in
+/// seconds since the epoch.
+fn matches(rule: &SmartRule, track: &SmartTrack, now: i64) -> bool {
+    let mut results = rule
+        .conditions
+        .iter()
+        .map(|condition| condition_matches(condition, track, now))
+        .chain(rule.groups.iter().map(|group| matches(group, track, now)));
+    if rule.match_all {
+        results.all(|matched| matched)
+    } else {
+        rule.conditions.is_empty() && rule.groups.is_empty() || results.any(|matched| matched)
+    }
+}
+
+fn condition_matches(condition: &SmartCondition, track: &SmartTrack, now: i64) -> bool {
+    use SmartOperator::*;
+    let number = condition.number;
+    match (track.value(condition.field), condition.operator) {
+        (Value::Text(values), operator) => {
+            let text = &condition.text;
+            let any = |test: &dyn Fn(&str) -> bool| values.iter().any(|value| test(value));
+            match operator {
+                Is => any(&|value| value == text),
+                IsNot => !any(&|value| value == text),
+                Contains => any(&|value| value.contains(text.as_str())),
+                DoesNotContain => !any(&|value| value.contains(text.as_str())),
+                StartsWith => any(&|value| value.starts_with(text.as_str())),
+                EndsWith => any(&|value| value.ends_with(text.as_str())),
+                IsEmpty => !any(&|value| !value.is_empty()),
+                IsNotEmpty => any(&|value| !value.is_empty()),
+                _ => false,
+            }
+        }
+        (Value::Number(value), operator) => match (value, operator) {
+            (Some(value), Is) => value == number,
+            (Some(value), IsNot) => value != number,
+            (Some(value), GreaterThan) => value > number,
+            (Some(value), LessThan) => value < number,
+            (None, IsNot) => true,
+            (value, IsEmpty) => value.is_none(),
+            (value, IsNotEmpty) => value.is_some(),
+            _ => false,
+        },
+        (Value::Date(value), operator) => {
+            let since = now - number as i64;
+            match (value, operator) {
+                (Some(value), Before) => (value as f64) < number,
+                (Some(value), After) => (value as f64) > number,
+                (Some(value), InTheLast) => value >= since,
+                (value, NotInTheLast) => value.is_none_or(|value| value < since),
+                (value, IsEmpty) => value.is_none(),
+                (value, IsNotEmpty) => value.is_some(),
+                _ => false,
+            }
+        }
+        (Value::Flag(value), IsTrue) => value,
+        (Value::Flag(value), IsFalse) => !value,
+        (Value::Flag(_), _) => false,
+    }
+}
+
+/// Whether `rule` tests how long ago something happened, so songs can
+/// start or stop matching as time passes.
+fn depends_on_time(rule: &SmartRule) -> bool {
+    rule.conditions.iter().any(|condition| {
+        matches!(condition.operator, SmartOperator::InTheLast | SmartOperator::NotInTheLast)
+    }) || rule.groups.iter().any(depends_on_time)
+}
+
+/// Order the songs `ids` of `tracks` for `playlist` and apply its limits.
+fn arrange(
+    playlist: &SmartPlaylist,
+    tracks: &HashMap<i64, SmartTrack>,
+    ids: impl IntoIterator<Item = i64>,
+) -> Vec<i64> {
+    let mut ids: Vec<(i64, &SmartTrack)> =
+        ids.into_iter().filter_map(|id| Some((id, tracks.get(&id)?))).collect();
+    ids.sort_by(|(a_id, a), (b_id, b)| {
+        compare(playlist.sort, playlist.descending, a, b)
+            .then_with(|| a.title.cmp(&b.title))
+            .then(a_id.cmp(b_id))
+    });
+    let mut total_secs = 0.0;
+    let mut arranged = Vec::new();
+    for (id, track) in ids {
+        if playlist.limit.is_some_and(|limit| arranged.len() >= limit as usize) {
+            break;
+        }
+        total_secs += track.duration_secs.unwrap_or(0.0);
+        if playlist.duration_limit_secs.is_some_and(|limit| total_secs > f64::from(limit)) {
+            break;
+        }
+        arranged.push(id);
+    }
+    arranged
+}
+
+/// Order of two songs by `sort`. Only the first key follows `descending`.
+fn compare(sort: SmartSortType, descending: bool, a: &SmartTrack, b: &SmartTrack) -> Ordering {
+    let album = |a: &SmartTrack, b: &SmartTrack| {
+        a.album
+            .cmp(&b.album)
+            .then(by_key(a.disc_number, b.disc_number, false))
+            .then(by_key(a.track_number, b.track_number, false))
+    };
+    match sort {
+        SmartSortType::Title => by_key(Some(&a.title), Some(&b.title), descending),
+        SmartSortType::Artist => by_key(a.artist.as_ref(), b.artist.as_ref(), descending)
+            .then_with(|| album(a, b)),
+        SmartSortType::Album => by_key(a.album.as_ref(), b.album.as_ref(), descending)
+            .then_with(|| album(a, b)),
+        SmartSortType::Year => by_key(a.year, b.year, descending).then_with(|| album(a, b)),
+        SmartSortType::Duration => by_key(a.duration_secs, b.duration_secs, descending),
+        SmartSortType::DateAdded => by_key(Some(a.date_added), Some(b.date_added), descending),
+        SmartSortType::PlayCount => by_key(Some(a.play_count), Some(b.play_count), descending),
+        SmartSortType::LastPlayed => by_key(a.last_played, b.last_played, descending),
+        SmartSortType::Random => by_key(Some(shuffle_key(a)), Some(shuffle_key(b)), descending),
+    }
+}
+
+/// Order of two sort keys, reversed when `descending`. Songs missing the
+/// key come last either way.
+fn by_key<T: PartialOrd>(a: Option<T>, b: Option<T>, descending: bool) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let order = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            if descending {
+                order.reverse()
+            } else {
+                order
+            }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// A stand-in for a random number that stays the same for a song.
+fn shuffle_key(track: &SmartTrack) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    track.path.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The songs of `tracks` matching `rule`, with its text [`folded`].
+fn matching(tracks: &HashMap<i64, SmartTrack>, rule: &SmartRule, now: i64) -> HashSet<i64> {
+    tracks
+        .iter()
+        .filter(|(_, track)| matches(rule, track, now))
+        .map(|(&id, _)| id)
+        .collect()
+}
+
+/// A playlist kept in step with the library.
+struct Watch {
+    /// The playlist, with its text [`folded`]
+    playlist: SmartPlaylist,
+    /// Songs matching the rule, before the limits
+    matching: HashSet<i64>,
+    /// Songs last sent
+    songs: Vec<i64>,
+    sink: SmartSink,
+}
+
+/// The songs rules are evaluated against, and the watched playlists.
+#[derive(Default)]
+pub struct SmartPlaylists {
+    /// Every song by id; `None` until a smart playlist is evaluated
+    tracks: Option<HashMap<i64, SmartTrack>>,
+    watches: HashMap<String, Watch>,
+}
+
+impl SmartPlaylists {
+    pub fn is_loaded(&self) -> bool {
+        self.tracks.is_some()
+    }
+
+    /// Start keeping `tracks` up to date.
+    pub fn load(&mut self, tracks: HashMap<i64, SmartTrack>) {
+        self.tracks = Some(tracks);
+    }
+
+    /// The songs of `playlist` at `now`, in its order. Empty until loaded.
+    pub fn select(&self, playlist: &SmartPlaylist, now: i64) -> Vec<i64> {
+        let Some(tracks) = &self.tracks else {
+            return Vec::new();
+        };
+        arrange(playlist, tracks, matching(tracks, &folded(&playlist.rule), now))
+    }
+
+    /// Watch `playlist` as `key`, replacing any watch of that key, and
+    /// return its songs now. Empty until loaded.
+    pub fn watch(
+        &mut self,
+        key: String,
+        mut playlist: SmartPlaylist,
+        sink: SmartSink,
+        now: i64,
+    ) -> Vec<i64> {
+        let Some(tracks) = &self.tracks else {
+            return Vec::new();
+        };
+        playlist.rule = folded(&playlist.rule);
+        let matching = matching(tracks, &playlist.rule, now);
+        let songs = arrange(&playlist, tracks, matching.iter().copied());
+        let watch = Watch {
+            playlist,
+            matching,
+            songs: songs.clone(),
+            sink,
+        };
+        self.watches.insert(key, watch);
+        songs
+    }
+
+    pub fn unwatch(&mut self, key: &str) {
+        self.watches.remove(key);
+    }
+
+    /// Update the songs in `changed`, `None` for removed ones, and return
+    /// the watches whose songs changed with their new songs.
+    ///
+    /// With no changes, only watches whose rules depend on the time are
+    /// checked again, for songs that `now` moved in or out of them.
+    pub fn apply(
+        &mut self,
+        changed: Vec<(i64, Option<SmartTrack>)>,
+        now: i64,
+    ) -> Vec<(String, Vec<i64>)> {
+        let Some(tracks) = &mut self.tracks else {
+            return Vec::new();
+        };
+        let ids: HashSet<i64> = changed.iter().map(|(id, _)| *id).collect();
+        for (id, track) in changed {
+            match track {
+                Some(track) => tracks.insert(id, track),
+                None => tracks.remove(&id),
+            };
+        }
+        let mut updates = Vec::new();
+        for (key, watch) in &mut self.watches {
+            let rule = &watch.playlist.rule;
+            if ids.is_empty() && !depends_on_time(rule) {
+                continue;
+            }
+            if depends_on_time(rule) {
+                watch.matching = matching(tracks, rule, now);
+            } else {
+                for id in &ids {
+                    match tracks.get(id) {
+                        Some(track) if matches(rule, track, now) => watch.matching.insert(*id),
+                        _ => watch.matching.remove(id),
+                    };
+                }
+            }
+            let songs = arrange(&watch.playlist, tracks, watch.matching.iter().copied());
+            // Songs still listed may have been retagged
+            if songs != watch.songs || songs.iter().any(|id| ids.contains(id)) {
+                watch.songs = songs.clone();
+                updates.push((key.clone(), songs));
+            }
+        }
+        updates
+    }
+
+    /// Send `songs` to the watch of `key`, ending it if nobody listens.
+    pub fn send(&mut self, key: &str, songs: Vec<LibrarySong>) {
+        let Some(watch) = self.watches.get_mut(key) else {
+            return;
+        };
+        if !(watch.sink)(songs) {
+            self.watches.remove(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::smart_playlists::{
+        smart_playlist_default, smart_playlist_from_json, smart_playlist_to_json,
+    };
+
+    const DAY: i64 = 24 * 60 * 60;
+    const NOW: i64 = 1_000 * DAY;
+
+    fn track(title: &str, artist: &str, genre: &str, format: &str, secs: f64) -> SmartTrack {
+        SmartTrack {
+            path: format!("/m/{}.{}", title, format),
+            path_key: fold(&format!("/m/{}.{}", title, format)),
+            title: fold(title),
+            artist: Some(fold(artist)),
+            artists: vec![fold(artist)],
+            genre: Some(fold(genre)),
+            format: format.to_string(),
+            duration_secs: Some(secs),
+            date_added: NOW - 100 * DAY,
+            ..Default::default()
+        }
+    }
+
+    fn condition(
+        field: SmartField,
+        operator: SmartOperator,
+        text: &str,
+        number: f64,
+    ) -> SmartCondition {
+        SmartCondition {
+            field,
+            operator,
+            text: text.to_string(),
+            number,
+        }
+    }
+
+    fn rule(match_all: bool, conditions: Vec<SmartCondition>) -> SmartRule {
+        SmartRule {
+            match_all,
+            conditions,
+            groups: Vec::new(),
+        }
+    }
+
+    fn library() -> HashMap<i64, SmartTrack> {
+        let mut tracks = vec![
+            track("Alpha", "Björk", "Pop", "flac", 200.0),
+            track("Bravo", "Queen", "Rock", "mp3", 360.0),
+            track("Charlie", "Queen", "Rock", "flac", 320.0),
+            track("Delta", "Air", "Electronic", "flac", 420.0),
+        ];
+        tracks[0].date_added = NOW - 5 * DAY;
+        tracks[1].play_count = 3;
+        tracks[1].last_played = Some(NOW - DAY);
+        tracks[2].favorite = true;
+        tracks[3].date_added = NOW - 10 * DAY;
+        tracks[3].artists.push(fold("Björk"));
+        tracks[3].genre = None;
+        (1..).zip(tracks).collect()
+    }
+
+    fn titles(tracks: &HashMap<i64, SmartTrack>, ids: &[i64]) -> Vec<String> {
+        ids.iter().map(|id| tracks[id].title.clone()).collect()
+    }
+
+    #[test]
+    fn test_rules() {
+        let mut smart = SmartPlaylists::default();
+        smart.load(library());
+        let select = |rule: SmartRule| {
+            let playlist = SmartPlaylist {
+                rule,
+                ..smart_playlist_default()
+            };
+            validate(&playlist).unwrap();
+            let tracks = smart.tracks.as_ref().unwrap();
+            titles(tracks, &smart.select(&playlist, NOW))
+        };
+        use SmartField::*;
+        use SmartOperator::*;
+
+        // FLAC added in the last 30 days
+        let recent_flac = rule(
+            true,
+            vec![
+                condition(Format, Is, "FLAC", 0.0),
+                condition(DateAdded, InTheLast, "", 30.0 * DAY as f64),
+            ],
+        );
+        assert_eq!(select(recent_flac), ["alpha", "delta"]);
+
+        // Never played rock over five minutes
+        let long_rock = rule(
+            true,
+            vec![
+                condition(PlayCount, Is, "", 0.0),
+                condition(Genre, Is, "rock", 0.0),
+                condition(DurationSecs, GreaterThan, "", 300.0),
+            ],
+        );
+        assert_eq!(select(long_rock), ["charlie"]);
+        let week = 7.0 * DAY as f64;
+        let not_lately = rule(true, vec![condition(LastPlayed, NotInTheLast, "", week)]);
+        assert_eq!(select(not_lately), ["alpha", "charlie", "delta"]);
+
+        // Artists match their credits, ignoring case and accents
+        let bjork = rule(true, vec![condition(Artist, Is, "BJORK", 0.0)]);
+        assert_eq!(select(bjork), ["alpha", "delta"]);
+        let favorites = SmartRule {
+            match_all: true,
+            conditions: vec![condition(Favorite, IsTrue, "", 0.0)],
+            groups: vec![rule(
+                false,
+                vec![condition(Artist, StartsWith, "que", 0.0), condition(Title, Is, "x", 0.0)],
+            )],
+        };
+        assert_eq!(select(favorites.clone()), ["charlie"]);
+
+        // Missing values only match negative operators
+        assert_eq!(select(rule(true, vec![condition(Genre, IsNot, "pop", 0.0)])).len(), 3);
+        assert_eq!(select(rule(true, vec![condition(Genre, Contains, "o", 0.0)])).len(), 3);
+        assert_eq!(select(rule(true, vec![condition(Genre, IsEmpty, "", 0.0)])), ["delta"]);
+        assert_eq!(select(rule(false, Vec::new())).len(), 4);
+
+        let invalid = SmartPlaylist {
+            rule: rule(true, vec![condition(Title, GreaterThan, "", 1.0)]),
+            ..smart_playlist_default()
+        };
+        assert!(validate(&invalid).is_err());
+
+        // Playlists are stored as JSON
+        let playlist = SmartPlaylist {
+            rule: favorites,
+            limit: Some(25),
+            ..smart_playlist_default()
+        };
+        let json = smart_playlist_to_json(playlist.clone());
+        assert!(json.contains("\"starts_with\""));
+        assert_eq!(smart_playlist_from_json(json), Ok(playlist));
+        assert!(smart_playlist_from_json(smart_playlist_to_json(invalid)).is_err());
+        assert!(smart_playlist_from_json("{}".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_sort_limits_and_updates() {
+        let mut smart = SmartPlaylists::default();
+        smart.load(library());
+        let mut playlist = SmartPlaylist {
+            sort: SmartSortType::Duration,
+            descending: true,
+            limit: Some(3),
+            ..smart_playlist_default()
+        };
+        let tracks = smart.tracks.clone().unwrap();
+        let select = |smart: &SmartPlaylists, playlist: &SmartPlaylist| {
+            titles(&tracks, &smart.select(playlist, NOW))
+        };
+        assert_eq!(select(&smart, &playlist), ["delta", "bravo", "charlie"]);
+        playlist.duration_limit_secs = Some(900);
+        assert_eq!(select(&smart, &playlist), ["delta", "bravo"]);
+        playlist.sort = SmartSortType::Random;
+        playlist.limit = None;
+        playlist.duration_limit_secs = None;
+        let shuffled = smart.select(&playlist, NOW);
+        assert_eq!(shuffled.len(), 4);
+        assert_eq!(smart.select(&playlist, NOW), shuffled);
+
+        // Only changed songs are checked again
+        let is_rock = condition(SmartField::Genre, SmartOperator::Is, "Rock", 0.0);
+        let rock = SmartPlaylist {
+            rule: rule(true, vec![is_rock]),
+            ..smart_playlist_default()
+        };
+        let ids = smart.watch("rock".to_string(), rock, Box::new(|_| true), NOW);
+        assert_eq!(ids, [2, 3]);
+        let mut retagged = tracks[&1].clone();
+        retagged.genre = Some("rock".to_string());
+        let updates = smart.apply(vec![(1, Some(retagged)), (3, None)], NOW);
+        assert_eq!(updates, [("rock".to_string(), vec![1, 2])]);
+        assert!(smart.apply(vec![(4, Some(tracks[&4].clone()))], NOW).is_empty());
+
+        // Rules on the time follow the clock without any changes
+        let week = 7.0 * DAY as f64;
+        let added = condition(SmartField::DateAdded, SmartOperator::InTheLast, "", week);
+        let recent = SmartPlaylist {
+            rule: rule(true, vec![added]),
+            ..smart_playlist_default()
+        };
+        let ids = smart.watch("recent".to_string(), recent, Box::new(|_| true), NOW);
+        assert_eq!(ids, [1]);
+        assert!(smart.apply(Vec::new(), NOW + DAY).is_empty());
+        assert_eq!(smart.apply(Vec::new(), NOW + 3 * DAY), [("recent".to_string(), vec![])]);
+        smart.unwatch("recent");
+
+        // Watches end once nobody listens
+        smart.send("rock", Vec::new());
+        smart.watch("gone".to_string(), smart_playlist_default(), Box::new(|_| false), NOW);
+        smart.send("gone", Vec::new());
+        assert_eq!(smart.watches.len(), 1);
+        smart.unwatch("rock");
+        assert!(smart.watches.is_empty());
+    }
+}
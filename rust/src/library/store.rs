@@ -0,0 +1,1802 @@
+//! The persistent music library, kept in SQLite.
+//!
+//! Scans and the folder watch write their results straight into the store,
+//! so the known files for an incremental scan come from here rather than
+//! from Dart. Songs are indexed for full-text search in an FTS5 table kept
+//! in step by triggers; searches match every word as a prefix, ignoring
+//! case and diacritics. The store also keeps a [`SearchIndex`] in memory for
+//! ranked, typo tolerant search.
+//!
+//! Albums and artists are grouped by the keys [`grouping`](super::grouping)
+//! gives each song, stored in its row and in `song_artists`, and recomputed
+//! when the grouping options change.
+//!
+//! The store also keeps where playback of long files was left off, which the
+//! audio engine saves as they play, and how often and when each song was
+//! played. The engine queues those writes with [`queue_shared_write`] so it
+//! never waits for a scan holding the library. Smart playlists are evaluated
+//! against [`SmartPlaylists`], which temporary triggers keep told of every
+//! song written, and which the writer thread also refreshes every minute
+//! for rules on the time.
+
+use crate::api::library::{
+    library_default_grouping_options, AlbumPage, GroupPage, GroupQuery, GroupSortType,
+    GroupingOptions, LibraryAlbum, LibraryFolder, LibraryGroup, LibrarySong, SongPage, SongQuery,
+    SongSortType,
+};
+use crate::api::scanner::{
+    AlbumPalette, AudioFileMetadata, ScanEventType, ScanMove, ScanStreamEvent, TrackRange,
+    WatchEvent,
+};
+use super::grouping::{name_key, sort_name, GroupTags, SongGroup};
+use super::playlists::LibraryTrack;
+use super::search::{fold, SearchFields, SearchIndex};
+use super::smart_playlists::{SmartPlaylists, SmartTrack};
+use crate::api::smart_playlists::SmartPlaylist;
+use parking_lot::Mutex;
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Row, Transaction};
+use std::collections::HashMap;
+use std::path::{Path, MAIN_SEPARATOR};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Version of the schema created by [`migrate`]
+const SCHEMA_VERSION: i32 = 6;
+
+const SCHEMA: &str = "
+    CREATE TABLE folders (
+        path TEXT PRIMARY KEY NOT NULL,
+        added_at INTEGER NOT NULL,
+        scanned_at INTEGER
+    );
+
+    CREATE TABLE songs (
+        id INTEGER PRIMARY KEY,
+        path TEXT NOT NULL UNIQUE,
+        folder TEXT NOT NULL,
+        display_title TEXT NOT NULL,
+        title TEXT,
+        artist TEXT,
+        album TEXT,
+        album_artist TEXT,
+        track_number INTEGER,
+        track_total INTEGER,
+        disc_number INTEGER,
+        disc_total INTEGER,
+        year INTEGER,
+        genre TEXT,
+        composer TEXT,
+        compilation INTEGER NOT NULL,
+        duration_secs INTEGER,
+        sample_rate INTEGER,
+        bit_depth INTEGER,
+        bitrate_kbps INTEGER,
+        channels INTEGER,
+        has_lyrics INTEGER NOT NULL,
+        musicbrainz_recording_id TEXT,
+        musicbrainz_release_id TEXT,
+        musicbrainz_release_group_id TEXT,
+        musicbrainz_artist_id TEXT,
+        musicbrainz_release_artist_id TEXT,
+        album_art_path TEXT,
+        palette_dominant INTEGER,
+        palette_vibrant INTEGER,
+        palette_muted INTEGER,
+        palette_text INTEGER,
+        palette_secondary_text INTEGER,
+        format TEXT NOT NULL,
+        last_modified INTEGER NOT NULL,
+        date_added INTEGER NOT NULL
+    );
+    CREATE INDEX songs_folder ON songs(folder);
+    CREATE INDEX songs_artist ON songs(artist COLLATE NOCASE);
+    CREATE INDEX songs_album ON songs(album COLLATE NOCASE);
+    CREATE INDEX songs_genre ON songs(genre COLLATE NOCASE);
+
+    CREATE VIRTUAL TABLE songs_fts USING fts5(
+        display_title, artist, album, album_artist, genre, composer,
+        content = 'songs', content_rowid = 'id',
+        tokenize = 'unicode61 remove_diacritics 2'
+    );
+    CREATE TRIGGER songs_fts_insert AFTER INSERT ON songs BEGIN
+        INSERT INTO songs_fts(rowid, display_title, artist, album, album_artist, genre, composer)
+        VALUES (new.id, new.display_title, new.artist, new.album, new.album_artist, new.genre,
+                new.composer);
+    END;
+    CREATE TRIGGER songs_fts_delete AFTER DELETE ON songs BEGIN
+        INSERT INTO songs_fts(songs_fts, rowid, display_title, artist, album, album_artist, genre,
+                              composer)
+        VALUES ('delete', old.id, old.display_title, old.artist, old.album, old.album_artist,
+                old.genre, old.composer);
+    END;
+";
+
+/// Grouping keys, added in version 2 and filled in by [`regroup`]
+const GROUPING_SCHEMA: &str = "
+    ALTER TABLE songs ADD COLUMN album_key TEXT;
+    ALTER TABLE songs ADD COLUMN album_folder_key TEXT;
+    ALTER TABLE songs ADD COLUMN album_sort TEXT;
+    ALTER TABLE songs ADD COLUMN album_artist_name TEXT;
+    ALTER TABLE songs ADD COLUMN album_artist_key TEXT;
+    ALTER TABLE songs ADD COLUMN album_artist_sort TEXT;
+    ALTER TABLE songs ADD COLUMN album_compilation INTEGER NOT NULL DEFAULT 0;
+    CREATE INDEX songs_album_key ON songs(album_key);
+    CREATE INDEX songs_album_folder_key ON songs(album_folder_key);
+
+    CREATE TABLE song_artists (
+        song_id INTEGER NOT NULL,
+        position INTEGER NOT NULL,
+        key TEXT NOT NULL,
+        name TEXT NOT NULL,
+        sort_name TEXT NOT NULL,
+        PRIMARY KEY (song_id, position)
+    ) WITHOUT ROWID;
+    CREATE INDEX song_artists_key ON song_artists(key);
+    CREATE TRIGGER song_artists_delete AFTER DELETE ON songs BEGIN
+        DELETE FROM song_artists WHERE song_id = old.id;
+    END;
+
+    CREATE TABLE settings (
+        name TEXT PRIMARY KEY NOT NULL,
+        value TEXT NOT NULL
+    );
+";
+
+/// Where tracks of CUE sheets lie in their files, added in version 3
+const CUE_SCHEMA: &str = "
+    ALTER TABLE songs ADD COLUMN source_path TEXT;
+    ALTER TABLE songs ADD COLUMN start_secs REAL;
+    ALTER TABLE songs ADD COLUMN end_secs REAL;
+    CREATE INDEX songs_source_path ON songs(source_path);
+";
+
+/// Where playback of long files was left off, added in version 4
+const RESUME_SCHEMA: &str = "
+    CREATE TABLE resume_positions (
+        path TEXT PRIMARY KEY NOT NULL,
+        position_secs REAL NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+";
+
+/// Play statistics, added in version 5
+const PLAYS_SCHEMA: &str = "
+    ALTER TABLE songs ADD COLUMN play_count INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE songs ADD COLUMN last_played INTEGER;
+    ALTER TABLE songs ADD COLUMN favorite INTEGER NOT NULL DEFAULT 0;
+";
+
+/// Keeps the full-text index in step with edits to the indexed columns only,
+/// so play counts and regrouping leave it alone; narrowed in version 6
+const FTS_UPDATE_SCHEMA: &str = "
+    DROP TRIGGER IF EXISTS songs_fts_update;
+    CREATE TRIGGER songs_fts_update
+    AFTER UPDATE OF display_title, artist, album, album_artist, genre, composer ON songs BEGIN
+        INSERT INTO songs_fts(songs_fts, rowid, display_title, artist, album, album_artist, genre,
+                              composer)
+        VALUES ('delete', old.id, old.display_title, old.artist, old.album, old.album_artist,
+                old.genre, old.composer);
+        INSERT INTO songs_fts(rowid, display_title, artist, album, album_artist, genre, composer)
+        VALUES (new.id, new.display_title, new.artist, new.album, new.album_artist, new.genre,
+                new.composer);
+    END;
+";
+
+/// Songs written since smart playlists were last updated, recorded by
+/// triggers that only live as long as the connection
+const CHANGES_SCHEMA: &str = "
+    CREATE TEMP TABLE changed_songs (id INTEGER PRIMARY KEY);
+    CREATE TEMP TRIGGER changed_songs_insert AFTER INSERT ON main.songs BEGIN
+        INSERT OR IGNORE INTO changed_songs (id) VALUES (new.id);
+    END;
+    CREATE TEMP TRIGGER changed_songs_update AFTER UPDATE ON main.songs BEGIN
+        INSERT OR IGNORE INTO changed_songs (id) VALUES (new.id);
+    END;
+    CREATE TEMP TRIGGER changed_songs_delete AFTER DELETE ON main.songs BEGIN
+        INSERT OR IGNORE INTO changed_songs (id) VALUES (old.id);
+    END;
+";
+
+/// Columns read into a [`LibrarySong`] by [`song_from_row`]
+const SONG_COLUMNS: &str = "id, folder, display_title, date_added, path, title, artist, album, \
+    album_artist, track_number, track_total, disc_number, disc_total, year, genre, composer, \
+    compilation, duration_secs, sample_rate, bit_depth, bitrate_kbps, channels, has_lyrics, \
+    musicbrainz_recording_id, musicbrainz_release_id, musicbrainz_release_group_id, \
+    musicbrainz_artist_id, musicbrainz_release_artist_id, album_art_path, palette_dominant, \
+    palette_vibrant, palette_muted, palette_text, palette_secondary_text, format, last_modified, \
+    album_key, (SELECT group_concat(key, char(31) ORDER BY position) FROM song_artists \
+    WHERE song_id = songs.id), source_path, start_secs, end_secs, play_count, last_played, \
+    favorite";
+
+/// Columns read into a [`SmartTrack`] by [`smart_track_from_row`]
+const SMART_COLUMNS: &str = "id, path, display_title, artist, (SELECT group_concat(name, \
+    char(31)) FROM song_artists WHERE song_id = songs.id), album, album_artist, genre, composer, \
+    format, year, track_number, disc_number, duration_secs, sample_rate, bit_depth, \
+    bitrate_kbps, channels, play_count, date_added, last_played, last_modified, favorite, \
+    compilation, has_lyrics";
+
+const UPSERT_SONG: &str = "
+    INSERT INTO songs (
+        path, folder, display_title, title, artist, album, album_artist, track_number,
+        track_total, disc_number, disc_total, year, genre, composer, compilation, duration_secs,
+        sample_rate, bit_depth, bitrate_kbps, channels, has_lyrics, musicbrainz_recording_id,
+        musicbrainz_release_id, musicbrainz_release_group_id, musicbrainz_artist_id,
+        musicbrainz_release_artist_id, album_art_path, palette_dominant, palette_vibrant,
+        palette_muted, palette_text, palette_secondary_text, format, last_modified, date_added,
+        source_path, start_secs, end_secs
+    ) VALUES (
+        ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19,
+        ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32, ?33, ?34, ?35, ?36, ?37,
+        ?38
+    )
+    ON CONFLICT(path) DO UPDATE SET
+        folder = excluded.folder, display_title = excluded.display_title,
+        title = excluded.title, artist = excluded.artist, album = excluded.album,
+        album_artist = excluded.album_artist, track_number = excluded.track_number,
+        track_total = excluded.track_total, disc_number = excluded.disc_number,
+        disc_total = excluded.disc_total, year = excluded.year, genre = excluded.genre,
+        composer = excluded.composer, compilation = excluded.compilation,
+        duration_secs = excluded.duration_secs, sample_rate = excluded.sample_rate,
+        bit_depth = excluded.bit_depth, bitrate_kbps = excluded.bitrate_kbps,
+        channels = excluded.channels, has_lyrics = excluded.has_lyrics,
+        musicbrainz_recording_id = excluded.musicbrainz_recording_id,
+        musicbrainz_release_id = excluded.musicbrainz_release_id,
+        musicbrainz_release_group_id = excluded.musicbrainz_release_group_id,
+        musicbrainz_artist_id = excluded.musicbrainz_artist_id,
+        musicbrainz_release_artist_id = excluded.musicbrainz_release_artist_id,
+        album_art_path = excluded.album_art_path, palette_dominant = excluded.palette_dominant,
+        palette_vibrant = excluded.palette_vibrant, palette_muted = excluded.palette_muted,
+        palette_text = excluded.palette_text,
+        palette_secondary_text = excluded.palette_secondary_text, format = excluded.format,
+        last_modified = excluded.last_modified, source_path = excluded.source_path,
+        start_secs = excluded.start_secs, end_secs = excluded.end_secs
+";
+
+/// The library opened by `library_open`, shared by the API calls
+static SHARED: Mutex<Option<LibraryStore>> = parking_lot::const_mutex(None);
+
+/// Open the store at `path` as the shared library, replacing any other.
+pub fn open_shared(path: &Path) -> Result<(), String> {
+    let store = LibraryStore::open(path).map_err(|e| e.to_string())?;
+    *SHARED.lock() = Some(store);
+    Ok(())
+}
+
+/// Run `f` on the shared library.
+pub fn with_shared<T>(
+    f: impl FnOnce(&mut LibraryStore) -> rusqlite::Result<T>,
+) -> Result<T, String> {
+    let mut shared = SHARED.lock();
+    let store = shared
+        .as_mut()
+        .ok_or_else(|| "The library has not been opened".to_string())?;
+    f(store).map_err(|e| e.to_string())
+}
+
+/// Work for the writer thread
+type QueuedWrite = Box<dyn FnOnce() + Send>;
+
+/// Writes from callers that must not wait for the shared library, like the
+/// audio engine, done in order on a thread of their own
+static WRITER: Mutex<Option<mpsc::Sender<QueuedWrite>>> = parking_lot::const_mutex(None);
+
+/// How often the writer thread checks smart playlists whose rules depend on
+/// the time again
+const SMART_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Run `f` on the shared library from the writer thread, without waiting.
+///
+/// Errors are dropped, including when no library is open.
+pub fn queue_shared_write(
+    f: impl FnOnce(&mut LibraryStore) -> rusqlite::Result<()> + Send + 'static,
+) {
+    queue_write(Box::new(move || {
+        let _ = with_shared(f);
+    }));
+}
+
+/// Wait until the writes queued so far are done.
+pub fn flush_shared_writes() {
+    let (done_tx, done_rx) = mpsc::channel();
+    queue_write(Box::new(move || {
+        let _ = done_tx.send(());
+    }));
+    let _ = done_rx.recv();
+}
+
+/// Start the writer thread if it is not running, so watched smart playlists
+/// follow the clock.
+pub fn start_shared_writer() {
+    start_writer(&mut WRITER.lock());
+}
+
+fn queue_write(write: QueuedWrite) {
+    let mut writer = WRITER.lock();
+    match start_writer(&mut writer) {
+        Some(tx) => {
+            let _ = tx.send(write);
+        }
+        // Without a thread, write here rather than not at all
+        None => {
+            drop(writer);
+            write();
+        }
+    }
+}
+
+/// The queue of the writer thread, spawning the thread if needed; `None` if
+/// it cannot be spawned.
+fn start_writer(
+    writer: &mut Option<mpsc::Sender<QueuedWrite>>,
+) -> Option<&mpsc::Sender<QueuedWrite>> {
+    if writer.is_none() {
+        let (tx, rx) = mpsc::channel::<QueuedWrite>();
+        let spawned = thread::Builder::new()
+            .name("library-writer".to_string())
+            .spawn(move || run_writer(rx));
+        if spawned.is_ok() {
+            *writer = Some(tx);
+        }
+    }
+    writer.as_ref()
+}
+
+/// Do queued writes in order, and refresh smart playlists between them.
+fn run_writer(rx: mpsc::Receiver<QueuedWrite>) {
+    let mut refreshed = Instant::now();
+    loop {
+        match rx.recv_timeout(SMART_REFRESH_INTERVAL.saturating_sub(refreshed.elapsed())) {
+            Ok(write) => write(),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+        if refreshed.elapsed() >= SMART_REFRESH_INTERVAL {
+            let _ = with_shared(|store| store.refresh_smart_playlists());
+            refreshed = Instant::now();
+        }
+    }
+}
+
+/// A library database.
+pub struct LibraryStore {
+    conn: Connection,
+    search: SearchIndex,
+    grouping: GroupingOptions,
+    smart: SmartPlaylists,
+}
+
+impl LibraryStore {
+    /// Open the database at `path`, creating or upgrading its schema.
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let mut conn = Connection::open(path)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        migrate(&mut conn)?;
+        conn.execute_batch(CHANGES_SCHEMA)?;
+        let search = search_index(&conn)?;
+        let grouping = grouping_options(&conn)?;
+        Ok(Self {
+            conn,
+            search,
+            grouping,
+            smart: SmartPlaylists::default(),
+        })
+    }
+
+    pub fn grouping_options(&self) -> &GroupingOptions {
+        &self.grouping
+    }
+
+    /// Store new grouping options and regroup every song by them.
+    pub fn set_grouping_options(&mut self, options: GroupingOptions) -> rusqlite::Result<()> {
+        if options == self.grouping {
+            return Ok(());
+        }
+        let tx = self.conn.transaction()?;
+        let settings = [
+            ("artist_separators", options.artist_separators.join("\n")),
+            ("articles", options.articles.join("\n")),
+            ("various_artists", options.various_artists.clone()),
+        ];
+        for (name, value) in settings {
+            tx.execute(
+                "INSERT OR REPLACE INTO settings (name, value) VALUES (?1, ?2)",
+                params![name, value],
+            )?;
+        }
+        regroup(&tx, &options, "1", [])?;
+        tx.commit()?;
+        self.grouping = options;
+        self.update_smart_playlists()
+    }
+
+    /// Register a music folder; already registered folders are unchanged.
+    pub fn add_folder(&self, path: &str) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT OR IGNORE INTO folders (path, added_at) VALUES (?1, ?2)",
+            params![path, now()],
+        )?;
+        Ok(())
+    }
+
+    /// Forget a music folder and its songs.
+    pub fn remove_folder(&mut self, path: &str) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        let removed: Vec<String> = tx
+            .prepare("SELECT path FROM songs WHERE folder = ?1")?
+            .query_map([path], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        tx.execute("DELETE FROM songs WHERE folder = ?1", [path])?;
+        tx.execute("DELETE FROM folders WHERE path = ?1", [path])?;
+        tx.commit()?;
+        for path in removed {
+            self.search.remove(&path);
+        }
+        self.update_smart_playlists()
+    }
+
+    pub fn folders(&self) -> rusqlite::Result<Vec<LibraryFolder>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT f.path, f.added_at, f.scanned_at,
+                    (SELECT COUNT(*) FROM songs s WHERE s.folder = f.path)
+             FROM folders f ORDER BY f.path",
+        )?;
+        let folders = stmt.query_map([], |row| {
+            Ok(LibraryFolder {
+                path: row.get(0)?,
+                added_at: row.get(1)?,
+                scanned_at: row.get(2)?,
+                song_count: row.get(3)?,
+            })
+        })?;
+        folders.collect()
+    }
+
+    /// Modification times of the songs at or below `root`, for an
+    /// incremental scan. Songs whose cached cover has been removed are left
+    /// out, so the scan reads them again.
+    pub fn known_files(&self, root: &str) -> rusqlite::Result<HashMap<String, i64>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT path, last_modified, album_art_path FROM songs
+             WHERE folder = ?1 OR substr(path, 1, length(?2)) = ?2",
+        )?;
+        let rows = stmt.query_map(params![root, under(root)], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, Option<String>>(2)?,
+            ))
+        })?;
+        let mut known = HashMap::new();
+        for row in rows {
+            let (path, modified, art) = row?;
+            if art.is_none_or(|art| Path::new(&art).exists()) {
+                known.insert(path, modified);
+            }
+        }
+        Ok(known)
+    }
+
+    /// Write the results of a scan of `root` into the library. Deletions
+    /// and moves arrive with the `Finished` event, which also records the
+    /// time of the scan.
+    pub fn apply_scan(&mut self, root: &str, event: &ScanStreamEvent) -> rusqlite::Result<()> {
+        let now = now();
+        let tx = self.conn.transaction()?;
+        if event.kind == ScanEventType::Finished {
+            move_songs(&tx, &self.grouping, &event.moved, |_| root)?;
+            for path in &event.deleted_paths {
+                tx.execute("DELETE FROM songs WHERE path = ?1", [path])?;
+            }
+            tx.execute(
+                "INSERT INTO folders (path, added_at, scanned_at) VALUES (?1, ?2, ?2)
+                 ON CONFLICT(path) DO UPDATE SET scanned_at = excluded.scanned_at",
+                params![root, now],
+            )?;
+        }
+        for metadata in &event.files {
+            upsert_song(&tx, &self.grouping, root, metadata, now)?;
+        }
+        tx.commit()?;
+        self.search.apply_scan(event);
+        self.update_smart_playlists()
+    }
+
+    /// Write changes picked up by the folder watch into the library.
+    pub fn apply_watch(&mut self, event: &WatchEvent) -> rusqlite::Result<()> {
+        let roots: Vec<String> = self.folders()?.into_iter().map(|f| f.path).collect();
+        let root_of = |path: &str| -> String {
+            roots
+                .iter()
+                .filter(|root| Path::new(path).starts_with(root))
+                .max_by_key(|root| root.len())
+                .cloned()
+                .unwrap_or_else(|| parent_dir(path))
+        };
+        let now = now();
+        let tx = self.conn.transaction()?;
+        move_songs(&tx, &self.grouping, &event.moved, root_of)?;
+        // Removed before the upserts, as a re-read file with a CUE sheet
+        // replaces its old songs with the tracks read
+        for path in &event.removed_paths {
+            tx.execute(
+                "DELETE FROM songs WHERE path = ?1 OR source_path = ?1
+                                      OR substr(path, 1, length(?2)) = ?2",
+                params![path, under(path)],
+            )?;
+        }
+        for metadata in event.added.iter().chain(&event.modified) {
+            upsert_song(&tx, &self.grouping, &root_of(&metadata.path), metadata, now)?;
+        }
+        tx.commit()?;
+        self.search.apply_watch(event);
+        self.update_smart_playlists()
+    }
+
+    /// The best `limit` songs for `query` from the search index, best
+    /// first.
+    pub fn search(&self, query: &str, limit: usize) -> rusqlite::Result<Vec<LibrarySong>> {
+        let mut songs = Vec::new();
+        for hit in self.search.search(query, limit) {
+            songs.extend(self.song_by_path(&hit.path)?);
+        }
+        Ok(songs)
+    }
+
+    pub fn song_by_path(&self, path: &str) -> rusqlite::Result<Option<LibrarySong>> {
+        self.conn
+            .query_row(
+                &format!("SELECT {} FROM songs WHERE path = ?1", SONG_COLUMNS),
+                [path],
+                song_from_row,
+            )
+            .optional()
+    }
+
+    /// A page of songs matching `query`.
+    pub fn songs(&self, query: &SongQuery) -> rusqlite::Result<SongPage> {
+        let mut filter = Filter::default();
+        filter.search(query.search.as_deref(), &[]);
+        filter.equal("folder", query.folder.as_deref());
+        if let Some(artist) = &query.artist {
+            filter.push(
+                "id IN (SELECT song_id FROM song_artists WHERE key = ?)",
+                [name_key(artist, &self.grouping)],
+            );
+        }
+        filter.equal_nocase("album", query.album.as_deref());
+        filter.equal("album_key", query.album_key.as_deref());
+        filter.equal_nocase("genre", query.genre.as_deref());
+
+        let direction = if query.descending { "DESC" } else { "ASC" };
+        let order = match query.sort {
+            SongSortType::Title => format!("display_title COLLATE NOCASE {}", direction),
+            SongSortType::Artist => format!(
+                "artist COLLATE NOCASE {}, album COLLATE NOCASE, disc_number, track_number",
+                direction
+            ),
+            SongSortType::Album => format!(
+                "album_sort {}, album_key, disc_number, track_number",
+                direction
+            ),
+            SongSortType::Year => format!(
+                "year {}, album COLLATE NOCASE, disc_number, track_number",
+                direction
+            ),
+            SongSortType::Duration => format!("duration_secs {}", direction),
+            SongSortType::DateAdded => format!("date_added {}", direction),
+            SongSortType::Path => format!("path {}", direction),
+        };
+        let sql = format!(
+            "SELECT {} FROM songs {} ORDER BY {}, id LIMIT ? OFFSET ?",
+            SONG_COLUMNS,
+            filter.where_clause(),
+            order
+        );
+        let songs = self.page(&sql, &filter, query.offset, query.limit, song_from_row)?;
+        let count = format!("SELECT COUNT(*) FROM songs {}", filter.where_clause());
+        let total = self.count(&count, &filter)?;
+        Ok(SongPage { songs, total })
+    }
+
+    /// A page of albums, grouped by their album keys.
+    pub fn albums(&self, query: &GroupQuery) -> rusqlite::Result<AlbumPage> {
+        let mut filter = Filter::default();
+        filter.push("album_key IS NOT NULL", []);
+        filter.search(query.search.as_deref(), &["album", "album_artist"]);
+        filter.equal("folder", query.folder.as_deref());
+        // Sort keys hold only letters, digits and spaces
+        let various_sort = sort_name(&self.grouping.various_artists, &self.grouping);
+        let grouped = format!(
+            "SELECT album_key AS key, MIN(album) AS name, MIN(album_sort) AS sort_name,
+                    MAX(album_compilation) = 1
+                        OR (MIN(album_key = album_folder_key)
+                            AND COUNT(DISTINCT album_artist_key) > 1) AS compilation,
+                    MIN(album_artist_name) AS owner, MIN(album_artist_sort) AS owner_sort,
+                    MAX(year) AS year, COUNT(*) AS song_count,
+                    MAX(COALESCE(disc_number, 1)) AS disc_count,
+                    COALESCE(SUM(duration_secs), 0) AS duration,
+                    MIN(album_art_path) AS art, MAX(date_added) AS added
+             FROM songs {}
+             GROUP BY album_key",
+            filter.where_clause()
+        );
+        let sql = format!(
+            "SELECT key, name, compilation, owner, year, song_count, disc_count, duration, art,
+                    IIF(compilation, '{}', owner_sort) AS owner_sort
+             FROM ({}) ORDER BY {} LIMIT ? OFFSET ?",
+            various_sort,
+            grouped,
+            group_order(query, &["sort_name", "owner_sort", "key"])
+        );
+        let albums = self.page(&sql, &filter, query.offset, query.limit, |row| {
+            let compilation: bool = row.get(2)?;
+            Ok(LibraryAlbum {
+                key: row.get(0)?,
+                name: row.get(1)?,
+                artist: if compilation {
+                    Some(self.grouping.various_artists.clone())
+                } else {
+                    row.get(3)?
+                },
+                compilation,
+                year: row.get(4)?,
+                song_count: row.get(5)?,
+                disc_count: row.get(6)?,
+                duration_secs: row.get(7)?,
+                album_art_path: row.get(8)?,
+            })
+        })?;
+        let total = self.count(&format!("SELECT COUNT(*) FROM ({})", grouped), &filter)?;
+        Ok(AlbumPage { albums, total })
+    }
+
+    /// A page of the artists songs credit, split and grouped by their
+    /// keys. Searches match the start of words of the artist's name.
+    pub fn artists(&self, query: &GroupQuery) -> rusqlite::Result<GroupPage> {
+        let mut filter = Filter::default();
+        let search = query.search.as_deref().map(|s| name_key(s, &self.grouping));
+        for word in search.iter().flat_map(|s| s.split_whitespace()) {
+            filter.push("' ' || a.key LIKE ?", [format!("% {}%", word)]);
+        }
+        filter.equal("s.folder", query.folder.as_deref());
+        let grouped = format!(
+            "SELECT a.key AS key, MIN(a.name) AS name, MIN(a.sort_name) AS sort_name,
+                    COUNT(*) AS song_count, COUNT(DISTINCT s.album_key) AS album_count,
+                    COALESCE(SUM(s.duration_secs), 0) AS duration,
+                    MIN(s.album_art_path) AS art, MAX(s.year) AS year,
+                    MAX(s.date_added) AS added
+             FROM song_artists a JOIN songs s ON s.id = a.song_id {}
+             GROUP BY a.key",
+            filter.where_clause()
+        );
+        self.group_page(&grouped, &filter, query, &["sort_name", "key"])
+    }
+
+    /// A page of genres.
+    pub fn genres(&self, query: &GroupQuery) -> rusqlite::Result<GroupPage> {
+        let mut filter = Filter::default();
+        filter.push("genre IS NOT NULL", []);
+        filter.search(query.search.as_deref(), &["genre"]);
+        filter.equal("folder", query.folder.as_deref());
+        let grouped = format!(
+            "SELECT lower(genre) AS key, genre AS name, COUNT(*) AS song_count,
+                    COUNT(DISTINCT album_key) AS album_count,
+                    COALESCE(SUM(duration_secs), 0) AS duration, MIN(album_art_path) AS art,
+                    MAX(year) AS year, MAX(date_added) AS added
+             FROM songs {}
+             GROUP BY genre COLLATE NOCASE",
+            filter.where_clause()
+        );
+        self.group_page(&grouped, &filter, query, &["name COLLATE NOCASE"])
+    }
+
+    /// The path and tags of every song, to match playlist entries against.
+    pub fn playlist_tracks(&self) -> rusqlite::Result<Vec<LibraryTrack>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT path, COALESCE(title, display_title), artist, duration_secs FROM songs",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(LibraryTrack {
+                path: row.get(0)?,
+                title: row.get(1)?,
+                artist: row.get(2)?,
+                duration_secs: row.get(3)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    /// Where playback of the file at `path` was left off, if it was saved.
+    pub fn resume_position(&self, path: &str) -> rusqlite::Result<Option<f64>> {
+        self.conn
+            .query_row(
+                "SELECT position_secs FROM resume_positions WHERE path = ?1",
+                [path],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    /// Save where playback of the file at `path` was left off, or forget it
+    /// for `None`.
+    pub fn set_resume_position(
+        &self,
+        path: &str,
+        position_secs: Option<f64>,
+    ) -> rusqlite::Result<()> {
+        match position_secs {
+            Some(position_secs) => self.conn.execute(
+                "INSERT OR REPLACE INTO resume_positions (path, position_secs, updated_at)
+                 VALUES (?1, ?2, ?3)",
+                params![path, position_secs, now()],
+            ),
+            None => self
+                .conn
+                .execute("DELETE FROM resume_positions WHERE path = ?1", [path]),
+        }?;
+        Ok(())
+    }
+
+    /// Count a play of the song at `path`, or of the CUE sheet track of that
+    /// file starting at `start_secs`.
+    pub fn record_play(&mut self, path: &str, start_secs: Option<f64>) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "UPDATE songs SET play_count = play_count + 1, last_played = ?3
+             WHERE IIF(?2 IS NULL, path = ?1, source_path = ?1 AND abs(start_secs - ?2) < 0.01)",
+            params![path, start_secs, now()],
+        )?;
+        self.update_smart_playlists()
+    }
+
+    pub fn set_favorite(&mut self, path: &str, favorite: bool) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "UPDATE songs SET favorite = ?2 WHERE path = ?1 AND favorite != ?2",
+            params![path, favorite],
+        )?;
+        self.update_smart_playlists()
+    }
+
+    /// The songs of `playlist`, in its order.
+    pub fn smart_playlist_songs(
+        &mut self,
+        playlist: &SmartPlaylist,
+    ) -> rusqlite::Result<Vec<LibrarySong>> {
+        self.load_smart_tracks()?;
+        let ids = self.smart.select(playlist, now());
+        self.songs_by_ids(&ids)
+    }
+
+    /// Send the songs of `playlist` to `sink` now and whenever they change,
+    /// until `sink` returns false or another playlist is watched as `key`.
+    pub fn watch_smart_playlist(
+        &mut self,
+        key: String,
+        playlist: SmartPlaylist,
+        sink: impl FnMut(Vec<LibrarySong>) -> bool + Send + 'static,
+    ) -> rusqlite::Result<()> {
+        self.load_smart_tracks()?;
+        let ids = self.smart.watch(key.clone(), playlist, Box::new(sink), now());
+        let songs = self.songs_by_ids(&ids)?;
+        self.smart.send(&key, songs);
+        Ok(())
+    }
+
+    pub fn unwatch_smart_playlist(&mut self, key: &str) {
+        self.smart.unwatch(key);
+    }
+
+    /// Read every song into the smart playlists, the first time one is
+    /// evaluated.
+    fn load_smart_tracks(&mut self) -> rusqlite::Result<()> {
+        if self.smart.is_loaded() {
+            return Ok(());
+        }
+        self.conn.execute("DELETE FROM changed_songs", [])?;
+        let tracks = self
+            .conn
+            .prepare(&format!("SELECT {} FROM songs", SMART_COLUMNS))?
+            .query_map([], |row| Ok((row.get(0)?, smart_track_from_row(row)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        self.smart.load(tracks);
+        Ok(())
+    }
+
+    /// Pass the songs written since the last update to the smart playlists,
+    /// and send the watched ones that changed.
+    fn update_smart_playlists(&mut self) -> rusqlite::Result<()> {
+        if !self.smart.is_loaded() {
+            self.conn.execute("DELETE FROM changed_songs", [])?;
+            return Ok(());
+        }
+        let ids: Vec<i64> = self
+            .conn
+            .prepare_cached("SELECT id FROM changed_songs")?
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        if ids.is_empty() {
+            return Ok(());
+        }
+        // Songs missing here were deleted
+        let mut tracks: HashMap<i64, SmartTrack> = self
+            .conn
+            .prepare_cached(&format!(
+                "SELECT {} FROM songs WHERE id IN (SELECT id FROM changed_songs)",
+                SMART_COLUMNS
+            ))?
+            .query_map([], |row| Ok((row.get(0)?, smart_track_from_row(row)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        self.conn.execute("DELETE FROM changed_songs", [])?;
+        let changed = ids.into_iter().map(|id| (id, tracks.remove(&id))).collect();
+        self.send_smart_updates(changed)
+    }
+
+    /// Check watched smart playlists whose rules depend on the time again,
+    /// and send the ones that time changed.
+    pub fn refresh_smart_playlists(&mut self) -> rusqlite::Result<()> {
+        self.send_smart_updates(Vec::new())
+    }
+
+    /// Apply `changed` to the smart playlists and send the watched ones
+    /// whose songs changed.
+    fn send_smart_updates(
+        &mut self,
+        changed: Vec<(i64, Option<SmartTrack>)>,
+    ) -> rusqlite::Result<()> {
+        for (key, ids) in self.smart.apply(changed, now()) {
+            let songs = self.songs_by_ids(&ids)?;
+            self.smart.send(&key, songs);
+        }
+        Ok(())
+    }
+
+    /// The songs with `ids`, in that order.
+    fn songs_by_ids(&self, ids: &[i64]) -> rusqlite::Result<Vec<LibrarySong>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(&format!("SELECT {} FROM songs WHERE id = ?1", SONG_COLUMNS))?;
+        let mut songs = Vec::with_capacity(ids.len());
+        for id in ids {
+            songs.extend(stmt.query_row([id], song_from_row).optional()?);
+        }
+        Ok(songs)
+    }
+
+    /// A page of the `grouped` query's rows, ordered by `query` with
+    /// `by_name` as the name order.
+    fn group_page(
+        &self,
+        grouped: &str,
+        filter: &Filter,
+        query: &GroupQuery,
+        by_name: &[&str],
+    ) -> rusqlite::Result<GroupPage> {
+        let sql = format!(
+            "SELECT key, name, song_count, album_count, duration, art FROM ({}) ORDER BY {} \
+             LIMIT ? OFFSET ?",
+            grouped,
+            group_order(query, by_name)
+        );
+        let groups = self.page(&sql, filter, query.offset, query.limit, |row| {
+            Ok(LibraryGroup {
+                key: row.get(0)?,
+                name: row.get(1)?,
+                song_count: row.get(2)?,
+                album_count: row.get(3)?,
+                duration_secs: row.get(4)?,
+                album_art_path: row.get(5)?,
+            })
+        })?;
+        let total = self.count(&format!("SELECT COUNT(*) FROM ({})", grouped), filter)?;
+        Ok(GroupPage { groups, total })
+    }
+
+    /// Run a query ending in `LIMIT ? OFFSET ?` with the filter's values.
+    fn page<T>(
+        &self,
+        sql: &str,
+        filter: &Filter,
+        offset: u32,
+        limit: Option<u32>,
+        map: impl FnMut(&Row<'_>) -> rusqlite::Result<T>,
+    ) -> rusqlite::Result<Vec<T>> {
+        let mut values = filter.values.clone();
+        values.push(Value::Integer(limit.map_or(-1, i64::from)));
+        values.push(Value::Integer(i64::from(offset)));
+        let mut stmt = self.conn.prepare(sql)?;
+        let rows = stmt.query_map(params_from_iter(values), map)?;
+        rows.collect()
+    }
+
+    fn count(&self, sql: &str, filter: &Filter) -> rusqlite::Result<u32> {
+        self.conn
+            .query_row(sql, params_from_iter(filter.values.iter()), |row| row.get(0))
+    }
+}
+
+/// Create the schema in a new database, or upgrade an older one.
+fn migrate(conn: &mut Connection) -> rusqlite::Result<()> {
+    let version: i32 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    if version >= SCHEMA_VERSION {
+        return Ok(());
+    }
+    let tx = conn.transaction()?;
+    if version < 1 {
+        tx.execute_batch(SCHEMA)?;
+    }
+    if version < 2 {
+        tx.execute_batch(GROUPING_SCHEMA)?;
+        regroup(&tx, &library_default_grouping_options(), "1", [])?;
+    }
+    if version < 3 {
+        tx.execute_batch(CUE_SCHEMA)?;
+    }
+    if version < 4 {
+        tx.execute_batch(RESUME_SCHEMA)?;
+    }
+    if version < 5 {
+        tx.execute_batch(PLAYS_SCHEMA)?;
+    }
+    if version < 6 {
+        tx.execute_batch(FTS_UPDATE_SCHEMA)?;
+    }
+    tx.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+    tx.commit()
+}
+
+/// Build the search index from the songs in the database.
+fn search_index(conn: &Connection) -> rusqlite::Result<SearchIndex> {
+    let mut index = SearchIndex::default();
+    let mut stmt =
+        conn.prepare("SELECT path, display_title, artist, album_artist, album FROM songs")?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let path: String = row.get(0)?;
+        let title: String = row.get(1)?;
+        let (artist, album_artist, album): (Option<String>, Option<String>, Option<String>) =
+            (row.get(2)?, row.get(3)?, row.get(4)?);
+        let fields = SearchFields {
+            title: &title,
+            artist: artist.as_deref(),
+            album_artist: album_artist.as_deref(),
+            album: album.as_deref(),
+        };
+        index.insert(&path, fields);
+    }
+    Ok(index)
+}
+
+/// The grouping options saved in the settings, or the defaults.
+fn grouping_options(conn: &Connection) -> rusqlite::Result<GroupingOptions> {
+    let mut options = library_default_grouping_options();
+    let mut stmt = conn.prepare("SELECT name, value FROM settings")?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let (name, value): (String, String) = (row.get(0)?, row.get(1)?);
+        let list = || value.split('\n').filter(|s| !s.is_empty()).map(str::to_string).collect();
+        match name.as_str() {
+            "artist_separators" => options.artist_separators = list(),
+            "articles" => options.articles = list(),
+            "various_artists" => options.various_artists = value,
+            _ => {}
+        }
+    }
+    Ok(options)
+}
+
+/// Recompute the grouping keys and credited artists of the songs matching
+/// `condition`.
+fn regroup(
+    tx: &Transaction<'_>,
+    options: &GroupingOptions,
+    condition: &str,
+    values: impl rusqlite::Params,
+) -> rusqlite::Result<()> {
+    type Tags = (i64, String, Option<String>, Option<String>, Option<String>, bool);
+    type Owner = (String, Option<String>, Option<String>, Option<String>);
+    let songs: Vec<Tags> = tx
+        .prepare(&format!(
+            "SELECT id, path, artist, album, album_artist, compilation FROM songs WHERE {}",
+            condition
+        ))?
+        .query_map(values, |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?))
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+    let mut update = tx.prepare_cached(
+        "UPDATE songs SET album_key = ?2, album_folder_key = ?3, album_sort = ?4,
+                          album_artist_name = ?5, album_artist_key = ?6, album_artist_sort = ?7,
+                          album_compilation = ?8
+         WHERE id = ?1",
+    )?;
+    // A tagged album of the same title in the folder
+    let mut tagged_album = tx.prepare_cached(
+        "SELECT album_key, MIN(album_artist_name), MIN(album_artist_key),
+                MIN(album_artist_sort)
+         FROM songs WHERE album_folder_key = ?1 AND album_key != ?1
+         GROUP BY album_key LIMIT 2",
+    )?;
+    // Songs joining it: those keyed by the folder that are not compilations
+    let mut join_album = tx.prepare_cached(
+        "UPDATE songs SET album_key = ?2, album_artist_name = ?3, album_artist_key = ?4,
+                          album_artist_sort = ?5
+         WHERE album_folder_key = ?1 AND album_key = ?1 AND album_compilation = 0",
+    )?;
+    let mut clear = tx.prepare_cached("DELETE FROM song_artists WHERE song_id = ?1")?;
+    let mut credit = tx.prepare_cached(
+        "INSERT INTO song_artists (song_id, position, key, name, sort_name)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    for (id, path, artist, album, album_artist, compilation) in &songs {
+        let tags = GroupTags {
+            path,
+            artist: artist.as_deref(),
+            album: album.as_deref(),
+            album_artist: album_artist.as_deref(),
+            compilation: *compilation,
+        };
+        let group = SongGroup::new(tags, options);
+        let owner = group.album_artist.as_ref();
+        update.execute(params![
+            id,
+            group.album_key,
+            group.folder_key,
+            group.album_sort,
+            owner.map(|a| &a.name),
+            owner.map(|a| &a.key),
+            owner.map(|a| &a.sort_name),
+            group.compilation,
+        ])?;
+        clear.execute([id])?;
+        for (position, artist) in group.artists.iter().enumerate() {
+            credit.execute(params![id, position, artist.key, artist.name, artist.sort_name])?;
+        }
+        let (Some(key), Some(folder_key)) = (&group.album_key, &group.folder_key) else {
+            continue;
+        };
+        if key != folder_key {
+            join_album.execute(params![
+                folder_key,
+                key,
+                owner.map(|a| &a.name),
+                owner.map(|a| &a.key),
+                owner.map(|a| &a.sort_name),
+            ])?;
+        } else if !group.compilation {
+            let tagged: Vec<Owner> = tagged_album
+                .query_map([folder_key], |row| {
+                    Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+                })?
+                .collect::<rusqlite::Result<_>>()?;
+            if let [(key, name, artist_key, sort_name)] = &tagged[..] {
+                join_album.execute(params![folder_key, key, name, artist_key, sort_name])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn upsert_song(
+    tx: &Transaction<'_>,
+    options: &GroupingOptions,
+    folder: &str,
+    metadata: &AudioFileMetadata,
+    now: i64,
+) -> rusqlite::Result<()> {
+    let m = metadata;
+    let palette = m.album_palette.as_ref();
+    let range = m.range.as_ref();
+    tx.prepare_cached(UPSERT_SONG)?.execute(params![
+        m.path,
+        folder,
+        display_title(m),
+        m.title,
+        m.artist,
+        m.album,
+        m.album_artist,
+        m.track_number,
+        m.track_total,
+        m.disc_number,
+        m.disc_total,
+        m.year,
+        m.genre,
+        m.composer,
+        m.compilation,
+        m.duration_secs,
+        m.sample_rate,
+        m.bit_depth,
+        m.bitrate_kbps,
+        m.channels,
+        m.has_lyrics,
+        m.musicbrainz_recording_id,
+        m.musicbrainz_release_id,
+        m.musicbrainz_release_group_id,
+        m.musicbrainz_artist_id,
+        m.musicbrainz_release_artist_id,
+        m.album_art_path,
+        palette.map(|p| p.dominant),
+        palette.map(|p| p.vibrant),
+        palette.map(|p| p.muted),
+        palette.map(|p| p.text),
+        palette.map(|p| p.secondary_text),
+        m.format,
+        m.last_modified,
+        now,
+        range.map(|r| &r.source_path),
+        range.map(|r| r.start_secs),
+        range.and_then(|r| r.end_secs),
+    ])?;
+    regroup(tx, options, "path = ?1", [&m.path])
+}
+
+/// Re-path moved songs, keeping their id and date added. `folder_of`
+/// gives the music folder of a new path.
+fn move_songs<'a, F: AsRef<str>>(
+    tx: &Transaction<'_>,
+    options: &GroupingOptions,
+    moved: &'a [ScanMove],
+    folder_of: impl Fn(&'a str) -> F,
+) -> rusqlite::Result<()> {
+    for ScanMove { old_path, new_path } in moved {
+        // A song already at the new path is the stale one
+        tx.execute("DELETE FROM songs WHERE path = ?1", [new_path])?;
+        tx.execute(
+            "UPDATE songs SET path = ?2, folder = ?3 WHERE path = ?1",
+            params![old_path, new_path, folder_of(new_path).as_ref()],
+        )?;
+        // Albums without an album artist are keyed by folder
+        regroup(tx, options, "path = ?1", [new_path])?;
+    }
+    Ok(())
+}
+
+fn song_from_row(row: &Row<'_>) -> rusqlite::Result<LibrarySong> {
+    let palette = match row.get::<_, Option<u32>>(29)? {
+        Some(dominant) => Some(AlbumPalette {
+            dominant,
+            vibrant: row.get(30)?,
+            muted: row.get(31)?,
+            text: row.get(32)?,
+            secondary_text: row.get(33)?,
+        }),
+        None => None,
+    };
+    let range = match row.get::<_, Option<String>>(38)? {
+        Some(source_path) => Some(TrackRange {
+            source_path,
+            start_secs: row.get(39)?,
+            end_secs: row.get(40)?,
+        }),
+        None => None,
+    };
+    Ok(LibrarySong {
+        id: row.get(0)?,
+        folder: row.get(1)?,
+        display_title: row.get(2)?,
+        date_added: row.get(3)?,
+        play_count: row.get(41)?,
+        last_played: row.get(42)?,
+        favorite: row.get(43)?,
+        album_key: row.get(36)?,
+        artist_keys: row
+            .get::<_, Option<String>>(37)?
+            .map_or_else(Vec::new, |keys| keys.split('\u{1f}').map(str::to_string).collect()),
+        metadata: AudioFileMetadata {
+            path: row.get(4)?,
+            title: row.get(5)?,
+            artist: row.get(6)?,
+            album: row.get(7)?,
+            album_artist: row.get(8)?,
+            track_number: row.get(9)?,
+            track_total: row.get(10)?,
+            disc_number: row.get(11)?,
+            disc_total: row.get(12)?,
+            year: row.get(13)?,
+            genre: row.get(14)?,
+            composer: row.get(15)?,
+            compilation: row.get(16)?,
+            duration_secs: row.get(17)?,
+            sample_rate: row.get(18)?,
+            bit_depth: row.get(19)?,
+            bitrate_kbps: row.get(20)?,
+            channels: row.get(21)?,
+            has_lyrics: row.get(22)?,
+            musicbrainz_recording_id: row.get(23)?,
+            musicbrainz_release_id: row.get(24)?,
+            musicbrainz_release_group_id: row.get(25)?,
+            musicbrainz_artist_id: row.get(26)?,
+            musicbrainz_release_artist_id: row.get(27)?,
+            album_art_path: row.get(28)?,
+            album_palette: palette,
+            format: row.get(34)?,
+            last_modified: row.get(35)?,
+            range,
+        },
+    })
+}
+
+/// A [`SmartTrack`] from a row of [`SMART_COLUMNS`].
+fn smart_track_from_row(row: &Row<'_>) -> rusqlite::Result<SmartTrack> {
+    let text = |index: usize| -> rusqlite::Result<Option<String>> {
+        Ok(row.get::<_, Option<String>>(index)?.map(|text| fold(&text)))
+    };
+    let path: String = row.get(1)?;
+    Ok(SmartTrack {
+        path_key: fold(&path),
+        path,
+        title: fold(&row.get::<_, String>(2)?),
+        artist: text(3)?,
+        artists: text(4)?
+            .map_or_else(Vec::new, |names| names.split('\u{1f}').map(str::to_string).collect()),
+        album: text(5)?,
+        album_artist: text(6)?,
+        genre: text(7)?,
+        composer: text(8)?,
+        format: fold(&row.get::<_, String>(9)?),
+        year: row.get(10)?,
+        track_number: row.get(11)?,
+        disc_number: row.get(12)?,
+        duration_secs: row.get(13)?,
+        sample_rate: row.get(14)?,
+        bit_depth: row.get(15)?,
+        bitrate_kbps: row.get(16)?,
+        channels: row.get(17)?,
+        play_count: row.get(18)?,
+        date_added: row.get(19)?,
+        last_played: row.get(20)?,
+        last_modified: row.get(21)?,
+        favorite: row.get(22)?,
+        compilation: row.get(23)?,
+        has_lyrics: row.get(24)?,
+    })
+}
+
+/// `ORDER BY` terms for a grouped query with `song_count`, `year` and
+/// `added` columns, where `by_name` orders by name. Only the first term
+/// follows the query's direction.
+fn group_order(query: &GroupQuery, by_name: &[&str]) -> String {
+    let direction = if query.descending { "DESC" } else { "ASC" };
+    let name = by_name.join(", ");
+    match query.sort {
+        GroupSortType::Name => {
+            let rest: String = by_name[1..].iter().map(|term| format!(", {}", term)).collect();
+            format!("{} {}{}", by_name[0], direction, rest)
+        }
+        GroupSortType::SongCount => format!("song_count {}, {}", direction, name),
+        GroupSortType::Year => format!("year {}, {}", direction, name),
+        GroupSortType::DateAdded => format!("added {}, {}", direction, name),
+    }
+}
+
+/// `WHERE` conditions with their bound values, in order.
+#[derive(Default)]
+struct Filter {
+    conditions: Vec<String>,
+    values: Vec<Value>,
+}
+
+impl Filter {
+    fn push<const N: usize>(&mut self, condition: &str, values: [String; N]) {
+        self.conditions.push(condition.to_string());
+        self.values.extend(values.into_iter().map(Value::Text));
+    }
+
+    fn equal(&mut self, column: &str, value: Option<&str>) {
+        if let Some(value) = value {
+            self.push(&format!("{} = ?", column), [value.to_string()]);
+        }
+    }
+
+    fn equal_nocase(&mut self, column: &str, value: Option<&str>) {
+        if let Some(value) = value {
+            self.push(&format!("{} = ? COLLATE NOCASE", column), [value.to_string()]);
+        }
+    }
+
+    /// Match songs containing every word of `search` as a word prefix, in
+    /// `columns` or any indexed column if empty.
+    fn search(&mut self, search: Option<&str>, columns: &[&str]) {
+        let Some(expression) = search.and_then(|search| match_expression(search, columns)) else {
+            return;
+        };
+        self.push(
+            "id IN (SELECT rowid FROM songs_fts WHERE songs_fts MATCH ?)",
+            [expression],
+        );
+    }
+
+    fn where_clause(&self) -> String {
+        if self.conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", self.conditions.join(" AND "))
+        }
+    }
+}
+
+/// FTS5 query matching every word of `search` as a prefix.
+fn match_expression(search: &str, columns: &[&str]) -> Option<String> {
+    let terms: Vec<String> = search
+        .split_whitespace()
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect();
+    if terms.is_empty() {
+        return None;
+    }
+    let terms = terms.join(" ");
+    Some(if columns.is_empty() {
+        terms
+    } else {
+        format!("{{{}}} : ({})", columns.join(" "), terms)
+    })
+}
+
+/// Title to show and sort by: the tagged title, or one made from the file
+/// name without a leading track number.
+pub fn display_title(metadata: &AudioFileMetadata) -> String {
+    if let Some(title) = &metadata.title {
+        return title.clone();
+    }
+    let stem = Path::new(&metadata.path)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let digits = stem.chars().take_while(char::is_ascii_digit).count();
+    let separators = stem[digits..]
+        .chars()
+        .take_while(|c| c.is_whitespace() || matches!(c, '.' | '_' | '-'))
+        .map(char::len_utf8)
+        .sum::<usize>();
+    let name = if (1..=3).contains(&digits) && separators > 0 && digits + separators < stem.len() {
+        &stem[digits + separators..]
+    } else {
+        &stem
+    };
+    name.replace('_', " ").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Prefix shared by the paths below `path`.
+fn under(path: &str) -> String {
+    format!("{}{}", path.trim_end_matches(MAIN_SEPARATOR), MAIN_SEPARATOR)
+}
+
+fn parent_dir(path: &str) -> String {
+    Path::new(path)
+        .parent()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Seconds since the epoch.
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::api::library::{library_default_group_query, library_default_song_query};
+    use crate::test_util::test_dir;
+
+    pub(crate) fn song(
+        path: &str,
+        title: Option<&str>,
+        artist: &str,
+        album: &str,
+        genre: &str,
+    ) -> AudioFileMetadata {
+        AudioFileMetadata {
+            path: path.to_string(),
+            title: title.map(str::to_string),
+            artist: Some(artist.to_string()),
+            album: Some(album.to_string()),
+            album_artist: None,
+            track_number: Some(1),
+            track_total: None,
+            disc_number: None,
+            disc_total: None,
+            year: Some(2001),
+            genre: Some(genre.to_string()),
+            composer: None,
+            compilation: false,
+            duration_secs: Some(200),
+            sample_rate: Some(44100),
+            bit_depth: None,
+            bitrate_kbps: Some(320),
+            channels: Some(2),
+            has_lyrics: false,
+            musicbrainz_recording_id: None,
+            musicbrainz_release_id: None,
+            musicbrainz_release_group_id: None,
+            musicbrainz_artist_id: None,
+            musicbrainz_release_artist_id: None,
+            album_art_path: None,
+            album_palette: Some(AlbumPalette {
+                dominant: 0xff102030,
+                vibrant: 1,
+                muted: 2,
+                text: 3,
+                secondary_text: 4,
+            }),
+            format: "mp3".to_string(),
+            last_modified: 100,
+            range: None,
+        }
+    }
+
+    pub(crate) fn event(kind: ScanEventType, files: Vec<AudioFileMetadata>) -> ScanStreamEvent {
+        ScanStreamEvent {
+            kind,
+            files,
+            failures: Vec::new(),
+            deleted_paths: Vec::new(),
+            moved: Vec::new(),
+            discovered: 0,
+            processed: 0,
+            failed: 0,
+        }
+    }
+
+    fn paths(page: &SongPage) -> Vec<&str> {
+        page.songs.iter().map(|s| s.metadata.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_scan_and_query() {
+        let dir = test_dir("store");
+        let db = dir.join("library.db");
+        let mut store = LibraryStore::open(&db).unwrap();
+
+        let batch = vec![
+            song("/m/a/01 - Zebra.mp3", None, "Björk", "Début", "Pop"),
+            song("/m/a/02.mp3", Some("Human Behaviour"), "Björk", "Début", "Pop"),
+            song("/m/b/x.flac", Some("Alpha"), "Air", "Moon Safari", "Electronic"),
+            song("/m/b/y.flac", Some("Gone"), "Air", "Moon Safari", "Electronic"),
+        ];
+        store.apply_scan("/m", &event(ScanEventType::Batch, batch)).unwrap();
+        let mut finished = event(ScanEventType::Finished, Vec::new());
+        finished.deleted_paths = vec!["/m/b/y.flac".to_string()];
+        finished.moved = vec![ScanMove {
+            old_path: "/m/b/x.flac".to_string(),
+            new_path: "/m/c/x.flac".to_string(),
+        }];
+        store.apply_scan("/m", &finished).unwrap();
+
+        let known = store.known_files("/m").unwrap();
+        assert_eq!(known.len(), 3);
+        assert_eq!(known.get("/m/c/x.flac"), Some(&100));
+        assert!(store.known_files("/other").unwrap().is_empty());
+
+        // Reopening keeps everything
+        drop(store);
+        let mut store = LibraryStore::open(&db).unwrap();
+        let all = store.songs(&library_default_song_query()).unwrap();
+        assert_eq!(all.total, 3);
+        assert_eq!(paths(&all), ["/m/c/x.flac", "/m/a/02.mp3", "/m/a/01 - Zebra.mp3"]);
+        let zebra = store.song_by_path("/m/a/01 - Zebra.mp3").unwrap().unwrap();
+        assert_eq!(zebra.display_title, "Zebra");
+        assert_eq!(zebra.metadata.title, None);
+        assert_eq!(zebra.metadata.duration_secs, Some(200));
+        assert_eq!(zebra.metadata.album_palette.unwrap().dominant, 0xff102030);
+
+        // Search ignores case and accents, and matches word prefixes
+        let search = |store: &LibraryStore, text: &str| {
+            let query = SongQuery {
+                search: Some(text.to_string()),
+                ..library_default_song_query()
+            };
+            store.songs(&query).unwrap().total
+        };
+        assert_eq!(search(&store, "bjork"), 2);
+        assert_eq!(search(&store, "DEBU hum"), 1);
+        assert_eq!(search(&store, "moon alp"), 1);
+        assert_eq!(search(&store, "\"quoted"), 0);
+        assert_eq!(search(&store, "   "), 3);
+        let found = |store: &LibraryStore, text: &str| -> Vec<String> {
+            let songs = store.search(text, 10).unwrap();
+            songs.into_iter().map(|s| s.metadata.path).collect()
+        };
+        assert_eq!(found(&store, "bjrok zebr"), ["/m/a/01 - Zebra.mp3"]);
+
+        let query = SongQuery {
+            sort: SongSortType::Title,
+            descending: true,
+            offset: 1,
+            limit: Some(1),
+            ..library_default_song_query()
+        };
+        let page = store.songs(&query).unwrap();
+        assert_eq!((paths(&page), page.total), (vec!["/m/a/02.mp3"], 3));
+        let query = SongQuery {
+            artist: Some("air".to_string()),
+            ..library_default_song_query()
+        };
+        assert_eq!(paths(&store.songs(&query).unwrap()), ["/m/c/x.flac"]);
+
+        let albums = store.albums(&library_default_group_query()).unwrap();
+        assert_eq!(albums.total, 2);
+        assert_eq!(albums.albums[0].name, "Début");
+        assert_eq!(albums.albums[0].artist.as_deref(), Some("Björk"));
+        assert_eq!((albums.albums[0].song_count, albums.albums[0].duration_secs), (2, 400));
+        let query = GroupQuery {
+            sort: GroupSortType::SongCount,
+            descending: true,
+            ..library_default_group_query()
+        };
+        let artists = store.artists(&query).unwrap();
+        let names: Vec<_> = artists.groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, ["Björk", "Air"]);
+        let query = GroupQuery {
+            search: Some("electro".to_string()),
+            ..library_default_group_query()
+        };
+        let genres = store.genres(&query).unwrap();
+        assert_eq!((genres.total, genres.groups[0].album_count), (1, 1));
+
+        // Watched changes: a new file, a moved folder and a removed folder
+        let watched = WatchEvent {
+            added: vec![song("/m/d/new.mp3", Some("New"), "Air", "Talkie Walkie", "Pop")],
+            modified: Vec::new(),
+            removed_paths: vec!["/m/a".to_string()],
+            moved: vec![ScanMove {
+                old_path: "/m/c/x.flac".to_string(),
+                new_path: "/m/e/x.flac".to_string(),
+            }],
+            failures: Vec::new(),
+            rescan_needed: false,
+        };
+        store.apply_watch(&watched).unwrap();
+        let all = store.songs(&library_default_song_query()).unwrap();
+        assert_eq!(paths(&all), ["/m/e/x.flac", "/m/d/new.mp3"]);
+        assert_eq!(found(&store, "talkie"), ["/m/d/new.mp3"]);
+        assert_eq!(found(&store, "alpha"), ["/m/e/x.flac"]);
+        assert!(found(&store, "bjork").is_empty());
+        assert!(all.songs.iter().all(|s| s.folder == "/m"));
+        let retagged = WatchEvent {
+            added: Vec::new(),
+            modified: vec![song("/m/d/new.mp3", Some("Mer du Japon"), "Air", "Talkie", "Pop")],
+            removed_paths: Vec::new(),
+            moved: Vec::new(),
+            failures: Vec::new(),
+            rescan_needed: false,
+        };
+        store.apply_watch(&retagged).unwrap();
+        assert_eq!((search(&store, "japon"), search(&store, "new")), (1, 0));
+
+        // A file that gained a CUE sheet is replaced by its tracks, which go
+        // when the file does
+        let track = |number: u32, start_secs: f64, end_secs: Option<f64>| AudioFileMetadata {
+            path: format!("/m/e/x.flac#{}", number),
+            range: Some(TrackRange {
+                source_path: "/m/e/x.flac".to_string(),
+                start_secs,
+                end_secs,
+            }),
+            ..song("", Some("Track"), "Air", "Moon Safari", "Electronic")
+        };
+        let mut watched = WatchEvent {
+            added: Vec::new(),
+            modified: vec![track(1, 0.0, Some(90.5)), track(2, 90.5, None)],
+            removed_paths: vec!["/m/e/x.flac".to_string()],
+            moved: Vec::new(),
+            failures: Vec::new(),
+            rescan_needed: false,
+        };
+        store.apply_watch(&watched).unwrap();
+        let all = store.songs(&library_default_song_query()).unwrap();
+        assert_eq!(all.total, 3);
+        let second = store.song_by_path("/m/e/x.flac#2").unwrap().unwrap();
+        assert_eq!(second.metadata.range, track(2, 90.5, None).range);
+        assert!(store.song_by_path("/m/e/x.flac").unwrap().is_none());
+        watched.modified.clear();
+        store.apply_watch(&watched).unwrap();
+        assert_eq!(paths(&store.songs(&library_default_song_query()).unwrap()), ["/m/d/new.mp3"]);
+        assert!(found(&store, "alpha").is_empty() && found(&store, "track").is_empty());
+
+        let folders = store.folders().unwrap();
+        assert_eq!(folders.len(), 1);
+        assert_eq!((folders[0].path.as_str(), folders[0].song_count), ("/m", 1));
+        assert!(folders[0].scanned_at.is_some());
+        store.remove_folder("/m").unwrap();
+        assert_eq!(store.songs(&library_default_song_query()).unwrap().total, 0);
+        assert_eq!(search(&store, "air"), 0);
+        assert!(found(&store, "air").is_empty());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_grouping() {
+        let dir = test_dir("grouping");
+        let db = dir.join("library.db");
+        let mut store = LibraryStore::open(&db).unwrap();
+
+        let tagged = |path: &str, artist: &str, album: &str, album_artist: Option<&str>| {
+            let mut song = song(path, None, artist, album, "Rock");
+            song.album_artist = album_artist.map(str::to_string);
+            song
+        };
+        let mut disc_two = tagged("/g/ar/CD2/1.mp3", "Beatles, The", "Abbey Road", None);
+        disc_two.disc_number = Some(2);
+        let batch = vec![
+            tagged("/g/ar/CD1/1.mp3", "The Beatles", "Abbey Road", Some("The Beatles")),
+            disc_two,
+            tagged("/g/v/1.mp3", "Queen feat. David Bowie", "Hits", None),
+            tagged("/g/v/2.mp3", "ABBA", "Hits", None),
+            tagged("/g/b/1.mp3", "David Bowie", "Hits", Some("David Bowie")),
+        ];
+        store.apply_scan("/g", &event(ScanEventType::Batch, batch)).unwrap();
+
+        let albums = store.albums(&library_default_group_query()).unwrap();
+        let summary: Vec<_> = albums
+            .albums
+            .iter()
+            .map(|a| (a.name.as_str(), a.artist.as_deref(), a.song_count, a.disc_count))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("Abbey Road", Some("The Beatles"), 2, 2),
+                ("Hits", Some("David Bowie"), 1, 1),
+                ("Hits", Some("Various Artists"), 2, 1),
+            ]
+        );
+        assert!(albums.albums[2].compilation && !albums.albums[1].compilation);
+
+        // Songs of one album, by disc and track
+        let query = SongQuery {
+            album_key: Some(albums.albums[2].key.clone()),
+            sort: SongSortType::Album,
+            ..library_default_song_query()
+        };
+        let hits = store.songs(&query).unwrap();
+        assert_eq!(paths(&hits), ["/g/v/1.mp3", "/g/v/2.mp3"]);
+        assert_eq!(hits.songs[0].artist_keys, ["queen", "david bowie"]);
+        assert_eq!(hits.songs[0].album_key.as_ref(), Some(&albums.albums[2].key));
+
+        let artists = store.artists(&library_default_group_query()).unwrap();
+        let names: Vec<_> =
+            artists.groups.iter().map(|g| (g.name.as_str(), g.song_count)).collect();
+        assert_eq!(names, [("ABBA", 1), ("The Beatles", 2), ("David Bowie", 2), ("Queen", 1)]);
+        let query = GroupQuery {
+            search: Some("bow".to_string()),
+            ..library_default_group_query()
+        };
+        assert_eq!(store.artists(&query).unwrap().groups[0].key, "david bowie");
+        let query = SongQuery {
+            artist: Some("beatles, the".to_string()),
+            ..library_default_song_query()
+        };
+        assert_eq!(store.songs(&query).unwrap().total, 2);
+
+        // New options regroup every song, and are kept
+        let mut options = store.grouping_options().clone();
+        options.artist_separators.clear();
+        options.various_artists = "Compilation".to_string();
+        store.set_grouping_options(options.clone()).unwrap();
+        drop(store);
+        let store = LibraryStore::open(&db).unwrap();
+        assert_eq!(store.grouping_options(), &options);
+        let artists = store.artists(&library_default_group_query()).unwrap();
+        assert!(artists.groups.iter().any(|g| g.key == "queen feat david bowie"));
+        let albums = store.albums(&library_default_group_query()).unwrap();
+        let compilation = albums.albums.iter().find(|a| a.compilation).unwrap();
+        assert_eq!(compilation.artist.as_deref(), Some("Compilation"));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_resume_positions() {
+        let dir = test_dir("resume");
+        let db = dir.join("library.db");
+        let store = LibraryStore::open(&db).unwrap();
+
+        assert_eq!(store.resume_position("/p/episode.mp3").unwrap(), None);
+        store.set_resume_position("/p/episode.mp3", Some(61.5)).unwrap();
+        store.set_resume_position("/p/episode.mp3", Some(754.25)).unwrap();
+        store.set_resume_position("/p/book.m4b", Some(30.0)).unwrap();
+        drop(store);
+        let store = LibraryStore::open(&db).unwrap();
+        assert_eq!(store.resume_position("/p/episode.mp3").unwrap(), Some(754.25));
+        store.set_resume_position("/p/book.m4b", None).unwrap();
+        assert_eq!(store.resume_position("/p/book.m4b").unwrap(), None);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_plays_and_smart_playlists() {
+        use crate::api::smart_playlists::{
+            smart_playlist_default, SmartCondition, SmartField, SmartOperator,
+        };
+        use std::sync::{Arc, Mutex};
+
+        let dir = test_dir("smart");
+        let db = dir.join("library.db");
+        let mut store = LibraryStore::open(&db).unwrap();
+
+        let long = |path: &str, genre: &str| {
+            let mut song = song(path, None, "Air", "Moon Safari", genre);
+            song.duration_secs = Some(400);
+            song.format = path.rsplit('.').next().unwrap().to_string();
+            song
+        };
+        let mut cue_track = long("/s/e.flac#2", "Rock");
+        cue_track.range = Some(TrackRange {
+            source_path: "/s/e.flac".to_string(),
+            start_secs: 90.5,
+            end_secs: None,
+        });
+        let batch = vec![long("/s/a.flac", "Rock"), long("/s/b.mp3", "rock"), cue_track];
+        store.apply_scan("/s", &event(ScanEventType::Batch, batch)).unwrap();
+        store.record_play("/s/b.mp3", None).unwrap();
+        store.record_play("/s/e.flac", Some(90.5)).unwrap();
+        store.set_favorite("/s/a.flac", true).unwrap();
+
+        // Statistics survive reopening and rescans
+        drop(store);
+        let mut store = LibraryStore::open(&db).unwrap();
+        let rescan = event(ScanEventType::Batch, vec![long("/s/b.mp3", "Rock")]);
+        store.apply_scan("/s", &rescan).unwrap();
+        let played = store.song_by_path("/s/b.mp3").unwrap().unwrap();
+        assert_eq!(played.play_count, 1);
+        assert!(played.last_played.is_some() && !played.favorite);
+        let track = store.song_by_path("/s/e.flac#2").unwrap().unwrap();
+        assert_eq!(track.play_count, 1);
+        assert!(store.song_by_path("/s/a.flac").unwrap().unwrap().favorite);
+
+        // Never played rock over five minutes
+        let condition = |field, operator, text: &str, number| SmartCondition {
+            field,
+            operator,
+            text: text.to_string(),
+            number,
+        };
+        let mut playlist = smart_playlist_default();
+        playlist.rule.conditions = vec![
+            condition(SmartField::PlayCount, SmartOperator::Is, "", 0.0),
+            condition(SmartField::Genre, SmartOperator::Is, "ROCK", 0.0),
+            condition(SmartField::DurationSecs, SmartOperator::GreaterThan, "", 300.0),
+        ];
+        let songs = store.smart_playlist_songs(&playlist).unwrap();
+        let paths: Vec<_> = songs.iter().map(|s| s.metadata.path.as_str()).collect();
+        assert_eq!(paths, ["/s/a.flac"]);
+
+        // Watched playlists follow plays and library changes
+        let sent: Arc<Mutex<Vec<Vec<String>>>> = Arc::default();
+        let sink = sent.clone();
+        store
+            .watch_smart_playlist("rock".to_string(), playlist, move |songs| {
+                let paths = songs.into_iter().map(|s| s.metadata.path).collect();
+                sink.lock().unwrap().push(paths);
+                true
+            })
+            .unwrap();
+        store.record_play("/s/a.flac", None).unwrap();
+        store.set_favorite("/s/b.mp3", true).unwrap();
+        let watched = WatchEvent {
+            added: vec![long("/s/d.ogg", "Rock")],
+            modified: Vec::new(),
+            removed_paths: Vec::new(),
+            moved: Vec::new(),
+            failures: Vec::new(),
+            rescan_needed: false,
+        };
+        store.apply_watch(&watched).unwrap();
+        let expected: [&[&str]; 3] = [&["/s/a.flac"], &[], &["/s/d.ogg"]];
+        assert_eq!(*sent.lock().unwrap(), expected);
+        store.unwatch_smart_playlist("rock");
+        store.remove_folder("/s").unwrap();
+        assert_eq!(sent.lock().unwrap().len(), 3);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,276 @@
+//! Writing tag changes back to audio files.
+
+use crate::api::tags::{TagChanges, TagField};
+use lofty::config::WriteOptions;
+use lofty::file::TaggedFile;
+use lofty::picture::{Picture, PictureType};
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::{ItemKey, Tag};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Apply `changes` to every tag of the file at `path`, adding the format's
+/// primary tag if it has none.
+///
+/// The tags are written to a copy beside the file, which then replaces it.
+/// Its modification time is moved at least a second past the old one, as
+/// scans compare whole seconds.
+pub fn write(path: &Path, changes: &TagChanges) -> Result<(), String> {
+    let cover = changes
+        .cover_art
+        .as_ref()
+        .map(|data| {
+            let mut picture = Picture::from_reader(&mut data.as_slice())
+                .map_err(|e| format!("Unsupported cover art: {}", e))?;
+            picture.set_pic_type(PictureType::CoverFront);
+            Ok::<_, String>(picture)
+        })
+        .transpose()?;
+
+    let previous = fs::metadata(path).and_then(|m| m.modified()).map_err(|e| e.to_string())?;
+    let mut tagged_file = Probe::open(path)
+        .map_err(|e| e.to_string())?
+        .guess_file_type()
+        .map_err(|e| e.to_string())?
+        .read()
+        .map_err(|e| e.to_string())?;
+    if tagged_file.primary_tag().is_none() {
+        tagged_file.insert_tag(Tag::new(tagged_file.primary_tag_type()));
+    }
+    let tag_types: Vec<_> = tagged_file.tags().iter().map(Tag::tag_type).collect();
+    for tag_type in tag_types {
+        if let Some(tag) = tagged_file.tag_mut(tag_type) {
+            apply(tag, changes, cover.as_ref());
+        }
+    }
+
+    let temp = temp_path(path);
+    if let Err(e) = replace(path, &temp, &tagged_file) {
+        let _ = fs::remove_file(&temp);
+        return Err(e.to_string());
+    }
+
+    let modified = SystemTime::now().max(previous + Duration::from_secs(1));
+    File::options()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_modified(modified))
+        .map_err(|e| e.to_string())
+}
+
+/// Save the tags of `tagged_file` into a copy of `path` at `temp`, then
+/// move the copy over `path`.
+fn replace(path: &Path, temp: &Path, tagged_file: &TaggedFile) -> lofty::error::Result<()> {
+    fs::copy(path, temp)?;
+    let mut file = File::options().read(true).write(true).open(temp)?;
+    tagged_file.save_to(&mut file, WriteOptions::default())?;
+    file.sync_all()?;
+    fs::rename(temp, path)?;
+    Ok(())
+}
+
+fn apply(tag: &mut Tag, changes: &TagChanges, cover: Option<&Picture>) {
+    for field in &changes.remove {
+        match field {
+            TagField::Title => tag.remove_title(),
+            TagField::Artist => tag.remove_artist(),
+            TagField::Album => tag.remove_album(),
+            TagField::AlbumArtist => tag.remove_key(&ItemKey::AlbumArtist),
+            TagField::TrackNumber => tag.remove_track(),
+            TagField::TrackTotal => tag.remove_track_total(),
+            TagField::DiscNumber => tag.remove_disk(),
+            TagField::DiscTotal => tag.remove_disk_total(),
+            TagField::Year => tag.remove_year(),
+            TagField::Genre => tag.remove_genre(),
+            TagField::Lyrics => tag.remove_key(&ItemKey::Lyrics),
+            TagField::CoverArt => {
+                while !tag.pictures().is_empty() {
+                    tag.remove_picture(0);
+                }
+            }
+        }
+    }
+
+    let text = |value: &Option<String>| value.as_ref().map(|v| v.trim().to_string());
+    if let Some(title) = text(&changes.title) {
+        tag.set_title(title);
+    }
+    if let Some(artist) = text(&changes.artist) {
+        tag.set_artist(artist);
+    }
+    if let Some(album) = text(&changes.album) {
+        tag.set_album(album);
+    }
+    if let Some(album_artist) = text(&changes.album_artist) {
+        tag.insert_text(ItemKey::AlbumArtist, album_artist);
+    }
+    if let Some(track) = changes.track_number {
+        tag.set_track(track);
+    }
+    if let Some(total) = changes.track_total {
+        tag.set_track_total(total);
+    }
+    if let Some(disc) = changes.disc_number {
+        tag.set_disk(disc);
+    }
+    if let Some(total) = changes.disc_total {
+        tag.set_disk_total(total);
+    }
+    if let Some(year) = changes.year {
+        tag.set_year(year);
+    }
+    if let Some(genre) = text(&changes.genre) {
+        tag.set_genre(genre);
+    }
+    if let Some(lyrics) = &changes.lyrics {
+        tag.insert_text(ItemKey::Lyrics, lyrics.clone());
+    }
+    if let Some(cover) = cover {
+        tag.remove_picture_type(PictureType::CoverFront);
+        tag.push_picture(cover.clone());
+    }
+}
+
+/// A hidden file beside `path`, which scans skip as it is not audio.
+pub fn temp_path(path: &Path) -> PathBuf {
+    let name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+    path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tags::{tags_default_changes, write_tags_batch};
+    use crate::library::metadata::extract_metadata;
+    use crate::test_util::{test_dir, write_flac, write_mp3, write_silence};
+    use lofty::tag::TagType;
+
+    fn read(path: &Path) -> (crate::api::scanner::AudioFileMetadata, usize) {
+        let tagged_file = lofty::read_from_path(path).unwrap();
+        let pictures = tagged_file.tags().iter().map(|t| t.pictures().len()).sum();
+        (extract_metadata(path, &tagged_file, "wav".to_string(), 0), pictures)
+    }
+
+    #[test]
+    fn test_write_tags() {
+        let dir = test_dir("tags");
+        let song = dir.join("song.wav");
+        write_silence(&song, 4410);
+        let before = fs::metadata(&song).unwrap().modified().unwrap();
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png.extend_from_slice(&[0; 32]);
+        let changes = TagChanges {
+            title: Some(" Song ".to_string()),
+            artist: Some("Artist".to_string()),
+            album: Some("Album".to_string()),
+            album_artist: Some("Album Artist".to_string()),
+            track_number: Some(3),
+            track_total: Some(12),
+            disc_number: Some(1),
+            disc_total: Some(2),
+            year: Some(1997),
+            genre: Some("Jazz".to_string()),
+            lyrics: Some("La la la".to_string()),
+            cover_art: Some(png),
+            ..tags_default_changes()
+        };
+        write(&song, &changes).unwrap();
+        let (metadata, pictures) = read(&song);
+        assert_eq!(metadata.title.as_deref(), Some("Song"));
+        assert_eq!(metadata.album_artist.as_deref(), Some("Album Artist"));
+        assert_eq!((metadata.track_number, metadata.track_total), (Some(3), Some(12)));
+        assert_eq!((metadata.disc_number, metadata.disc_total), (Some(1), Some(2)));
+        assert_eq!((metadata.year, metadata.genre.as_deref()), (Some(1997), Some("Jazz")));
+        assert!(metadata.has_lyrics);
+        assert_eq!(pictures, 1);
+        let after = fs::metadata(&song).unwrap().modified().unwrap();
+        assert!(after >= before + Duration::from_secs(1));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        // Removals, and the same changes for a whole album
+        let other = dir.join("other.wav");
+        write_silence(&other, 4410);
+        let changes = TagChanges {
+            album: Some("Fixed".to_string()),
+            remove: vec![TagField::Title, TagField::Lyrics, TagField::CoverArt],
+            ..tags_default_changes()
+        };
+        let paths = [&song, &other, &dir.join("missing.wav")]
+            .map(|p| p.to_string_lossy().to_string())
+            .to_vec();
+        let failures = write_tags_batch(paths, changes);
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].path.ends_with("missing.wav"));
+        let (metadata, pictures) = read(&song);
+        assert_eq!((metadata.title, metadata.album.as_deref()), (None, Some("Fixed")));
+        assert_eq!(metadata.artist.as_deref(), Some("Artist"));
+        assert!(!metadata.has_lyrics && pictures == 0);
+        assert_eq!(read(&other).0.album.as_deref(), Some("Fixed"));
+
+        // Files that are not audio, and covers that are not images, are
+        // left alone
+        let text = dir.join("notes.txt");
+        fs::write(&text, "not audio").unwrap();
+        assert!(write(&text, &tags_default_changes()).is_err());
+        assert_eq!(fs::read_to_string(&text).unwrap(), "not audio");
+        let changes = TagChanges {
+            cover_art: Some(b"not an image".to_vec()),
+            ..tags_default_changes()
+        };
+        assert!(write(&other, &changes).is_err());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 3);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_write_tags_formats() {
+        let dir = test_dir("tags-formats");
+        let mp3 = dir.join("song.mp3");
+        let flac = dir.join("song.flac");
+        write_mp3(&mp3, 20);
+        write_flac(&flac);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png.extend_from_slice(&[0; 32]);
+        let changes = TagChanges {
+            title: Some("Song".to_string()),
+            album_artist: Some("Album Artist".to_string()),
+            track_number: Some(3),
+            disc_total: Some(2),
+            year: Some(1997),
+            lyrics: Some("La la la".to_string()),
+            cover_art: Some(png),
+            ..tags_default_changes()
+        };
+        for (path, tag_type) in [(&mp3, TagType::Id3v2), (&flac, TagType::VorbisComments)] {
+            write(path, &changes).unwrap();
+            let tagged_file = lofty::read_from_path(path).unwrap();
+            assert_eq!(tagged_file.primary_tag().map(Tag::tag_type), Some(tag_type));
+            let (metadata, pictures) = read(path);
+            assert_eq!(metadata.title.as_deref(), Some("Song"));
+            assert_eq!(metadata.album_artist.as_deref(), Some("Album Artist"));
+            assert_eq!((metadata.track_number, metadata.disc_total), (Some(3), Some(2)));
+            assert_eq!(metadata.year, Some(1997));
+            assert!(metadata.has_lyrics);
+            assert_eq!(pictures, 1);
+        }
+
+        // A comment too long for a FLAC block fails after the copy is made;
+        // the copy is removed and the file left as it was
+        let before = fs::read(&flac).unwrap();
+        let changes = TagChanges {
+            lyrics: Some("a".repeat(1 << 24)),
+            ..tags_default_changes()
+        };
+        assert!(write(&flac, &changes).is_err());
+        assert!(!temp_path(&flac).exists());
+        assert_eq!(fs::read(&flac).unwrap(), before);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
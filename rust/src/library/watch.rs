@@ -0,0 +1,580 @@
+//! Live library updates from file system notifications.
+//!
+//! Notifications come in bursts while files are copied in or retagged, so a
+//! path is only handled once it has been quiet for [`SETTLE_TIME`]. Changed
+//! files are then read exactly like a full scan reads them.
+
+use super::artwork::ArtworkCache;
+use super::cue;
+use super::filter::ScanFilter;
+use super::scan::{audio_format, read_file};
+use crate::api::scanner::{ScanMove, ScanOptions, WatchEvent};
+use notify::event::{AccessKind, AccessMode, ModifyKind, RenameMode};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use walkdir::WalkDir;
+
+/// How long a path has to be quiet before it is handled
+pub const SETTLE_TIME: Duration = Duration::from_millis(750);
+
+/// How often the stop condition is checked while nothing happens
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Watch `roots` on a thread of its own until `stopped` returns true,
+/// reporting changes there as they settle.
+///
+/// Returns once every root is watched. Fails, without starting the thread's
+/// loop, if a root cannot be watched or `options` has an invalid exclude
+/// pattern.
+pub fn spawn(
+    roots: Vec<String>,
+    art_cache_dir: Option<String>,
+    options: ScanOptions,
+    stopped: impl Fn() -> bool + Send + 'static,
+    on_event: impl FnMut(WatchEvent) + Send + 'static,
+) -> Result<(), String> {
+    let (started_tx, started_rx) = mpsc::channel();
+    thread::Builder::new()
+        .name("library-watch".to_string())
+        .spawn(move || {
+            let artwork = art_cache_dir.and_then(|dir| ArtworkCache::new(dir).ok());
+            let started = WatchedLibrary::new(&roots, &options, artwork.as_ref())
+                .and_then(|library| Ok((watch_roots(&library.roots)?, library)));
+            match started {
+                Ok(((_watcher, rx), library)) => {
+                    let _ = started_tx.send(Ok(()));
+                    run(&library, &rx, stopped, on_event);
+                }
+                Err(e) => {
+                    let _ = started_tx.send(Err(e));
+                }
+            }
+        })
+        .map_err(|e| e.to_string())?;
+    started_rx.recv().map_err(|e| e.to_string())?
+}
+
+type Notifications = Receiver<notify::Result<notify::Event>>;
+
+/// Start notifications for every file under `roots`.
+fn watch_roots(roots: &[PathBuf]) -> Result<(RecommendedWatcher, Notifications), String> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(|e| e.to_string())?;
+    for root in roots {
+        watcher
+            .watch(root, RecursiveMode::Recursive)
+            .map_err(|e| format!("Cannot watch {}: {}", root.display(), e))?;
+    }
+    Ok((watcher, rx))
+}
+
+/// Hand settled notifications to `on_event` until `stopped` returns true.
+fn run(
+    library: &WatchedLibrary,
+    rx: &Notifications,
+    stopped: impl Fn() -> bool,
+    mut on_event: impl FnMut(WatchEvent),
+) {
+    let mut debouncer = Debouncer::new(SETTLE_TIME);
+    while !stopped() {
+        match rx.recv_timeout(POLL_INTERVAL) {
+            Ok(Ok(event)) => debouncer.record(event, Instant::now()),
+            // Errors such as a full event queue mean changes were missed
+            Ok(Err(_)) => debouncer.missed(),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        let settled = debouncer.take_settled(Instant::now());
+        if !settled.is_empty() {
+            on_event(library.apply(settled));
+        }
+    }
+}
+
+/// What a notification says happened to a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Created,
+    Changed,
+    Removed,
+}
+
+/// Notifications for a path that has not settled yet.
+#[derive(Debug, Clone, Copy)]
+struct Burst {
+    /// The path did not exist before the burst
+    created: bool,
+    last_seen: Instant,
+}
+
+/// Collects notifications until their paths settle.
+pub struct Debouncer {
+    settle_time: Duration,
+    bursts: HashMap<PathBuf, Burst>,
+    renames: Vec<(PathBuf, PathBuf)>,
+    rescan: bool,
+}
+
+/// Changes ready to be applied to the library.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Settled {
+    /// Changed paths, with whether each appeared during its burst
+    pub paths: Vec<(PathBuf, bool)>,
+    /// Renames within the watched folders, in the order they happened
+    pub renames: Vec<(PathBuf, PathBuf)>,
+    /// Notifications were lost
+    pub rescan: bool,
+}
+
+impl Settled {
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty() && self.renames.is_empty() && !self.rescan
+    }
+}
+
+impl Debouncer {
+    pub fn new(settle_time: Duration) -> Self {
+        Self {
+            settle_time,
+            bursts: HashMap::new(),
+            renames: Vec::new(),
+            rescan: false,
+        }
+    }
+
+    /// Record a notification seen at `now`.
+    pub fn record(&mut self, event: notify::Event, now: Instant) {
+        if event.need_rescan() {
+            self.rescan = true;
+        }
+        let mut paths = event.paths.into_iter();
+        let change = match event.kind {
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                if let (Some(from), Some(to)) = (paths.next(), paths.next()) {
+                    self.rename(from, to, now);
+                }
+                return;
+            }
+            EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+                Change::Created
+            }
+            EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+                Change::Removed
+            }
+            // Unpaired renames only say that the path changed
+            EventKind::Modify(ModifyKind::Name(_)) => {
+                for path in paths {
+                    let change = if path.exists() { Change::Created } else { Change::Removed };
+                    self.change(path, change, now);
+                }
+                return;
+            }
+            EventKind::Modify(_) | EventKind::Access(AccessKind::Close(AccessMode::Write)) => {
+                Change::Changed
+            }
+            _ => return,
+        };
+        for path in paths {
+            self.change(path, change, now);
+        }
+    }
+
+    /// Record a change to `path` seen at `now`.
+    pub fn change(&mut self, path: PathBuf, change: Change, now: Instant) {
+        self.bursts
+            .entry(path)
+            .and_modify(|burst| burst.last_seen = now)
+            .or_insert(Burst {
+                created: change == Change::Created,
+                last_seen: now,
+            });
+    }
+
+    /// Record a rename. A path that only appeared during its burst cannot
+    /// be in the library yet, so it is simply recorded under its new name.
+    pub fn rename(&mut self, from: PathBuf, to: PathBuf, now: Instant) {
+        // Some backends also report the two halves of the rename on their own
+        self.bursts.remove(&to);
+        match self.bursts.remove(&from) {
+            Some(burst) if burst.created => self.change(to, Change::Created, now),
+            _ => self.renames.push((from, to)),
+        }
+    }
+
+    /// Note that notifications were lost.
+    pub fn missed(&mut self) {
+        self.rescan = true;
+    }
+
+    /// Take the paths that have been quiet for the settle time, along with
+    /// all renames so far.
+    pub fn take_settled(&mut self, now: Instant) -> Settled {
+        let mut settled = Settled {
+            paths: Vec::new(),
+            renames: std::mem::take(&mut self.renames),
+            rescan: std::mem::take(&mut self.rescan),
+        };
+        self.bursts.retain(|path, burst| {
+            let quiet = now.duration_since(burst.last_seen) >= self.settle_time;
+            if quiet {
+                settled.paths.push((path.clone(), burst.created));
+            }
+            !quiet
+        });
+        settled.paths.sort();
+        settled
+    }
+}
+
+/// The watched folders and how their files are read.
+pub struct WatchedLibrary<'a> {
+    roots: Vec<PathBuf>,
+    filter: ScanFilter,
+    options: &'a ScanOptions,
+    artwork: Option<&'a ArtworkCache>,
+}
+
+impl<'a> WatchedLibrary<'a> {
+    pub fn new(
+        roots: &[String],
+        options: &'a ScanOptions,
+        artwork: Option<&'a ArtworkCache>,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            roots: roots.iter().map(PathBuf::from).collect(),
+            filter: ScanFilter::new(options)?,
+            options,
+            artwork,
+        })
+    }
+
+    /// Read the settled changes into an event for Dart.
+    pub fn apply(&self, settled: Settled) -> WatchEvent {
+        let mut event = WatchEvent {
+            added: Vec::new(),
+            modified: Vec::new(),
+            removed_paths: Vec::new(),
+            moved: Vec::new(),
+            failures: Vec::new(),
+            rescan_needed: settled.rescan,
+        };
+        // Files to read, with whether they are new to the library
+        let mut reads: HashMap<PathBuf, (String, bool)> = HashMap::new();
+        let mut read = |path: PathBuf, ext: String, created: bool| {
+            reads
+                .entry(path)
+                .and_modify(|(_, known)| *known &= created)
+                .or_insert((ext, created));
+        };
+
+        // Moved files are read again too, in case they were not in the
+        // library under their old path
+        for (from, to) in settled.renames {
+            let from_watched = self.root_of(&from).is_some();
+            if to.is_dir() && self.root_of(&to).is_none() {
+                if from_watched {
+                    event.removed_paths.push(path_string(&from));
+                }
+                continue;
+            }
+            let files = self.audio_files(&to);
+            if files.is_empty() && from_watched {
+                event.removed_paths.push(path_string(&from));
+            }
+            for (file, ext) in files {
+                let old_path = match file.strip_prefix(&to) {
+                    Ok(relative) if !relative.as_os_str().is_empty() => from.join(relative),
+                    _ => from.clone(),
+                };
+                if from_watched {
+                    event.moved.push(ScanMove {
+                        old_path: path_string(&old_path),
+                        new_path: path_string(&file),
+                    });
+                }
+                read(file, ext, !from_watched);
+            }
+        }
+
+        for (path, created) in settled.paths {
+            if self.filter.is_marker(&path) {
+                // A marker hides or reveals its whole folder
+                let Some(dir) = path.parent() else { continue };
+                if path.exists() {
+                    event.removed_paths.push(path_string(dir));
+                } else {
+                    for (file, ext) in self.audio_files(dir) {
+                        read(file, ext, true);
+                    }
+                }
+                continue;
+            }
+            if !path.exists() {
+                if !created {
+                    event.removed_paths.push(path_string(&path));
+                }
+                continue;
+            }
+            let files = self.audio_files(&path);
+            // A known file that is now excluded leaves the library
+            if files.is_empty() && !created && path.is_file() {
+                event.removed_paths.push(path_string(&path));
+            }
+            for (file, ext) in files {
+                read(file, ext, created);
+            }
+        }
+
+        let results: Vec<_> = reads
+            .into_par_iter()
+            .map(|(path, (ext, created))| {
+                let sheet_modified = cue::sidecar(&path).map_or(0, |sheet| modified_secs(&sheet));
+                let modified = modified_secs(&path).max(sheet_modified);
+                let result = read_file(&path, ext, modified, self.options, self.artwork);
+                (path, created, result)
+            })
+            .collect();
+        for (path, created, result) in results {
+            match result {
+                Ok(songs) if songs.is_empty() => {
+                    // Too short to be listed
+                    if !created {
+                        event.removed_paths.push(path_string(&path));
+                    }
+                }
+                Ok(songs) if created => event.added.extend(songs),
+                Ok(songs) => {
+                    // Tracks of a CUE sheet replace the songs the file had
+                    if songs.iter().any(|song| song.range.is_some()) {
+                        event.removed_paths.push(path_string(&path));
+                    }
+                    event.modified.extend(songs)
+                }
+                Err(failure) => event.failures.push(failure),
+            }
+        }
+        event.added.sort_by(|a, b| a.path.cmp(&b.path));
+        event.modified.sort_by(|a, b| a.path.cmp(&b.path));
+        event
+    }
+
+    /// The watched folder containing `path`; the innermost if roots nest.
+    fn root_of(&self, path: &Path) -> Option<&Path> {
+        self.roots
+            .iter()
+            .filter(|root| path.starts_with(root))
+            .max_by_key(|root| root.components().count())
+            .map(PathBuf::as_path)
+    }
+
+    /// Whether the scan options leave `path` out of the library.
+    fn excluded(&self, path: &Path) -> bool {
+        let Some(root) = self.root_of(path) else {
+            return true;
+        };
+        let depth = path.strip_prefix(root).map_or(0, |p| p.components().count());
+        let too_deep = self
+            .options
+            .max_depth
+            .is_some_and(|max_depth| depth > max_depth as usize + 1);
+        too_deep || self.filter.excludes(root, path)
+    }
+
+    /// Audio files at or below `path` that a scan would list, with their
+    /// formats.
+    fn audio_files(&self, path: &Path) -> Vec<(PathBuf, String)> {
+        if self.excluded(path) {
+            return Vec::new();
+        }
+        if !path.is_dir() {
+            return audio_format(path, self.options)
+                .map(|ext| vec![(path.to_path_buf(), ext)])
+                .unwrap_or_default();
+        }
+        WalkDir::new(path)
+            .follow_links(self.options.follow_symlinks)
+            .into_iter()
+            .filter_entry(|entry| entry.depth() == 0 || !self.excluded(entry.path()))
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                let ext = audio_format(e.path(), self.options)?;
+                Some((e.into_path(), ext))
+            })
+            .collect()
+    }
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+/// Modification time in seconds since the epoch, or 0 if unknown.
+fn modified_secs(path: &Path) -> i64 {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::scanner::scan_default_options;
+    use crate::test_util::{test_dir, write_silence};
+    use notify::event::{CreateKind, DataChange, RemoveKind};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    fn event(kind: EventKind, paths: &[&str]) -> notify::Event {
+        let mut event = notify::Event::new(kind);
+        event.paths = paths.iter().map(PathBuf::from).collect();
+        event
+    }
+
+    #[test]
+    fn test_debouncer_settles_bursts() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut debouncer = Debouncer::new(Duration::from_millis(500));
+
+        // A file being copied in: created, then written for a while
+        debouncer.record(event(EventKind::Create(CreateKind::File), &["/m/new.mp3"]), at(0));
+        let write = EventKind::Modify(ModifyKind::Data(DataChange::Any));
+        debouncer.record(event(write, &["/m/new.mp3"]), at(300));
+        debouncer.record(event(write, &["/m/old.mp3"]), at(100));
+        debouncer.record(event(EventKind::Remove(RemoveKind::File), &["/m/gone.mp3"]), at(100));
+        // Temporary files renamed into place count as created
+        debouncer.record(event(EventKind::Create(CreateKind::File), &["/m/a.part"]), at(100));
+        let rename = EventKind::Modify(ModifyKind::Name(RenameMode::Both));
+        debouncer.record(event(rename, &["/m/a.part", "/m/a.mp3"]), at(200));
+        let from = EventKind::Modify(ModifyKind::Name(RenameMode::From));
+        let to = EventKind::Modify(ModifyKind::Name(RenameMode::To));
+        debouncer.record(event(from, &["/m/x.mp3"]), at(200));
+        debouncer.record(event(to, &["/m/y.mp3"]), at(200));
+        debouncer.record(event(rename, &["/m/x.mp3", "/m/y.mp3"]), at(200));
+
+        let settled = debouncer.take_settled(at(650));
+        assert_eq!(
+            settled.paths,
+            [(PathBuf::from("/m/gone.mp3"), false), (PathBuf::from("/m/old.mp3"), false)]
+        );
+        assert_eq!(settled.renames, [(PathBuf::from("/m/x.mp3"), PathBuf::from("/m/y.mp3"))]);
+        assert!(!settled.rescan);
+
+        let settled = debouncer.take_settled(at(800));
+        assert_eq!(
+            settled.paths,
+            [(PathBuf::from("/m/a.mp3"), true), (PathBuf::from("/m/new.mp3"), true)]
+        );
+        assert!(settled.renames.is_empty());
+        debouncer.missed();
+        assert!(debouncer.take_settled(at(900)).rescan);
+        assert!(debouncer.take_settled(at(1000)).is_empty());
+    }
+
+    #[test]
+    fn test_apply_settled_changes() {
+        let dir = test_dir("watch");
+        let music = dir.join("music");
+        std::fs::create_dir_all(music.join("album")).unwrap();
+        std::fs::create_dir_all(music.join("renamed")).unwrap();
+        std::fs::create_dir_all(music.join("Ringtones")).unwrap();
+        let names = ["album/new.wav", "album/changed.wav", "renamed/moved.wav", "Ringtones/ring.wav"];
+        for name in names {
+            write_silence(&music.join(name), 4410);
+        }
+        std::fs::write(music.join("album/broken.wav"), b"not audio").unwrap();
+        std::fs::write(music.join("album/cover.jpg"), b"").unwrap();
+
+        let options = scan_default_options();
+        let roots = [music.to_string_lossy().to_string()];
+        let library = WatchedLibrary::new(&roots, &options, None).unwrap();
+        let path = |name: &str| music.join(name);
+        let settled = Settled {
+            paths: vec![
+                (path("album/new.wav"), true),
+                (path("album/changed.wav"), false),
+                (path("album/broken.wav"), true),
+                (path("album/cover.jpg"), true),
+                (path("album/deleted.wav"), false),
+                (path("album/flicker.wav"), true),
+                (path("Ringtones/ring.wav"), true),
+            ],
+            renames: vec![(path("old/moved.wav"), path("renamed/moved.wav"))],
+            rescan: false,
+        };
+        let event = library.apply(settled);
+        let names = |files: &[crate::api::scanner::AudioFileMetadata]| {
+            files.iter().map(|f| f.path.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(names(&event.added), [path_string(&path("album/new.wav"))]);
+        assert_eq!(
+            names(&event.modified),
+            [path_string(&path("album/changed.wav")), path_string(&path("renamed/moved.wav"))]
+        );
+        assert_eq!(event.removed_paths, [path_string(&path("album/deleted.wav"))]);
+        assert_eq!(
+            event.moved,
+            [ScanMove {
+                old_path: path_string(&path("old/moved.wav")),
+                new_path: path_string(&path("renamed/moved.wav")),
+            }]
+        );
+        assert_eq!(event.failures.len(), 1);
+        assert!(!event.rescan_needed);
+
+        // A renamed folder moves every file in it, and a marker file hides
+        // its folder
+        std::fs::rename(music.join("renamed"), music.join("moved")).unwrap();
+        std::fs::write(music.join("album/.nomedia"), b"").unwrap();
+        let event = library.apply(Settled {
+            paths: vec![(path("album/.nomedia"), true)],
+            renames: vec![(path("renamed"), path("moved"))],
+            rescan: true,
+        });
+        assert_eq!(event.moved.len(), 1);
+        assert_eq!(event.moved[0].old_path, path_string(&path("renamed/moved.wav")));
+        assert_eq!(event.removed_paths, [path_string(&path("album"))]);
+        assert!(event.rescan_needed);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_spawn_reports_changes_from_its_thread() {
+        let dir = test_dir("watch-spawn");
+        let roots = vec![dir.to_string_lossy().to_string()];
+        let missing = vec![dir.join("missing").to_string_lossy().to_string()];
+        assert!(spawn(missing, None, scan_default_options(), || false, |_| {}).is_err());
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = {
+            let stop = Arc::clone(&stop);
+            move || stop.load(Ordering::Acquire)
+        };
+        let (tx, rx) = mpsc::channel();
+        spawn(roots, None, scan_default_options(), stopped, move |event| {
+            let _ = tx.send(event);
+        })
+        .unwrap();
+
+        write_silence(&dir.join("new.wav"), 4410);
+        let event = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(event.added.len(), 1);
+        assert!(event.added[0].path.ends_with("new.wav"));
+
+        // The thread ends, dropping the callback and its sender
+        stop.store(true, Ordering::Release);
+        let ended = rx.recv_timeout(Duration::from_secs(5));
+        assert!(matches!(ended, Err(RecvTimeoutError::Disconnected)));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,116 @@
+//! Fixtures shared by the tests of the library and the audio engine.
+
+use std::path::{Path, PathBuf};
+
+/// Sample encoding of a test WAV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WavFormat {
+    Int16,
+    Int24,
+    Float32,
+}
+
+/// An empty directory for one test, named after `name` and this process.
+pub(crate) fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("flick-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Write interleaved `samples` as a WAV file of `channels` channels.
+pub(crate) fn write_wav(
+    path: &Path,
+    rate: u32,
+    channels: u16,
+    format: WavFormat,
+    samples: &[f32],
+) {
+    let (tag, bytes_per_sample) = match format {
+        WavFormat::Int16 => (1u16, 2u16),
+        WavFormat::Int24 => (1, 3),
+        WavFormat::Float32 => (3, 4),
+    };
+    let block_align = channels * bytes_per_sample;
+    let data_len = samples.len() as u32 * bytes_per_sample as u32;
+
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&tag.to_le_bytes());
+    bytes.extend_from_slice(&channels.to_le_bytes());
+    bytes.extend_from_slice(&rate.to_le_bytes());
+    bytes.extend_from_slice(&(rate * block_align as u32).to_le_bytes());
+    bytes.extend_from_slice(&block_align.to_le_bytes());
+    bytes.extend_from_slice(&(bytes_per_sample * 8).to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for &sample in samples {
+        match format {
+            WavFormat::Int16 => {
+                let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16;
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            WavFormat::Int24 => {
+                let value = (sample.clamp(-1.0, 1.0) * 8_388_607.0).round() as i32;
+                bytes.extend_from_slice(&value.to_le_bytes()[..3]);
+            }
+            WavFormat::Float32 => bytes.extend_from_slice(&sample.to_le_bytes()),
+        }
+    }
+    std::fs::write(path, bytes).unwrap();
+}
+
+/// Write `frames` frames of 16-bit stereo silence at 44.1kHz.
+pub(crate) fn write_silence(path: &Path, frames: usize) {
+    write_wav(path, 44100, 2, WavFormat::Int16, &vec![0.0; frames * 2]);
+}
+
+/// Write a FLAC file of 44.1kHz stereo that holds no audio frames, only its
+/// STREAMINFO block and some padding.
+pub(crate) fn write_flac(path: &Path) {
+    let mut bytes = b"fLaC".to_vec();
+    bytes.extend_from_slice(&[0, 0, 0, 34]);
+    bytes.extend_from_slice(&4096u16.to_be_bytes());
+    bytes.extend_from_slice(&4096u16.to_be_bytes());
+    bytes.extend_from_slice(&[0; 6]);
+    // Sample rate, channels - 1, bits per sample - 1 and sample count
+    let format = (44100u64 << 44) | (1 << 41) | (15 << 36);
+    bytes.extend_from_slice(&format.to_be_bytes());
+    bytes.extend_from_slice(&[0; 16]);
+    bytes.extend_from_slice(&[0x81, 0, 0, 64]);
+    bytes.extend_from_slice(&[0; 64]);
+    std::fs::write(path, bytes).unwrap();
+}
+
+/// Write `frames` silent MPEG-1 Layer III frames of 128kbps 44.1kHz stereo.
+pub(crate) fn write_mp3(path: &Path, frames: usize) {
+    let mut frame = vec![0; 417];
+    frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x04]);
+    std::fs::write(path, frame.repeat(frames)).unwrap();
+}
+
+/// Write interleaved stereo float `samples` at `rate`.
+pub(crate) fn write_track(path: &Path, rate: u32, samples: &[f32]) {
+    write_wav(path, rate, 2, WavFormat::Float32, samples);
+}
+
+/// Stereo test signal with distinct, non-repeating left/right values.
+pub(crate) fn signal(frames: usize, offset: f32) -> Vec<f32> {
+    (0..frames)
+        .flat_map(|i| {
+            let t = i as f32 / frames as f32;
+            [offset + t * 0.25, -offset - t * 0.25]
+        })
+        .collect()
+}
+
+/// Assert that two runs of samples match to within `tolerance`.
+pub(crate) fn assert_samples_eq(actual: &[f32], expected: &[f32], tolerance: f32) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+        assert!((a - e).abs() <= tolerance, "sample {}: got {}, expected {}", i, a, e);
+    }
+}